@@ -82,6 +82,18 @@ pub struct GooseConfiguration {
     /// How often to optionally print running metrics
     #[options(no_short, meta = "TIME")]
     pub running_metrics: Option<usize>,
+    /// Sets significant digits of recorded times (1-5, default: 3)
+    #[options(no_short, meta = "DIGITS")]
+    pub significant_digits: usize,
+    /// Sets percentiles of response times to display (ie "50,90,99.5")
+    #[options(no_short, meta = "LIST")]
+    pub percentiles: String,
+    /// Fails load test unless thresholds are met (ie "GET / p95 < 300ms")
+    #[options(no_short, meta = "\"THRESHOLD\"")]
+    pub thresholds: String,
+    /// Aborts load test early if a condition is met (ie "error rate > 5% over 30s")
+    #[options(no_short, meta = "\"CONDITION\"")]
+    pub abort_on: String,
     /// Doesn't reset metrics after all users have started
     #[options(no_short)]
     pub no_reset_metrics: bool,
@@ -100,6 +112,12 @@ pub struct GooseConfiguration {
     /// Doesn't display an error summary
     #[options(no_short)]
     pub no_error_summary: bool,
+    /// Groups matching paths under a name (ie "/node/\d+=/node/{id}")
+    #[options(no_short, meta = "\"REGEX=NAME\"")]
+    pub path_rules: String,
+    /// Groups numeric and UUID path segments as {id} and {uuid}
+    #[options(no_short)]
+    pub normalize_paths: bool,
     /// Create an html-formatted report
     #[options(no_short, meta = "NAME")]
     pub report_file: String,
@@ -127,6 +145,12 @@ pub struct GooseConfiguration {
     /// Sets log format (csv, json, raw, pretty)
     #[options(no_short, meta = "FORMAT")]
     pub scenario_format: Option<GooseLogFormat>,
+    /// Sets timer log file name
+    #[options(no_short, meta = "NAME")]
+    pub timer_log: String,
+    /// Sets log format (csv, json, raw, pretty)
+    #[options(no_short, meta = "FORMAT")]
+    pub timer_format: Option<GooseLogFormat>,
     /// Sets error log file name
     #[options(short = "E", meta = "NAME")]
     pub error_log: String,
@@ -195,6 +219,30 @@ pub struct GooseConfiguration {
     /// Follows base_url redirect with subsequent requests
     #[options(no_short)]
     pub sticky_follow: bool,
+    /// Sends requests through an HTTP or SOCKS proxy
+    #[options(no_short, meta = "URL")]
+    pub proxy: String,
+    /// Overrides DNS (ie example.com:443:10.0.0.1,...)
+    #[options(no_short, meta = "\"HOST:PORT:ADDR\"")]
+    pub resolve: String,
+    /// Binds users to local IPs in turn (ie 10.0.0.2,10.0.0.3)
+    #[options(no_short, meta = "\"ADDR\"")]
+    pub local_address: String,
+    /// Sets TLS client certificate (PEM)
+    #[options(no_short, meta = "FILE")]
+    pub client_cert: String,
+    /// Sets TLS client private key (PKCS#8 PEM)
+    #[options(no_short, meta = "FILE")]
+    pub client_key: String,
+    /// Trusts additional CA certificates (PEM)
+    #[options(no_short, meta = "FILE")]
+    pub ca_cert: String,
+    /// Sets minimum TLS version (1.0, 1.1, 1.2, 1.3)
+    #[options(no_short, meta = "VERSION")]
+    pub min_tls_version: String,
+    /// Doesn't verify TLS certificates
+    #[options(no_short)]
+    pub insecure: bool,
 }
 
 /// Optionally defines a subset of active Scenarios to run during a load test.
@@ -278,6 +326,18 @@ pub(crate) struct GooseDefaults {
     pub no_print_metrics: Option<bool>,
     /// An optional default for not displaying an error summary.
     pub no_error_summary: Option<bool>,
+    /// An optional default number of significant digits recorded times are accurate to.
+    pub significant_digits: Option<usize>,
+    /// An optional default list of percentiles of response times to display.
+    pub percentiles: Option<String>,
+    /// Optional default thresholds the load test must meet.
+    pub thresholds: Option<String>,
+    /// Optional default conditions that abort the load test early.
+    pub abort_on: Option<String>,
+    /// Optional default rules for grouping request paths under a name.
+    pub path_rules: Option<String>,
+    /// An optional default for grouping numeric and UUID path segments.
+    pub normalize_paths: Option<bool>,
     /// An optional default for the html-formatted report file name.
     pub report_file: Option<String>,
     /// An optional default for the flag that disables granular data in HTML report graphs.
@@ -296,6 +356,10 @@ pub(crate) struct GooseDefaults {
     pub scenario_log: Option<String>,
     /// An optional default for the scenario log file format.
     pub scenario_format: Option<GooseLogFormat>,
+    /// An optional default for the timer log file name.
+    pub timer_log: Option<String>,
+    /// An optional default for the timer log file format.
+    pub timer_format: Option<GooseLogFormat>,
     /// An optional default for the error log file name.
     pub error_log: Option<String>,
     /// An optional default for the error log format.
@@ -324,6 +388,22 @@ pub(crate) struct GooseDefaults {
     pub throttle_requests: Option<usize>,
     /// An optional default to follows base_url redirect with subsequent request.
     pub sticky_follow: Option<bool>,
+    /// An optional default proxy to send requests through.
+    pub proxy: Option<String>,
+    /// Optional default DNS overrides.
+    pub resolve: Option<String>,
+    /// Optional default local addresses to bind users to.
+    pub local_address: Option<String>,
+    /// An optional default TLS client certificate file.
+    pub client_cert: Option<String>,
+    /// An optional default TLS client private key file.
+    pub client_key: Option<String>,
+    /// An optional default file of additional CA certificates to trust.
+    pub ca_cert: Option<String>,
+    /// An optional default minimum TLS version.
+    pub min_tls_version: Option<String>,
+    /// An optional default to not verify TLS certificates.
+    pub insecure: Option<bool>,
     /// An optional default for host telnet Controller listens on.
     pub telnet_host: Option<String>,
     /// An optional default for port telnet Controller listens on.
@@ -378,6 +458,22 @@ pub enum GooseDefault {
     NoPrintMetrics,
     /// An optional default for not displaying an error summary.
     NoErrorSummary,
+    /// An optional default number of significant digits recorded times are accurate to.
+    SignificantDigits,
+    /// An optional default list of percentiles of response times to display, as a comma
+    /// separated list (ie `50,90,99.5`).
+    Percentiles,
+    /// Optional default thresholds the load test must meet, as a semicolon separated list
+    /// (ie `GET / p95 < 300ms;error rate < 1%`).
+    Thresholds,
+    /// Optional default conditions that abort the load test early, as a semicolon separated
+    /// list (ie `error rate > 5% over 30s;GET / p95 > 2000ms for 1m`).
+    AbortOn,
+    /// Optional default rules for grouping request paths under a name, as a semicolon
+    /// separated list of `regex=name`.
+    PathRules,
+    /// An optional default for grouping numeric and UUID path segments.
+    NormalizePaths,
     /// An optional default for the report file name.
     ReportFile,
     /// An optional default for the flag that disables granular data in HTML report graphs.
@@ -396,6 +492,10 @@ pub enum GooseDefault {
     ScenarioLog,
     /// An optional default for the scenario log file format.
     ScenarioFormat,
+    /// An optional default for the timer log file name.
+    TimerLog,
+    /// An optional default for the timer log file format.
+    TimerFormat,
     /// An optional default for the error log file name.
     ErrorLog,
     /// An optional default for the error log format.
@@ -424,6 +524,22 @@ pub enum GooseDefault {
     ThrottleRequests,
     /// An optional default to follows base_url redirect with subsequent request.
     StickyFollow,
+    /// An optional default proxy to send requests through.
+    Proxy,
+    /// Optional default DNS overrides, as a comma separated list of `host:port:addr`.
+    Resolve,
+    /// Optional default local addresses to bind users to, as a comma separated list.
+    LocalAddress,
+    /// An optional default TLS client certificate file, in PEM format.
+    ClientCert,
+    /// An optional default TLS client private key file, in PKCS#8 PEM format.
+    ClientKey,
+    /// An optional default file of additional CA certificates to trust, in PEM format.
+    CaCert,
+    /// An optional default minimum TLS version, one of `1.0`, `1.1`, `1.2` or `1.3`.
+    MinTlsVersion,
+    /// An optional default to not verify TLS certificates.
+    Insecure,
     /// An optional default for host telnet Controller listens on.
     TelnetHost,
     /// An optional default for port telnet Controller listens on.
@@ -465,18 +581,30 @@ pub enum GooseDefault {
 ///
 /// The following run-time options can be configured with a custom default using a
 /// borrowed string slice ([`&str`]):
+///  - [`GooseDefault::AbortOn`]
+///  - [`GooseDefault::CaCert`]
+///  - [`GooseDefault::ClientCert`]
+///  - [`GooseDefault::ClientKey`]
 ///  - [`GooseDefault::DebugLog`]
 ///  - [`GooseDefault::ErrorLog`]
 ///  - [`GooseDefault::GooseLog`]
 ///  - [`GooseDefault::HatchRate`]
 ///  - [`GooseDefault::Host`]
+///  - [`GooseDefault::LocalAddress`]
+///  - [`GooseDefault::MinTlsVersion`]
+///  - [`GooseDefault::PathRules`]
+///  - [`GooseDefault::Percentiles`]
+///  - [`GooseDefault::Proxy`]
 ///  - [`GooseDefault::ReportFile`]
 ///  - [`GooseDefault::RequestLog`]
+///  - [`GooseDefault::Resolve`]
 ///  - [`GooseDefault::ScenarioLog`]
 ///  - [`GooseDefault::Scenarios`]
 ///  - [`GooseDefault::TelnetHost`]
 ///  - [`GooseDefault::TestPlan`]
+///  - [`GooseDefault::Thresholds`]
 ///  - [`GooseDefault::Timeout`]
+///  - [`GooseDefault::TimerLog`]
 ///  - [`GooseDefault::TransactionLog`]
 ///  - [`GooseDefault::WebSocketHost`]
 ///
@@ -491,6 +619,7 @@ pub enum GooseDefault {
 ///  - [`GooseDefault::Quiet`]
 ///  - [`GooseDefault::Verbose`]
 ///  - [`GooseDefault::ThrottleRequests`]
+///  - [`GooseDefault::SignificantDigits`]
 ///  - [`GooseDefault::TelnetPort`]
 ///  - [`GooseDefault::WebSocketPort`]
 ///
@@ -503,6 +632,7 @@ pub enum GooseDefault {
 ///  - [`GooseDefault::NoScenarioMetrics`]
 ///  - [`GooseDefault::RequestBody`]
 ///  - [`GooseDefault::NoErrorSummary`]
+///  - [`GooseDefault::NormalizePaths`]
 ///  - [`GooseDefault::NoDebugBody`]
 ///  - [`GooseDefault::NoTelnet`]
 ///  - [`GooseDefault::NoWebSocket`]
@@ -511,12 +641,14 @@ pub enum GooseDefault {
 ///  - [`GooseDefault::NoStatusCodes`]
 ///  - [`GooseDefault::StickyFollow`]
 ///  - [`GooseDefault::NoGranularData`]
+///  - [`GooseDefault::Insecure`]
 ///
 /// The following run-time flags can be configured with a custom default using a
 /// [`GooseLogFormat`].
 ///  - [`GooseDefault::RequestFormat`]
 ///  - [`GooseDefault::TransactionFormat`]
 ///  - [`GooseDefault::ScenarioFormat`]
+///  - [`GooseDefault::TimerFormat`]
 ///  - [`GooseDefault::ErrorFormat`]
 ///  - [`GooseDefault::DebugFormat`]
 ///
@@ -551,6 +683,9 @@ impl GooseDefaultType<&str> for GooseAttack {
     fn set_default(mut self, key: GooseDefault, value: &str) -> Result<Box<Self>, GooseError> {
         match key {
             // Set valid defaults.
+            GooseDefault::CaCert => self.defaults.ca_cert = Some(value.to_string()),
+            GooseDefault::ClientCert => self.defaults.client_cert = Some(value.to_string()),
+            GooseDefault::ClientKey => self.defaults.client_key = Some(value.to_string()),
             GooseDefault::DebugLog => self.defaults.debug_log = Some(value.to_string()),
             GooseDefault::ErrorLog => self.defaults.error_log = Some(value.to_string()),
             GooseDefault::GooseLog => self.defaults.goose_log = Some(value.to_string()),
@@ -562,8 +697,16 @@ impl GooseDefaultType<&str> for GooseAttack {
                     Some(value.to_string())
                 }
             }
+            GooseDefault::LocalAddress => self.defaults.local_address = Some(value.to_string()),
+            GooseDefault::MinTlsVersion => self.defaults.min_tls_version = Some(value.to_string()),
+            GooseDefault::PathRules => self.defaults.path_rules = Some(value.to_string()),
+            GooseDefault::Percentiles => self.defaults.percentiles = Some(value.to_string()),
+            GooseDefault::Thresholds => self.defaults.thresholds = Some(value.to_string()),
+            GooseDefault::AbortOn => self.defaults.abort_on = Some(value.to_string()),
+            GooseDefault::Proxy => self.defaults.proxy = Some(value.to_string()),
             GooseDefault::ReportFile => self.defaults.report_file = Some(value.to_string()),
             GooseDefault::RequestLog => self.defaults.request_log = Some(value.to_string()),
+            GooseDefault::Resolve => self.defaults.resolve = Some(value.to_string()),
             GooseDefault::ScenarioLog => self.defaults.scenario_log = Some(value.to_string()),
             GooseDefault::Scenarios => {
                 self.defaults.scenarios = Some(value.parse::<Scenarios>().unwrap())
@@ -573,6 +716,7 @@ impl GooseDefaultType<&str> for GooseAttack {
                 self.defaults.test_plan = Some(value.parse::<TestPlan>().unwrap())
             }
             GooseDefault::Timeout => self.defaults.timeout = Some(value.to_string()),
+            GooseDefault::TimerLog => self.defaults.timer_log = Some(value.to_string()),
             GooseDefault::TransactionLog => self.defaults.transaction_log = Some(value.to_string()),
             GooseDefault::WebSocketHost => self.defaults.websocket_host = Some(value.to_string()),
             // Otherwise display a helpful and explicit error.
@@ -584,6 +728,7 @@ impl GooseDefaultType<&str> for GooseAttack {
             | GooseDefault::Quiet
             | GooseDefault::Verbose
             | GooseDefault::ThrottleRequests
+            | GooseDefault::SignificantDigits
             | GooseDefault::TelnetPort
             | GooseDefault::WebSocketPort => {
                 return Err(GooseError::InvalidOption {
@@ -603,6 +748,7 @@ impl GooseDefaultType<&str> for GooseAttack {
             | GooseDefault::RequestBody
             | GooseDefault::NoPrintMetrics
             | GooseDefault::NoErrorSummary
+            | GooseDefault::NormalizePaths
             | GooseDefault::NoDebugBody
             | GooseDefault::NoTelnet
             | GooseDefault::NoWebSocket
@@ -610,7 +756,8 @@ impl GooseDefaultType<&str> for GooseAttack {
             | GooseDefault::NoGzip
             | GooseDefault::NoStatusCodes
             | GooseDefault::StickyFollow
-            | GooseDefault::NoGranularData => {
+            | GooseDefault::NoGranularData
+            | GooseDefault::Insecure => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: value.to_string(),
@@ -624,6 +771,7 @@ impl GooseDefaultType<&str> for GooseAttack {
             | GooseDefault::ErrorFormat
             | GooseDefault::TransactionFormat
             | GooseDefault::ScenarioFormat
+            | GooseDefault::TimerFormat
             | GooseDefault::RequestFormat => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
@@ -661,6 +809,7 @@ impl GooseDefaultType<usize> for GooseAttack {
             GooseDefault::Quiet => self.defaults.quiet = Some(value as u8),
             GooseDefault::Verbose => self.defaults.verbose = Some(value as u8),
             GooseDefault::ThrottleRequests => self.defaults.throttle_requests = Some(value),
+            GooseDefault::SignificantDigits => self.defaults.significant_digits = Some(value),
             GooseDefault::TelnetPort => self.defaults.telnet_port = Some(value as u16),
             GooseDefault::WebSocketPort => self.defaults.websocket_port = Some(value as u16),
             // Otherwise display a helpful and explicit error.
@@ -676,8 +825,20 @@ impl GooseDefaultType<usize> for GooseAttack {
             | GooseDefault::TelnetHost
             | GooseDefault::TestPlan
             | GooseDefault::Timeout
+            | GooseDefault::TimerLog
             | GooseDefault::TransactionLog
-            | GooseDefault::WebSocketHost => {
+            | GooseDefault::WebSocketHost
+            | GooseDefault::Proxy
+            | GooseDefault::Resolve
+            | GooseDefault::LocalAddress
+            | GooseDefault::ClientCert
+            | GooseDefault::ClientKey
+            | GooseDefault::CaCert
+            | GooseDefault::MinTlsVersion
+            | GooseDefault::PathRules
+            | GooseDefault::Percentiles
+            | GooseDefault::Thresholds
+            | GooseDefault::AbortOn => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{}", value),
@@ -694,6 +855,7 @@ impl GooseDefaultType<usize> for GooseAttack {
             | GooseDefault::RequestBody
             | GooseDefault::NoPrintMetrics
             | GooseDefault::NoErrorSummary
+            | GooseDefault::NormalizePaths
             | GooseDefault::NoDebugBody
             | GooseDefault::NoTelnet
             | GooseDefault::NoWebSocket
@@ -701,7 +863,8 @@ impl GooseDefaultType<usize> for GooseAttack {
             | GooseDefault::NoGzip
             | GooseDefault::NoStatusCodes
             | GooseDefault::StickyFollow
-            | GooseDefault::NoGranularData => {
+            | GooseDefault::NoGranularData
+            | GooseDefault::Insecure => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{}", value),
@@ -715,6 +878,7 @@ impl GooseDefaultType<usize> for GooseAttack {
             | GooseDefault::DebugFormat
             | GooseDefault::ErrorFormat
             | GooseDefault::ScenarioFormat
+            | GooseDefault::TimerFormat
             | GooseDefault::TransactionFormat => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
@@ -752,6 +916,7 @@ impl GooseDefaultType<bool> for GooseAttack {
             GooseDefault::RequestBody => self.defaults.request_body = Some(value),
             GooseDefault::NoPrintMetrics => self.defaults.no_print_metrics = Some(value),
             GooseDefault::NoErrorSummary => self.defaults.no_error_summary = Some(value),
+            GooseDefault::NormalizePaths => self.defaults.normalize_paths = Some(value),
             GooseDefault::NoDebugBody => self.defaults.no_debug_body = Some(value),
             GooseDefault::NoTelnet => self.defaults.no_telnet = Some(value),
             GooseDefault::NoWebSocket => self.defaults.no_websocket = Some(value),
@@ -760,6 +925,7 @@ impl GooseDefaultType<bool> for GooseAttack {
             GooseDefault::NoStatusCodes => self.defaults.no_status_codes = Some(value),
             GooseDefault::StickyFollow => self.defaults.sticky_follow = Some(value),
             GooseDefault::NoGranularData => self.defaults.no_granular_report = Some(value),
+            GooseDefault::Insecure => self.defaults.insecure = Some(value),
             // Otherwise display a helpful and explicit error.
             GooseDefault::DebugLog
             | GooseDefault::ErrorLog
@@ -773,8 +939,20 @@ impl GooseDefaultType<bool> for GooseAttack {
             | GooseDefault::TelnetHost
             | GooseDefault::TestPlan
             | GooseDefault::Timeout
+            | GooseDefault::TimerLog
             | GooseDefault::TransactionLog
-            | GooseDefault::WebSocketHost => {
+            | GooseDefault::WebSocketHost
+            | GooseDefault::Proxy
+            | GooseDefault::Resolve
+            | GooseDefault::LocalAddress
+            | GooseDefault::ClientCert
+            | GooseDefault::ClientKey
+            | GooseDefault::CaCert
+            | GooseDefault::MinTlsVersion
+            | GooseDefault::PathRules
+            | GooseDefault::Percentiles
+            | GooseDefault::Thresholds
+            | GooseDefault::AbortOn => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{}", value),
@@ -793,6 +971,7 @@ impl GooseDefaultType<bool> for GooseAttack {
             | GooseDefault::Quiet
             | GooseDefault::Verbose
             | GooseDefault::ThrottleRequests
+            | GooseDefault::SignificantDigits
             | GooseDefault::TelnetPort
             | GooseDefault::WebSocketPort => {
                 return Err(GooseError::InvalidOption {
@@ -808,6 +987,7 @@ impl GooseDefaultType<bool> for GooseAttack {
             | GooseDefault::DebugFormat
             | GooseDefault::ErrorFormat
             | GooseDefault::ScenarioFormat
+            | GooseDefault::TimerFormat
             | GooseDefault::TransactionFormat => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
@@ -849,6 +1029,7 @@ impl GooseDefaultType<GooseCoordinatedOmissionMitigation> for GooseAttack {
             | GooseDefault::RequestBody
             | GooseDefault::NoPrintMetrics
             | GooseDefault::NoErrorSummary
+            | GooseDefault::NormalizePaths
             | GooseDefault::NoDebugBody
             | GooseDefault::NoTelnet
             | GooseDefault::NoWebSocket
@@ -856,7 +1037,8 @@ impl GooseDefaultType<GooseCoordinatedOmissionMitigation> for GooseAttack {
             | GooseDefault::NoGzip
             | GooseDefault::NoStatusCodes
             | GooseDefault::StickyFollow
-            | GooseDefault::NoGranularData => {
+            | GooseDefault::NoGranularData
+            | GooseDefault::Insecure => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{:?}", value),
@@ -879,8 +1061,20 @@ impl GooseDefaultType<GooseCoordinatedOmissionMitigation> for GooseAttack {
             | GooseDefault::TelnetHost
             | GooseDefault::TestPlan
             | GooseDefault::Timeout
+            | GooseDefault::TimerLog
             | GooseDefault::TransactionLog
-            | GooseDefault::WebSocketHost => {
+            | GooseDefault::WebSocketHost
+            | GooseDefault::Proxy
+            | GooseDefault::Resolve
+            | GooseDefault::LocalAddress
+            | GooseDefault::ClientCert
+            | GooseDefault::ClientKey
+            | GooseDefault::CaCert
+            | GooseDefault::MinTlsVersion
+            | GooseDefault::PathRules
+            | GooseDefault::Percentiles
+            | GooseDefault::Thresholds
+            | GooseDefault::AbortOn => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{:?}", value),
@@ -899,6 +1093,7 @@ impl GooseDefaultType<GooseCoordinatedOmissionMitigation> for GooseAttack {
             | GooseDefault::Quiet
             | GooseDefault::Verbose
             | GooseDefault::ThrottleRequests
+            | GooseDefault::SignificantDigits
             | GooseDefault::TelnetPort
             | GooseDefault::WebSocketPort => {
                 return Err(GooseError::InvalidOption {
@@ -914,6 +1109,7 @@ impl GooseDefaultType<GooseCoordinatedOmissionMitigation> for GooseAttack {
             | GooseDefault::DebugFormat
             | GooseDefault::ErrorFormat
             | GooseDefault::ScenarioFormat
+            | GooseDefault::TimerFormat
             | GooseDefault::TransactionFormat => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
@@ -941,6 +1137,7 @@ impl GooseDefaultType<GooseLogFormat> for GooseAttack {
             GooseDefault::ErrorFormat => self.defaults.error_format = Some(value),
             GooseDefault::TransactionFormat => self.defaults.transaction_format = Some(value),
             GooseDefault::ScenarioFormat => self.defaults.scenario_format = Some(value),
+            GooseDefault::TimerFormat => self.defaults.timer_format = Some(value),
             // Otherwise display a helpful and explicit error.
             GooseDefault::NoResetMetrics
             | GooseDefault::NoMetrics
@@ -949,6 +1146,7 @@ impl GooseDefaultType<GooseLogFormat> for GooseAttack {
             | GooseDefault::RequestBody
             | GooseDefault::NoPrintMetrics
             | GooseDefault::NoErrorSummary
+            | GooseDefault::NormalizePaths
             | GooseDefault::NoDebugBody
             | GooseDefault::NoTelnet
             | GooseDefault::NoWebSocket
@@ -956,7 +1154,8 @@ impl GooseDefaultType<GooseLogFormat> for GooseAttack {
             | GooseDefault::NoGzip
             | GooseDefault::NoStatusCodes
             | GooseDefault::StickyFollow
-            | GooseDefault::NoGranularData => {
+            | GooseDefault::NoGranularData
+            | GooseDefault::Insecure => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{:?}", value),
@@ -979,8 +1178,20 @@ impl GooseDefaultType<GooseLogFormat> for GooseAttack {
             | GooseDefault::TelnetHost
             | GooseDefault::TestPlan
             | GooseDefault::Timeout
+            | GooseDefault::TimerLog
             | GooseDefault::TransactionLog
-            | GooseDefault::WebSocketHost => {
+            | GooseDefault::WebSocketHost
+            | GooseDefault::Proxy
+            | GooseDefault::Resolve
+            | GooseDefault::LocalAddress
+            | GooseDefault::ClientCert
+            | GooseDefault::ClientKey
+            | GooseDefault::CaCert
+            | GooseDefault::MinTlsVersion
+            | GooseDefault::PathRules
+            | GooseDefault::Percentiles
+            | GooseDefault::Thresholds
+            | GooseDefault::AbortOn => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{:?}", value),
@@ -999,6 +1210,7 @@ impl GooseDefaultType<GooseLogFormat> for GooseAttack {
             | GooseDefault::Quiet
             | GooseDefault::Verbose
             | GooseDefault::ThrottleRequests
+            | GooseDefault::SignificantDigits
             | GooseDefault::TelnetPort
             | GooseDefault::WebSocketPort => {
                 return Err(GooseError::InvalidOption {
@@ -1432,6 +1644,78 @@ impl GooseConfiguration {
             },
         ]);
 
+        // Configure `significant_digits`.
+        self.significant_digits = self
+            .get_value(vec![
+                // Use --significant-digits if set.
+                GooseValue {
+                    value: Some(self.significant_digits),
+                    filter: self.significant_digits == 0,
+                    message: "significant_digits",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.significant_digits,
+                    filter: defaults.significant_digits.is_none(),
+                    message: "significant_digits",
+                },
+            ])
+            .unwrap_or(crate::metrics::DEFAULT_SIGNIFICANT_DIGITS);
+
+        // Configure `percentiles`.
+        self.percentiles = self
+            .get_value(vec![
+                // Use --percentiles if set.
+                GooseValue {
+                    value: Some(self.percentiles.to_string()),
+                    filter: self.percentiles.is_empty(),
+                    message: "percentiles",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.percentiles.clone(),
+                    filter: defaults.percentiles.is_none(),
+                    message: "percentiles",
+                },
+            ])
+            .unwrap_or_else(|| crate::metrics::DEFAULT_PERCENTILES.to_string());
+
+        // Configure `thresholds`.
+        self.thresholds = self
+            .get_value(vec![
+                // Use --thresholds if set.
+                GooseValue {
+                    value: Some(self.thresholds.to_string()),
+                    filter: self.thresholds.is_empty(),
+                    message: "thresholds",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.thresholds.clone(),
+                    filter: defaults.thresholds.is_none(),
+                    message: "thresholds",
+                },
+            ])
+            .unwrap_or_default();
+
+        // Configure `abort_on`.
+        self.abort_on = self
+            .get_value(vec![
+                // Use --abort-on if set.
+                GooseValue {
+                    value: Some(self.abort_on.to_string()),
+                    filter: self.abort_on.is_empty(),
+                    message: "abort_on",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.abort_on.clone(),
+                    filter: defaults.abort_on.is_none(),
+                    message: "abort_on",
+                },
+            ])
+            .unwrap_or_default();
+
         // Configure `no_reset_metrics`.
         self.no_reset_metrics = self
             .get_value(vec![
@@ -1540,6 +1824,42 @@ impl GooseConfiguration {
             ])
             .unwrap_or(false);
 
+        // Configure `path_rules`.
+        self.path_rules = self
+            .get_value(vec![
+                // Use --path-rules if set.
+                GooseValue {
+                    value: Some(self.path_rules.to_string()),
+                    filter: self.path_rules.is_empty(),
+                    message: "path_rules",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.path_rules.clone(),
+                    filter: defaults.path_rules.is_none(),
+                    message: "path_rules",
+                },
+            ])
+            .unwrap_or_default();
+
+        // Configure `normalize_paths`.
+        self.normalize_paths = self
+            .get_value(vec![
+                // Use --normalize-paths if set.
+                GooseValue {
+                    value: Some(self.normalize_paths),
+                    filter: !self.normalize_paths,
+                    message: "normalize_paths",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.normalize_paths,
+                    filter: defaults.normalize_paths.is_none(),
+                    message: "normalize_paths",
+                },
+            ])
+            .unwrap_or(false);
+
         // Configure `report_file`.
         self.report_file = match self.get_value(vec![
             // Use --report-file if set.
@@ -1559,6 +1879,132 @@ impl GooseConfiguration {
             None => "".to_string(),
         };
 
+        // Configure `proxy`.
+        self.proxy = self
+            .get_value(vec![
+                // Use --proxy if set.
+                GooseValue {
+                    value: Some(self.proxy.to_string()),
+                    filter: self.proxy.is_empty(),
+                    message: "proxy",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.proxy.clone(),
+                    filter: defaults.proxy.is_none(),
+                    message: "proxy",
+                },
+            ])
+            .unwrap_or_default();
+
+        // Configure `resolve`.
+        self.resolve = self
+            .get_value(vec![
+                // Use --resolve if set.
+                GooseValue {
+                    value: Some(self.resolve.to_string()),
+                    filter: self.resolve.is_empty(),
+                    message: "resolve",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.resolve.clone(),
+                    filter: defaults.resolve.is_none(),
+                    message: "resolve",
+                },
+            ])
+            .unwrap_or_default();
+
+        // Configure `local_address`.
+        self.local_address = self
+            .get_value(vec![
+                // Use --local-address if set.
+                GooseValue {
+                    value: Some(self.local_address.to_string()),
+                    filter: self.local_address.is_empty(),
+                    message: "local_address",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.local_address.clone(),
+                    filter: defaults.local_address.is_none(),
+                    message: "local_address",
+                },
+            ])
+            .unwrap_or_default();
+
+        // Configure `client_cert`.
+        self.client_cert = self
+            .get_value(vec![
+                // Use --client-cert if set.
+                GooseValue {
+                    value: Some(self.client_cert.to_string()),
+                    filter: self.client_cert.is_empty(),
+                    message: "client_cert",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.client_cert.clone(),
+                    filter: defaults.client_cert.is_none(),
+                    message: "client_cert",
+                },
+            ])
+            .unwrap_or_default();
+
+        // Configure `client_key`.
+        self.client_key = self
+            .get_value(vec![
+                // Use --client-key if set.
+                GooseValue {
+                    value: Some(self.client_key.to_string()),
+                    filter: self.client_key.is_empty(),
+                    message: "client_key",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.client_key.clone(),
+                    filter: defaults.client_key.is_none(),
+                    message: "client_key",
+                },
+            ])
+            .unwrap_or_default();
+
+        // Configure `ca_cert`.
+        self.ca_cert = self
+            .get_value(vec![
+                // Use --ca-cert if set.
+                GooseValue {
+                    value: Some(self.ca_cert.to_string()),
+                    filter: self.ca_cert.is_empty(),
+                    message: "ca_cert",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.ca_cert.clone(),
+                    filter: defaults.ca_cert.is_none(),
+                    message: "ca_cert",
+                },
+            ])
+            .unwrap_or_default();
+
+        // Configure `min_tls_version`.
+        self.min_tls_version = self
+            .get_value(vec![
+                // Use --min-tls-version if set.
+                GooseValue {
+                    value: Some(self.min_tls_version.to_string()),
+                    filter: self.min_tls_version.is_empty(),
+                    message: "min_tls_version",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.min_tls_version.clone(),
+                    filter: defaults.min_tls_version.is_none(),
+                    message: "min_tls_version",
+                },
+            ])
+            .unwrap_or_default();
+
         // Configure `no_granular_report`.
         self.no_debug_body = self
             .get_value(vec![
@@ -1777,6 +2223,24 @@ impl GooseConfiguration {
                 },
             ])
             .unwrap_or(false);
+
+        // Configure `insecure`.
+        self.insecure = self
+            .get_value(vec![
+                // Use --insecure if set.
+                GooseValue {
+                    value: Some(self.insecure),
+                    filter: !self.insecure,
+                    message: "insecure",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.insecure,
+                    filter: defaults.insecure.is_none(),
+                    message: "insecure",
+                },
+            ])
+            .unwrap_or(false);
     }
 
     /// Validate configured [`GooseConfiguration`] values.
@@ -1816,6 +2280,20 @@ impl GooseConfiguration {
             }
         }
 
+        // If set, the proxy, DNS overrides and local addresses must be valid.
+        if !self.proxy.is_empty() {
+            crate::goose::parse_proxy(&self.proxy)?;
+        }
+        crate::goose::parse_resolve(&self.resolve)?;
+        crate::goose::parse_path_rules(&self.path_rules)?;
+        crate::metrics::parse_percentiles(&self.percentiles)?;
+        crate::metrics::parse_thresholds(&self.thresholds)?;
+        crate::metrics::parse_abort_conditions(&self.abort_on)?;
+        crate::goose::parse_local_address(&self.local_address)?;
+
+        // If set, the TLS certificates, keys and minimum version must be valid.
+        crate::goose::validate_tls(self)?;
+
         // Validate `users`.
         if let Some(users) = self.users.as_ref() {
             if users == &0 {
@@ -1960,6 +2438,15 @@ impl GooseConfiguration {
                         "`configuration.scenario_log` can not be set with `configuration.no_metrics`."
                             .to_string(),
                 });
+            // Timer log can't be written if metrics are disabled.
+            } else if !self.timer_log.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.timer_log`".to_string(),
+                    value: self.timer_log.to_string(),
+                    detail:
+                        "`configuration.timer_log` can not be set with `configuration.no_metrics`."
+                            .to_string(),
+                });
             // Error log can't be written if metrics are disabled.
             } else if !self.error_log.is_empty() {
                 return Err(GooseError::InvalidOption {
@@ -1978,6 +2465,24 @@ impl GooseConfiguration {
                         "`configuration.report_file` can not be set with `configuration.no_metrics`."
                             .to_string(),
                 });
+            // Thresholds can't be checked if metrics are disabled.
+            } else if !self.thresholds.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.thresholds`".to_string(),
+                    value: self.thresholds.to_string(),
+                    detail:
+                        "`configuration.thresholds` can not be set with `configuration.no_metrics`."
+                            .to_string(),
+                });
+            // Abort conditions can't be checked if metrics are disabled.
+            } else if !self.abort_on.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.abort_on`".to_string(),
+                    value: self.abort_on.to_string(),
+                    detail:
+                        "`configuration.abort_on` can not be set with `configuration.no_metrics`."
+                            .to_string(),
+                });
             // Coordinated Omission Mitigation can't be enabled if metrics are disabled.
             } else if self.co_mitigation.as_ref().unwrap()
                 != &GooseCoordinatedOmissionMitigation::Disabled
@@ -2047,6 +2552,16 @@ impl GooseConfiguration {
             }
         }
 
+        // Be sure significant_digits is in allowed range.
+        if !(1..=5).contains(&self.significant_digits) {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.significant_digits`".to_string(),
+                value: self.significant_digits.to_string(),
+                detail: "`configuration.significant_digits` must be set to between 1 and 5 digits."
+                    .to_string(),
+            });
+        }
+
         Ok(())
     }
 
@@ -2127,9 +2642,21 @@ mod test {
         let request_log = "custom-goose-request.log".to_string();
         let transaction_log = "custom-goose-transaction.log".to_string();
         let scenario_log = "custom-goose-scenario.log".to_string();
+        let timer_log = "custom-goose-timer.log".to_string();
         let debug_log = "custom-goose-debug.log".to_string();
         let error_log = "custom-goose-error.log".to_string();
         let throttle_requests: usize = 25;
+        let proxy = "http://proxy.example.com:3128".to_string();
+        let resolve = "example.com:443:10.0.0.1".to_string();
+        let local_address = "10.0.0.2,10.0.0.3".to_string();
+        let client_cert = "client.pem".to_string();
+        let client_key = "client-key.pem".to_string();
+        let ca_cert = "ca.pem".to_string();
+        let min_tls_version = "1.2".to_string();
+        let path_rules = r"/node/\d+=/node/{id}".to_string();
+        let percentiles = "90,99.5,99.99".to_string();
+        let thresholds = "GET / p95 < 300ms;error rate < 1%".to_string();
+        let abort_on = "error rate > 5% over 30s".to_string();
 
         let goose_attack = GooseAttack::initialize()
             .unwrap()
@@ -2165,6 +2692,16 @@ mod test {
             .unwrap()
             .set_default(GooseDefault::NoErrorSummary, true)
             .unwrap()
+            .set_default(GooseDefault::NormalizePaths, true)
+            .unwrap()
+            .set_default(GooseDefault::PathRules, path_rules.as_str())
+            .unwrap()
+            .set_default(GooseDefault::Percentiles, percentiles.as_str())
+            .unwrap()
+            .set_default(GooseDefault::Thresholds, thresholds.as_str())
+            .unwrap()
+            .set_default(GooseDefault::AbortOn, abort_on.as_str())
+            .unwrap()
             .set_default(GooseDefault::NoTelnet, true)
             .unwrap()
             .set_default(GooseDefault::NoWebSocket, true)
@@ -2189,6 +2726,10 @@ mod test {
             .unwrap()
             .set_default(GooseDefault::ScenarioFormat, GooseLogFormat::Raw)
             .unwrap()
+            .set_default(GooseDefault::TimerLog, timer_log.as_str())
+            .unwrap()
+            .set_default(GooseDefault::TimerFormat, GooseLogFormat::Csv)
+            .unwrap()
             .set_default(GooseDefault::ErrorLog, error_log.as_str())
             .unwrap()
             .set_default(GooseDefault::ErrorFormat, GooseLogFormat::Csv)
@@ -2208,7 +2749,25 @@ mod test {
             .unwrap()
             .set_default(GooseDefault::ThrottleRequests, throttle_requests)
             .unwrap()
+            .set_default(GooseDefault::SignificantDigits, 4)
+            .unwrap()
             .set_default(GooseDefault::StickyFollow, true)
+            .unwrap()
+            .set_default(GooseDefault::Proxy, proxy.as_str())
+            .unwrap()
+            .set_default(GooseDefault::Resolve, resolve.as_str())
+            .unwrap()
+            .set_default(GooseDefault::LocalAddress, local_address.as_str())
+            .unwrap()
+            .set_default(GooseDefault::ClientCert, client_cert.as_str())
+            .unwrap()
+            .set_default(GooseDefault::ClientKey, client_key.as_str())
+            .unwrap()
+            .set_default(GooseDefault::CaCert, ca_cert.as_str())
+            .unwrap()
+            .set_default(GooseDefault::MinTlsVersion, min_tls_version.as_str())
+            .unwrap()
+            .set_default(GooseDefault::Insecure, true)
             .unwrap();
 
         assert!(goose_attack.defaults.host == Some(host));
@@ -2222,12 +2781,18 @@ mod test {
         assert!(goose_attack.defaults.quiet == Some(quiet as u8));
         assert!(goose_attack.defaults.verbose == Some(verbose as u8));
         assert!(goose_attack.defaults.running_metrics == Some(15));
+        assert!(goose_attack.defaults.significant_digits == Some(4));
         assert!(goose_attack.defaults.no_reset_metrics == Some(true));
         assert!(goose_attack.defaults.no_metrics == Some(true));
         assert!(goose_attack.defaults.no_transaction_metrics == Some(true));
         assert!(goose_attack.defaults.no_scenario_metrics == Some(true));
         assert!(goose_attack.defaults.no_print_metrics == Some(true));
         assert!(goose_attack.defaults.no_error_summary == Some(true));
+        assert!(goose_attack.defaults.normalize_paths == Some(true));
+        assert!(goose_attack.defaults.path_rules == Some(path_rules));
+        assert!(goose_attack.defaults.percentiles == Some(percentiles));
+        assert!(goose_attack.defaults.thresholds == Some(thresholds));
+        assert!(goose_attack.defaults.abort_on == Some(abort_on));
         assert!(goose_attack.defaults.no_telnet == Some(true));
         assert!(goose_attack.defaults.no_websocket == Some(true));
         assert!(goose_attack.defaults.no_autostart == Some(true));
@@ -2236,6 +2801,8 @@ mod test {
         assert!(goose_attack.defaults.report_file == Some(report_file));
         assert!(goose_attack.defaults.request_log == Some(request_log));
         assert!(goose_attack.defaults.request_format == Some(GooseLogFormat::Raw));
+        assert!(goose_attack.defaults.timer_log == Some(timer_log));
+        assert!(goose_attack.defaults.timer_format == Some(GooseLogFormat::Csv));
         assert!(goose_attack.defaults.error_log == Some(error_log));
         assert!(goose_attack.defaults.error_format == Some(GooseLogFormat::Csv));
         assert!(goose_attack.defaults.debug_log == Some(debug_log));
@@ -2247,5 +2814,13 @@ mod test {
         );
         assert!(goose_attack.defaults.throttle_requests == Some(throttle_requests));
         assert!(goose_attack.defaults.sticky_follow == Some(true));
+        assert!(goose_attack.defaults.proxy == Some(proxy));
+        assert!(goose_attack.defaults.resolve == Some(resolve));
+        assert!(goose_attack.defaults.local_address == Some(local_address));
+        assert!(goose_attack.defaults.client_cert == Some(client_cert));
+        assert!(goose_attack.defaults.client_key == Some(client_key));
+        assert!(goose_attack.defaults.ca_cert == Some(ca_cert));
+        assert!(goose_attack.defaults.min_tls_version == Some(min_tls_version));
+        assert!(goose_attack.defaults.insecure == Some(true));
     }
 }
@@ -287,30 +287,86 @@
 //! See the License for the specific language governing permissions and
 //! limitations under the License.
 
+use base64::engine::general_purpose;
+use base64::Engine;
+use bytes::Bytes;
 use downcast_rs::{impl_downcast, Downcast};
+use flate2::read::GzDecoder;
+use futures::{SinkExt, StreamExt};
 use http::method::Method;
+use lazy_static::lazy_static;
 use regex::Regex;
-use reqwest::{header, Client, ClientBuilder, RequestBuilder, Response};
+use reqwest::cookie::CookieStore;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::{header, Client, ClientBuilder, RequestBuilder, Response, ResponseBuilderExt};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
-use std::sync::Arc;
+use std::io::Read;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
 use std::time::Duration;
 use std::{fmt, str};
 use std::{future::Future, pin::Pin, time::Instant};
+use tokio::net::TcpStream;
+use tokio::sync::Semaphore;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+use tower::{Layer, Service};
 use url::Url;
 
 use crate::logger::GooseLog;
 use crate::metrics::{
-    GooseCoordinatedOmissionMitigation, GooseMetric, GooseRawRequest, GooseRequestMetric,
+    CustomMetric, CustomMetricValue, GooseCoordinatedOmissionMitigation, GooseMetric,
+    GooseRawRequest, GooseRequestMetric, StreamMetric, TimerMetric,
 };
 use crate::{GooseConfiguration, GooseError, WeightedTransactions};
 
+/// A message sent or received on a WebSocket connection opened with
+/// [`GooseUser::websocket_connect`].
+pub use tokio_tungstenite::tungstenite::Message as GooseWebSocketMessage;
+
 /// By default Goose sets the following User-Agent header when making requests.
 static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
 /// By default Goose times out requests after 60,000 milliseconds.
 static GOOSE_REQUEST_TIMEOUT: u64 = 60_000;
 
+/// By default Goose opens up to 6 connections per host when loading static resources, like
+/// most browsers.
+static GOOSE_RESOURCE_CONNECTIONS_PER_HOST: usize = 6;
+
+tokio::task_local! {
+    // How long the DNS lookups triggered by the request currently being made took, if any,
+    // as recorded by the GooseDnsResolver.
+    static DNS_TIME: Arc<Mutex<Option<u64>>>;
+    // How long opening the connections needed by the request currently being made took, if
+    // any, as recorded by the GooseConnectLayer.
+    static CONNECT_TIME: Arc<Mutex<Option<u64>>>;
+}
+
+lazy_static! {
+    // Matches HTML tags that can reference static resources, capturing the tag name and
+    // its attributes.
+    static ref RESOURCE_TAG: Regex =
+        Regex::new(r#"(?is)<(img|script|link|source)\b([^>]*)>"#).unwrap();
+    // Matches the attributes of an HTML tag that are used to find static resources.
+    static ref RESOURCE_ATTRIBUTE: Regex =
+        Regex::new(r#"(?is)\b(src|srcset|href|rel)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap();
+    // Matches the first operation defined in a GraphQL document, capturing its type and
+    // optional name.
+    static ref GRAPHQL_OPERATION: Regex =
+        Regex::new(r#"(?m)^\s*(query|mutation|subscription)\b\s*([_A-Za-z][_0-9A-Za-z]*)?"#)
+            .unwrap();
+    // Matches a path segment that is a UUID.
+    static ref UUID_SEGMENT: Regex =
+        Regex::new(r#"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"#)
+            .unwrap();
+}
+
 /// `transaction!(foo)` expands to `Transaction::new(foo)`, but also does some boxing to work around a limitation in the compiler.
 #[macro_export]
 macro_rules! transaction {
@@ -364,10 +420,50 @@ pub enum TransactionError {
         /// which contains the [`GooseDebug`](./struct.GooseDebug.html) that wasn't sent.
         source: flume::SendError<Option<GooseLog>>,
     },
-    /// Attempted an unrecognized HTTP request method.
-    InvalidMethod {
-        /// The unrecognized HTTP request method.
-        method: Method,
+    /// Attempted to make an HTTP request with a [`GooseMethod::Extension`] method whose name
+    /// isn't a valid HTTP method.
+    InvalidExtensionMethod {
+        /// The invalid extension method.
+        method: String,
+    },
+    /// Attempted to make an HTTP request with a [`GooseMethod::Custom`] method, which only
+    /// describes non-HTTP operations recorded with
+    /// [`GooseUser::record_operation`](./struct.GooseUser.html#method.record_operation).
+    CustomMethod {
+        /// The custom method.
+        method: String,
+    },
+    /// Wraps a [`tungstenite::Error`](https://docs.rs/tungstenite/*/tungstenite/error/enum.Error.html)
+    /// returned by a WebSocket connection.
+    WebSocket(tokio_tungstenite::tungstenite::Error),
+    /// Attempted to use a WebSocket connection that is not open.
+    WebSocketNotConnected {
+        /// The name of the WebSocket connection.
+        name: String,
+    },
+    /// Wraps a [`tonic::transport::Error`](https://docs.rs/tonic/*/tonic/transport/struct.Error.html)
+    /// returned when configuring a gRPC channel.
+    #[cfg(feature = "grpc")]
+    Grpc(tonic::transport::Error),
+    /// Attempted to make a gRPC call to a service and method that don't form a valid path.
+    #[cfg(feature = "grpc")]
+    InvalidGrpcPath {
+        /// The invalid path.
+        path: String,
+    },
+    /// Wraps a [`std::io::Error`](https://doc.rust-lang.org/std/io/struct.Error.html), for
+    /// example when a file added to a [`GooseMultipartForm`] can't be read.
+    Io(std::io::Error),
+    /// Attempted to store a cookie that couldn't be parsed, or isn't allowed to be set by
+    /// the [`GooseUser::base_url`](./struct.GooseUser.html#structfield.base_url).
+    InvalidCookie {
+        /// The invalid cookie, and why it was rejected.
+        cookie: String,
+    },
+    /// Attempted to stop a custom timer that was not started.
+    TimerNotStarted {
+        /// The name of the timer.
+        name: String,
     },
 }
 /// Implement a helper to provide a text description of all possible types of errors.
@@ -382,7 +478,17 @@ impl TransactionError {
             }
             TransactionError::MetricsFailed { .. } => "failed to send metrics to parent thread",
             TransactionError::LoggerFailed { .. } => "failed to send log message to logger thread",
-            TransactionError::InvalidMethod { .. } => "unrecognized HTTP request method",
+            TransactionError::InvalidExtensionMethod { .. } => "invalid HTTP extension method",
+            TransactionError::CustomMethod { .. } => "custom method is not an HTTP request method",
+            TransactionError::WebSocket(_) => "tungstenite::Error",
+            TransactionError::WebSocketNotConnected { .. } => "websocket is not connected",
+            #[cfg(feature = "grpc")]
+            TransactionError::Grpc(_) => "tonic::transport::Error",
+            #[cfg(feature = "grpc")]
+            TransactionError::InvalidGrpcPath { .. } => "invalid gRPC service or method",
+            TransactionError::Io(_) => "io::Error",
+            TransactionError::InvalidCookie { .. } => "invalid cookie",
+            TransactionError::TimerNotStarted { .. } => "timer was not started",
         }
     }
 }
@@ -407,6 +513,35 @@ impl fmt::Display for TransactionError {
             TransactionError::LoggerFailed { ref source } => {
                 write!(f, "TransactionError: {} ({})", self.describe(), source)
             }
+            TransactionError::WebSocket(ref source) => {
+                write!(f, "TransactionError: {} ({})", self.describe(), source)
+            }
+            TransactionError::WebSocketNotConnected { ref name } => {
+                write!(f, "TransactionError: {} ({})", self.describe(), name)
+            }
+            TransactionError::InvalidExtensionMethod { ref method } => {
+                write!(f, "TransactionError: {} ({})", self.describe(), method)
+            }
+            TransactionError::CustomMethod { ref method } => {
+                write!(f, "TransactionError: {} ({})", self.describe(), method)
+            }
+            #[cfg(feature = "grpc")]
+            TransactionError::Grpc(ref source) => {
+                write!(f, "TransactionError: {} ({})", self.describe(), source)
+            }
+            #[cfg(feature = "grpc")]
+            TransactionError::InvalidGrpcPath { ref path } => {
+                write!(f, "TransactionError: {} ({})", self.describe(), path)
+            }
+            TransactionError::Io(ref source) => {
+                write!(f, "TransactionError: {} ({})", self.describe(), source)
+            }
+            TransactionError::InvalidCookie { ref cookie } => {
+                write!(f, "TransactionError: {} ({})", self.describe(), cookie)
+            }
+            TransactionError::TimerNotStarted { ref name } => {
+                write!(f, "TransactionError: {} ({})", self.describe(), name)
+            }
             _ => write!(f, "TransactionError: {}", self.describe()),
         }
     }
@@ -421,6 +556,10 @@ impl std::error::Error for TransactionError {
             TransactionError::RequestCanceled { ref source } => Some(source),
             TransactionError::MetricsFailed { ref source } => Some(source),
             TransactionError::LoggerFailed { ref source } => Some(source),
+            TransactionError::WebSocket(ref source) => Some(source),
+            #[cfg(feature = "grpc")]
+            TransactionError::Grpc(ref source) => Some(source),
+            TransactionError::Io(ref source) => Some(source),
             _ => None,
         }
     }
@@ -440,6 +579,28 @@ impl From<url::ParseError> for TransactionError {
     }
 }
 
+/// Auto-convert IO errors.
+impl From<std::io::Error> for TransactionError {
+    fn from(err: std::io::Error) -> TransactionError {
+        TransactionError::Io(err)
+    }
+}
+
+/// Auto-convert WebSocket errors.
+impl From<tokio_tungstenite::tungstenite::Error> for TransactionError {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> TransactionError {
+        TransactionError::WebSocket(err)
+    }
+}
+
+/// Auto-convert gRPC transport errors.
+#[cfg(feature = "grpc")]
+impl From<tonic::transport::Error> for TransactionError {
+    fn from(err: tonic::transport::Error) -> TransactionError {
+        TransactionError::Grpc(err)
+    }
+}
+
 /// When the throttle is enabled and the load test ends, the throttle channel is
 /// shut down. This causes a
 /// [`flume::SendError`](https://docs.rs/flume/*/flume/struct.SendError.html),
@@ -496,6 +657,14 @@ pub struct Scenario {
     pub weighted_on_stop_transactions: WeightedTransactions,
     /// An optional default host to run this `Scenario` against.
     pub host: Option<String>,
+    /// An optional network profile emulated by all users running this `Scenario`.
+    pub network_profile: Option<GooseNetworkProfile>,
+    /// Options for the HTTP client of all users running this `Scenario`.
+    pub client_options: GooseClientOptions,
+    /// An optional authentication strategy used by all users running this `Scenario`.
+    pub auth: Option<GooseAuth>,
+    /// Whether users running this `Scenario` clear their cookies between iterations.
+    pub clear_cookies_each_iteration: bool,
 }
 impl Scenario {
     /// Creates a new [`Scenario`](./struct.Scenario.html). Once created, a
@@ -522,6 +691,10 @@ impl Scenario {
             weighted_on_start_transactions: Vec::new(),
             weighted_on_stop_transactions: Vec::new(),
             host: None,
+            network_profile: None,
+            client_options: GooseClientOptions::default(),
+            auth: None,
+            clear_cookies_each_iteration: false,
         }
     }
 
@@ -647,6 +820,527 @@ impl Scenario {
 
         Ok(self)
     }
+
+    /// Configure all users running this scenario to emulate a slower network, adding
+    /// latency to each request and limiting how fast request and response bodies are
+    /// transferred, as described by the [`GooseNetworkProfile`]. This makes it possible for
+    /// a "mobile" scenario to be slower than a "desktop" scenario in the same load test.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let mobile = GooseNetworkProfile::new("3G")
+    ///         .set_latency(Duration::from_millis(300))
+    ///         .set_download_bandwidth(200_000)?
+    ///         .set_upload_bandwidth(50_000)?;
+    ///     scenario!("MobileUsers").set_network_profile(mobile);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_network_profile(mut self, network_profile: GooseNetworkProfile) -> Self {
+        trace!("{} set_network_profile: {:?}", self.name, network_profile);
+        self.network_profile = Some(network_profile);
+        self
+    }
+
+    /// Configure which HTTP version all users running this scenario use, as described by
+    /// [`GooseHttpVersion`]. By default HTTP/1.1 is used, unless HTTP/2 is negotiated
+    /// when connecting to an HTTPS server.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut example_transactions = scenario!("ExampleTransactions")
+    ///     .set_http_version(GooseHttpVersion::Http2PriorKnowledge);
+    /// ```
+    pub fn set_http_version(mut self, http_version: GooseHttpVersion) -> Self {
+        trace!("{} set_http_version: {:?}", self.name, http_version);
+        self.client_options.http_version = http_version;
+        self
+    }
+
+    /// Configure how long idle connections are kept open by all users running this
+    /// scenario, or [`None`] to keep them open until the server closes them. Defaults to
+    /// 90 seconds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let mut example_transactions = scenario!("ExampleTransactions")
+    ///     .set_pool_idle_timeout(Some(Duration::from_secs(15)));
+    /// ```
+    pub fn set_pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        trace!("{} set_pool_idle_timeout: {:?}", self.name, timeout);
+        self.client_options.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Configure how many idle connections to each host are kept open by each user running
+    /// this scenario. By default there is no limit. For example, browsers typically open up
+    /// to six connections to each host.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut example_transactions = scenario!("ExampleTransactions")
+    ///     .set_pool_max_idle_per_host(6);
+    /// ```
+    pub fn set_pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        trace!("{} set_pool_max_idle_per_host: {}", self.name, max_idle);
+        self.client_options.pool_max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    /// Configure the interval of TCP keepalive probes sent on the connections of all users
+    /// running this scenario, or [`None`] to disable TCP keepalive, which is the default.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let mut example_transactions = scenario!("ExampleTransactions")
+    ///     .set_tcp_keepalive(Some(Duration::from_secs(60)));
+    /// ```
+    pub fn set_tcp_keepalive(mut self, interval: Option<Duration>) -> Self {
+        trace!("{} set_tcp_keepalive: {:?}", self.name, interval);
+        self.client_options.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Configure whether `TCP_NODELAY` is set on the connections of all users running this
+    /// scenario, sending small packets immediately instead of buffering them with Nagle's
+    /// algorithm. Defaults to `true`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut example_transactions = scenario!("ExampleTransactions").set_tcp_nodelay(false);
+    /// ```
+    pub fn set_tcp_nodelay(mut self, nodelay: bool) -> Self {
+        trace!("{} set_tcp_nodelay: {}", self.name, nodelay);
+        self.client_options.tcp_nodelay = Some(nodelay);
+        self
+    }
+
+    /// Configure all users running this scenario to authenticate their requests, as
+    /// described by the [`GooseAuth`]. This replaces authentication that would otherwise
+    /// be handled in an [`on_start`](./struct.Transaction.html#method.set_on_start)
+    /// transaction, and keeps OAuth2 access tokens fresh during long load tests.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut example_transactions = scenario!("ExampleTransactions").set_auth(
+    ///     GooseAuth::oauth2_client_credentials("https://auth.example.com/token", "id", "secret"),
+    /// );
+    /// ```
+    pub fn set_auth(mut self, auth: GooseAuth) -> Self {
+        trace!("{} set_auth: {}", self.name, auth.describe());
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Configure all users running this scenario to remove their cookies between
+    /// iterations, as with [`GooseUser::clear_cookies`]. Each iteration then starts a new
+    /// session, modeling a new visitor without replacing the user's client.
+    ///
+    /// Cookies set by [`on_start`](./struct.Transaction.html#method.set_on_start)
+    /// transactions are kept for the first iteration, and cookies set by the last
+    /// iteration are kept for [`on_stop`](./struct.Transaction.html#method.set_on_stop)
+    /// transactions.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut example_transactions = scenario!("ExampleTransactions")
+    ///     .set_clear_cookies_each_iteration();
+    /// ```
+    pub fn set_clear_cookies_each_iteration(mut self) -> Self {
+        trace!("{} set_clear_cookies_each_iteration", self.name);
+        self.clear_cookies_each_iteration = true;
+        self
+    }
+}
+
+/// The HTTP version used by users running a [`Scenario`], configured with
+/// [`Scenario::set_http_version`].
+///
+/// The HTTP version each response was received with is recorded in
+/// [`GooseRequestMetric::http_version`](../metrics/struct.GooseRequestMetric.html#structfield.http_version).
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum GooseHttpVersion {
+    /// Use HTTP/1.1, unless HTTP/2 is negotiated when connecting to an HTTPS server. HTTP/2
+    /// is only negotiated when Goose is built with the `rustls-tls` feature.
+    #[default]
+    Negotiate,
+    /// Only use HTTP/1.1.
+    Http1Only,
+    /// Only use HTTP/2, assuming the server supports it without negotiating.
+    Http2PriorKnowledge,
+}
+
+/// Options for the HTTP client of users running a [`Scenario`], configured with
+/// [`Scenario::set_http_version`], [`Scenario::set_pool_idle_timeout`],
+/// [`Scenario::set_pool_max_idle_per_host`], [`Scenario::set_tcp_keepalive`] and
+/// [`Scenario::set_tcp_nodelay`]. Options that aren't configured keep the defaults of the
+/// client.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct GooseClientOptions {
+    // Defaults to [`GooseHttpVersion::Negotiate`].
+    http_version: GooseHttpVersion,
+    // Each option defaults to [`None`] to keep the default of the client.
+    pool_idle_timeout: Option<Option<Duration>>,
+    pool_max_idle_per_host: Option<usize>,
+    tcp_keepalive: Option<Option<Duration>>,
+    tcp_nodelay: Option<bool>,
+}
+impl GooseClientOptions {
+    /// Internal helper that applies the options to the client builder of a new [`GooseUser`].
+    fn configure(&self, mut builder: ClientBuilder) -> ClientBuilder {
+        match self.http_version {
+            GooseHttpVersion::Negotiate => {}
+            GooseHttpVersion::Http1Only => builder = builder.http1_only(),
+            GooseHttpVersion::Http2PriorKnowledge => builder = builder.http2_prior_knowledge(),
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(max_idle) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(interval) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(interval);
+        }
+        if let Some(nodelay) = self.tcp_nodelay {
+            builder = builder.tcp_nodelay(nodelay);
+        }
+        builder
+    }
+}
+
+/// How a [`GooseUser`] authenticates its requests, configured for all users running a
+/// [`Scenario`] with [`Scenario::set_auth`], or for a single user with
+/// [`GooseUser::set_auth`].
+///
+/// An `Authorization` header is added to every request made by the user, unless the
+/// request already sets one. OAuth2 access tokens are requested from the token endpoint
+/// before the first request, and requested again shortly before they expire: if the
+/// server returned a refresh token it is used to refresh the access token. Token requests
+/// are recorded like any other request, named `oauth2 token` unless configured with
+/// [`GooseAuth::set_token_request_name`]. If a token can't be obtained, the request that
+/// needed it fails with [`TransactionError::RequestFailed`] without being sent.
+///
+/// WebSocket and gRPC connections are not authenticated.
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct GooseAuth {
+    // The authentication strategy.
+    strategy: GooseAuthStrategy,
+    // Optional OAuth2 scope, defaults to [`None`].
+    scope: Option<String>,
+    // How long before an OAuth2 access token expires to refresh it, defaults to 30 seconds.
+    refresh_margin: Duration,
+    // The name OAuth2 token requests are recorded with, defaults to `oauth2 token`.
+    token_request_name: String,
+}
+/// The authentication strategies supported by [`GooseAuth`].
+#[derive(Clone, Hash, PartialEq, Eq)]
+enum GooseAuthStrategy {
+    Basic {
+        username: String,
+        password: String,
+    },
+    Bearer {
+        token: String,
+    },
+    OAuth2 {
+        token_url: String,
+        client_id: String,
+        client_secret: String,
+        // Set for the resource owner password grant, otherwise client credentials are used.
+        user: Option<(String, String)>,
+    },
+}
+impl GooseAuth {
+    // Internal helper to wrap a strategy with default options.
+    fn new(strategy: GooseAuthStrategy) -> Self {
+        GooseAuth {
+            strategy,
+            scope: None,
+            refresh_margin: Duration::from_secs(30),
+            token_request_name: "oauth2 token".to_string(),
+        }
+    }
+
+    /// Authenticate with HTTP basic authentication.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let auth = GooseAuth::basic("admin", "password");
+    /// ```
+    pub fn basic(username: &str, password: &str) -> Self {
+        GooseAuth::new(GooseAuthStrategy::Basic {
+            username: username.to_string(),
+            password: password.to_string(),
+        })
+    }
+
+    /// Authenticate with a static bearer token, which never expires.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let auth = GooseAuth::bearer("eyJhbGciOiJIUzI1NiJ9");
+    /// ```
+    pub fn bearer(token: &str) -> Self {
+        GooseAuth::new(GooseAuthStrategy::Bearer {
+            token: token.to_string(),
+        })
+    }
+
+    /// Authenticate with OAuth2 access tokens requested from `token_url` with the client
+    /// credentials grant. The client authenticates with HTTP basic authentication, or if
+    /// `client_secret` is empty by sending its `client_id` in the request.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let auth = GooseAuth::oauth2_client_credentials(
+    ///     "https://auth.example.com/oauth/token",
+    ///     "load-test",
+    ///     "secret",
+    /// );
+    /// ```
+    pub fn oauth2_client_credentials(
+        token_url: &str,
+        client_id: &str,
+        client_secret: &str,
+    ) -> Self {
+        GooseAuth::new(GooseAuthStrategy::OAuth2 {
+            token_url: token_url.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            user: None,
+        })
+    }
+
+    /// Authenticate with OAuth2 access tokens requested from `token_url` with the resource
+    /// owner password grant, on behalf of the user identified by `username` and `password`.
+    /// The client authenticates as with [`GooseAuth::oauth2_client_credentials`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let auth = GooseAuth::oauth2_password(
+    ///     "https://auth.example.com/oauth/token",
+    ///     "load-test",
+    ///     "",
+    ///     "user@example.com",
+    ///     "password",
+    /// );
+    /// ```
+    pub fn oauth2_password(
+        token_url: &str,
+        client_id: &str,
+        client_secret: &str,
+        username: &str,
+        password: &str,
+    ) -> Self {
+        GooseAuth::new(GooseAuthStrategy::OAuth2 {
+            token_url: token_url.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            user: Some((username.to_string(), password.to_string())),
+        })
+    }
+
+    /// Request OAuth2 access tokens for the provided space-separated `scope`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let auth = GooseAuth::oauth2_client_credentials("https://auth.example.com/token", "id", "secret")
+    ///     .set_scope("read write");
+    /// ```
+    pub fn set_scope(mut self, scope: &str) -> Self {
+        self.scope = Some(scope.to_string());
+        self
+    }
+
+    /// Configure how long before an OAuth2 access token expires to replace it, which
+    /// defaults to 30 seconds. Tokens that the server doesn't set an expiry for are never
+    /// replaced.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let auth = GooseAuth::oauth2_client_credentials("https://auth.example.com/token", "id", "secret")
+    ///     .set_refresh_margin(Duration::from_secs(60));
+    /// ```
+    pub fn set_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.refresh_margin = refresh_margin;
+        self
+    }
+
+    /// Configure the name OAuth2 token requests are recorded with in the metrics, which
+    /// defaults to `oauth2 token`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let auth = GooseAuth::oauth2_client_credentials("https://auth.example.com/token", "id", "secret")
+    ///     .set_token_request_name("login");
+    /// ```
+    pub fn set_token_request_name(mut self, name: &str) -> Self {
+        self.token_request_name = name.to_string();
+        self
+    }
+
+    // Internal helper to describe the strategy in logs, without including any secrets.
+    fn describe(&self) -> String {
+        match &self.strategy {
+            GooseAuthStrategy::Basic { username, .. } => format!("basic ({})", username),
+            GooseAuthStrategy::Bearer { .. } => "bearer".to_string(),
+            GooseAuthStrategy::OAuth2 {
+                token_url, user, ..
+            } => match user {
+                Some((username, _)) => format!("oauth2 password ({}, {})", token_url, username),
+                None => format!("oauth2 client credentials ({})", token_url),
+            },
+        }
+    }
+}
+
+/// An OAuth2 access token obtained by a [`GooseUser`].
+struct GooseAuthToken {
+    access_token: String,
+    // When the token expires, if the server set an expiry.
+    expires: Option<Instant>,
+    refresh_token: Option<String>,
+}
+
+/// A successful response from an OAuth2 token endpoint.
+#[derive(Deserialize)]
+struct GooseAuthTokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+    refresh_token: Option<String>,
+}
+
+/// Describes the network conditions emulated by users running a [`Scenario`], configured
+/// with [`Scenario::set_network_profile`].
+///
+/// Network conditions are emulated by the client, which can only slow down requests: the
+/// latency of the network being load tested is added to the configured latency. The
+/// latency is added once to each HTTP request before it's sent. Request bodies are held
+/// back for as long as it would take to upload them at the configured upload bandwidth,
+/// and response bodies are read no faster than the configured download bandwidth,
+/// including bodies read with [`GooseUser::request_stream`]. WebSocket and gRPC traffic
+/// is not affected.
+///
+/// The name of the profile is recorded with each request made while it is active, in
+/// [`GooseRequestMetric::network_profile`](../metrics/struct.GooseRequestMetric.html#structfield.network_profile).
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct GooseNetworkProfile {
+    // The name of the profile, recorded in request metrics.
+    name: String,
+    // Defaults to no added latency.
+    latency: Duration,
+    // Bytes per second, defaults to [`None`] for unlimited.
+    download_bandwidth: Option<u64>,
+    // Bytes per second, defaults to [`None`] for unlimited.
+    upload_bandwidth: Option<u64>,
+}
+impl GooseNetworkProfile {
+    /// Create a named network profile, which adds no latency and doesn't limit bandwidth
+    /// until configured.
+    pub fn new(name: &str) -> Self {
+        GooseNetworkProfile {
+            name: name.to_string(),
+            latency: Duration::from_millis(0),
+            download_bandwidth: None,
+            upload_bandwidth: None,
+        }
+    }
+
+    /// The name of the network profile.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Add latency to each request.
+    pub fn set_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Limit how fast response bodies are downloaded, in bytes per second.
+    pub fn set_download_bandwidth(mut self, bytes_per_second: u64) -> Result<Self, GooseError> {
+        self.download_bandwidth = Some(validate_bandwidth("download", bytes_per_second)?);
+        Ok(self)
+    }
+
+    /// Limit how fast request bodies are uploaded, in bytes per second.
+    pub fn set_upload_bandwidth(mut self, bytes_per_second: u64) -> Result<Self, GooseError> {
+        self.upload_bandwidth = Some(validate_bandwidth("upload", bytes_per_second)?);
+        Ok(self)
+    }
+
+    /// How long to hold back a request with a body of `body_size` bytes before sending it.
+    fn request_delay(&self, body_size: u64) -> Duration {
+        let upload = match self.upload_bandwidth {
+            Some(bandwidth) => transfer_time(body_size as usize, bandwidth),
+            None => Duration::from_millis(0),
+        };
+        self.latency + upload
+    }
+}
+
+/// Internal helper to validate the bandwidth of a [`GooseNetworkProfile`].
+fn validate_bandwidth(direction: &str, bytes_per_second: u64) -> Result<u64, GooseError> {
+    if bytes_per_second == 0 {
+        return Err(GooseError::InvalidOption {
+            option: format!("GooseNetworkProfile::set_{}_bandwidth", direction),
+            value: bytes_per_second.to_string(),
+            detail: "Bandwidth must be at least 1 byte per second.".to_string(),
+        });
+    }
+    Ok(bytes_per_second)
+}
+
+/// Internal helper that returns how long it takes to transfer `bytes` at `bandwidth` bytes
+/// per second.
+fn transfer_time(bytes: usize, bandwidth: u64) -> Duration {
+    Duration::from_secs_f64(bytes as f64 / bandwidth as f64)
+}
+
+/// Internal helper that waits until `bytes` could have been transferred at `bandwidth`
+/// bytes per second since `started`.
+async fn throttle_transfer(started: Instant, bytes: usize, bandwidth: u64) {
+    if let Some(wait) = transfer_time(bytes, bandwidth).checked_sub(started.elapsed()) {
+        tokio::time::sleep(wait).await;
+    }
 }
 
 /// Commands sent from the parent thread to the user threads, and from the manager to the
@@ -662,6 +1356,27 @@ pub enum GooseUserCommand {
 }
 
 /// Supported HTTP methods.
+///
+/// All standard methods are supported, and other methods such as those defined by WebDAV
+/// can be requested as a [`GooseMethod::Extension`].
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+///
+/// let mut transaction = transaction!(propfind);
+///
+/// /// List the properties of a WebDAV collection.
+/// async fn propfind(user: &mut GooseUser) -> TransactionResult {
+///     let goose_request = GooseRequest::builder()
+///         .method(GooseMethod::Extension("PROPFIND".to_string()))
+///         .path("webdav/")
+///         .build();
+///     let _goose = user.request(goose_request).await?;
+///
+///     Ok(())
+/// }
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd)]
 pub enum GooseMethod {
     Delete,
@@ -670,34 +1385,85 @@ pub enum GooseMethod {
     Patch,
     Post,
     Put,
+    /// The protocol of a non-HTTP operation recorded with
+    /// [`GooseUser::record_operation`](./struct.GooseUser.html#method.record_operation),
+    /// for example `REDIS`. Can not be used to make HTTP requests.
+    Custom(String),
+    Connect,
+    Options,
+    Trace,
+    /// An HTTP extension method, such as the WebDAV `PROPFIND` method. The name is sent
+    /// exactly as provided, so is normally upper case. Displayed with an `HTTP` prefix,
+    /// such as `HTTP PROPFIND`, so it's never confused with the protocol of a
+    /// [`GooseMethod::Custom`] operation.
+    Extension(String),
 }
-/// Display method in upper case.
+/// Display method in upper case, prefixing extension methods with `HTTP`.
 impl fmt::Display for GooseMethod {
     // Implement display of `GooseMethod` with `{}` marker.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            GooseMethod::Connect => write!(f, "CONNECT"),
             GooseMethod::Delete => write!(f, "DELETE"),
             GooseMethod::Get => write!(f, "GET"),
             GooseMethod::Head => write!(f, "HEAD"),
+            GooseMethod::Options => write!(f, "OPTIONS"),
             GooseMethod::Patch => write!(f, "PATCH"),
             GooseMethod::Post => write!(f, "POST"),
             GooseMethod::Put => write!(f, "PUT"),
+            GooseMethod::Trace => write!(f, "TRACE"),
+            GooseMethod::Extension(method) => write!(f, "HTTP {}", method),
+            GooseMethod::Custom(protocol) => write!(f, "{}", protocol),
         }
     }
 }
 
 /// Convert [`http::method::Method`](https://docs.rs/http/*/http/method/struct.Method.html)
-/// to [`GooseMethod`](./enum.GooseMethod.html).
-pub fn goose_method_from_method(method: Method) -> Result<GooseMethod, Box<TransactionError>> {
-    Ok(match method {
+/// to [`GooseMethod`](./enum.GooseMethod.html). Methods that aren't standard are converted
+/// to [`GooseMethod::Extension`].
+pub fn goose_method_from_method(method: Method) -> GooseMethod {
+    match method {
+        Method::CONNECT => GooseMethod::Connect,
         Method::DELETE => GooseMethod::Delete,
         Method::GET => GooseMethod::Get,
         Method::HEAD => GooseMethod::Head,
+        Method::OPTIONS => GooseMethod::Options,
         Method::PATCH => GooseMethod::Patch,
         Method::POST => GooseMethod::Post,
         Method::PUT => GooseMethod::Put,
-        _ => {
-            return Err(Box::new(TransactionError::InvalidMethod { method }));
+        Method::TRACE => GooseMethod::Trace,
+        _ => GooseMethod::Extension(method.as_str().to_string()),
+    }
+}
+
+/// Convert [`GooseMethod`](./enum.GooseMethod.html) to
+/// [`http::method::Method`](https://docs.rs/http/*/http/method/struct.Method.html).
+/// Returns [`TransactionError::InvalidExtensionMethod`] if the name of a
+/// [`GooseMethod::Extension`] isn't a valid method, and [`TransactionError::CustomMethod`]
+/// for a [`GooseMethod::Custom`] method, which isn't an HTTP method.
+pub fn method_from_goose_method(method: &GooseMethod) -> Result<Method, Box<TransactionError>> {
+    Ok(match method {
+        GooseMethod::Connect => Method::CONNECT,
+        GooseMethod::Delete => Method::DELETE,
+        GooseMethod::Get => Method::GET,
+        GooseMethod::Head => Method::HEAD,
+        GooseMethod::Options => Method::OPTIONS,
+        GooseMethod::Patch => Method::PATCH,
+        GooseMethod::Post => Method::POST,
+        GooseMethod::Put => Method::PUT,
+        GooseMethod::Trace => Method::TRACE,
+        GooseMethod::Extension(name) => match Method::from_bytes(name.as_bytes()) {
+            Ok(m) => m,
+            Err(_) => {
+                return Err(Box::new(TransactionError::InvalidExtensionMethod {
+                    method: name.to_string(),
+                }))
+            }
+        },
+        GooseMethod::Custom(protocol) => {
+            return Err(Box::new(TransactionError::CustomMethod {
+                method: protocol.to_string(),
+            }))
         }
     })
 }
@@ -716,6 +1482,300 @@ impl GooseResponse {
     }
 }
 
+/// The responses to a batch of requests made with
+/// [`request_batch()`](struct.GooseUser.html#method.request_batch).
+#[derive(Debug)]
+pub struct GooseBatchResponse {
+    /// A response for each request in the batch, in the order the requests were provided.
+    pub responses: Vec<GooseResponse>,
+    /// How many milliseconds it took for all requests in the batch to complete.
+    pub elapsed: u64,
+}
+
+/// gRPC calls are recorded with their gRPC status code plus this offset as the status code,
+/// so `OK` is recorded as `1000` and `UNIMPLEMENTED` as `1012`. This keeps successful calls
+/// apart from requests that received no response, which are recorded with status code `0`,
+/// and gRPC status codes apart from HTTP status codes.
+#[cfg(feature = "grpc")]
+pub const GRPC_STATUS_CODE_OFFSET: u16 = 1000;
+
+/// The response to a gRPC call made with
+/// [`grpc_unary()`](struct.GooseUser.html#method.grpc_unary) or
+/// [`grpc_server_streaming()`](struct.GooseUser.html#method.grpc_server_streaming).
+#[cfg(feature = "grpc")]
+#[derive(Debug)]
+pub struct GooseGrpcResponse<T> {
+    /// The call, which is recorded as a request.
+    pub request: GooseRequestMetric,
+    /// The response, or the [`tonic::Status`](https://docs.rs/tonic/*/tonic/struct.Status.html)
+    /// returned by the server if the call failed.
+    pub response: Result<T, tonic::Status>,
+}
+
+/// A non-HTTP operation, such as a Redis query or a message sent to a queue, recorded with
+/// [`record_operation()`](struct.GooseUser.html#method.record_operation).
+///
+/// Operations are recorded as requests, so they count exactly like HTTP requests in the
+/// metrics, the error summary, the request log and the HTML report. They are named
+/// `{protocol} {name}`, for example `REDIS GET session`.
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+/// use std::time::Duration;
+///
+/// let operation = GooseOperation::new("GET session", Duration::from_millis(3))
+///     .protocol("redis")
+///     .target("redis://127.0.0.1:6379");
+/// ```
+#[derive(Clone, Debug)]
+pub struct GooseOperation {
+    // The protocol used by the operation, displayed as the method of the request.
+    protocol: String,
+    // The name of the operation.
+    name: String,
+    // What the operation was performed against, displayed as the url of the request.
+    target: String,
+    // An optional protocol specific status code.
+    status_code: u16,
+    // How many milliseconds the operation took.
+    response_time: u128,
+    // Why the operation failed, or `None` if it succeeded.
+    error: Option<String>,
+}
+impl GooseOperation {
+    /// Describe a successful operation named `name` that took `response_time` to complete.
+    ///
+    /// Defaults to the `CUSTOM` protocol.
+    pub fn new(name: &str, response_time: Duration) -> Self {
+        GooseOperation {
+            protocol: "CUSTOM".to_string(),
+            name: name.to_string(),
+            target: "".to_string(),
+            status_code: 0,
+            response_time: response_time.as_millis(),
+            error: None,
+        }
+    }
+
+    /// Set the protocol of the operation, which is displayed in upper case where HTTP
+    /// requests display their method, for example `REDIS`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let operation = GooseOperation::new("publish order", Duration::from_millis(12))
+    ///     .protocol("amqp");
+    /// ```
+    pub fn protocol(mut self, protocol: &str) -> Self {
+        self.protocol = protocol.to_uppercase();
+        self
+    }
+
+    /// Set what the operation was performed against, for example the address of the
+    /// server. This is logged where HTTP requests log their url.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let operation = GooseOperation::new("ping", Duration::from_millis(1))
+    ///     .protocol("tcp")
+    ///     .target("10.0.0.5:7000");
+    /// ```
+    pub fn target(mut self, target: &str) -> Self {
+        self.target = target.to_string();
+        self
+    }
+
+    /// Set a protocol specific status code, which is tracked like an HTTP status code.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let operation = GooseOperation::new("query", Duration::from_millis(8))
+    ///     .protocol("grpc")
+    ///     .status_code(5);
+    /// ```
+    pub fn status_code(mut self, status_code: u16) -> Self {
+        self.status_code = status_code;
+        self
+    }
+
+    /// Record that the operation failed, and why.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let operation = GooseOperation::new("GET session", Duration::from_millis(500))
+    ///     .protocol("redis")
+    ///     .error("connection reset");
+    /// ```
+    pub fn error(mut self, error: &str) -> Self {
+        self.error = Some(error.to_string());
+        self
+    }
+
+    /// Record that the operation failed if `result` is an error, using the error as the
+    /// reason.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let result: Result<usize, String> = Err("timed out".to_string());
+    /// let operation = GooseOperation::new("GET session", Duration::from_millis(500))
+    ///     .protocol("redis")
+    ///     .result(&result);
+    /// ```
+    pub fn result<T, E: fmt::Display>(self, result: &Result<T, E>) -> Self {
+        match result {
+            Ok(_) => self,
+            Err(e) => self.error(&e.to_string()),
+        }
+    }
+}
+
+/// How to read a streaming response with
+/// [`request_stream()`](struct.GooseUser.html#method.request_stream).
+///
+/// A [Server-Sent Events](https://html.spec.whatwg.org/multipage/server-sent-events.html)
+/// stream is split into events, skipping comments and events without any data. Any other
+/// stream, such as a chunked response, is read as one event per chunk received.
+///
+/// By default the stream is read until the server closes it. It can optionally be closed
+/// after a maximum number of events, or after a maximum duration.
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+/// use std::time::Duration;
+///
+/// // Read up to 10 events, for no longer than 5 seconds.
+/// let stream = GooseStream::server_sent_events()
+///     .max_events(10)
+///     .max_duration(Duration::from_secs(5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct GooseStream {
+    // Whether to parse the stream as Server-Sent Events, or one event per chunk.
+    server_sent_events: bool,
+    // Optionally stop reading after this many events.
+    max_events: Option<usize>,
+    // Optionally stop reading after this long, measured from when the request was sent.
+    max_duration: Option<Duration>,
+}
+impl GooseStream {
+    /// Read a Server-Sent Events (`text/event-stream`) response.
+    pub fn server_sent_events() -> Self {
+        GooseStream {
+            server_sent_events: true,
+            max_events: None,
+            max_duration: None,
+        }
+    }
+
+    /// Read any streaming response, such as a chunked response, as one event per chunk.
+    pub fn chunks() -> Self {
+        GooseStream {
+            server_sent_events: false,
+            max_events: None,
+            max_duration: None,
+        }
+    }
+
+    /// Close the stream after receiving `max_events` events.
+    pub fn max_events(mut self, max_events: usize) -> Self {
+        self.max_events = Some(max_events);
+        self
+    }
+
+    /// Close the stream if it is still open `max_duration` after the request was sent.
+    pub fn max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+}
+
+/// An event received on a stream read with
+/// [`request_stream()`](struct.GooseUser.html#method.request_stream).
+#[derive(Clone, Debug)]
+pub struct GooseStreamEvent {
+    /// How many milliseconds after the request was sent the event was received.
+    pub elapsed: u64,
+    /// The type of the event, if set by a Server-Sent Events `event` field.
+    pub event: Option<String>,
+    /// The id of the event, if set by a Server-Sent Events `id` field.
+    pub id: Option<String>,
+    /// The data of the event, or the contents of the chunk when not reading Server-Sent
+    /// Events.
+    pub data: String,
+}
+
+/// The response to a streaming request made with
+/// [`request_stream()`](struct.GooseUser.html#method.request_stream).
+#[derive(Debug)]
+pub struct GooseStreamResponse {
+    /// The request that opened the stream.
+    pub request: GooseRequestMetric,
+    /// The events received on the stream.
+    pub events: Vec<GooseStreamEvent>,
+    /// The metrics collected while reading the stream.
+    pub stream: StreamMetric,
+}
+
+/// The response to a GraphQL operation made with
+/// [`graphql()`](struct.GooseUser.html#method.graphql).
+#[derive(Debug)]
+pub struct GooseGraphqlResponse<T> {
+    /// The request that sent the operation.
+    pub request: GooseRequestMetric,
+    /// The `data` returned by the server, deserialized into the requested type, or `None` if
+    /// no data was returned or it could not be deserialized.
+    pub data: Option<T>,
+    /// The `errors` returned by the server, if any.
+    pub errors: Vec<GooseGraphqlError>,
+}
+
+/// An error returned by a GraphQL server in the `errors` array of a response.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GooseGraphqlError {
+    /// A description of the error.
+    pub message: String,
+    /// The path of the response field that caused the error, if any.
+    #[serde(default)]
+    pub path: Vec<serde_json::Value>,
+    /// Additional server specific details about the error, if any.
+    #[serde(default)]
+    pub extensions: Option<serde_json::Value>,
+}
+
+/// The body of a GraphQL request.
+#[derive(Serialize)]
+struct GraphqlRequestBody<'a, V> {
+    query: &'a str,
+    variables: V,
+    #[serde(rename = "operationName", skip_serializing_if = "Option::is_none")]
+    operation_name: Option<&'a str>,
+}
+
+/// The body of a GraphQL response, before `data` is deserialized.
+#[derive(Deserialize)]
+struct GraphqlResponseBody {
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+    #[serde(default)]
+    errors: Option<Vec<GooseGraphqlError>>,
+}
+
 /// Object created by [`log_debug()`](struct.GooseUser.html#method.log_debug) and written
 /// to log to assist in debugging.
 #[derive(Debug, Deserialize, Serialize)]
@@ -815,6 +1875,594 @@ pub trait GooseUserData: Downcast + Send + Sync + 'static {}
 impl_downcast!(GooseUserData);
 impl<T: Send + Sync + 'static> GooseUserData for T {}
 
+/// By default Goose caches responses with a `Last-Modified` header but no explicit
+/// expiration for this fraction of the time since they were last modified, as browsers do.
+static GOOSE_HEURISTIC_FRESHNESS: f64 = 0.1;
+
+/// A response stored in the HTTP cache of a [`GooseUser`], enabled with
+/// [`GooseUser::enable_http_cache`].
+#[derive(Clone, Debug)]
+struct GooseCachedResponse {
+    /// The URL the response was loaded from.
+    url: Url,
+    /// The status code of the response.
+    status: reqwest::StatusCode,
+    /// The HTTP version of the response.
+    version: reqwest::Version,
+    /// The headers of the response, updated when it's revalidated.
+    headers: header::HeaderMap,
+    /// The body of the response.
+    body: Vec<u8>,
+    /// When the response has to be revalidated with the server before being used again.
+    fresh_until: Instant,
+}
+impl GooseCachedResponse {
+    /// Store a copy of a response, or return [`None`] if it can't be cached.
+    fn new(response: &Response, body: &[u8]) -> Option<Self> {
+        // Only complete responses that can be used again or revalidated are stored.
+        if response.status() != reqwest::StatusCode::OK {
+            return None;
+        }
+        let freshness = cache_freshness(response.headers())?;
+        if freshness.is_zero()
+            && !response.headers().contains_key(header::ETAG)
+            && !response.headers().contains_key(header::LAST_MODIFIED)
+        {
+            return None;
+        }
+
+        Some(GooseCachedResponse {
+            url: response.url().clone(),
+            status: response.status(),
+            version: response.version(),
+            headers: response.headers().clone(),
+            body: body.to_vec(),
+            fresh_until: Instant::now() + freshness,
+        })
+    }
+
+    /// Whether the response can be used without revalidating it with the server.
+    fn is_fresh(&self) -> bool {
+        Instant::now() < self.fresh_until
+    }
+
+    /// The conditional request headers used to revalidate the response with the server.
+    fn validators(&self) -> Vec<(header::HeaderName, header::HeaderValue)> {
+        let mut validators = Vec::new();
+        if let Some(etag) = self.headers.get(header::ETAG) {
+            validators.push((header::IF_NONE_MATCH, etag.clone()));
+        }
+        if let Some(last_modified) = self.headers.get(header::LAST_MODIFIED) {
+            validators.push((header::IF_MODIFIED_SINCE, last_modified.clone()));
+        }
+        validators
+    }
+
+    /// Update the response with the headers of a `304 Not Modified` response confirming
+    /// it's still valid. Returns `false` if it can no longer be cached.
+    fn revalidate(&mut self, headers: &header::HeaderMap) -> bool {
+        for (name, value) in headers {
+            // The stored body determines the length and encoding of the response.
+            if name != header::CONTENT_LENGTH && name != header::TRANSFER_ENCODING {
+                self.headers.insert(name.clone(), value.clone());
+            }
+        }
+        match cache_freshness(&self.headers) {
+            Some(freshness) => {
+                self.fresh_until = Instant::now() + freshness;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Build a response from the stored copy.
+    fn to_response(&self) -> Response {
+        build_response(
+            self.url.clone(),
+            self.status,
+            self.version,
+            self.headers.clone(),
+            http::Extensions::new(),
+            self.body.clone(),
+        )
+    }
+}
+
+/// Internal helper that determines how long a response can be used without revalidating
+/// it, based on its `Cache-Control`, `Expires`, `Date` and `Last-Modified` headers. Returns
+/// [`None`] if the response must not be stored.
+fn cache_freshness(headers: &header::HeaderMap) -> Option<Duration> {
+    let header_date = |name| {
+        headers
+            .get(name)
+            .and_then(|value: &header::HeaderValue| value.to_str().ok())
+            .and_then(|value| chrono::DateTime::parse_from_rfc2822(value).ok())
+    };
+    let vary = headers.get(header::VARY).and_then(|v| v.to_str().ok());
+    if matches!(vary, Some(vary) if vary.trim() == "*") {
+        return None;
+    }
+
+    // Explicit directives take precedence.
+    let mut max_age = None;
+    for value in headers.get_all(header::CACHE_CONTROL) {
+        for directive in value.to_str().unwrap_or("").split(',') {
+            let directive = directive.trim().to_lowercase();
+            if directive == "no-store" {
+                return None;
+            } else if directive == "no-cache" {
+                return Some(Duration::ZERO);
+            } else if let Some(seconds) = directive.strip_prefix("max-age=") {
+                max_age = Some(seconds.trim_matches('"').parse::<u64>().unwrap_or(0));
+            }
+        }
+    }
+    if let Some(seconds) = max_age {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    // Otherwise compare the expiration to the time the response was sent.
+    let date = header_date(header::DATE).map_or_else(chrono::Utc::now, |date| date.into());
+    let lifetime = if headers.contains_key(header::EXPIRES) {
+        // Invalid dates mean the response has already expired.
+        match header_date(header::EXPIRES) {
+            Some(expires) => expires.signed_duration_since(date),
+            None => chrono::Duration::zero(),
+        }
+    // Otherwise guess, based on how long ago the response was last modified.
+    } else if let Some(last_modified) = header_date(header::LAST_MODIFIED) {
+        let age = date.signed_duration_since(last_modified).num_milliseconds();
+        chrono::Duration::milliseconds((age as f64 * GOOSE_HEURISTIC_FRESHNESS) as i64)
+    } else {
+        chrono::Duration::zero()
+    };
+    Some(lifetime.to_std().unwrap_or(Duration::ZERO))
+}
+
+/// Internal helper that applies the `--proxy`, `--resolve` and `--local-address` options
+/// to the client builder of a new [`GooseUser`]. Users are bound to the local addresses in
+/// turn, by the order in which the load test allocated them.
+fn configure_connections(
+    mut builder: ClientBuilder,
+    configuration: &GooseConfiguration,
+    user_index: usize,
+) -> Result<ClientBuilder, GooseError> {
+    if !configuration.proxy.is_empty() {
+        builder = builder.proxy(parse_proxy(&configuration.proxy)?);
+    }
+    for (host, addresses) in parse_resolve(&configuration.resolve)? {
+        builder = builder.resolve_to_addrs(&host, &addresses);
+    }
+    let local_addresses = parse_local_address(&configuration.local_address)?;
+    if !local_addresses.is_empty() {
+        // Spread users across all local addresses in turn.
+        builder = builder.local_address(local_addresses[user_index % local_addresses.len()]);
+    }
+    Ok(builder)
+}
+
+/// The `--client-cert`, `--client-key`, `--ca-cert`, `--min-tls-version` and `--insecure`
+/// options, loaded once per load test and applied to the client builder of each new
+/// [`GooseUser`].
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+#[derive(Clone, Default)]
+pub(crate) struct GooseTlsOptions {
+    identity: Option<reqwest::Identity>,
+    ca_certs: Vec<reqwest::Certificate>,
+    min_tls_version: Option<reqwest::tls::Version>,
+    insecure: bool,
+}
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+impl GooseTlsOptions {
+    /// Loads any configured certificates and keys, returning an error if they're invalid.
+    pub(crate) fn load(configuration: &GooseConfiguration) -> Result<Self, GooseError> {
+        let identity =
+            if !configuration.client_cert.is_empty() || !configuration.client_key.is_empty() {
+                Some(load_identity(
+                    &configuration.client_cert,
+                    &configuration.client_key,
+                )?)
+            } else {
+                None
+            };
+        let ca_certs = if !configuration.ca_cert.is_empty() {
+            load_ca_cert(&configuration.ca_cert)?
+        } else {
+            Vec::new()
+        };
+        Ok(GooseTlsOptions {
+            identity,
+            ca_certs,
+            min_tls_version: parse_min_tls_version(&configuration.min_tls_version)?,
+            insecure: configuration.insecure,
+        })
+    }
+
+    /// Internal helper that applies the options to the client builder of a new [`GooseUser`].
+    fn configure(&self, mut builder: ClientBuilder) -> ClientBuilder {
+        if let Some(identity) = self.identity.as_ref() {
+            builder = builder.identity(identity.clone());
+        }
+        for certificate in &self.ca_certs {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        if let Some(version) = self.min_tls_version {
+            builder = builder.min_tls_version(version);
+        }
+        if self.insecure {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+        builder
+    }
+}
+
+/// Without a TLS implementation none of the TLS options can be applied.
+#[cfg(not(any(feature = "native-tls", feature = "rustls-tls")))]
+#[derive(Clone, Default)]
+pub(crate) struct GooseTlsOptions;
+#[cfg(not(any(feature = "native-tls", feature = "rustls-tls")))]
+impl GooseTlsOptions {
+    /// Returns an error if any of the TLS options are configured.
+    pub(crate) fn load(configuration: &GooseConfiguration) -> Result<Self, GooseError> {
+        for (option, value) in [
+            ("client_cert", configuration.client_cert.as_str()),
+            ("client_key", configuration.client_key.as_str()),
+            ("ca_cert", configuration.ca_cert.as_str()),
+            ("min_tls_version", configuration.min_tls_version.as_str()),
+            ("insecure", if configuration.insecure { "true" } else { "" }),
+        ] {
+            if !value.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: format!("`configuration.{}`", option),
+                    value: value.to_string(),
+                    detail: format!(
+                        "`configuration.{}` requires the `native-tls` or `rustls-tls` feature.",
+                        option
+                    ),
+                });
+            }
+        }
+        Ok(GooseTlsOptions)
+    }
+
+    /// There are no options to apply.
+    fn configure(&self, builder: ClientBuilder) -> ClientBuilder {
+        builder
+    }
+}
+
+/// Validates the TLS options, loading any configured certificates and keys.
+pub(crate) fn validate_tls(configuration: &GooseConfiguration) -> Result<(), GooseError> {
+    GooseTlsOptions::load(configuration).map(|_| ())
+}
+
+/// Internal helper that reads a file configured with one of the TLS options.
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+fn read_tls_file(option: &str, path: &str) -> Result<Vec<u8>, GooseError> {
+    std::fs::read(path).map_err(|e| GooseError::InvalidOption {
+        option: format!("`configuration.{}`", option),
+        value: path.to_string(),
+        detail: format!("`configuration.{}` must be a readable file: {}", option, e),
+    })
+}
+
+/// Loads the `--client-cert` and `--client-key` options, a PEM certificate (optionally
+/// followed by its intermediate certificates) and its PKCS#8 PEM private key.
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+pub(crate) fn load_identity(
+    client_cert: &str,
+    client_key: &str,
+) -> Result<reqwest::Identity, GooseError> {
+    // A client certificate is useless without its private key, and vice versa.
+    if client_cert.is_empty() || client_key.is_empty() {
+        let (option, value) = if client_cert.is_empty() {
+            ("client_key", client_key)
+        } else {
+            ("client_cert", client_cert)
+        };
+        return Err(GooseError::InvalidOption {
+            option: format!("`configuration.{}`", option),
+            value: value.to_string(),
+            detail:
+                "`configuration.client_cert` and `configuration.client_key` must be set together."
+                    .to_string(),
+        });
+    }
+    let certificate = read_tls_file("client_cert", client_cert)?;
+    let key = read_tls_file("client_key", client_key)?;
+
+    // Native TLS is used if both TLS features are enabled, matching reqwest.
+    #[cfg(feature = "native-tls")]
+    let identity = reqwest::Identity::from_pkcs8_pem(&certificate, &key);
+    #[cfg(not(feature = "native-tls"))]
+    let identity = {
+        // RustLS loads the certificate chain and private key from a single buffer.
+        let mut pem = certificate;
+        pem.push(b'\n');
+        pem.extend_from_slice(&key);
+        reqwest::Identity::from_pem(&pem)
+    };
+    identity.map_err(|e| GooseError::InvalidOption {
+        option: "`configuration.client_cert`".to_string(),
+        value: client_cert.to_string(),
+        detail: format!(
+            "`configuration.client_cert` and `configuration.client_key` must be a PEM certificate and PKCS#8 PEM private key: {}",
+            e
+        ),
+    })
+}
+
+/// Loads the `--ca-cert` option, a bundle of one or more PEM certificates.
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+pub(crate) fn load_ca_cert(ca_cert: &str) -> Result<Vec<reqwest::Certificate>, GooseError> {
+    let invalid = |detail: String| GooseError::InvalidOption {
+        option: "`configuration.ca_cert`".to_string(),
+        value: ca_cert.to_string(),
+        detail,
+    };
+    let certificates = reqwest::Certificate::from_pem_bundle(&read_tls_file("ca_cert", ca_cert)?)
+        .map_err(|e| {
+        invalid(format!(
+            "`configuration.ca_cert` must contain PEM certificates: {}",
+            e
+        ))
+    })?;
+    if certificates.is_empty() {
+        return Err(invalid(
+            "`configuration.ca_cert` must contain at least one PEM certificate.".to_string(),
+        ));
+    }
+    Ok(certificates)
+}
+
+/// Parses the `--min-tls-version` option, one of `1.0`, `1.1`, `1.2` or `1.3`.
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+pub(crate) fn parse_min_tls_version(
+    min_tls_version: &str,
+) -> Result<Option<reqwest::tls::Version>, GooseError> {
+    match min_tls_version.trim() {
+        "" => Ok(None),
+        "1.0" => Ok(Some(reqwest::tls::Version::TLS_1_0)),
+        "1.1" => Ok(Some(reqwest::tls::Version::TLS_1_1)),
+        "1.2" => Ok(Some(reqwest::tls::Version::TLS_1_2)),
+        "1.3" => Ok(Some(reqwest::tls::Version::TLS_1_3)),
+        _ => Err(GooseError::InvalidOption {
+            option: "`configuration.min_tls_version`".to_string(),
+            value: min_tls_version.to_string(),
+            detail: "`configuration.min_tls_version` must be one of 1.0, 1.1, 1.2 or 1.3."
+                .to_string(),
+        }),
+    }
+}
+
+/// Parses the `--proxy` option, an `http`, `https`, `socks5` or `socks5h` URL.
+pub(crate) fn parse_proxy(proxy: &str) -> Result<reqwest::Proxy, GooseError> {
+    let invalid = |detail: String| GooseError::InvalidOption {
+        option: "`configuration.proxy`".to_string(),
+        value: proxy.to_string(),
+        detail: format!(
+            "`configuration.proxy` must be a valid proxy URL: {}",
+            detail
+        ),
+    };
+    // The client only checks the scheme when connecting through the proxy. Proxies without
+    // a scheme use HTTP.
+    let scheme = proxy.split_once("://").map_or("http", |(scheme, _)| scheme);
+    if !["http", "https", "socks5", "socks5h"].contains(&scheme) {
+        return Err(invalid(format!("unsupported scheme {}", scheme)));
+    }
+    reqwest::Proxy::all(proxy).map_err(|e| invalid(e.to_string()))
+}
+
+/// Parses the `--resolve` option, a comma separated list of curl style `host:port:addr` DNS
+/// overrides, into host names and all of the socket addresses each of them resolves to.
+///
+/// The client overrides host names regardless of the port being connected to, so the port
+/// is validated but otherwise ignored: requests connect to the port of the requested URL.
+pub(crate) fn parse_resolve(resolve: &str) -> Result<Vec<(String, Vec<SocketAddr>)>, GooseError> {
+    let mut overrides: Vec<(String, Vec<SocketAddr>)> = Vec::new();
+    for entry in resolve.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let invalid = || GooseError::InvalidOption {
+            option: "`configuration.resolve`".to_string(),
+            value: entry.to_string(),
+            detail: "`configuration.resolve` must be a comma separated list of HOST:PORT:ADDR."
+                .to_string(),
+        };
+        let mut parts = entry.splitn(3, ':');
+        let (host, port, address) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(host), Some(port), Some(address)) if !host.is_empty() => (host, port, address),
+            _ => return Err(invalid()),
+        };
+        port.parse::<u16>().map_err(|_| invalid())?;
+        // IPv6 addresses may optionally be wrapped in brackets.
+        let address = address
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+            .map_err(|_| invalid())?;
+        // A port of 0 connects to the port of the requested URL.
+        let address = SocketAddr::new(address, 0);
+        // The client only keeps the last override of each host name, so group them.
+        match overrides
+            .iter_mut()
+            .find(|(h, _)| h.eq_ignore_ascii_case(host))
+        {
+            Some((_, addresses)) => addresses.push(address),
+            None => overrides.push((host.to_string(), vec![address])),
+        }
+    }
+    Ok(overrides)
+}
+
+/// Parses the `--local-address` option, a comma separated list of IP addresses.
+pub(crate) fn parse_local_address(local_address: &str) -> Result<Vec<IpAddr>, GooseError> {
+    local_address
+        .split(',')
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+        .map(|a| {
+            a.parse::<IpAddr>().map_err(|_| GooseError::InvalidOption {
+                option: "`configuration.local_address`".to_string(),
+                value: a.to_string(),
+                detail:
+                    "`configuration.local_address` must be a comma separated list of IP addresses."
+                        .to_string(),
+            })
+        })
+        .collect()
+}
+
+/// A rule grouping the paths of unnamed requests under a single name in the metrics, the
+/// request log and the HTML report.
+#[derive(Clone, Debug)]
+pub(crate) struct GoosePathRule {
+    /// Matches the paths to group.
+    pub(crate) pattern: Regex,
+    /// Replaces each match, and can refer to capture groups with `$1` or `${group}`.
+    pub(crate) name: String,
+}
+impl GoosePathRule {
+    /// Create a new path rule, returning [`GooseError::InvalidOption`] if the regular
+    /// expression is invalid.
+    pub(crate) fn new(pattern: &str, name: &str) -> Result<Self, GooseError> {
+        let pattern = Regex::new(pattern).map_err(|e| GooseError::InvalidOption {
+            option: "`configuration.path_rules`".to_string(),
+            value: pattern.to_string(),
+            detail: format!("path rules must be valid regular expressions: {}", e),
+        })?;
+        Ok(GoosePathRule {
+            pattern,
+            name: name.to_string(),
+        })
+    }
+}
+
+/// Parses the `--path-rules` option, a semicolon separated list of `regex=name` rules. The
+/// name follows the last `=`, so patterns may contain `=`.
+pub(crate) fn parse_path_rules(path_rules: &str) -> Result<Vec<GoosePathRule>, GooseError> {
+    path_rules
+        .split(';')
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(|rule| match rule.rsplit_once('=') {
+            Some((pattern, name)) if !pattern.is_empty() => GoosePathRule::new(pattern, name),
+            _ => Err(GooseError::InvalidOption {
+                option: "`configuration.path_rules`".to_string(),
+                value: rule.to_string(),
+                detail:
+                    "`configuration.path_rules` must be a semicolon separated list of REGEX=NAME."
+                        .to_string(),
+            }),
+        })
+        .collect()
+}
+
+/// Replaces numeric path segments with `{id}` and UUID path segments with `{uuid}`, leaving
+/// any query string unchanged.
+pub(crate) fn normalize_path(path: &str) -> Cow<'_, str> {
+    let (path_only, query) = match path.find('?') {
+        Some(index) => path.split_at(index),
+        None => (path, ""),
+    };
+    let mut changed = false;
+    let segments: Vec<&str> = path_only
+        .split('/')
+        .map(|segment| {
+            if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+                changed = true;
+                "{id}"
+            } else if UUID_SEGMENT.is_match(segment) {
+                changed = true;
+                "{uuid}"
+            } else {
+                segment
+            }
+        })
+        .collect();
+    if changed {
+        Cow::Owned(format!("{}{}", segments.join("/"), query))
+    } else {
+        Cow::Borrowed(path)
+    }
+}
+
+/// A cookie stored by a [`GooseUser`], returned by [`GooseUser::get_cookies`] and
+/// [`GooseUser::get_cookie`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GooseCookie {
+    /// The name of the cookie.
+    pub name: String,
+    /// The value of the cookie.
+    pub value: String,
+    /// The domain the cookie is sent to, or [`None`] if it's only sent to the host that set it.
+    pub domain: Option<String>,
+    /// The path the cookie is sent to, including all sub-paths.
+    pub path: String,
+    /// Whether the cookie is only sent over HTTPS.
+    pub secure: bool,
+    /// Whether the cookie is hidden from scripts running in a browser.
+    pub http_only: bool,
+}
+impl GooseCookie {
+    // Internal helper to copy a cookie out of the cookie store.
+    fn new(cookie: &cookie_store::Cookie) -> Self {
+        GooseCookie {
+            name: cookie.name().to_string(),
+            value: cookie.value().to_string(),
+            domain: match cookie.domain {
+                cookie_store::CookieDomain::Suffix(ref domain) => Some(domain.to_string()),
+                _ => None,
+            },
+            path: String::from(&cookie.path),
+            secure: cookie.secure().unwrap_or(false),
+            http_only: cookie.http_only().unwrap_or(false),
+        }
+    }
+}
+
+/// The cookie store of the default client of a [`GooseUser`], which unlike
+/// [`reqwest::cookie::Jar`] allows cookies to be inspected and removed.
+#[derive(Debug, Default)]
+struct GooseCookieJar(std::sync::RwLock<cookie_store::CookieStore>);
+impl CookieStore for GooseCookieJar {
+    fn set_cookies(
+        &self,
+        cookie_headers: &mut dyn Iterator<Item = &header::HeaderValue>,
+        url: &Url,
+    ) {
+        let cookies = cookie_headers.filter_map(|value| {
+            let value = str::from_utf8(value.as_bytes()).ok()?;
+            cookie::Cookie::parse(value).map(|c| c.into_owned()).ok()
+        });
+        self.0.write().unwrap().store_response_cookies(cookies, url);
+    }
+
+    fn cookies(&self, url: &Url) -> Option<header::HeaderValue> {
+        let cookies = self
+            .0
+            .read()
+            .unwrap()
+            .get_request_values(url)
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join("; ");
+        if cookies.is_empty() {
+            return None;
+        }
+        header::HeaderValue::from_str(&cookies).ok()
+    }
+}
+
+/// A WebSocket connection opened by a [`GooseUser`] with
+/// [`GooseUser::websocket_connect`].
+struct GooseWebSocket {
+    /// The WebSocket URL that was connected to.
+    url: String,
+    /// The open WebSocket connection.
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
 /// An individual user state, repeatedly running all [`Transaction`](./struct.Transaction.html)s
 /// in a specific [`Scenario`](./struct.Scenario.html).
 pub struct GooseUser {
@@ -858,6 +2506,34 @@ pub struct GooseUser {
     /// Optional per-user session data of a generic type implementing the
     /// [`GooseUserData`] trait.
     session_data: Option<Box<dyn GooseUserData>>,
+    /// Custom timers that have been started and not yet stopped, keyed by name.
+    timers: HashMap<String, Instant>,
+    /// The cookies stored by the default client, also sent when opening WebSocket connections.
+    cookie_jar: Arc<GooseCookieJar>,
+    /// Open WebSocket connections, keyed by name.
+    websockets: HashMap<String, GooseWebSocket>,
+    /// The path GraphQL operations are sent to.
+    graphql_path: String,
+    /// Whether to ask for gzip compressed responses, decompressing them once downloaded so
+    /// their compressed size can be recorded. Only enabled for the default client.
+    decompress_gzip: bool,
+    /// The network conditions emulated by this user, configured per [`Scenario`].
+    pub(crate) network_profile: Option<GooseNetworkProfile>,
+    /// Responses stored by the optional HTTP cache, keyed by URL.
+    http_cache: Option<Mutex<HashMap<String, GooseCachedResponse>>>,
+    /// How this user authenticates its requests, configured per [`Scenario`] or with
+    /// [`GooseUser::set_auth`].
+    pub(crate) auth: Option<GooseAuth>,
+    /// Rules grouping request paths under a name, configured with `--path-rules` and
+    /// [`GooseAttack::add_path_rule`](../struct.GooseAttack.html#method.add_path_rule).
+    pub(crate) path_rules: Arc<Vec<GoosePathRule>>,
+    /// The current OAuth2 access token, locked while it's being requested so concurrent
+    /// requests share a single token request.
+    auth_token: tokio::sync::Mutex<Option<GooseAuthToken>>,
+    /// The gRPC channel used by this user and the uri it connects to, created the first
+    /// time a gRPC call is made.
+    #[cfg(feature = "grpc")]
+    grpc_channel: Option<(String, tonic::transport::Channel)>,
 }
 impl GooseUser {
     /// Create a new user state.
@@ -867,25 +2543,43 @@ impl GooseUser {
         configuration: &GooseConfiguration,
         load_test_hash: u64,
     ) -> Result<Self, GooseError> {
-        trace!("new GooseUser");
+        GooseUser::new_with_client_options(
+            scenarios_index,
+            base_url,
+            configuration,
+            load_test_hash,
+            &GooseClientOptions::default(),
+            &GooseTlsOptions::load(configuration)?,
+            0,
+        )
+    }
 
-        // Either use manually configured timeout, or default.
-        let timeout = if configuration.timeout.is_some() {
-            match crate::util::get_float_from_string(configuration.timeout.clone()) {
-                Some(f) => f as u64 * 1_000,
-                None => GOOSE_REQUEST_TIMEOUT,
-            }
-        } else {
-            GOOSE_REQUEST_TIMEOUT
-        };
+    /// Create a new user state, with a client configured with the options of the
+    /// [`Scenario`] it runs and the TLS options loaded for the load test. The `user_index`
+    /// is the order in which the user was allocated, and selects its `--local-address`.
+    pub(crate) fn new_with_client_options(
+        scenarios_index: usize,
+        base_url: Url,
+        configuration: &GooseConfiguration,
+        load_test_hash: u64,
+        client_options: &GooseClientOptions,
+        tls_options: &GooseTlsOptions,
+        user_index: usize,
+    ) -> Result<Self, GooseError> {
+        trace!("new GooseUser");
 
-        let client = Client::builder()
+        let cookie_jar = Arc::new(GooseCookieJar::default());
+        let builder = Client::builder()
             .user_agent(APP_USER_AGENT)
-            .cookie_store(true)
-            .timeout(Duration::from_millis(timeout))
-            // Enable gzip unless `--no-gzip` flag is enabled.
-            .gzip(!configuration.no_gzip)
-            .build()?;
+            .cookie_provider(cookie_jar.clone())
+            .dns_resolver(Arc::new(GooseDnsResolver))
+            .connector_layer(GooseConnectLayer)
+            .timeout(request_timeout(configuration))
+            // Responses are decompressed by the GooseUser, so the compressed size is known.
+            .gzip(false);
+        let builder =
+            configure_connections(client_options.configure(builder), configuration, user_index)?;
+        let client = tls_options.configure(builder).build()?;
 
         Ok(GooseUser {
             started: Instant::now(),
@@ -906,6 +2600,19 @@ impl GooseUser {
             slept: 0,
             transaction_name: None,
             session_data: None,
+            timers: HashMap::new(),
+            cookie_jar,
+            websockets: HashMap::new(),
+            graphql_path: "graphql".to_string(),
+            // Enable gzip unless `--no-gzip` flag is enabled.
+            decompress_gzip: !configuration.no_gzip,
+            network_profile: None,
+            http_cache: None,
+            auth: None,
+            auth_token: tokio::sync::Mutex::new(None),
+            path_rules: Arc::new(Vec::new()),
+            #[cfg(feature = "grpc")]
+            grpc_channel: None,
         })
     }
 
@@ -1319,6 +3026,48 @@ impl GooseUser {
         self.request(goose_request).await
     }
 
+    /// A helper to make a `POST` request of a `multipart/form-data` body on a path and
+    /// collect relevant metrics. Automatically prepends the correct host.
+    ///
+    /// Calls to `post_multipart()` return a [`GooseResponse`](./struct.GooseResponse.html) object which
+    /// contains a copy of the request you made ([`GooseRequestMetric`](./struct.GooseRequestMetric.html)),
+    /// and the response ([`reqwest::Response`](https://docs.rs/reqwest/*/reqwest/struct.Response.html)).
+    ///
+    /// To upload a form with a different method, use [`GooseRequestBuilder::multipart`].
+    ///
+    /// # Example
+    /// POST a text field and a file.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(post_function);
+    ///
+    /// /// A very simple transaction that uploads a file.
+    /// async fn post_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let form = GooseMultipartForm::new()
+    ///         .text("title", "Notes")
+    ///         .bytes("file", "notes.txt", "text/plain", "Some notes.");
+    ///     let _goose = user.post_multipart("path/to/foo/", form).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn post_multipart(
+        &mut self,
+        path: &str,
+        form: GooseMultipartForm,
+    ) -> Result<GooseResponse, Box<TransactionError>> {
+        // POST multipart request.
+        let goose_request = GooseRequest::builder()
+            .method(GooseMethod::Post)
+            .path(path)
+            .multipart(form)
+            .build();
+
+        // Make the request and return the GooseResponse.
+        self.request(goose_request).await
+    }
+
     /// A helper to make a `HEAD` request of a path and collect relevant metrics.
     /// Automatically prepends the correct host.
     ///
@@ -1391,6 +3140,171 @@ impl GooseUser {
         self.request(goose_request).await
     }
 
+    /// A helper to make a `PUT` request of a path and collect relevant metrics.
+    /// Automatically prepends the correct host.
+    ///
+    /// Calls to `put()` return a [`GooseResponse`](./struct.GooseResponse.html) object which
+    /// contains a copy of the request you made ([`GooseRequestMetric`](./struct.GooseRequestMetric.html)),
+    /// and the response ([`reqwest::Response`](https://docs.rs/reqwest/*/reqwest/struct.Response.html)).
+    ///
+    /// If you need to set headers, change timeouts, or otherwise make use of the
+    /// [`reqwest::RequestBuilder`](https://docs.rs/reqwest/*/reqwest/struct.RequestBuilder.html)
+    /// object, refer to [`GooseUser::get_request_builder`].
+    ///
+    /// # Example
+    /// PUT an arbitrary body.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(put_function);
+    ///
+    /// /// A very simple transaction that makes a PUT request.
+    /// async fn put_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.put("path/to/foo/", "BODY BEING PUT").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn put<T: Into<reqwest::Body>>(
+        &mut self,
+        path: &str,
+        body: T,
+    ) -> Result<GooseResponse, Box<TransactionError>> {
+        // Build a Reqwest RequestBuilder object.
+        let url = self.build_url(path)?;
+        let reqwest_request_builder = self.client.put(url);
+
+        // PUT request.
+        let goose_request = GooseRequest::builder()
+            .method(GooseMethod::Put)
+            .path(path)
+            .set_request_builder(reqwest_request_builder.body(body))
+            .build();
+
+        // Make the request and return the GooseResponse.
+        self.request(goose_request).await
+    }
+
+    /// A helper to make a `PATCH` request of a path and collect relevant metrics.
+    /// Automatically prepends the correct host.
+    ///
+    /// Calls to `patch()` return a [`GooseResponse`](./struct.GooseResponse.html) object which
+    /// contains a copy of the request you made ([`GooseRequestMetric`](./struct.GooseRequestMetric.html)),
+    /// and the response ([`reqwest::Response`](https://docs.rs/reqwest/*/reqwest/struct.Response.html)).
+    ///
+    /// If you need to set headers, change timeouts, or otherwise make use of the
+    /// [`reqwest::RequestBuilder`](https://docs.rs/reqwest/*/reqwest/struct.RequestBuilder.html)
+    /// object, refer to [`GooseUser::get_request_builder`].
+    ///
+    /// # Example
+    /// PATCH an arbitrary body.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(patch_function);
+    ///
+    /// /// A very simple transaction that makes a PATCH request.
+    /// async fn patch_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.patch("path/to/foo/", "BODY OF PATCH").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn patch<T: Into<reqwest::Body>>(
+        &mut self,
+        path: &str,
+        body: T,
+    ) -> Result<GooseResponse, Box<TransactionError>> {
+        // Build a Reqwest RequestBuilder object.
+        let url = self.build_url(path)?;
+        let reqwest_request_builder = self.client.patch(url);
+
+        // PATCH request.
+        let goose_request = GooseRequest::builder()
+            .method(GooseMethod::Patch)
+            .path(path)
+            .set_request_builder(reqwest_request_builder.body(body))
+            .build();
+
+        // Make the request and return the GooseResponse.
+        self.request(goose_request).await
+    }
+
+    /// A helper to make an `OPTIONS` request of a path and collect relevant metrics.
+    /// Automatically prepends the correct host.
+    ///
+    /// To test CORS preflight requests, set the `Origin` and
+    /// `Access-Control-Request-Method` headers with [`GooseUser::get_request_builder`].
+    ///
+    /// Calls to `options()` return a [`GooseResponse`](./struct.GooseResponse.html) object which
+    /// contains a copy of the request you made ([`GooseRequestMetric`](./struct.GooseRequestMetric.html)),
+    /// and the response ([`reqwest::Response`](https://docs.rs/reqwest/*/reqwest/struct.Response.html)).
+    ///
+    /// If you need to set headers, change timeouts, or otherwise make use of the
+    /// [`reqwest::RequestBuilder`](https://docs.rs/reqwest/*/reqwest/struct.RequestBuilder.html)
+    /// object, refer to [`GooseUser::get_request_builder`].
+    ///
+    /// # Example
+    /// Make an OPTIONS request.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(options_function);
+    ///
+    /// /// A very simple transaction that makes an OPTIONS request.
+    /// async fn options_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.options("path/to/foo/").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn options(&mut self, path: &str) -> Result<GooseResponse, Box<TransactionError>> {
+        // OPTIONS request.
+        let goose_request = GooseRequest::builder()
+            .method(GooseMethod::Options)
+            .path(path)
+            .build();
+
+        // Make the request and return the GooseResponse.
+        self.request(goose_request).await
+    }
+
+    /// A helper to make an `TRACE` request of a path and collect relevant metrics.
+    /// Automatically prepends the correct host.
+    ///
+    /// Calls to `trace()` return a [`GooseResponse`](./struct.GooseResponse.html) object which
+    /// contains a copy of the request you made ([`GooseRequestMetric`](./struct.GooseRequestMetric.html)),
+    /// and the response ([`reqwest::Response`](https://docs.rs/reqwest/*/reqwest/struct.Response.html)).
+    ///
+    /// If you need to set headers, change timeouts, or otherwise make use of the
+    /// [`reqwest::RequestBuilder`](https://docs.rs/reqwest/*/reqwest/struct.RequestBuilder.html)
+    /// object, refer to [`GooseUser::get_request_builder`].
+    ///
+    /// # Example
+    /// Make an TRACE request.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(trace_function);
+    ///
+    /// /// A very simple transaction that makes an TRACE request.
+    /// async fn trace_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.trace("path/to/foo/").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn trace(&mut self, path: &str) -> Result<GooseResponse, Box<TransactionError>> {
+        // TRACE request.
+        let goose_request = GooseRequest::builder()
+            .method(GooseMethod::Trace)
+            .path(path)
+            .build();
+
+        // Make the request and return the GooseResponse.
+        self.request(goose_request).await
+    }
+
     /// Used to get a [`reqwest::RequestBuilder`] object. If no [`reqwest::RequestBuilder`] is
     /// already defined in the [`GooseRequest`] passed to [`GooseUser::request`] it will automatically
     /// invoke this function.
@@ -1440,16 +3354,8 @@ impl GooseUser {
         // Prepend the `base_url` to all relative paths.
         let url = self.build_url(path)?;
 
-        // Invoke appropriate Reqwest convenience function to generate an
-        // appropriate RequestBuilder.
-        Ok(match method {
-            GooseMethod::Delete => self.client.delete(&url),
-            GooseMethod::Get => self.client.get(&url),
-            GooseMethod::Head => self.client.head(&url),
-            GooseMethod::Patch => self.client.patch(&url),
-            GooseMethod::Post => self.client.post(&url),
-            GooseMethod::Put => self.client.put(&url),
-        })
+        // Generate a RequestBuilder for the equivalent HTTP method.
+        Ok(self.client.request(method_from_goose_method(method)?, &url))
     }
 
     /// Makes a request for the provided [`GooseRequest`] object, and if metrics are enabled
@@ -1462,6 +3368,11 @@ impl GooseUser {
     /// the request you made ([`GooseRequestMetric`](./struct.GooseRequestMetric.html)), and the
     /// response ([`reqwest::Response`](https://docs.rs/reqwest/*/reqwest/struct.Response.html)).
     ///
+    /// Unless the request is read as a [stream](./struct.GooseUser.html#method.request_stream),
+    /// the response body is downloaded before `request()` returns, so the `response_time` of
+    /// the request includes the download. The returned response is a copy holding the
+    /// downloaded body, with the status, headers and extensions of the original response.
+    ///
     /// # Example
     /// Make a GET request.
     /// ```rust
@@ -1487,326 +3398,277 @@ impl GooseUser {
     /// ```
     pub async fn request<'a>(
         &mut self,
-        mut request: GooseRequest<'_>,
+        request: GooseRequest<'_>,
     ) -> Result<GooseResponse, Box<TransactionError>> {
-        // If the RequestBuilder is already defined in the GooseRequest use it.
-        let request_builder = if request.request_builder.is_some() {
-            request.request_builder.take().unwrap()
-        // Otherwise get a new RequestBuilder.
-        } else {
-            self.get_request_builder(&request.method, request.path)?
-        };
+        let error_on_fail = request.error_on_fail;
 
-        // Determine the name for this request.
-        let request_name = self.get_request_name(&request);
+        // Make the request and collect the relevant metrics.
+        let goose_response = self.send_request(request).await?;
 
-        // If throttle-requests is enabled...
-        if self.is_throttled && self.throttle.is_some() {
-            // ...wait until there's room to add a token to the throttle channel before proceeding.
-            debug!("GooseUser: waiting on throttle");
-            // Will result in TransactionError::RequestCanceled if this fails.
-            if let Err(e) = self.throttle.clone().unwrap().send_async(true).await {
-                return Err(Box::new(e.into()));
-            }
-        };
+        self.process_response(goose_response, error_on_fail)
+    }
 
-        // Once past the throttle, the request is officially started.
+    /// Makes a batch of requests in parallel for the provided [`GooseRequest`] objects, and if
+    /// metrics are enabled captures relevant metrics for each request.
+    ///
+    /// This can be used to simulate a browser or single page application that makes several
+    /// requests at the same time, for example firing off multiple XHRs after loading a page.
+    /// All requests share the same client, and therefore the same cookies, as the
+    /// [`GooseUser`]. The batch only completes once all requests have completed.
+    ///
+    /// Calls to `request_batch()` return a [`Result`] containing a [`GooseBatchResponse`] on
+    /// success, which includes a [`GooseResponse`] for each request in the same order they
+    /// were provided, and how long it took for all requests in the batch to complete. Each
+    /// request is recorded in the metrics as it would be if made with
+    /// [`GooseUser::request`]. The time it took for the whole batch to complete is also
+    /// recorded as a custom timer (see [`GooseUser::start_timer`]) with the provided `name`,
+    /// which only succeeds if all requests in the batch succeed.
+    ///
+    /// # Example
+    /// Make three GET requests in parallel.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(dashboard);
+    ///
+    /// /// Load the dashboard, as the browser would, in parallel.
+    /// async fn dashboard(user: &mut GooseUser) -> TransactionResult {
+    ///     let requests = vec![
+    ///         GooseRequest::builder().path("api/profile").build(),
+    ///         GooseRequest::builder().path("api/messages").build(),
+    ///         GooseRequest::builder().path("api/notifications").build(),
+    ///     ];
+    ///     let batch = user.request_batch("dashboard", requests).await?;
+    ///
+    ///     // Do stuff with batch.responses here.
+    ///     assert_eq!(batch.responses.len(), 3);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn request_batch(
+        &mut self,
+        name: &str,
+        requests: Vec<GooseRequest<'_>>,
+    ) -> Result<GooseBatchResponse, Box<TransactionError>> {
         let started = Instant::now();
 
-        // Create a Reqwest Request object from the RequestBuilder.
-        let built_request = match request_builder.build() {
-            Ok(r) => r,
-            Err(e) => return Err(Box::new(e.into())),
-        };
+        // Start all requests at the same time, remembering which should fail the transaction.
+        let mut error_on_fail = Vec::with_capacity(requests.len());
+        let mut batch = Vec::with_capacity(requests.len());
+        for request in requests {
+            error_on_fail.push(request.error_on_fail);
+            batch.push(self.send_request(request));
+        }
+        let results = futures::future::join_all(batch).await;
+        let elapsed = started.elapsed().as_millis();
+        debug!(
+            "batch {}: {} requests completed in {} ms",
+            name,
+            results.len(),
+            elapsed
+        );
 
-        // Get a string version of request path for logging.
-        let path = match Url::parse(built_request.url().as_ref()) {
-            Ok(u) => u.path().to_string(),
-            Err(e) => {
-                error!("failed to parse url: {}", e);
-                "".to_string()
-            }
-        };
+        // Record how long the whole batch took.
+        let success = results.iter().all(|result| match result {
+            Ok(goose_response) => goose_response.request.success,
+            Err(_) => false,
+        });
+        self.record_timer(name, elapsed, success)?;
 
-        // Grab a copy of any headers set by this request, included in the request log
-        // and the debug log.
-        let mut headers: Vec<String> = Vec::new();
-        for header in built_request.headers() {
-            headers.push(format!("{:?}", header));
+        let mut responses = Vec::with_capacity(results.len());
+        for (result, error_on_fail) in results.into_iter().zip(error_on_fail) {
+            responses.push(self.process_response(result?, error_on_fail)?);
         }
 
-        // If enabled, grab a copy of the request body, included in the request log and
-        // the debug log.
-        let body = if self.config.request_body {
-            // Get a bytes representation of the body, if any.
-            let body_bytes = match built_request.body() {
-                Some(b) => b.as_bytes().unwrap_or(b""),
-                None => b"",
-            };
-            // Convert the bytes into a &str if valid utf8.
-            str::from_utf8(body_bytes).unwrap_or("")
-        } else {
-            ""
-        };
-
-        // Record the complete client request, included in the request log and the debug log.
-        let raw_request = GooseRawRequest::new(
-            goose_method_from_method(built_request.method().clone())?,
-            built_request.url().as_str(),
-            headers,
-            body,
-        );
+        Ok(GooseBatchResponse {
+            responses,
+            elapsed: elapsed as u64,
+        })
+    }
 
-        // Record information about the request.
-        let mut request_metric = GooseRequestMetric::new(
-            raw_request,
-            request_name,
-            self.started.elapsed().as_millis(),
+    /// Makes a request for the provided [`GooseRequest`] object and reads the streaming
+    /// response, such as a Server-Sent Events stream or a chunked response, as described by
+    /// the provided [`GooseStream`].
+    ///
+    /// [`GooseUser::request`] only measures how long it takes for the response headers to
+    /// be returned. The request made by `request_stream()` is recorded in the same way, and
+    /// then the body is read as it arrives, tracking the time to the first byte, the time to
+    /// the first event, how many events were received, the time between events and the total
+    /// duration of the stream. These are aggregated in
+    /// [`GooseMetrics::streams`](../metrics/struct.GooseMetrics.html#structfield.streams),
+    /// and included in the HTML report.
+    ///
+    /// If the stream is interrupted before it is closed, the request is marked as failed
+    /// with [`GooseUser::set_failure`].
+    ///
+    /// Streams aren't limited by the `--timeout` of the client, which applies to the whole
+    /// response including its body: the request for a stream with a
+    /// [`GooseStream::max_duration`] times out the `--timeout` after that duration, and a
+    /// stream without a maximum duration is read until the server closes it. A timeout set
+    /// on the request's own [`RequestBuilder`] is used as is.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let mut transaction = transaction!(prices);
+    ///
+    /// /// Subscribe to price updates, reading up to 10 events.
+    /// async fn prices(user: &mut GooseUser) -> TransactionResult {
+    ///     let request = GooseRequest::builder().path("prices").build();
+    ///     let stream = GooseStream::server_sent_events()
+    ///         .max_events(10)
+    ///         .max_duration(Duration::from_secs(30));
+    ///     let prices = user.request_stream(request, stream).await?;
+    ///
+    ///     // Do stuff with prices.events here.
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn request_stream(
+        &mut self,
+        mut request: GooseRequest<'_>,
+        stream: GooseStream,
+    ) -> Result<GooseStreamResponse, Box<TransactionError>> {
+        // The body is read below as it arrives, so the client's timeout would close long
+        // streams. Without a `max_duration` the stream is read until the server closes it.
+        request.stream = true;
+        request.timeout = Some(match stream.max_duration {
+            Some(max_duration) => request_timeout(&self.config).saturating_add(max_duration),
+            None => Duration::MAX,
+        });
+        let goose = self.request(request).await?;
+        let mut request = goose.request;
+
+        // Times are measured from when the request was sent, which was `response_time`
+        // milliseconds before the headers were received.
+        let headers_received = Instant::now();
+        let response_time = request.response_time;
+        let since_request = || response_time + headers_received.elapsed().as_millis() as u64;
+
+        let mut stream_metric = StreamMetric::new(
+            request.elapsed,
+            request.raw.method.clone(),
+            &request.name,
             self.weighted_users_index,
         );
+        let mut events: Vec<GooseStreamEvent> = Vec::new();
+
+        // There's no stream to read if the request failed.
+        let mut response = match goose.response {
+            Ok(response) if request.success => response,
+            _ => {
+                stream_metric.success = false;
+                stream_metric.duration = response_time;
+                self.send_stream_metric(&stream_metric)?;
+                return Ok(GooseStreamResponse {
+                    request,
+                    events,
+                    stream: stream_metric,
+                });
+            }
+        };
 
-        // Make the actual request.
-        let response = self.client.execute(built_request).await;
-        request_metric.set_response_time(started.elapsed().as_millis());
-
-        // Determine if the request suceeded or failed.
-        match &response {
-            Ok(r) => {
-                let status_code = r.status();
-                debug!("{:?}: status_code {}", &path, status_code);
-
-                // Update the request_metric object.
-                request_metric.set_status_code(Some(status_code));
-                request_metric.set_final_url(r.url().as_str());
-
-                // Check if we were expecting a specific status code.
-                if let Some(expect_status_code) = request.expect_status_code {
-                    // Record a failure if the expected status code was not returned.
-                    if status_code != expect_status_code {
-                        request_metric.success = false;
-                        request_metric.error = format!("{}: {}", status_code, request_name);
-                    }
-                // Otherwise record a failure if the returned status code was not a success.
-                } else if !status_code.is_success() {
-                    request_metric.success = false;
-                    request_metric.error = format!("{}: {}", status_code, request_name);
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut stream_error = None;
+        let mut received_bytes = 0;
+        let max_events = stream.max_events.unwrap_or(usize::MAX);
+        while events.len() < max_events {
+            let chunk = if let Some(max_duration) = stream.max_duration {
+                let remaining = max_duration.saturating_sub(Duration::from_millis(since_request()));
+                match tokio::time::timeout(remaining, response.chunk()).await {
+                    Ok(chunk) => chunk,
+                    // The stream was open for the maximum duration.
+                    Err(_) => break,
                 }
+            } else {
+                response.chunk().await
+            };
 
-                // Load test user was redirected.
-                if self.config.sticky_follow && request_metric.raw.url != request_metric.final_url {
-                    let base_url = self.base_url.to_string();
-                    // Check if the URL redirected started with the load test base_url.
-                    if !request_metric.final_url.starts_with(&base_url) {
-                        let redirected_url = match Url::parse(&request_metric.final_url) {
-                            Ok(u) => u,
-                            Err(e) => return Err(Box::new(e.into())),
-                        };
-                        let redirected_base_url =
-                            redirected_url[..url::Position::BeforePath].to_string();
-                        info!(
-                            "base_url for user {} redirected from {} to {}",
-                            self.weighted_users_index + 1,
-                            &base_url,
-                            &redirected_base_url
-                        );
-                        let _ = self.set_base_url(&redirected_base_url);
-                    }
+            let bytes = match chunk {
+                Ok(Some(bytes)) => bytes,
+                // The server closed the stream.
+                Ok(None) => break,
+                Err(e) => {
+                    stream_error = Some(e);
+                    break;
                 }
-            }
-            Err(e) => {
-                // @TODO: what can we learn from a reqwest error?
-                warn!("{:?}: {}", &path, e);
-                request_metric.success = false;
-                request_metric.set_status_code(None);
-                request_metric.error = clean_reqwest_error(e, request_name);
-            }
-        };
-
-        // If enabled, track the cadence between each time the same request is made while
-        // this GooseUser is running. If requests are blocked by the upstream server, this
-        // allows Goose to backfill the requests that should have been made based on
-        // cadence statistics.
-        request_metric.user_cadence = self
-            .coordinated_omission_mitigation(&request_metric)
-            .await?;
-
-        // Send a copy of the raw request object to the parent process if
-        // we're tracking metrics.
-        if !self.config.no_metrics {
-            self.send_request_metric_to_parent(request_metric.clone())?;
-        }
-
-        if request.error_on_fail && !request_metric.success {
-            error!("{:?} {}", &path, &request_metric.error);
-            return Err(Box::new(TransactionError::RequestFailed {
-                raw_request: request_metric,
-            }));
-        }
-
-        Ok(GooseResponse::new(request_metric, response))
-    }
-
-    /// Tracks the time it takes for the current GooseUser to loop through all Transactions
-    /// if Coordinated Omission Mitigation is enabled.
-    pub(crate) async fn update_request_cadence(&mut self, thread_number: usize) {
-        if let Some(co_mitigation) = self.config.co_mitigation.as_ref() {
-            // Return immediately if coordinated omission mitigation is disabled.
-            if co_mitigation == &GooseCoordinatedOmissionMitigation::Disabled {
-                return;
-            }
-
-            // Grab the current timestamp to calculate the difference since the last
-            // time through the loop.
-            let now = std::time::Instant::now();
-
-            // Swap out the `slept` counter, which is the total time the GooseUser slept
-            // between transactions, a potentially randomly changing value. Reset to 0 for the
-            // next loop through all Transactions.
-            self.request_cadence.delays_since_last_time = self.slept;
-            self.slept = 0;
-
-            // How much time passed since the last time this GooseUser looped through all
-            // transactions, accounting for time waiting between Transactions due to `set_wait_time`.
-            let elapsed = (now - self.request_cadence.last_time).as_millis() as u64
-                - self.request_cadence.delays_since_last_time;
+            };
 
-            // Update `minimum_cadence` if this was the fastest seen.
-            if elapsed < self.request_cadence.minimum_cadence
-                || self.request_cadence.minimum_cadence == 0
+            // Emulate the download bandwidth of this user's scenario, if configured.
+            if let Some(bandwidth) = self
+                .network_profile
+                .as_ref()
+                .and_then(|network_profile| network_profile.download_bandwidth)
             {
-                self.request_cadence.minimum_cadence = elapsed;
-            // Update `maximum_cadence` if this was the slowest seen.
-            } else if elapsed > self.request_cadence.maximum_cadence {
-                self.request_cadence.maximum_cadence = elapsed;
+                received_bytes += bytes.len();
+                throttle_transfer(headers_received, received_bytes, bandwidth).await;
             }
 
-            // Update request_cadence metrics based on the timing of the current request.
-            self.request_cadence.counter += 1;
-            self.request_cadence.total_elapsed += elapsed;
-            self.request_cadence.last_time = now;
-            self.request_cadence.average_cadence =
-                self.request_cadence.total_elapsed / self.request_cadence.counter;
+            let elapsed = since_request();
+            if stream_metric.time_to_first_byte.is_none() {
+                stream_metric.time_to_first_byte = Some(elapsed);
+            }
+            let received = if stream.server_sent_events {
+                buffer.extend(bytes.iter().filter(|byte| **byte != b'\r'));
+                parse_server_sent_events(&mut buffer, elapsed)
+            } else {
+                vec![GooseStreamEvent {
+                    elapsed,
+                    event: None,
+                    id: None,
+                    data: String::from_utf8_lossy(&bytes).to_string(),
+                }]
+            };
 
-            if self.request_cadence.counter > 3 {
-                if self.request_cadence.coordinated_omission_counter < 0 {
-                    debug!(
-                        "user {} enabled coordinated omission mitigation",
-                        thread_number
-                    );
-                    self.request_cadence.coordinated_omission_counter += 1;
+            for event in received {
+                if events.len() >= max_events {
+                    break;
                 }
-                // Calculate the expected cadence for this Transaction request.
-                let cadence = match co_mitigation {
-                    // Expected cadence is the average time between requests.
-                    GooseCoordinatedOmissionMitigation::Average => {
-                        self.request_cadence.average_cadence
-                    }
-                    // Expected cadence is the maximum time between requests.
-                    GooseCoordinatedOmissionMitigation::Maximum => {
-                        self.request_cadence.maximum_cadence
-                    }
-                    // Expected cadence is the minimum time between requests.
-                    GooseCoordinatedOmissionMitigation::Minimum => {
-                        self.request_cadence.minimum_cadence
-                    }
-                    // This is not possible as we would have exited already if coordinated
-                    // omission mitigation was disabled.
-                    GooseCoordinatedOmissionMitigation::Disabled => unreachable!(),
-                };
-                if elapsed > (cadence * 2) {
-                    debug!(
-                        "user {}: coordinated_omission_mitigation: elapsed({}) > cadence({})",
-                        thread_number, elapsed, cadence
-                    );
-                    self.request_cadence.coordinated_omission_counter += 1;
-                    self.request_cadence.coordinated_omission_mitigation = elapsed;
-                } else {
-                    self.request_cadence.coordinated_omission_mitigation = 0;
+                match events.last() {
+                    Some(previous) => stream_metric
+                        .time_between_events
+                        .push(event.elapsed - previous.elapsed),
+                    None => stream_metric.time_to_first_event = Some(event.elapsed),
                 }
-                // Always track the expected cadence.
-                self.request_cadence.user_cadence = cadence;
+                events.push(event);
             }
-        } else {
-            // Coordinated Omission Mitigation defaults to average.
-            unreachable!();
         }
-    }
-
-    /// If Coordinated Omission Mitigation is enabled, compares how long has passed since the last
-    /// loop through all Transactions by the current GooseUser. Through this mechanism, Goose is
-    /// able to detect stalls on the upstream server being load tested, backfilling requests based
-    /// on what statistically should have happened. Can be disabled with `--co-mitigation disabled`.
-    async fn coordinated_omission_mitigation(
-        &self,
-        request_metric: &GooseRequestMetric,
-    ) -> Result<u64, Box<TransactionError>> {
-        if let Some(co_mitigation) = self.config.co_mitigation.as_ref() {
-            // Return immediately if coordinated omission mitigation is disabled.
-            if co_mitigation == &GooseCoordinatedOmissionMitigation::Disabled {
-                return Ok(0);
-            }
-
-            // Generate an info level alert if this specific request took longer than the normal
-            // cadence, as that means this specific request will likely trigger Coordinated
-            // Omission Mitigation.
-            if self.request_cadence.counter > 3
-                && request_metric.response_time > self.request_cadence.user_cadence
-            {
-                let transaction_name = if let Some(transaction_name) = &self.transaction_name {
-                    format!(", transaction name: \"{}\"", transaction_name)
-                } else {
-                    "".to_string()
-                };
-                info!(
-                    "{:.3}s into goose attack: \"{} {}\" [{}] took abnormally long ({} ms){}",
-                    request_metric.elapsed as f64 / 1_000.0,
-                    request_metric.raw.method,
-                    request_metric.raw.url,
-                    request_metric.status_code,
-                    request_metric.response_time,
-                    transaction_name,
-                );
-            }
-
-            // Check if Coordinated Omission Mitigation has been triggered.
-            if self.request_cadence.coordinated_omission_mitigation > 0 {
-                // Base our coordinated omission generated request metric on the actual
-                // metric that triggered this logic.
-                let mut coordinated_omission_request_metric = request_metric.clone();
-                // Record data points specific to coordinated_omission.
-                coordinated_omission_request_metric.coordinated_omission_elapsed =
-                    self.request_cadence.coordinated_omission_mitigation;
-                // Record data points specific to coordinated_omission.
-                coordinated_omission_request_metric.user_cadence =
-                    self.request_cadence.user_cadence;
-                // Send the coordinated omission mitigation generated metrics to the parent.
-                self.send_request_metric_to_parent(coordinated_omission_request_metric)?;
-            }
-            Ok(self.request_cadence.user_cadence)
-        } else {
-            // A setting for coordinated omission mitigation is required, defaults to Average.
-            unreachable!();
+        stream_metric.events = events.len();
+        stream_metric.duration = since_request();
+        stream_metric.success = stream_error.is_none();
+        self.send_stream_metric(&stream_metric)?;
+
+        if let Some(e) = stream_error {
+            self.set_failure(
+                &format!("stream interrupted: {}", e),
+                &mut request,
+                None,
+                None,
+            )?;
         }
+
+        Ok(GooseStreamResponse {
+            request,
+            events,
+            stream: stream_metric,
+        })
     }
 
-    fn send_request_metric_to_parent(
-        &self,
-        request_metric: GooseRequestMetric,
-    ) -> TransactionResult {
-        // If requests-file is enabled, send a copy of the raw request to the logger thread.
-        if !self.config.request_log.is_empty() {
-            if let Some(logger) = self.logger.as_ref() {
-                if let Err(e) = logger.send(Some(GooseLog::Request(request_metric.clone()))) {
-                    return Err(Box::new(e.into()));
-                }
-            }
+    /// Internal helper that sends stream metrics to the parent when enabled.
+    fn send_stream_metric(&self, stream_metric: &StreamMetric) -> TransactionResult {
+        if self.config.no_metrics {
+            return Ok(());
         }
 
         // Parent is not defined when running
         // [`test_start`](../struct.GooseAttack.html#method.test_start),
         // [`test_stop`](../struct.GooseAttack.html#method.test_stop), and during testing.
         if let Some(metrics_channel) = self.metrics_channel.clone() {
-            if let Err(e) = metrics_channel.send(GooseMetric::Request(request_metric)) {
+            if let Err(e) = metrics_channel.send(GooseMetric::Stream(stream_metric.clone())) {
                 return Err(Box::new(e.into()));
             }
         }
@@ -1814,1472 +3676,5503 @@ impl GooseUser {
         Ok(())
     }
 
-    /// If `request_name` is set, unwrap and use this. Otherwise, if the Transaction has a name
-    /// set use it. Otherwise use the path.
-    fn get_request_name<'a>(&'a self, request: &'a GooseRequest) -> &'a str {
-        match request.name {
-            // If a request.name is set, unwrap and return it.
-            Some(rn) => rn,
-            None => {
-                // Otherwise determine if the current Transaction is named, and if so return it.
-                if let Some(transaction_name) = &self.transaction_name {
-                    transaction_name
-                } else {
-                    // Otherwise return a copy of the the path.
-                    request.path
-                }
-            }
-        }
+    /// Sets the path that [`GooseUser::graphql`] sends operations to, which defaults to
+    /// `graphql`. As with other requests, relative paths are appended to the
+    /// [`GooseUser::base_url`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(setup_graphql).set_on_start();
+    ///
+    /// /// Send GraphQL operations to /api/graphql.
+    /// async fn setup_graphql(user: &mut GooseUser) -> TransactionResult {
+    ///     user.set_graphql_path("/api/graphql");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_graphql_path(&mut self, path: &str) {
+        self.graphql_path = path.to_string();
     }
 
-    /// Manually mark a request as a success.
+    /// Sends a GraphQL `query` with the provided `variables`, and deserializes the returned
+    /// `data` into `T`.
     ///
-    /// Goose determines if a request was successful based on the the HTTP response status
-    /// code. By default, it uses [`reqwest::StatusCode::is_success`]. If an alternative
-    /// HTTP response code is expected, use [`GooseRequestBuilder::expect_status_code`]. If
-    /// validation requires additional logic, you can use set_success().
+    /// All GraphQL operations are sent to the same endpoint (see
+    /// [`GooseUser::set_graphql_path`]), so instead of being named by path, the request is
+    /// named by the type and name of the operation defined in the query, for example
+    /// `query GetUser` or `mutation AddToCart`. Anonymous operations are named
+    /// `query (anonymous)`. Use `()` if the operation has no variables.
     ///
-    /// A copy of your original request is returned with the response, and a mutable copy
-    /// must be included when setting a request as a success.
+    /// GraphQL servers often return errors with a `200` status code. If the response
+    /// contains an `errors` array, or is not a valid GraphQL response, the request is
+    /// marked as failed with [`GooseUser::set_failure`], and so included in the error
+    /// summary. The response, including any partial `data`, is still returned.
     ///
     /// # Example
     /// ```rust
     /// use goose::prelude::*;
+    /// use serde::Deserialize;
     ///
-    /// let mut transaction = transaction!(get_function);
+    /// #[derive(Deserialize)]
+    /// struct UserData {
+    ///     user: User,
+    /// }
     ///
-    /// /// A simple transaction that makes a GET request.
-    /// async fn get_function(user: &mut GooseUser) -> TransactionResult {
-    ///     let mut goose = user.get("404").await?;
+    /// #[derive(Deserialize)]
+    /// struct User {
+    ///     name: String,
+    /// }
     ///
-    ///     if let Ok(response) = &goose.response {
-    ///         // We expect a 404 here.
-    ///         if response.status() == 404 {
-    ///             return user.set_success(&mut goose.request);
-    ///         }
+    /// let mut transaction = transaction!(get_user);
+    ///
+    /// /// Load a user with GraphQL.
+    /// async fn get_user(user: &mut GooseUser) -> TransactionResult {
+    ///     let query = "query GetUser($id: ID!) { user(id: $id) { name } }";
+    ///     let variables = serde_json::json!({ "id": 1 });
+    ///     let goose = user.graphql::<_, UserData>(query, variables).await?;
+    ///
+    ///     if let Some(_data) = goose.data {
+    ///         // Do stuff with data.user.name here.
     ///     }
     ///
-    ///     Err(Box::new(TransactionError::RequestFailed {
-    ///         raw_request: goose.request.clone(),
-    ///     }))
+    ///     Ok(())
     /// }
-    /// ````
-    pub fn set_success(&self, request: &mut GooseRequestMetric) -> TransactionResult {
-        // Only send update if this was previously not a success.
-        if !request.success {
-            request.success = true;
-            request.update = true;
-            self.send_request_metric_to_parent(request.clone())?;
-        }
+    /// ```
+    pub async fn graphql<V, T>(
+        &mut self,
+        query: &str,
+        variables: V,
+    ) -> Result<GooseGraphqlResponse<T>, Box<TransactionError>>
+    where
+        V: Serialize,
+        T: DeserializeOwned,
+    {
+        let (operation_type, operation_name) = graphql_operation(query);
+        let name = match operation_name {
+            Some(operation_name) => format!("{} {}", operation_type, operation_name),
+            None => format!("{} (anonymous)", operation_type),
+        };
 
-        Ok(())
+        let path = self.graphql_path.clone();
+        let body = GraphqlRequestBody {
+            query,
+            variables,
+            operation_name,
+        };
+        let reqwest_request_builder = self.get_request_builder(&GooseMethod::Post, &path)?;
+        let goose_request = GooseRequest::builder()
+            .method(GooseMethod::Post)
+            .path(path.as_str())
+            .name(name.as_str())
+            .set_request_builder(reqwest_request_builder.json(&body))
+            .build();
+        let goose = self.request(goose_request).await?;
+        let mut graphql = GooseGraphqlResponse {
+            request: goose.request,
+            data: None,
+            errors: Vec::new(),
+        };
+
+        // A failed request has already been recorded.
+        let response = match goose.response {
+            Ok(response) => response,
+            Err(_) => return Ok(graphql),
+        };
+        let headers = response.headers().clone();
+        let (failure, body) = match response.text().await {
+            Ok(body) => match serde_json::from_str::<GraphqlResponseBody>(&body) {
+                Ok(parsed) => {
+                    graphql.errors = parsed.errors.unwrap_or_default();
+                    let mut failure = if graphql.errors.is_empty() {
+                        None
+                    } else {
+                        let messages: Vec<&str> = graphql
+                            .errors
+                            .iter()
+                            .map(|error| error.message.as_str())
+                            .collect();
+                        Some(format!("GraphQL error: {}", messages.join("; ")))
+                    };
+                    match parsed.data {
+                        Some(serde_json::Value::Null) | None => (),
+                        Some(data) => match serde_json::from_value(data) {
+                            Ok(data) => graphql.data = Some(data),
+                            Err(e) => {
+                                failure.get_or_insert(format!("invalid GraphQL data: {}", e));
+                            }
+                        },
+                    }
+                    (failure, body)
+                }
+                Err(e) => (Some(format!("invalid GraphQL response: {}", e)), body),
+            },
+            Err(e) => (
+                Some(format!("failed to read GraphQL response: {}", e)),
+                String::new(),
+            ),
+        };
+
+        if let Some(tag) = failure {
+            // The returned `RequestFailed` error is only needed to fail a transaction.
+            if let Err(e) =
+                self.set_failure(&tag, &mut graphql.request, Some(&headers), Some(&body))
+            {
+                if !matches!(*e, TransactionError::RequestFailed { .. }) {
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(graphql)
     }
 
-    /// Manually mark a request as a failure.
-    ///
-    /// By default, Goose will consider any response with a 2xx status code as a success.
-    /// You may require more advanced logic, in which a 2xx status code is actually a
-    /// failure. A copy of your original request is returned with the response, and a
-    /// mutable copy must be included when setting a request as a failure.
-    ///
-    /// Calls to `set_failure` must include four parameters. The first, `tag`, is an
-    /// arbitrary string identifying the reason for the failure, used when logging. The
-    /// second, `request`, is a mutable reference to the
-    /// ([`GooseRequestMetric`](./struct.GooseRequestMetric.html)) object of the request being
-    /// identified as a failure (the contained `success` field will be set to `false`,
-    /// and the `update` field will be set to `true`). The last two parameters, `header`
-    /// and `body`, are optional and used to provide more detail in logs.
+    /// Records a non-HTTP operation described by the provided [`GooseOperation`].
     ///
-    /// The value of `tag` will normally be collected into the errors summary table if
-    /// metrics are being displayed. However, if `set_failure` is called multiple times,
-    /// or is called on a request that was already an error, only the first error will
-    /// be collected.
+    /// This makes it possible to load test services that Goose can't make requests to
+    /// directly, such as Redis, raw TCP services or message queues, using another async
+    /// client from inside a [`Transaction`]. The operation is recorded in the metrics as
+    /// it would be if it was an HTTP request made with [`GooseUser::request`], including
+    /// in the response time percentiles, the error summary, the request log and the HTML
+    /// report.
     ///
-    /// This also calls [`GooseUser::log_debug`].
+    /// Returns the [`GooseRequestMetric`] that was recorded.
     ///
     /// # Example
     /// ```rust
     /// use goose::prelude::*;
+    /// use std::time::Instant;
     ///
-    /// let mut transaction = transaction!(loadtest_index_page);
+    /// let mut transaction = transaction!(session);
     ///
-    /// async fn loadtest_index_page(user: &mut GooseUser) -> TransactionResult {
-    ///     let mut goose = user.get("").await?;
+    /// /// Load a session from Redis.
+    /// async fn session(user: &mut GooseUser) -> TransactionResult {
+    ///     let started = Instant::now();
+    ///     // Use any async client here, for example the redis crate.
+    ///     let result: Result<String, String> = Ok("session".to_string());
     ///
-    ///     if let Ok(response) = goose.response {
-    ///         // We only need to check pages that returned a success status code.
-    ///         if response.status().is_success() {
-    ///             match response.text().await {
-    ///                 Ok(text) => {
-    ///                     // If the expected string doesn't exist, this page load
-    ///                     // was a failure.
-    ///                     if !text.contains("this string must exist") {
-    ///                         // As this is a named request, pass in the name not the URL
-    ///                         return user.set_failure("string missing", &mut goose.request, None, None);
-    ///                     }
-    ///                 }
-    ///                 // Empty page, this is a failure.
-    ///                 Err(_) => {
-    ///                     return user.set_failure("empty page", &mut goose.request, None, None);
-    ///                 }
-    ///             }
-    ///         }
-    ///     };
+    ///     let operation = GooseOperation::new("GET session", started.elapsed())
+    ///         .protocol("redis")
+    ///         .target("redis://127.0.0.1:6379")
+    ///         .result(&result);
+    ///     let _request = user.record_operation(operation).await?;
     ///
     ///     Ok(())
     /// }
-    /// ````
-    pub fn set_failure(
+    /// ```
+    pub async fn record_operation(
         &self,
-        tag: &str,
-        request: &mut GooseRequestMetric,
-        headers: Option<&header::HeaderMap>,
-        body: Option<&str>,
-    ) -> TransactionResult {
-        // Only send update if this was previously a success.
-        if request.success {
-            request.success = false;
-            request.update = true;
-            request.error = tag.to_string();
-            self.send_request_metric_to_parent(request.clone())?;
+        operation: GooseOperation,
+    ) -> Result<GooseRequestMetric, Box<TransactionError>> {
+        let raw_request = GooseRawRequest::new(
+            GooseMethod::Custom(operation.protocol),
+            &operation.target,
+            Vec::new(),
+            "",
+        );
+
+        // The operation started `response_time` milliseconds ago.
+        let mut request_metric = GooseRequestMetric::new(
+            raw_request,
+            &operation.name,
+            self.started
+                .elapsed()
+                .as_millis()
+                .saturating_sub(operation.response_time),
+            self.weighted_users_index,
+        );
+        request_metric.set_response_time(operation.response_time);
+        request_metric.status_code = operation.status_code;
+        if let Some(error) = operation.error {
+            request_metric.success = false;
+            request_metric.error = format!("{}: {}", error, operation.name);
         }
-        // Write failure to log, converting `&mut request` to `&request` as needed by `log_debug()`.
-        self.log_debug(tag, Some(&*request), headers, body)?;
 
-        // Print log to stdout.
-        info!("set_failure: {}", tag);
+        // Track the cadence of this operation for coordinated omission mitigation, exactly
+        // as it would be for a request.
+        request_metric.user_cadence = self
+            .coordinated_omission_mitigation(&request_metric)
+            .await?;
 
-        Err(Box::new(TransactionError::RequestFailed {
-            raw_request: request.clone(),
-        }))
+        if !self.config.no_metrics {
+            self.send_request_metric_to_parent(request_metric.clone())?;
+        }
+
+        Ok(request_metric)
     }
 
-    /// Write to [`debug_file`](../struct.GooseConfiguration.html#structfield.debug_file)
-    /// if enabled.
+    /// Opens a WebSocket connection to `path`, which can be used by this [`GooseUser`] with
+    /// the provided `name` until it is closed with [`GooseUser::websocket_close`].
     ///
-    /// This function provides a mechanism for optional debug logging when a load test
-    /// is running. This can be especially helpful when writing a load test. Each entry
-    /// must include a tag, which is an arbitrary string identifying the debug message.
-    /// It may also optionally include references to the GooseRequestMetric made, the headers
-    /// returned by the server, and the response body returned by the server,
+    /// Relative paths are appended to the [`GooseUser::base_url`], switching to the `ws` or
+    /// `wss` scheme. Cookies set by earlier requests, for example when logging in, are sent
+    /// with the opening handshake, unless the client was replaced with
+    /// [`GooseUser::set_client_builder`]. Opening a connection with the name of a connection that
+    /// is already open replaces it.
     ///
-    /// As the response body can be large, the `--no-debug-body` option (or
-    /// [`GooseDefault::NoDebugBody`](../config/enum.GooseDefault.html#variant.NoDebugBody) default)
-    /// can be set to prevent the debug log from including the response body. When this option
-    /// is enabled, the body will always show up as `null` in the debug log.
+    /// All WebSocket activity is recorded as requests with the `WS` method (see
+    /// [`GooseUser::record_operation`]), so it shows up in the request metrics, the error
+    /// summary and the request log. The time it took to connect is recorded as
+    /// `WS {name} connect`. Connecting, and all other WebSocket activity, fails if it takes
+    /// longer than the `--timeout` configured for requests (60 seconds by default).
     ///
-    /// Calls to [`GooseUser::set_failure`] automatically invoke `log_debug`.
+    /// Returns the [`GooseRequestMetric`] that was recorded, or a
+    /// [`TransactionError::WebSocket`] if the connection could not be opened.
     ///
-    /// To enable the debug log, a load test must be run with the `--debug-log-file=foo`
-    /// option set, where `foo` is either a relative or an absolute path of the log file
-    /// to create. Any existing file will be overwritten.
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
     ///
-    /// In the following example, we are logging debug messages whenever there are errors.
+    /// let mut transaction = transaction!(chat);
+    ///
+    /// /// Open a WebSocket, send a message and wait for the reply, then close it.
+    /// async fn chat(user: &mut GooseUser) -> TransactionResult {
+    ///     user.websocket_connect("chat", "/chat").await?;
+    ///     let _reply = user.websocket_request("chat", "hello").await?;
+    ///     user.websocket_close("chat").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn websocket_connect(
+        &mut self,
+        name: &str,
+        path: &str,
+    ) -> Result<GooseRequestMetric, Box<TransactionError>> {
+        // Build the WebSocket URL, switching from the http to the ws scheme. Cookies are
+        // looked up with the matching http URL.
+        let mut url = match Url::parse(&self.build_url(path)?) {
+            Ok(u) => u,
+            Err(e) => return Err(Box::new(e.into())),
+        };
+        let mut http_url = url.clone();
+        // Switching between these special schemes can't fail.
+        match url.scheme() {
+            "http" => {
+                let _ = url.set_scheme("ws");
+            }
+            "https" => {
+                let _ = url.set_scheme("wss");
+            }
+            "ws" => {
+                let _ = http_url.set_scheme("http");
+            }
+            "wss" => {
+                let _ = http_url.set_scheme("https");
+            }
+            _ => (),
+        }
+
+        // Send the same cookies and User-Agent as the default client.
+        let mut request = match url.as_str().into_client_request() {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(e.into())),
+        };
+        // The WebSocket client uses its own version of the http types.
+        use tokio_tungstenite::tungstenite::http::header as ws_header;
+        if let Some(cookies) = self.cookie_jar.cookies(&http_url) {
+            if let Ok(cookies) = ws_header::HeaderValue::from_bytes(cookies.as_bytes()) {
+                request.headers_mut().insert(ws_header::COOKIE, cookies);
+            }
+        }
+        request.headers_mut().insert(
+            ws_header::USER_AGENT,
+            ws_header::HeaderValue::from_static(APP_USER_AGENT),
+        );
+
+        let started = Instant::now();
+        let result = with_websocket_timeout(
+            request_timeout(&self.config),
+            tokio_tungstenite::connect_async(request),
+        )
+        .await;
+        let mut operation = self.websocket_operation(name, "connect", url.as_str(), started);
+        match result {
+            Ok((stream, response)) => {
+                let request_metric = self
+                    .record_operation(operation.status_code(response.status().as_u16()))
+                    .await?;
+                self.websockets.insert(
+                    name.to_string(),
+                    GooseWebSocket {
+                        url: url.to_string(),
+                        stream,
+                    },
+                );
+                Ok(request_metric)
+            }
+            Err(e) => {
+                if let tokio_tungstenite::tungstenite::Error::Http(ref response) = e {
+                    operation = operation.status_code(response.status().as_u16());
+                }
+                self.record_operation(operation.error(&e.to_string()))
+                    .await?;
+                Err(Box::new(e.into()))
+            }
+        }
+    }
+
+    /// Sends a message on the WebSocket connection opened with the provided `name`.
+    ///
+    /// The time it took to send the message is recorded as `WS {name} send`.
+    ///
+    /// Returns the [`GooseRequestMetric`] that was recorded, or a
+    /// [`TransactionError::WebSocket`] if the message could not be sent, in which case the
+    /// connection is closed.
     ///
     /// # Example
     /// ```rust
     /// use goose::prelude::*;
     ///
-    /// let mut transaction = transaction!(loadtest_index_page);
+    /// let mut transaction = transaction!(post_update);
     ///
-    /// async fn loadtest_index_page(user: &mut GooseUser) -> TransactionResult {
-    ///     let mut goose = user.get("").await?;
+    /// /// Post an update on a WebSocket opened by an earlier transaction.
+    /// async fn post_update(user: &mut GooseUser) -> TransactionResult {
+    ///     user.websocket_send("updates", r#"{"status": "online"}"#).await?;
     ///
-    ///     match goose.response {
-    ///         Ok(response) => {
-    ///             // Grab a copy of the headers so we can include them when logging errors.
-    ///             let headers = &response.headers().clone();
-    ///             // We only need to check pages that returned a success status code.
-    ///             if !response.status().is_success() {
-    ///                 match response.text().await {
-    ///                     Ok(html) => {
-    ///                         // Server returned an error code, log everything.
-    ///                         user.log_debug(
-    ///                             "error loading /",
-    ///                             Some(&goose.request),
-    ///                             Some(headers),
-    ///                             Some(&html),
-    ///                         );
-    ///                     },
-    ///                     Err(e) => {
-    ///                         // No body was returned, log everything else.
-    ///                         user.log_debug(
-    ///                             &format!("error loading /: {}", e),
-    ///                             Some(&goose.request),
-    ///                             Some(headers),
-    ///                             None,
-    ///                         );
-    ///                     }
-    ///                 }
-    ///             }
-    ///         },
-    ///         // No response from server.
-    ///         Err(e) => {
-    ///             user.log_debug(
-    ///                 "no response from server when loading /",
-    ///                 Some(&goose.request),
-    ///                 None,
-    ///                 None,
-    ///             );
-    ///         }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn websocket_send<M: Into<GooseWebSocketMessage>>(
+        &mut self,
+        name: &str,
+        message: M,
+    ) -> Result<GooseRequestMetric, Box<TransactionError>> {
+        let started = Instant::now();
+        let (url, result) = {
+            let timeout = request_timeout(&self.config);
+            let websocket = self.get_websocket(name)?;
+            let result =
+                with_websocket_timeout(timeout, websocket.stream.send(message.into())).await;
+            (websocket.url.to_string(), result)
+        };
+        let operation = self.websocket_operation(name, "send", &url, started);
+        match result {
+            Ok(_) => self.record_operation(operation).await,
+            Err(e) => Err(self.websocket_failed(name, operation, e).await),
+        }
+    }
+
+    /// Waits for the next message on the WebSocket connection opened with the provided
+    /// `name`.
+    ///
+    /// Ping and pong messages are handled automatically, and are not returned. If the
+    /// server closes the connection, the close message is returned and the connection is
+    /// closed. The time spent waiting for the message is recorded as `WS {name} receive`.
+    ///
+    /// Returns the message, or a [`TransactionError::WebSocket`] if the connection was
+    /// lost or no message was received before the `--timeout`, in which case the
+    /// connection is closed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(live_updates);
+    ///
+    /// /// Wait for a live update pushed by the server.
+    /// async fn live_updates(user: &mut GooseUser) -> TransactionResult {
+    ///     if let GooseWebSocketMessage::Text(update) = user.websocket_receive("updates").await? {
+    ///         // Do something with the update.
     ///     }
     ///
     ///     Ok(())
     /// }
-    /// ````
-    pub fn log_debug(
-        &self,
-        tag: &str,
-        request: Option<&GooseRequestMetric>,
-        headers: Option<&header::HeaderMap>,
-        body: Option<&str>,
-    ) -> TransactionResult {
-        if !self.config.debug_log.is_empty() {
-            // Logger is not defined when running
-            // [`test_start`](../struct.GooseAttack.html#method.test_start),
-            // [`test_stop`](../struct.GooseAttack.html#method.test_stop), and during testing.
-            if let Some(logger) = self.logger.clone() {
-                if self.config.no_debug_body {
-                    if let Err(e) = logger.send(Some(GooseLog::Debug(GooseDebug::new(
-                        tag, request, headers, None,
-                    )))) {
-                        return Err(Box::new(e.into()));
-                    }
-                } else if let Err(e) = logger.send(Some(GooseLog::Debug(GooseDebug::new(
-                    tag, request, headers, body,
-                )))) {
-                    return Err(Box::new(e.into()));
+    /// ```
+    pub async fn websocket_receive(
+        &mut self,
+        name: &str,
+    ) -> Result<GooseWebSocketMessage, Box<TransactionError>> {
+        let started = Instant::now();
+        let (url, result) = {
+            let timeout = request_timeout(&self.config);
+            let websocket = self.get_websocket(name)?;
+            let result =
+                with_websocket_timeout(timeout, next_websocket_message(&mut websocket.stream))
+                    .await;
+            (websocket.url.to_string(), result)
+        };
+        let operation = self.websocket_operation(name, "receive", &url, started);
+        match result {
+            Ok(message) => {
+                self.record_operation(operation).await?;
+                if message.is_close() {
+                    self.websockets.remove(name);
                 }
+                Ok(message)
             }
+            Err(e) => Err(self.websocket_failed(name, operation, e).await),
         }
-
-        Ok(())
     }
 
-    /// Manually build a
-    /// [`reqwest::Client`](https://docs.rs/reqwest/*/reqwest/struct.Client.html).
+    /// Sends a message on the WebSocket connection opened with the provided `name`, and
+    /// waits for the next message received in reply.
     ///
-    /// By default, Goose configures the following options when building a
-    /// [`reqwest::Client`](https://docs.rs/reqwest/*/reqwest/struct.Client.html):
-    ///  - reports itself as the
-    ///    [`user_agent`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.user_agent)
-    ///    requesting web pages (ie `goose/0.15.2`);
-    ///  - [stores cookies](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.cookie_store),
-    ///    generally necessary if you aim to simulate logged in users;
-    ///  - enables
-    ///    [`gzip`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.gzip) compression;
-    ///  - sets a 60 second [`timeout`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.timeout) all
-    ///    on all requests.
+    /// The round-trip latency, from sending the message until the reply is received, is
+    /// recorded as `WS {name} message`. If the server closes the connection instead of
+    /// replying, this is recorded as an error.
     ///
-    /// # Default configuration:
+    /// Returns the reply, or a [`TransactionError::WebSocket`] if no reply was received, in
+    /// which case the connection is closed.
     ///
+    /// # Example
     /// ```rust
-    /// use reqwest::Client;
-    /// use core::time::Duration;
+    /// use goose::prelude::*;
     ///
-    /// static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+    /// let mut transaction = transaction!(search);
     ///
-    /// let builder = Client::builder()
-    ///   .user_agent(APP_USER_AGENT)
-    ///   .cookie_store(true)
-    ///   .gzip(true)
-    ///   .timeout(Duration::from_secs(60));
-    /// ```
+    /// /// Search over a WebSocket opened by an earlier transaction.
+    /// async fn search(user: &mut GooseUser) -> TransactionResult {
+    ///     let _results = user.websocket_request("search", "goose").await?;
     ///
-    /// Alternatively, you can use this function to manually build a
-    /// [`reqwest::Client`](https://docs.rs/reqwest/*/reqwest/struct.Client.html).
-    /// with custom configuration. Available options are found in the
-    /// [`reqwest::ClientBuilder`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html)
-    /// documentation.
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn websocket_request<M: Into<GooseWebSocketMessage>>(
+        &mut self,
+        name: &str,
+        message: M,
+    ) -> Result<GooseWebSocketMessage, Box<TransactionError>> {
+        let started = Instant::now();
+        let (url, result) = {
+            let timeout = request_timeout(&self.config);
+            let websocket = self.get_websocket(name)?;
+            let result = with_websocket_timeout(timeout, async {
+                websocket.stream.send(message.into()).await?;
+                match next_websocket_message(&mut websocket.stream).await {
+                    // The connection was closed instead of replying.
+                    Ok(GooseWebSocketMessage::Close(_)) => {
+                        Err(tokio_tungstenite::tungstenite::Error::ConnectionClosed)
+                    }
+                    result => result,
+                }
+            })
+            .await;
+            (websocket.url.to_string(), result)
+        };
+        let operation = self.websocket_operation(name, "message", &url, started);
+        match result {
+            Ok(reply) => {
+                self.record_operation(operation).await?;
+                Ok(reply)
+            }
+            Err(e) => Err(self.websocket_failed(name, operation, e).await),
+        }
+    }
+
+    /// Closes the WebSocket connection opened with the provided `name`.
     ///
-    /// When manually building a
-    /// [`reqwest::Client`](https://docs.rs/reqwest/*/reqwest/struct.Client.html),
-    /// there are a few things to be aware of:
-    ///  - Manually building a client in [`test_start`](../struct.GooseAttack.html#method.test_start)
-    ///    will only affect requests made during test setup;
-    ///  - Manually building a client in [`test_stop`](../struct.GooseAttack.html#method.test_stop)
-    ///    will only affect requests made during test teardown;
-    ///  - A manually built client is specific to a single Goose thread -- if you are
-    ///    generating a large load test with many users, each will need to manually build their
-    ///    own client (typically you'd do this in a Transaction that is registered with
-    ///   [`Transaction::set_on_start()`] in each Scenario requiring a custom client;
-    ///  - Manually building a client will completely replace the automatically built client
-    ///    with a brand new one, so any configuration, cookies or headers set in the previously
-    ///    built client will be gone;
-    ///  - You must include all desired configuration, as you are completely replacing Goose
-    ///    defaults. For example, if you want Goose clients to store cookies, you will have to
-    ///    include
-    ///    [`.cookie_store(true)`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.cookie_store).
+    /// The time it took to close the connection is recorded as `WS {name} close`.
     ///
-    /// In the following example, the Goose client is configured with a different user agent,
-    /// sets a default header on every request, stores cookies, supports gzip compression, and
-    /// times out requests after 30 seconds.
+    /// Returns the [`GooseRequestMetric`] that was recorded, or a
+    /// [`TransactionError::WebSocket`] if the connection was not closed cleanly.
     ///
-    /// ## Example
+    /// # Example
     /// ```rust
     /// use goose::prelude::*;
-    /// use core::time::Duration;
-    ///
-    /// transaction!(setup_custom_client).set_on_start();
-    ///
-    /// async fn setup_custom_client(user: &mut GooseUser) -> TransactionResult {
-    ///     use reqwest::{Client, header};
-    ///
-    ///     // Build a custom HeaderMap to include with all requests made by this client.
-    ///     let mut headers = header::HeaderMap::new();
-    ///     headers.insert("X-Custom-Header", header::HeaderValue::from_str("custom value").unwrap());
     ///
-    ///     // Build a custom client.
-    ///     let builder = Client::builder()
-    ///         .default_headers(headers)
-    ///         .user_agent("custom user agent")
-    ///         .cookie_store(true)
-    ///         .gzip(true)
-    ///         .timeout(Duration::from_secs(30));
+    /// let mut transaction = transaction!(logout).set_on_stop();
     ///
-    ///     // Assign the custom client to this GooseUser.
-    ///     user.set_client_builder(builder).await?;
+    /// /// Close the WebSocket opened when the user logged in.
+    /// async fn logout(user: &mut GooseUser) -> TransactionResult {
+    ///     user.websocket_close("updates").await?;
     ///
     ///     Ok(())
     /// }
     /// ```
+    pub async fn websocket_close(
+        &mut self,
+        name: &str,
+    ) -> Result<GooseRequestMetric, Box<TransactionError>> {
+        let mut websocket = match self.websockets.remove(name) {
+            Some(websocket) => websocket,
+            None => {
+                return Err(Box::new(TransactionError::WebSocketNotConnected {
+                    name: name.to_string(),
+                }))
+            }
+        };
+        let started = Instant::now();
+        let result = with_websocket_timeout(request_timeout(&self.config), async {
+            websocket.stream.close(None).await?;
+            // Wait for the server to acknowledge the close.
+            while let Some(message) = websocket.stream.next().await {
+                if let Err(e) = message {
+                    if !matches!(e, tokio_tungstenite::tungstenite::Error::ConnectionClosed) {
+                        return Err(e);
+                    }
+                    break;
+                }
+            }
+            Ok(())
+        })
+        .await;
+        let operation = self.websocket_operation(name, "close", &websocket.url, started);
+        match result {
+            Ok(_) => self.record_operation(operation).await,
+            Err(e) => {
+                self.record_operation(operation.error(&e.to_string()))
+                    .await?;
+                Err(Box::new(e.into()))
+            }
+        }
+    }
+
+    /// Internal helper that returns the open WebSocket connection with the provided `name`.
+    fn get_websocket(&mut self, name: &str) -> Result<&mut GooseWebSocket, Box<TransactionError>> {
+        match self.websockets.get_mut(name) {
+            Some(websocket) => Ok(websocket),
+            None => Err(Box::new(TransactionError::WebSocketNotConnected {
+                name: name.to_string(),
+            })),
+        }
+    }
+
+    /// Internal helper that describes WebSocket activity as a [`GooseOperation`].
+    fn websocket_operation(
+        &self,
+        name: &str,
+        action: &str,
+        url: &str,
+        started: Instant,
+    ) -> GooseOperation {
+        GooseOperation::new(&format!("{} {}", name, action), started.elapsed())
+            .protocol("ws")
+            .target(url)
+    }
+
+    /// Internal helper that records a failed WebSocket operation and closes the connection,
+    /// returning the error.
+    async fn websocket_failed(
+        &mut self,
+        name: &str,
+        operation: GooseOperation,
+        error: tokio_tungstenite::tungstenite::Error,
+    ) -> Box<TransactionError> {
+        self.websockets.remove(name);
+        if let Err(e) = self
+            .record_operation(operation.error(&error.to_string()))
+            .await
+        {
+            return e;
+        }
+        Box::new(error.into())
+    }
+
+    /// Sets the endpoint this [`GooseUser`] makes gRPC calls to.
     ///
-    /// # Alternative Compression Algorithms
-    /// Reqwest also supports
-    /// [`brotli`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.brotli) and
-    /// [`deflate`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.deflate) compression.
+    /// By default gRPC calls are made to the [`GooseUser::base_url`] without TLS. The
+    /// [`tonic::transport::Endpoint`](https://docs.rs/tonic/*/tonic/transport/struct.Endpoint.html)
+    /// can be used to connect to another host, or to configure TLS and timeouts. The channel
+    /// connects the first time a call is made, and is then reused by all calls made by this
+    /// user.
     ///
-    /// To enable either, you must enable the features in your load test's `Cargo.toml`, for example:
-    /// ```text
-    /// reqwest = { version = "^0.11.4",  default-features = false, features = [
-    ///     "brotli",
-    ///     "cookies",
-    ///     "deflate",
-    ///     "gzip",
-    ///     "json",
-    /// ] }
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(setup_grpc).set_on_start();
+    ///
+    /// /// Make gRPC calls to a separate port.
+    /// async fn setup_grpc(user: &mut GooseUser) -> TransactionResult {
+    ///     let endpoint = tonic::transport::Endpoint::from_static("http://127.0.0.1:50051");
+    ///     user.set_grpc_endpoint(endpoint);
+    ///
+    ///     Ok(())
+    /// }
     /// ```
+    #[cfg(feature = "grpc")]
+    pub fn set_grpc_endpoint(&mut self, endpoint: tonic::transport::Endpoint) {
+        let uri = endpoint.uri().to_string();
+        self.grpc_channel = Some((uri, endpoint.connect_lazy()));
+    }
+
+    /// Makes a unary gRPC call to `method` of `service`, sending a single message and
+    /// returning a single message.
     ///
-    /// Once enabled, you can add `.brotli(true)` and/or `.deflate(true)` to your custom
-    /// [`reqwest::Client::builder()`], following the documentation above.
+    /// Messages are encoded with [`prost`](https://docs.rs/prost), for example using types
+    /// generated by [`tonic-build`](https://docs.rs/tonic-build). The call is recorded in
+    /// the metrics as a request with the `GRPC` method named `{service}/{method}` (see
+    /// [`GooseUser::record_operation`]), and the gRPC status code plus
+    /// [`GRPC_STATUS_CODE_OFFSET`] is recorded as its status code, where `1000` is `OK`. Calls
+    /// that return any other status are failures.
     ///
-    /// # Custom Cookies
-    /// Custom cookies can also be manually set when building a custom [`reqwest::Client`]. This requires
-    /// loading the [`GooseUser::base_url`] being load tested in order to properly build the cookie. Then
-    /// a custom [`reqwest::cookie::Jar`] is created and the custom cookie is added with
-    /// [`reqwest::cookie::Jar::add_cookie_str`]. Finally, the new cookie jar must be specified as the
-    /// [`reqwest::ClientBuilder::cookie_provider`] for the custom client.
+    /// Calls are made on a channel that is kept open by this user, see
+    /// [`GooseUser::set_grpc_endpoint`].
     ///
-    /// ## Example
+    /// # Example
     /// ```rust
-    /// use reqwest::{cookie::Jar, Client};
-    /// use std::sync::Arc;
-    ///
     /// use goose::prelude::*;
     ///
-    /// transaction!(custom_cookie_with_custom_client).set_on_start();
+    /// #[derive(Clone, PartialEq, prost::Message)]
+    /// pub struct HelloRequest {
+    ///     #[prost(string, tag = "1")]
+    ///     pub name: String,
+    /// }
     ///
-    /// async fn custom_cookie_with_custom_client(user: &mut GooseUser) -> TransactionResult {
-    ///     // Prepare the contents of a custom cookie.
-    ///     let cookie = "my-custom-cookie=custom-value";
+    /// #[derive(Clone, PartialEq, prost::Message)]
+    /// pub struct HelloReply {
+    ///     #[prost(string, tag = "1")]
+    ///     pub message: String,
+    /// }
     ///
-    ///     // Pre-load one or more cookies into a custom cookie jar to use with this client.
-    ///     let jar = Jar::default();
-    ///     jar.add_cookie_str(
-    ///         cookie,
-    ///         &user.base_url,
-    ///     );
+    /// let mut transaction = transaction!(say_hello);
     ///
-    ///     // Build a custom client.
-    ///     let builder = Client::builder()
-    ///         .user_agent("example-loadtest")
-    ///         .cookie_store(true)
-    ///         .cookie_provider(Arc::new(jar))
-    ///         .gzip(true);
+    /// /// Call the SayHello method of the Greeter service.
+    /// async fn say_hello(user: &mut GooseUser) -> TransactionResult {
+    ///     let request = HelloRequest {
+    ///         name: "Goose".to_string(),
+    ///     };
+    ///     let goose = user
+    ///         .grpc_unary::<_, HelloReply>("helloworld.Greeter", "SayHello", request)
+    ///         .await?;
     ///
-    ///     // Assign the custom client to this GooseUser.
-    ///     user.set_client_builder(builder).await?;
+    ///     if let Ok(_reply) = goose.response {
+    ///         // Do stuff with reply.message here.
+    ///     }
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn set_client_builder(
+    #[cfg(feature = "grpc")]
+    pub async fn grpc_unary<Req, Resp>(
         &mut self,
-        builder: ClientBuilder,
-    ) -> Result<(), TransactionError> {
-        self.client = builder.build()?;
+        service: &str,
+        method: &str,
+        message: Req,
+    ) -> Result<GooseGrpcResponse<Resp>, Box<TransactionError>>
+    where
+        Req: prost::Message + Send + Sync + 'static,
+        Resp: prost::Message + Default + Send + Sync + 'static,
+    {
+        let path = grpc_path(service, method)?;
+        let (uri, mut client) = self.grpc_client()?;
 
-        Ok(())
+        let started = Instant::now();
+        let response = match client.ready().await {
+            Ok(()) => client
+                .unary(
+                    tonic::Request::new(message),
+                    path.clone(),
+                    tonic::codec::ProstCodec::default(),
+                )
+                .await
+                .map(|response| response.into_inner()),
+            Err(e) => Err(tonic::Status::unavailable(e.to_string())),
+        };
+        let elapsed = started.elapsed();
+
+        let request = self
+            .record_grpc_call(service, method, &uri, &path, elapsed, &response)
+            .await?;
+
+        Ok(GooseGrpcResponse { request, response })
     }
 
-    /// Some websites use multiple domains to serve traffic, redirecting depending on
-    /// the user's roll. For this reason, Goose needs to respect a redirect of the
-    /// `base_url` and subsequent paths should be built from the redirect domain.
-    ///
-    /// For example, if the `base_url` (ie `--host`) is set to `foo.example.com` and the
-    /// load test requests `/login`, thereby loading `http://foo.example.com/login` and
-    /// this request gets redirected by the server to `http://foo-secure.example.com/`,
-    /// subsequent requests made by this user need to be against the new
-    /// `foo-secure.example.com domain`. (Further, if the `base_url` is again redirected,
-    /// such as when loading `http://foo-secure.example.com/logout`, the user should
-    /// again follow for subsequent requests, perhaps in this case back to
-    /// `foo.example.com`.)
-    ///
-    /// Load tests can also request absolute URLs, and if these URLs are redirected
-    /// it does not affect the `base_url` of the load test. For example, if
-    /// `foo.example.com` is the base url, and the load test requests
-    /// `http://bar.example.com` (a different domain) and this request gets redirected
-    /// to `http://other.example.com`, subsequent relative requests would still be made
-    /// against `foo.example.com`.
+    /// Makes a server-streaming gRPC call to `method` of `service`, sending a single message
+    /// and reading all messages streamed back until the server ends the stream.
     ///
-    /// This functionality is used internally by Goose to follow redirects of the
-    /// `base_url` when `--sticky-follow` is specified at run time, or
-    /// [`set_default`](../struct.GooseAttack.html#method.set_default)
-    /// `(`[`GooseDefault::StickyFollow`](../config/enum.GooseDefault.html#variant.StickyFollow)
-    /// `, true)` is enabled. It is also
-    /// available to be manually invoked from a load test such as in the following
-    /// example.
+    /// The call is recorded in the same way as [`GooseUser::grpc_unary`], with the response
+    /// time covering the whole stream. If the server returns an error status part way
+    /// through the stream, the call fails and the messages already received are discarded.
     ///
     /// # Example
     /// ```rust
     /// use goose::prelude::*;
-    /// use std::time::Duration;
     ///
-    /// #[tokio::main]
-    /// async fn main() -> Result<(), GooseError> {
-    ///     let _goose_metrics = GooseAttack::initialize()?
-    ///         .register_scenario(scenario!("LoadtestTransactions")
-    ///             .set_host("http://foo.example.com/")
-    ///             .set_wait_time(Duration::from_secs(0), Duration::from_secs(3))?
-    ///             .register_transaction(transaction!(transaction_foo).set_weight(10)?)
-    ///             .register_transaction(transaction!(transaction_bar))
-    ///         )
-    ///         // Set a default run time so this test runs to completion.
-    ///         .set_default(GooseDefault::RunTime, 1)?
-    ///         .execute()
-    ///         .await?;
+    /// #[derive(Clone, PartialEq, prost::Message)]
+    /// pub struct ListRequest {
+    ///     #[prost(uint32, tag = "1")]
+    ///     pub limit: u32,
+    /// }
     ///
-    ///     Ok(())
+    /// #[derive(Clone, PartialEq, prost::Message)]
+    /// pub struct Item {
+    ///     #[prost(string, tag = "1")]
+    ///     pub name: String,
     /// }
     ///
-    /// async fn transaction_foo(user: &mut GooseUser) -> TransactionResult {
-    ///     let _goose = user.get("").await?;
+    /// let mut transaction = transaction!(list_items);
     ///
-    ///     Ok(())
-    /// }
+    /// /// Stream items from the Inventory service.
+    /// async fn list_items(user: &mut GooseUser) -> TransactionResult {
+    ///     let goose = user
+    ///         .grpc_server_streaming::<_, Item>("shop.Inventory", "List", ListRequest { limit: 10 })
+    ///         .await?;
     ///
-    /// async fn transaction_bar(user: &mut GooseUser) -> TransactionResult {
-    ///     // Before this transaction runs, all requests are being made against
-    ///     // http://foo.example.com, after this transaction runs all subsequent
-    ///     // requests are made against http://bar.example.com/.
-    ///     user.set_base_url("http://bar.example.com/");
-    ///     let _goose = user.get("").await?;
+    ///     if let Ok(_items) = goose.response {
+    ///         // Do stuff with the streamed items here.
+    ///     }
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub fn set_base_url(&mut self, host: &str) -> Result<(), Box<TransactionError>> {
-        self.base_url = match Url::parse(host) {
-            Ok(u) => u,
-            Err(e) => return Err(Box::new(e.into())),
+    #[cfg(feature = "grpc")]
+    pub async fn grpc_server_streaming<Req, Resp>(
+        &mut self,
+        service: &str,
+        method: &str,
+        message: Req,
+    ) -> Result<GooseGrpcResponse<Vec<Resp>>, Box<TransactionError>>
+    where
+        Req: prost::Message + Send + Sync + 'static,
+        Resp: prost::Message + Default + Send + Sync + 'static,
+    {
+        let path = grpc_path(service, method)?;
+        let (uri, mut client) = self.grpc_client()?;
+
+        let started = Instant::now();
+        let response = match client.ready().await {
+            Ok(()) => match client
+                .server_streaming(
+                    tonic::Request::new(message),
+                    path.clone(),
+                    tonic::codec::ProstCodec::default(),
+                )
+                .await
+            {
+                Ok(response) => {
+                    let mut stream = response.into_inner();
+                    let mut messages = Vec::new();
+                    loop {
+                        match stream.message().await {
+                            Ok(Some(message)) => messages.push(message),
+                            Ok(None) => break Ok(messages),
+                            Err(status) => break Err(status),
+                        }
+                    }
+                }
+                Err(status) => Err(status),
+            },
+            Err(e) => Err(tonic::Status::unavailable(e.to_string())),
         };
-        Ok(())
-    }
-}
+        let elapsed = started.elapsed();
 
-/// Defines the HTTP requests that Goose makes.
-///
-/// Can be manually created and configured with [`GooseRequest::builder`], but it's typically
-/// more convenient to use the [`GooseUser::get`], [`GooseUser::get_named`], [`GooseUser::post`],
-/// [`GooseUser::post_form`], [`GooseUser::post_json`], [`GooseUser::head`] and
-/// [`GooseUser::delete`] helpers.
-///
-/// For complete instructions review [`GooseRequestBuilder`].
-#[derive(Debug)]
-pub struct GooseRequest<'a> {
-    // Defaults to `""`.
-    path: &'a str,
-    // Defaults to [`GooseMethod::Get`].
-    method: GooseMethod,
-    // Defaults to [`None`].
-    name: Option<&'a str>,
-    // Defaults to [`None`].
-    expect_status_code: Option<u16>,
-    // Defaults to [`false`].
-    error_on_fail: bool,
-    // Defaults to [`None`].
-    request_builder: Option<RequestBuilder>,
-}
-impl<'a> GooseRequest<'a> {
-    /// Convenience function to bring [`GooseRequestBuilder`] into scope.
-    pub fn builder() -> GooseRequestBuilder<'a> {
-        GooseRequestBuilder::new()
+        let request = self
+            .record_grpc_call(service, method, &uri, &path, elapsed, &response)
+            .await?;
+
+        Ok(GooseGrpcResponse { request, response })
     }
-}
 
-/// Used to build a [`GooseRequest`] object, necessary to make a request with Goose.
-///
-/// It's only necessary to build manually if the [`GooseUser::get`], [`GooseUser::get_named`],
-/// [`GooseUser::post`], [`GooseUser::post_form`], [`GooseUser::post_json`], [`GooseUser::head`]
-/// and [`GooseUser::delete`] helpers don't provide you with enough flexibility.
-///
-/// # Example
-/// ```rust
-/// use goose::prelude::*;
-///
-/// let mut a_transaction = transaction!(transaction_function);
-///
-/// // A simple transaction that loads a relative path.
-/// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
-///     // Manually create a GooseRequestBuilder object.
-///     let goose_request = GooseRequest::builder()
-///         // Set a relative path to request.
-///         .path("about")
-///         // Name the request in the metircs.
-///         .name("About page")
-///         // Build the GooseRequest object.
-///         .build();
-///
-///     // Make the configured request.
-///     let _goose = user.request(goose_request).await?;
-///
-///     Ok(())
-/// }
-/// ```
-pub struct GooseRequestBuilder<'a> {
-    path: &'a str,
-    method: GooseMethod,
-    name: Option<&'a str>,
-    expect_status_code: Option<u16>,
-    error_on_fail: bool,
-    request_builder: Option<RequestBuilder>,
-}
-impl<'a> GooseRequestBuilder<'a> {
-    // Internal method to build a [`GooseRequest`] from a [`GooseRequestBuilder`].
-    fn new() -> Self {
-        Self {
-            path: "",
-            method: GooseMethod::Get,
-            name: None,
-            expect_status_code: None,
-            error_on_fail: false,
-            request_builder: None,
+    /// Internal helper that returns a client for this user's gRPC channel and the uri it
+    /// connects to, creating a channel to the [`GooseUser::base_url`] if none is set yet.
+    #[cfg(feature = "grpc")]
+    fn grpc_client(
+        &mut self,
+    ) -> Result<(String, tonic::client::Grpc<tonic::transport::Channel>), Box<TransactionError>>
+    {
+        if self.grpc_channel.is_none() {
+            let endpoint = tonic::transport::Endpoint::from_shared(self.base_url.to_string())
+                .map_err(|e| Box::new(e.into()))?;
+            self.set_grpc_endpoint(endpoint);
+        }
+        match &self.grpc_channel {
+            Some((uri, channel)) => Ok((uri.clone(), tonic::client::Grpc::new(channel.clone()))),
+            None => unreachable!("gRPC channel was just created"),
         }
     }
 
-    /// Set the path to request.
-    ///
-    /// Typically is a relative path allowing Goose to append a configurable base_url.
-    ///
-    /// Defaults to `""` (the main index).
-    ///
-    /// # Example
-    /// This can be implemented in a simpler way using the [`GooseUser::get`] helper function.
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// let mut a_transaction = transaction!(transaction_function);
-    ///
-    /// // A simple transaction that loads a relative path.
-    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     // Manually create a GooseRequestBuilder object.
-    ///     let goose_request = GooseRequest::builder()
-    ///         // Set a relative path to request.
-    ///         .path("a/relative/path")
-    ///         // Build the GooseRequest object.
-    ///         .build();
-    ///
-    ///     // Make the configured request.
-    ///     let _goose = user.request(goose_request).await?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    pub fn path(mut self, path: impl Into<&'a str>) -> Self {
-        self.path = path.into();
-        self
+    /// Internal helper that records a gRPC call as a request.
+    #[cfg(feature = "grpc")]
+    async fn record_grpc_call<T>(
+        &self,
+        service: &str,
+        method: &str,
+        uri: &str,
+        path: &tonic::codegen::http::uri::PathAndQuery,
+        elapsed: Duration,
+        response: &Result<T, tonic::Status>,
+    ) -> Result<GooseRequestMetric, Box<TransactionError>> {
+        let code = match response {
+            Ok(_) => tonic::Code::Ok,
+            Err(status) => status.code(),
+        };
+        let mut operation = GooseOperation::new(&format!("{}/{}", service, method), elapsed)
+            .protocol("grpc")
+            .target(&format!("{}{}", uri.trim_end_matches('/'), path))
+            .status_code(GRPC_STATUS_CODE_OFFSET + code as u16);
+        if code != tonic::Code::Ok {
+            operation = operation.error(&format!("{:?}", code));
+        }
+        self.record_operation(operation).await
     }
 
-    /// Set the method of the request.
-    ///
-    /// Must be set to a [`GooseMethod`].
-    ///
-    /// Defaults to [`GooseMethod::Get`].
-    ///
-    /// # Example
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// let mut a_transaction = transaction!(transaction_function);
-    ///
-    /// // Make a DELETE request.
-    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     // Manually create a GooseRequestBuilder object.
-    ///     let goose_request = GooseRequest::builder()
-    ///         // Set a relative path to request.
-    ///         .path("path/to/delete")
-    ///         // Set the method to DELETE.
-    ///         .method(GooseMethod::Delete)
-    ///         // Build the GooseRequest object.
-    ///         .build();
-    ///
-    ///     // Make the configured DELETE request.
-    ///     let _goose = user.request(goose_request).await?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    pub fn method(mut self, method: GooseMethod) -> Self {
-        self.method = method;
-        self
-    }
+    /// Internal helper that makes the request for the provided [`GooseRequest`] object, and
+    /// if enabled loads the static resources embedded in the returned HTML page.
+    async fn send_request(
+        &self,
+        mut request: GooseRequest<'_>,
+    ) -> Result<GooseResponse, Box<TransactionError>> {
+        let resources = request.resources.take();
 
-    /// Set a name for the request, affecting how it shows up in metrics.
-    ///
-    /// Must be set to a [`GooseMethod`].
-    ///
-    /// Defaults to [`GooseMethod::Get`].
-    ///
-    /// # Example
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// let mut a_transaction = transaction!(transaction_function);
-    ///
-    /// // Make a named request.
-    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     // Manually create a GooseRequestBuilder object.
-    ///     let goose_request = GooseRequest::builder()
-    ///         // Set a relative path to request.
-    ///         .path("path/to/request")
-    ///         // Name the request in the metrics.
-    ///         .name("custom name")
-    ///         // Build the GooseRequest object.
-    ///         .build();
-    ///
-    ///     // Make the configured request.
-    ///     let _goose = user.request(goose_request).await?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    pub fn name(mut self, name: impl Into<&'a str>) -> Self {
-        self.name = Some(name.into());
-        self
-    }
+        let goose_response = self.execute_request(request).await?;
 
-    /// Manually configure the expected HTTP response status code.
-    ///
-    /// Defaults to [`reqwest::StatusCode::is_success`].
-    ///
-    /// # Example
-    /// Intentionally request a 404 page, and do not trigger an error.
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// let mut a_transaction = transaction!(transaction_function);
-    ///
-    /// // Make a named request.
-    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     // Manually create a GooseRequestBuilder object.
-    ///     let goose_request = GooseRequest::builder()
-    ///         // Set a relative path to request.
-    ///         .path("no/such/path")
-    ///         // Tell Goose to expect a 404 HTTP response status code.
-    ///         .expect_status_code(404)
-    ///         // Build the GooseRequest object.
-    ///         .build();
-    ///
-    ///     // Make the configured request.
-    ///     let _goose = user.request(goose_request).await?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    pub fn expect_status_code(mut self, status_code: u16) -> Self {
-        self.expect_status_code = Some(status_code);
-        self
+        // If enabled, load the static resources embedded in a successfully loaded HTML page.
+        match resources {
+            Some(resources) if goose_response.request.success => {
+                Ok(self.load_resources(goose_response, &resources).await)
+            }
+            _ => Ok(goose_response),
+        }
     }
 
-    /// Configure whether the request should return on error when it
-    /// fails
-    ///
-    /// Defaults to [`false`].
-    ///
-    /// # Example
-    /// Intentionally request a 404 page, and do not trigger an error.
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// let mut a_transaction = transaction!(transaction_function);
-    ///
-    /// // Make a named request.
-    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     // Manually create a GooseRequestBuilder object.
-    ///     let goose_request = GooseRequest::builder()
-    ///         // Set a relative path to request.
-    ///         .path("no/such/path")
-    ///         // Tell Goose to expect a 404 HTTP response status code.
-    ///         .expect_status_code(404)
-    ///         // Tell Goose to return an error if the status code is
-    ///         // not a 404
-    ///         .error_on_fail()
-    ///         // Build the GooseRequest object.
-    ///         .build();
-    ///
-    ///     // Make the configured request.
-    ///     let _goose = user.request(goose_request).await?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    pub fn error_on_fail(mut self) -> Self {
-        self.error_on_fail = true;
-        self
+    /// Internal helper that processes the response to a request, following redirects to
+    /// other domains if `--sticky-follow` is enabled and optionally returning an error if
+    /// the request failed.
+    fn process_response(
+        &mut self,
+        goose_response: GooseResponse,
+        error_on_fail: bool,
+    ) -> Result<GooseResponse, Box<TransactionError>> {
+        // Load test user was redirected.
+        if self.config.sticky_follow && goose_response.request.redirected {
+            let base_url = self.base_url.to_string();
+            // Check if the URL redirected started with the load test base_url.
+            if !goose_response.request.final_url.starts_with(&base_url) {
+                let redirected_url = match Url::parse(&goose_response.request.final_url) {
+                    Ok(u) => u,
+                    Err(e) => return Err(Box::new(e.into())),
+                };
+                let redirected_base_url = redirected_url[..url::Position::BeforePath].to_string();
+                info!(
+                    "base_url for user {} redirected from {} to {}",
+                    self.weighted_users_index + 1,
+                    &base_url,
+                    &redirected_base_url
+                );
+                let _ = self.set_base_url(&redirected_base_url);
+            }
+        }
+
+        if error_on_fail && !goose_response.request.success {
+            error!(
+                "{:?} {}",
+                &goose_response.request.raw.url, &goose_response.request.error
+            );
+            return Err(Box::new(TransactionError::RequestFailed {
+                raw_request: goose_response.request,
+            }));
+        }
+
+        Ok(goose_response)
     }
 
-    /// Manually create the [`reqwest::RequestBuilder`] used to make a request.
-    ///
-    /// # Example
-    /// Manually create a `RequestBuilder` in order to set a timeout.
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// let mut a_transaction = transaction!(transaction_function);
-    ///
-    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     use std::time::Duration;
-    ///
-    ///     // Manually interact with the Reqwest RequestBuilder object.
-    ///     let request_builder = user.get_request_builder(&GooseMethod::Get, "no/such/path")?
-    ///         // Configure the request to timeout if it takes longer than 500 milliseconds.
-    ///         .timeout(Duration::from_millis(500));
-    ///
-    ///     // Manually build a GooseRequest in order to set our custom RequestBuilder.
-    ///     let goose_request = GooseRequest::builder()
-    ///         // Manually add our custom RequestBuilder object.
-    ///         .set_request_builder(request_builder)
-    ///         // Turn the GooseRequestBuilder object into a GooseRequest.
-    ///         .build();
-    ///
-    ///     // Finally make the actual request with our custom GooseRequest object.
-    ///     let _goose = user.request(goose_request).await?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    pub fn set_request_builder(mut self, request_builder: RequestBuilder) -> Self {
-        self.request_builder = Some(request_builder);
-        self
-    }
+    /// Internal helper that makes the request for the provided [`GooseRequest`] object, and if
+    /// metrics are enabled captures relevant metrics. Only requires a shared reference to the
+    /// [`GooseUser`] so multiple requests can be made concurrently.
+    async fn execute_request(
+        &self,
+        mut request: GooseRequest<'_>,
+    ) -> Result<GooseResponse, Box<TransactionError>> {
+        // If the RequestBuilder is already defined in the GooseRequest use it.
+        let mut request_builder = if request.request_builder.is_some() {
+            request.request_builder.take().unwrap()
+        // Otherwise get a new RequestBuilder.
+        } else {
+            self.get_request_builder(&request.method, request.path)?
+        };
 
-    /// Build the [`GooseRequest`] object which is then passed to [`GooseUser::request`].
-    ///
-    /// # Example
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// // Build the default "GET /".
-    /// let goose_request = GooseRequest::builder().build();
-    /// ```
-    pub fn build(self) -> GooseRequest<'a> {
-        let Self {
-            path,
-            method,
-            name,
-            expect_status_code,
-            error_on_fail,
-            request_builder,
-        } = self;
-        GooseRequest {
-            path,
-            method,
-            name,
-            expect_status_code,
-            error_on_fail,
-            request_builder,
+        // Send the multipart form, if any, describing its parts in logs instead of the body.
+        let mut body_description = None;
+        if let Some(form) = request.multipart.take() {
+            body_description = Some(form.describe());
+            request_builder = match form.apply(request_builder) {
+                Ok(r) => r,
+                Err(e) => return Err(Box::new(e.into())),
+            };
         }
-    }
-}
 
-/// Remove path from Reqwest error to avoid having a lot of distincts error
-/// when path parameters are used.
-fn clean_reqwest_error(e: &reqwest::Error, request_name: &str) -> String {
-    let kind = if e.is_builder() {
-        "builder error"
-    } else if e.is_request() {
-        "error sending request"
-    } else if e.is_body() {
-        "request or response body error"
-    } else if e.is_decode() {
-        "error decoding response body"
-    } else if e.is_redirect() {
-        "error following redirect"
-    } else {
-        "Http status"
-    };
+        // Determine the name for this request.
+        let request_name = self.get_request_name(&request);
 
-    if let Some(ref e) = std::error::Error::source(e) {
-        format!("{} {}: {}", kind, request_name, e)
-    } else {
-        format!("{} {}", kind, request_name)
-    }
-}
+        // Create a Reqwest Request object from the RequestBuilder.
+        let mut built_request = match request_builder.build() {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(e.into())),
+        };
 
-/// A helper to determine which host should be prepended to relative load test
-/// paths in this Scenario.
-///
-/// The first of these defined will be returned as the prepended host:
-///  1. `--host` (host specified on the command line when running load test)
-///  2. [`Scenario`](./struct.Scenario.html)`.host` (default host defined
-///     for the current scenario)
-///  3. [`GooseDefault::Host`](../config/enum.GooseDefault.html#variant.Host) (default
-///     host defined for the current load test)
-pub fn get_base_url(
-    config_host: Option<String>,
-    scenario_host: Option<String>,
-    default_host: Option<String>,
-) -> Result<Url, GooseError> {
-    // If the `--host` CLI option is set, build the URL with it.
-    match config_host {
-        Some(host) => Ok(
-            Url::parse(&host).map_err(|parse_error| GooseError::InvalidHost {
-                host,
-                detail: "There was a failure parsing the host specified with --host.".to_string(),
-                parse_error,
-            })?,
-        ),
-        None => {
-            match scenario_host {
-                // Otherwise, if `Scenario.host` is defined, usee this
-                Some(host) => {
-                    Ok(
-                        Url::parse(&host).map_err(|parse_error| GooseError::InvalidHost {
-                            host,
-                            detail: "There was a failure parsing the host specified with the Scenario.set_host() function.".to_string(),
-                            parse_error,
-                        })?,
-                    )
-                }
-                // Otherwise, use global `GooseAttack.host`. `unwrap` okay as host validation was done at startup.
-                None => {
-                    // Host is required, if we get here it's safe to unwrap this variable.
-                    let default_host = default_host.unwrap();
-                    Ok(
-                        Url::parse(&default_host).map_err(|parse_error| GooseError::InvalidHost {
-                            host: default_host.to_string(),
-                            detail: "There was a failure parsing the host specified globally with the GooseAttack.set_default() function.".to_string(),
-                            parse_error,
-                        })?,
-                    )
-                }
+        // Override the timeout of the client, unless the request sets its own timeout.
+        if let (Some(timeout), None) = (request.timeout, built_request.timeout()) {
+            *built_request.timeout_mut() = Some(timeout);
+        }
+
+        // Authenticate the request, if configured.
+        self.authorize(&mut built_request).await?;
+
+        // If the HTTP cache is enabled, look for a stored copy of the response.
+        let cache_key = self.http_cache_key(&built_request, request.stream);
+        let cached_response = cache_key
+            .as_ref()
+            .and_then(|key| self.get_cached_response(key));
+        let cache_hit =
+            matches!(&cached_response, Some(cached_response) if cached_response.is_fresh());
+
+        // If throttle-requests is enabled and the request has to be sent to the server...
+        if !cache_hit && self.is_throttled && self.throttle.is_some() {
+            // ...wait until there's room to add a token to the throttle channel before proceeding.
+            debug!("GooseUser: waiting on throttle");
+            // Will result in TransactionError::RequestCanceled if this fails.
+            if let Err(e) = self.throttle.clone().unwrap().send_async(true).await {
+                return Err(Box::new(e.into()));
+            }
+        };
+
+        // Once past the throttle, the request is officially started.
+        let started = Instant::now();
+
+        // Ask the server to confirm a stale copy of the response is still valid.
+        if let (Some(cached_response), false) = (cached_response.as_ref(), cache_hit) {
+            for (name, value) in cached_response.validators() {
+                built_request.headers_mut().insert(name, value);
             }
         }
-    }
-}
 
-/// The function type of a goose transaction function.
-pub type TransactionFunction = Arc<
-    dyn for<'r> Fn(
-            &'r mut GooseUser,
-        ) -> Pin<Box<dyn Future<Output = TransactionResult> + Send + 'r>>
-        + Send
-        + Sync,
->;
+        // Ask for a gzip compressed response, unless it is read as a stream.
+        let decompress = self.decompress_gzip && !request.stream;
+        if decompress {
+            built_request
+                .headers_mut()
+                .entry(header::ACCEPT_ENCODING)
+                .or_insert(header::HeaderValue::from_static("gzip"));
+        }
 
-/// An individual transaction within a [`Scenario`](./struct.Scenario.html).
-#[derive(Clone)]
-pub struct Transaction {
-    /// An index into [`Scenario`](./struct.Scenario.html)`.transaction`, indicating which
-    /// transaction this is.
-    pub transactions_index: usize,
-    /// An optional name for the transaction, used when displaying metrics.
-    pub name: String,
-    /// An integer value that controls the frequency that this transaction will be run.
-    pub weight: usize,
-    /// An integer value that controls when this transaction runs compared to other transactions in the same
-    /// [`Scenario`](./struct.Scenario.html).
-    pub sequence: usize,
-    /// A flag indicating that this transaction runs when the user starts.
-    pub on_start: bool,
-    /// A flag indicating that this transaction runs when the user stops.
-    pub on_stop: bool,
-    /// A required function that is executed each time this transaction runs.
-    pub function: TransactionFunction,
-}
-impl Transaction {
-    pub fn new(function: TransactionFunction) -> Self {
-        trace!("new transaction");
-        Transaction {
-            transactions_index: usize::max_value(),
-            name: "".to_string(),
-            weight: 1,
-            sequence: 0,
-            on_start: false,
-            on_stop: false,
-            function,
+        // Get a string version of request path for logging.
+        let path = match Url::parse(built_request.url().as_ref()) {
+            Ok(u) => u.path().to_string(),
+            Err(e) => {
+                error!("failed to parse url: {}", e);
+                "".to_string()
+            }
+        };
+
+        // Grab a copy of any headers set by this request, included in the request log
+        // and the debug log.
+        let mut headers: Vec<String> = Vec::new();
+        for header in built_request.headers() {
+            headers.push(format!("{:?}", header));
         }
-    }
 
-    /// Set an optional name for the transaction, used when displaying metrics.
-    ///
-    /// Individual requests can also be named using [`GooseRequestBuilder`], or for GET
-    /// requests with the [`GooseUser::get_named`] helper.
-    ///
-    /// # Example
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// transaction!(my_transaction_function).set_name("foo");
-    ///
-    /// async fn my_transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     let _goose = user.get("").await?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    pub fn set_name(mut self, name: &str) -> Self {
-        trace!("[{}] set_name: {}", self.transactions_index, self.name);
-        self.name = name.to_string();
-        self
-    }
+        // Get a bytes representation of the body, if any. Streamed bodies can't be read, so
+        // their size is only known if they set a `Content-Length`, as multipart forms do.
+        let body_bytes = match built_request.body() {
+            Some(b) => b.as_bytes().unwrap_or(b""),
+            None => b"",
+        };
+        let request_body_size = match built_request.body().and_then(|b| b.as_bytes()) {
+            Some(b) => b.len() as u64,
+            None => built_request
+                .headers()
+                .get(header::CONTENT_LENGTH)
+                .and_then(|length| length.to_str().ok()?.parse().ok())
+                .unwrap_or(0),
+        };
 
-    /// Set an optional flag indicating that this transaction should be run when
-    /// a user first starts. This could be used to log the user in, and
-    /// so all subsequent transaction are done as a logged in user. A transaction
-    /// with this flag set will only run at start time (and optionally at stop
-    /// time as well, if that flag is also set).
-    ///
-    /// On-start transactions can be sequenced and weighted. Sequences allow
-    /// multiple on-start transactions to run in a controlled order. Weights allow
-    /// on-start transactions to run multiple times when a user starts.
-    ///
-    /// # Example
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// transaction!(my_on_start_function).set_on_start();
-    ///
-    /// async fn my_on_start_function(user: &mut GooseUser) -> TransactionResult {
-    ///     let _goose = user.get("").await?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    pub fn set_on_start(mut self) -> Self {
-        trace!(
-            "{} [{}] set_on_start transaction",
-            self.name,
-            self.transactions_index
-        );
-        self.on_start = true;
-        self
-    }
+        // If enabled, grab a copy of the request body, included in the request log and
+        // the debug log.
+        let body = if self.config.request_body {
+            match body_description.as_deref() {
+                Some(description) => description,
+                // Convert the bytes into a &str if valid utf8.
+                None => str::from_utf8(body_bytes).unwrap_or(""),
+            }
+        } else {
+            ""
+        };
 
-    /// Set an optional flag indicating that this transaction should be run when
-    /// a user stops. This could be used to log a user out when the user
-    /// finishes its load test. A transaction with this flag set will only run at
-    /// stop time (and optionally at start time as well, if that flag is
-    /// also set).
-    ///
-    /// On-stop transactions can be sequenced and weighted. Sequences allow
-    /// multiple on-stop transactions to run in a controlled order. Weights allow
-    /// on-stop transactions to run multiple times when a user stops.
-    ///
-    /// # Example
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// transaction!(my_on_stop_function).set_on_stop();
-    ///
-    /// async fn my_on_stop_function(user: &mut GooseUser) -> TransactionResult {
-    ///     let _goose = user.get("").await?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    pub fn set_on_stop(mut self) -> Self {
-        trace!(
-            "{} [{}] set_on_stop transaction",
-            self.name,
-            self.transactions_index
+        // Record the complete client request, included in the request log and the debug log.
+        let raw_request = GooseRawRequest::new(
+            goose_method_from_method(built_request.method().clone()),
+            built_request.url().as_str(),
+            headers,
+            body,
         );
-        self.on_stop = true;
-        self
-    }
 
-    /// Sets a weight on an individual transaction. The larger the value of weight, the more often it will be run
-    /// in the Scenario. For example, if one transaction has a weight of 3 and another transaction has a weight of
-    /// 1, the first transaction will run 3 times as often.
-    ///
-    /// # Example
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// #[tokio::main]
-    /// async fn main() -> Result<(), GooseError> {
-    ///     transaction!(transaction_function).set_weight(3)?;
-    ///
-    ///     Ok(())
-    /// }
-    ///
-    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     let _goose = user.get("").await?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    pub fn set_weight(mut self, weight: usize) -> Result<Self, GooseError> {
-        trace!(
-            "{} [{}] set_weight: {}",
-            self.name,
-            self.transactions_index,
-            weight
+        // Record information about the request.
+        let mut request_metric = GooseRequestMetric::new(
+            raw_request,
+            &request_name,
+            self.started.elapsed().as_millis(),
+            self.weighted_users_index,
         );
-        if weight == 0 {
-            return Err(GooseError::InvalidWeight {
-                weight,
-                detail: "Weight must be set to at least 1.".to_string(),
+        request_metric.request_body_size = request_body_size;
+
+        let mut revalidated = false;
+        let mut decode_error = None;
+        let response = if cache_hit {
+            // Fresh responses are loaded from the cache without contacting the server.
+            request_metric.cache_hit = true;
+            Ok(cached_response.as_ref().unwrap().to_response())
+        } else {
+            // Emulate the network conditions of this user's scenario, if configured.
+            let download_bandwidth = match self.network_profile.as_ref() {
+                Some(network_profile) => {
+                    request_metric.network_profile = Some(network_profile.name.to_string());
+                    tokio::time::sleep(network_profile.request_delay(request_body_size)).await;
+                    network_profile.download_bandwidth
+                }
+                None => None,
+            };
+
+            // Make the actual request, recording how long any DNS lookups and new connections
+            // it triggers take.
+            let dns_time = Arc::new(Mutex::new(None));
+            let connect_time = Arc::new(Mutex::new(None));
+            let response = DNS_TIME
+                .scope(
+                    dns_time.clone(),
+                    CONNECT_TIME.scope(connect_time.clone(), self.client.execute(built_request)),
+                )
+                .await;
+            request_metric.timings.dns = *dns_time.lock().unwrap();
+            // The connector resolves the host name before connecting, so exclude the lookup.
+            request_metric.timings.connect = connect_time
+                .lock()
+                .unwrap()
+                .map(|connect| connect.saturating_sub(request_metric.timings.dns.unwrap_or(0)));
+
+            // Download the body so its download time is included in the response time, unless
+            // it is being read as a stream.
+            match response {
+                Ok(r) => {
+                    request_metric.timings.time_to_first_byte =
+                        Some(started.elapsed().as_millis() as u64);
+                    if request.stream {
+                        Ok(r)
+                    } else {
+                        let download_started = Instant::now();
+                        let response = match read_response_bytes(r, download_bandwidth).await {
+                            Ok((body, mut copy)) => {
+                                // Record the size of the body as it was received.
+                                request_metric.response_body_size = body.len() as u64;
+                                let decompressed = if decompress {
+                                    decompress_response(&body, &mut copy).await
+                                } else {
+                                    None
+                                };
+                                let (body, copy) = match decompressed {
+                                    Some(Ok(decompressed)) => decompressed,
+                                    // Bodies that can't be decoded are returned as received,
+                                    // and the request is recorded as failed.
+                                    Some(Err(e)) => {
+                                        decode_error = Some(e);
+                                        (body, copy)
+                                    }
+                                    None => (body, copy),
+                                };
+                                // Update the HTTP cache, if enabled.
+                                match cache_key.as_ref() {
+                                    Some(key) if decode_error.is_none() => {
+                                        let (copy, from_cache) = self.update_http_cache(
+                                            key,
+                                            cached_response,
+                                            copy,
+                                            body.as_ref(),
+                                        );
+                                        revalidated = from_cache;
+                                        Ok(copy)
+                                    }
+                                    _ => Ok(copy),
+                                }
+                            }
+                            Err(e) => Err(e),
+                        };
+                        request_metric.timings.download =
+                            Some(download_started.elapsed().as_millis() as u64);
+                        response
+                    }
+                }
+                Err(e) => Err(e),
+            }
+        };
+        request_metric.set_response_time(started.elapsed().as_millis());
+
+        // Determine if the request suceeded or failed.
+        match &response {
+            Ok(r) => {
+                let status_code = r.status();
+                debug!("{:?}: status_code {}", &path, status_code);
+
+                // Update the request_metric object, recording the status code returned by
+                // the server when it confirmed a cached response is still valid.
+                if revalidated {
+                    request_metric.set_status_code(Some(reqwest::StatusCode::NOT_MODIFIED));
+                } else {
+                    request_metric.set_status_code(Some(status_code));
+                }
+                request_metric.set_final_url(r.url().as_str());
+                // Responses loaded from the cache weren't received over a connection.
+                if !request_metric.cache_hit {
+                    request_metric.http_version = Some(format!("{:?}", r.version()));
+                }
+
+                // Check if we were expecting a specific status code.
+                if let Some(expect_status_code) = request.expect_status_code {
+                    // Record a failure if the expected status code was not returned.
+                    if status_code != expect_status_code {
+                        request_metric.success = false;
+                        request_metric.error = format!("{}: {}", status_code, request_name);
+                    }
+                // Otherwise record a failure if the returned status code was not a success.
+                } else if !status_code.is_success() {
+                    request_metric.success = false;
+                    request_metric.error = format!("{}: {}", status_code, request_name);
+                }
+
+                // Record a failure if the response body couldn't be decompressed.
+                if let Some(e) = decode_error {
+                    warn!("{:?}: {}", &path, e);
+                    request_metric.success = false;
+                    request_metric.error =
+                        format!("error decoding response body {}: {}", request_name, e);
+                }
+            }
+            Err(e) => {
+                // @TODO: what can we learn from a reqwest error?
+                warn!("{:?}: {}", &path, e);
+                request_metric.success = false;
+                request_metric.set_status_code(None);
+                request_metric.error = clean_reqwest_error(e, &request_name);
+            }
+        };
+
+        // If enabled, track the cadence between each time the same request is made while
+        // this GooseUser is running. If requests are blocked by the upstream server, this
+        // allows Goose to backfill the requests that should have been made based on
+        // cadence statistics. Cache hits never reach the server, so can't be blocked.
+        if !request_metric.cache_hit {
+            request_metric.user_cadence = self
+                .coordinated_omission_mitigation(&request_metric)
+                .await?;
+        }
+
+        // Send a copy of the raw request object to the parent process if
+        // we're tracking metrics.
+        if !self.config.no_metrics {
+            self.send_request_metric_to_parent(request_metric.clone())?;
+        }
+
+        Ok(GooseResponse::new(request_metric, response))
+    }
+
+    /// Internal helper that loads the static resources embedded in the HTML page returned by
+    /// a request, as configured with [`GooseRequestBuilder::load_resources`]. Returns a copy of
+    /// the page response so the body can still be read by the load test.
+    async fn load_resources(
+        &self,
+        goose_response: GooseResponse,
+        resources: &GooseResources,
+    ) -> GooseResponse {
+        let GooseResponse { request, response } = goose_response;
+        let response = match response {
+            Ok(r) => r,
+            Err(e) => return GooseResponse::new(request, Err(e)),
+        };
+
+        // Only HTML pages have embedded resources.
+        let is_html = match response.headers().get(header::CONTENT_TYPE) {
+            Some(content_type) => content_type.to_str().unwrap_or("").contains("html"),
+            None => false,
+        };
+        if !is_html {
+            return GooseResponse::new(request, Ok(response));
+        }
+
+        // Read the page so it can be parsed.
+        let page_url = response.url().clone();
+        let (html, response) = match read_response_body(response).await {
+            Ok(r) => r,
+            Err(e) => return GooseResponse::new(request, Err(e)),
+        };
+
+        // Load all resources concurrently, limiting how many connections are opened to each
+        // host, and naming them after the parent page in the metrics.
+        let mut hosts: HashMap<String, Arc<Semaphore>> = HashMap::new();
+        let mut loads = Vec::new();
+        for (url, kind) in resources.parse(&html, &page_url) {
+            let host = hosts
+                .entry(url[..url::Position::BeforePath].to_string())
+                .or_insert_with(|| Arc::new(Semaphore::new(resources.connections_per_host)))
+                .clone();
+            let name = format!("{} > {}", request.name, kind);
+            loads.push(async move {
+                let _connection = host.acquire().await;
+                self.load_resource(url, name).await
             });
         }
-        self.weight = weight;
+        futures::future::join_all(loads).await;
 
-        Ok(self)
+        GooseResponse::new(request, Ok(response))
     }
 
-    /// Defines the sequence value of an individual transactions. Transactions are run in order of their
-    /// sequence value, so a transaction with a sequence value of 1 will run before a transaction with a
-    /// sequence value of 2. Transactions with no sequence value (or a sequence value of 0) will run last,
-    /// after all transactions with positive sequence values.
-    ///
-    /// All transactions with the same sequence value will run in a random order. Transactions can be
-    /// assigned both squence values and weights.
-    ///
-    /// # Examples
-    /// In this first example, the variable names indicate the order the transactions will be run in:
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// let runs_first = transaction!(first_transaction_function).set_sequence(3);
-    /// let runs_second = transaction!(second_transaction_function).set_sequence(5835);
-    /// let runs_last = transaction!(third_transaction_function);
-    ///
-    /// async fn first_transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     let _goose = user.get("1").await?;
-    ///
-    ///     Ok(())
-    /// }
-    ///
-    /// async fn second_transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     let _goose = user.get("2").await?;
-    ///
-    ///     Ok(())
-    /// }
-    ///
-    /// async fn third_transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     let _goose = user.get("3").await?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    ///
-    /// In the following example, the `runs_first` transactions runs two times, then one instance of `runs_second`
-    /// and two instances of `also_runs_second` are all three run. The user will do this over and over
-    /// the entire time it runs, with `runs_first` always running first, then the other transactions being
-    /// run in a random and weighted order:
-    /// ```rust
-    /// use goose::prelude::*;
-    ///
-    /// #[tokio::main]
-    /// async fn main() -> Result<(), GooseError> {
-    ///     let runs_first = transaction!(first_transaction_function).set_sequence(1).set_weight(2)?;
-    ///     let runs_second = transaction!(second_transaction_function_a).set_sequence(2);
-    ///     let also_runs_second = transaction!(second_transaction_function_b).set_sequence(2).set_weight(2)?;
-    ///
-    ///     Ok(())
-    /// }
-    ///
-    /// async fn first_transaction_function(user: &mut GooseUser) -> TransactionResult {
-    ///     let _goose = user.get("1").await?;
-    ///
-    ///     Ok(())
-    /// }
-    ///
-    /// async fn second_transaction_function_a(user: &mut GooseUser) -> TransactionResult {
-    ///     let _goose = user.get("2a").await?;
-    ///
-    ///     Ok(())
-    /// }
-    ///
-    ///     async fn second_transaction_function_b(user: &mut GooseUser) -> TransactionResult {
-    ///       let _goose = user.get("2b").await?;
-    ///
-    ///       Ok(())
-    ///     }
-    /// ```
-    pub fn set_sequence(mut self, sequence: usize) -> Self {
-        trace!(
-            "{} [{}] set_sequence: {}",
-            self.name,
-            self.transactions_index,
-            sequence
-        );
-        if sequence < 1 {
-            info!(
-                "setting sequence to 0 for transaction {} is unnecessary, sequence disabled",
-                self.name
-            );
+    /// Internal helper that requests and downloads a single static resource. Failures are
+    /// recorded in the metrics like any other request, but don't affect the parent page.
+    async fn load_resource(&self, url: Url, name: String) {
+        let goose_request = GooseRequest::builder()
+            .path(url.as_str())
+            .name(name.as_str())
+            .build();
+        if let Ok(goose) = self.execute_request(goose_request).await {
+            // Download the resource as a browser would.
+            if let Ok(response) = goose.response {
+                let _ = response.bytes().await;
+            }
         }
-        self.sequence = sequence;
-        self
     }
-}
-impl Hash for Transaction {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.transactions_index.hash(state);
-        self.name.hash(state);
-        self.weight.hash(state);
-        self.sequence.hash(state);
-        self.on_start.hash(state);
-        self.on_stop.hash(state);
+
+    /// Tracks the time it takes for the current GooseUser to loop through all Transactions
+    /// if Coordinated Omission Mitigation is enabled.
+    pub(crate) async fn update_request_cadence(&mut self, thread_number: usize) {
+        if let Some(co_mitigation) = self.config.co_mitigation.as_ref() {
+            // Return immediately if coordinated omission mitigation is disabled.
+            if co_mitigation == &GooseCoordinatedOmissionMitigation::Disabled {
+                return;
+            }
+
+            // Grab the current timestamp to calculate the difference since the last
+            // time through the loop.
+            let now = std::time::Instant::now();
+
+            // Swap out the `slept` counter, which is the total time the GooseUser slept
+            // between transactions, a potentially randomly changing value. Reset to 0 for the
+            // next loop through all Transactions.
+            self.request_cadence.delays_since_last_time = self.slept;
+            self.slept = 0;
+
+            // How much time passed since the last time this GooseUser looped through all
+            // transactions, accounting for time waiting between Transactions due to `set_wait_time`.
+            let elapsed = (now - self.request_cadence.last_time).as_millis() as u64
+                - self.request_cadence.delays_since_last_time;
+
+            // Update `minimum_cadence` if this was the fastest seen.
+            if elapsed < self.request_cadence.minimum_cadence
+                || self.request_cadence.minimum_cadence == 0
+            {
+                self.request_cadence.minimum_cadence = elapsed;
+            // Update `maximum_cadence` if this was the slowest seen.
+            } else if elapsed > self.request_cadence.maximum_cadence {
+                self.request_cadence.maximum_cadence = elapsed;
+            }
+
+            // Update request_cadence metrics based on the timing of the current request.
+            self.request_cadence.counter += 1;
+            self.request_cadence.total_elapsed += elapsed;
+            self.request_cadence.last_time = now;
+            self.request_cadence.average_cadence =
+                self.request_cadence.total_elapsed / self.request_cadence.counter;
+
+            if self.request_cadence.counter > 3 {
+                if self.request_cadence.coordinated_omission_counter < 0 {
+                    debug!(
+                        "user {} enabled coordinated omission mitigation",
+                        thread_number
+                    );
+                    self.request_cadence.coordinated_omission_counter += 1;
+                }
+                // Calculate the expected cadence for this Transaction request.
+                let cadence = match co_mitigation {
+                    // Expected cadence is the average time between requests.
+                    GooseCoordinatedOmissionMitigation::Average => {
+                        self.request_cadence.average_cadence
+                    }
+                    // Expected cadence is the maximum time between requests.
+                    GooseCoordinatedOmissionMitigation::Maximum => {
+                        self.request_cadence.maximum_cadence
+                    }
+                    // Expected cadence is the minimum time between requests.
+                    GooseCoordinatedOmissionMitigation::Minimum => {
+                        self.request_cadence.minimum_cadence
+                    }
+                    // This is not possible as we would have exited already if coordinated
+                    // omission mitigation was disabled.
+                    GooseCoordinatedOmissionMitigation::Disabled => unreachable!(),
+                };
+                if elapsed > (cadence * 2) {
+                    debug!(
+                        "user {}: coordinated_omission_mitigation: elapsed({}) > cadence({})",
+                        thread_number, elapsed, cadence
+                    );
+                    self.request_cadence.coordinated_omission_counter += 1;
+                    self.request_cadence.coordinated_omission_mitigation = elapsed;
+                } else {
+                    self.request_cadence.coordinated_omission_mitigation = 0;
+                }
+                // Always track the expected cadence.
+                self.request_cadence.user_cadence = cadence;
+            }
+        } else {
+            // Coordinated Omission Mitigation defaults to average.
+            unreachable!();
+        }
+    }
+
+    /// If Coordinated Omission Mitigation is enabled, compares how long has passed since the last
+    /// loop through all Transactions by the current GooseUser. Through this mechanism, Goose is
+    /// able to detect stalls on the upstream server being load tested, backfilling requests based
+    /// on what statistically should have happened. Can be disabled with `--co-mitigation disabled`.
+    async fn coordinated_omission_mitigation(
+        &self,
+        request_metric: &GooseRequestMetric,
+    ) -> Result<u64, Box<TransactionError>> {
+        if let Some(co_mitigation) = self.config.co_mitigation.as_ref() {
+            // Return immediately if coordinated omission mitigation is disabled.
+            if co_mitigation == &GooseCoordinatedOmissionMitigation::Disabled {
+                return Ok(0);
+            }
+
+            // Generate an info level alert if this specific request took longer than the normal
+            // cadence, as that means this specific request will likely trigger Coordinated
+            // Omission Mitigation.
+            if self.request_cadence.counter > 3
+                && request_metric.response_time > self.request_cadence.user_cadence
+            {
+                let transaction_name = if let Some(transaction_name) = &self.transaction_name {
+                    format!(", transaction name: \"{}\"", transaction_name)
+                } else {
+                    "".to_string()
+                };
+                info!(
+                    "{:.3}s into goose attack: \"{} {}\" [{}] took abnormally long ({} ms){}",
+                    request_metric.elapsed as f64 / 1_000.0,
+                    request_metric.raw.method,
+                    request_metric.raw.url,
+                    request_metric.status_code,
+                    request_metric.response_time,
+                    transaction_name,
+                );
+            }
+
+            // Check if Coordinated Omission Mitigation has been triggered.
+            if self.request_cadence.coordinated_omission_mitigation > 0 {
+                // Base our coordinated omission generated request metric on the actual
+                // metric that triggered this logic.
+                let mut coordinated_omission_request_metric = request_metric.clone();
+                // Record data points specific to coordinated_omission.
+                coordinated_omission_request_metric.coordinated_omission_elapsed =
+                    self.request_cadence.coordinated_omission_mitigation;
+                // Record data points specific to coordinated_omission.
+                coordinated_omission_request_metric.user_cadence =
+                    self.request_cadence.user_cadence;
+                // Send the coordinated omission mitigation generated metrics to the parent.
+                self.send_request_metric_to_parent(coordinated_omission_request_metric)?;
+            }
+            Ok(self.request_cadence.user_cadence)
+        } else {
+            // A setting for coordinated omission mitigation is required, defaults to Average.
+            unreachable!();
+        }
+    }
+
+    fn send_request_metric_to_parent(
+        &self,
+        request_metric: GooseRequestMetric,
+    ) -> TransactionResult {
+        // If requests-file is enabled, send a copy of the raw request to the logger thread.
+        if !self.config.request_log.is_empty() {
+            if let Some(logger) = self.logger.as_ref() {
+                if let Err(e) = logger.send(Some(GooseLog::Request(request_metric.clone()))) {
+                    return Err(Box::new(e.into()));
+                }
+            }
+        }
+
+        // Parent is not defined when running
+        // [`test_start`](../struct.GooseAttack.html#method.test_start),
+        // [`test_stop`](../struct.GooseAttack.html#method.test_stop), and during testing.
+        if let Some(metrics_channel) = self.metrics_channel.clone() {
+            if let Err(e) = metrics_channel.send(GooseMetric::Request(request_metric)) {
+                return Err(Box::new(e.into()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// If `request_name` is set, unwrap and use this. Otherwise, if the Transaction has a name
+    /// set use it. Otherwise use the path, grouped by the first matching path rule or, if
+    /// `--normalize-paths` is set, with numeric and UUID segments replaced.
+    fn get_request_name<'a>(&'a self, request: &'a GooseRequest) -> Cow<'a, str> {
+        match request.name {
+            // If a request.name is set, unwrap and return it.
+            Some(rn) => Cow::Borrowed(rn),
+            None => {
+                // Otherwise determine if the current Transaction is named, and if so return it.
+                if let Some(transaction_name) = &self.transaction_name {
+                    Cow::Borrowed(transaction_name)
+                // Otherwise group the path with the first matching path rule, if any.
+                } else if let Some(rule) = self
+                    .path_rules
+                    .iter()
+                    .find(|rule| rule.pattern.is_match(request.path))
+                {
+                    rule.pattern.replace_all(request.path, rule.name.as_str())
+                // Otherwise replace numeric and UUID segments if `--normalize-paths` is set.
+                } else if self.config.normalize_paths {
+                    normalize_path(request.path)
+                } else {
+                    // Otherwise return the path.
+                    Cow::Borrowed(request.path)
+                }
+            }
+        }
+    }
+
+    /// Manually mark a request as a success.
+    ///
+    /// Goose determines if a request was successful based on the the HTTP response status
+    /// code. By default, it uses [`reqwest::StatusCode::is_success`]. If an alternative
+    /// HTTP response code is expected, use [`GooseRequestBuilder::expect_status_code`]. If
+    /// validation requires additional logic, you can use set_success().
+    ///
+    /// A copy of your original request is returned with the response, and a mutable copy
+    /// must be included when setting a request as a success.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(get_function);
+    ///
+    /// /// A simple transaction that makes a GET request.
+    /// async fn get_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let mut goose = user.get("404").await?;
+    ///
+    ///     if let Ok(response) = &goose.response {
+    ///         // We expect a 404 here.
+    ///         if response.status() == 404 {
+    ///             return user.set_success(&mut goose.request);
+    ///         }
+    ///     }
+    ///
+    ///     Err(Box::new(TransactionError::RequestFailed {
+    ///         raw_request: goose.request.clone(),
+    ///     }))
+    /// }
+    /// ````
+    pub fn set_success(&self, request: &mut GooseRequestMetric) -> TransactionResult {
+        // Only send update if this was previously not a success.
+        if !request.success {
+            request.success = true;
+            request.update = true;
+            self.send_request_metric_to_parent(request.clone())?;
+        }
+
+        Ok(())
+    }
+
+    /// Manually mark a request as a failure.
+    ///
+    /// By default, Goose will consider any response with a 2xx status code as a success.
+    /// You may require more advanced logic, in which a 2xx status code is actually a
+    /// failure. A copy of your original request is returned with the response, and a
+    /// mutable copy must be included when setting a request as a failure.
+    ///
+    /// Calls to `set_failure` must include four parameters. The first, `tag`, is an
+    /// arbitrary string identifying the reason for the failure, used when logging. The
+    /// second, `request`, is a mutable reference to the
+    /// ([`GooseRequestMetric`](./struct.GooseRequestMetric.html)) object of the request being
+    /// identified as a failure (the contained `success` field will be set to `false`,
+    /// and the `update` field will be set to `true`). The last two parameters, `header`
+    /// and `body`, are optional and used to provide more detail in logs.
+    ///
+    /// The value of `tag` will normally be collected into the errors summary table if
+    /// metrics are being displayed. However, if `set_failure` is called multiple times,
+    /// or is called on a request that was already an error, only the first error will
+    /// be collected.
+    ///
+    /// This also calls [`GooseUser::log_debug`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(loadtest_index_page);
+    ///
+    /// async fn loadtest_index_page(user: &mut GooseUser) -> TransactionResult {
+    ///     let mut goose = user.get("").await?;
+    ///
+    ///     if let Ok(response) = goose.response {
+    ///         // We only need to check pages that returned a success status code.
+    ///         if response.status().is_success() {
+    ///             match response.text().await {
+    ///                 Ok(text) => {
+    ///                     // If the expected string doesn't exist, this page load
+    ///                     // was a failure.
+    ///                     if !text.contains("this string must exist") {
+    ///                         // As this is a named request, pass in the name not the URL
+    ///                         return user.set_failure("string missing", &mut goose.request, None, None);
+    ///                     }
+    ///                 }
+    ///                 // Empty page, this is a failure.
+    ///                 Err(_) => {
+    ///                     return user.set_failure("empty page", &mut goose.request, None, None);
+    ///                 }
+    ///             }
+    ///         }
+    ///     };
+    ///
+    ///     Ok(())
+    /// }
+    /// ````
+    pub fn set_failure(
+        &self,
+        tag: &str,
+        request: &mut GooseRequestMetric,
+        headers: Option<&header::HeaderMap>,
+        body: Option<&str>,
+    ) -> TransactionResult {
+        // Only send update if this was previously a success.
+        if request.success {
+            request.success = false;
+            request.update = true;
+            request.error = tag.to_string();
+            self.send_request_metric_to_parent(request.clone())?;
+        }
+        // Write failure to log, converting `&mut request` to `&request` as needed by `log_debug()`.
+        self.log_debug(tag, Some(&*request), headers, body)?;
+
+        // Print log to stdout.
+        info!("set_failure: {}", tag);
+
+        Err(Box::new(TransactionError::RequestFailed {
+            raw_request: request.clone(),
+        }))
+    }
+
+    /// Write to [`debug_file`](../struct.GooseConfiguration.html#structfield.debug_file)
+    /// if enabled.
+    ///
+    /// This function provides a mechanism for optional debug logging when a load test
+    /// is running. This can be especially helpful when writing a load test. Each entry
+    /// must include a tag, which is an arbitrary string identifying the debug message.
+    /// It may also optionally include references to the GooseRequestMetric made, the headers
+    /// returned by the server, and the response body returned by the server,
+    ///
+    /// As the response body can be large, the `--no-debug-body` option (or
+    /// [`GooseDefault::NoDebugBody`](../config/enum.GooseDefault.html#variant.NoDebugBody) default)
+    /// can be set to prevent the debug log from including the response body. When this option
+    /// is enabled, the body will always show up as `null` in the debug log.
+    ///
+    /// Calls to [`GooseUser::set_failure`] automatically invoke `log_debug`.
+    ///
+    /// To enable the debug log, a load test must be run with the `--debug-log-file=foo`
+    /// option set, where `foo` is either a relative or an absolute path of the log file
+    /// to create. Any existing file will be overwritten.
+    ///
+    /// In the following example, we are logging debug messages whenever there are errors.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(loadtest_index_page);
+    ///
+    /// async fn loadtest_index_page(user: &mut GooseUser) -> TransactionResult {
+    ///     let mut goose = user.get("").await?;
+    ///
+    ///     match goose.response {
+    ///         Ok(response) => {
+    ///             // Grab a copy of the headers so we can include them when logging errors.
+    ///             let headers = &response.headers().clone();
+    ///             // We only need to check pages that returned a success status code.
+    ///             if !response.status().is_success() {
+    ///                 match response.text().await {
+    ///                     Ok(html) => {
+    ///                         // Server returned an error code, log everything.
+    ///                         user.log_debug(
+    ///                             "error loading /",
+    ///                             Some(&goose.request),
+    ///                             Some(headers),
+    ///                             Some(&html),
+    ///                         );
+    ///                     },
+    ///                     Err(e) => {
+    ///                         // No body was returned, log everything else.
+    ///                         user.log_debug(
+    ///                             &format!("error loading /: {}", e),
+    ///                             Some(&goose.request),
+    ///                             Some(headers),
+    ///                             None,
+    ///                         );
+    ///                     }
+    ///                 }
+    ///             }
+    ///         },
+    ///         // No response from server.
+    ///         Err(e) => {
+    ///             user.log_debug(
+    ///                 "no response from server when loading /",
+    ///                 Some(&goose.request),
+    ///                 None,
+    ///                 None,
+    ///             );
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ````
+    pub fn log_debug(
+        &self,
+        tag: &str,
+        request: Option<&GooseRequestMetric>,
+        headers: Option<&header::HeaderMap>,
+        body: Option<&str>,
+    ) -> TransactionResult {
+        if !self.config.debug_log.is_empty() {
+            // Logger is not defined when running
+            // [`test_start`](../struct.GooseAttack.html#method.test_start),
+            // [`test_stop`](../struct.GooseAttack.html#method.test_stop), and during testing.
+            if let Some(logger) = self.logger.clone() {
+                if self.config.no_debug_body {
+                    if let Err(e) = logger.send(Some(GooseLog::Debug(GooseDebug::new(
+                        tag, request, headers, None,
+                    )))) {
+                        return Err(Box::new(e.into()));
+                    }
+                } else if let Err(e) = logger.send(Some(GooseLog::Debug(GooseDebug::new(
+                    tag, request, headers, body,
+                )))) {
+                    return Err(Box::new(e.into()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Starts a custom timer.
+    ///
+    /// Custom timers measure how long any part of a load test takes, for example a
+    /// business flow like adding an item to the cart through confirming the order. A
+    /// timer can cover part of a [`Transaction`], or span multiple transactions, as it
+    /// keeps running until it is stopped by the same [`GooseUser`] with
+    /// [`GooseUser::stop_timer`].
+    ///
+    /// Timers are identified by name. Starting a timer that is already running restarts
+    /// it. Stopped timers are aggregated by name in
+    /// [`GooseMetrics::timers`](../metrics/struct.GooseMetrics.html#structfield.timers),
+    /// and are optionally written to the `--timer-log`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(checkout);
+    ///
+    /// /// Time how long it takes to check out.
+    /// async fn checkout(user: &mut GooseUser) -> TransactionResult {
+    ///     user.start_timer("checkout");
+    ///
+    ///     let cart = user.post("cart", "item=1").await?;
+    ///     let order = user.post("order", "confirm=1").await?;
+    ///
+    ///     // The checkout is only successful if both requests succeeded.
+    ///     let success = cart.request.success && order.request.success;
+    ///     user.stop_timer("checkout", success)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn start_timer(&mut self, name: &str) {
+        self.timers.insert(name.to_string(), Instant::now());
+    }
+
+    /// Stops a custom timer started with [`GooseUser::start_timer`], recording if the timed
+    /// operation was successful.
+    ///
+    /// Returns how many milliseconds passed since the timer was started, or
+    /// [`TransactionError::TimerNotStarted`] if no timer with this name is running.
+    pub fn stop_timer(&mut self, name: &str, success: bool) -> Result<u64, Box<TransactionError>> {
+        let started = match self.timers.remove(name) {
+            Some(started) => started,
+            None => {
+                return Err(Box::new(TransactionError::TimerNotStarted {
+                    name: name.to_string(),
+                }))
+            }
+        };
+        let run_time = started.elapsed().as_millis();
+        self.record_timer(name, run_time, success)?;
+
+        Ok(run_time as u64)
+    }
+
+    /// Internal helper that sends a custom timer to the parent and the logger when enabled.
+    fn record_timer(&self, name: &str, run_time: u128, success: bool) -> TransactionResult {
+        if self.config.no_metrics {
+            return Ok(());
+        }
+
+        let timer_metric = TimerMetric::new(
+            self.started.elapsed().as_millis(),
+            name,
+            run_time,
+            success,
+            self.weighted_users_index,
+        );
+
+        // If timer-log is enabled, send a copy of the timer metric to the logger thread.
+        if !self.config.timer_log.is_empty() {
+            if let Some(logger) = self.logger.as_ref() {
+                if let Err(e) = logger.send(Some(GooseLog::Timer(timer_metric.clone()))) {
+                    return Err(Box::new(e.into()));
+                }
+            }
+        }
+
+        // Parent is not defined when running
+        // [`test_start`](../struct.GooseAttack.html#method.test_start),
+        // [`test_stop`](../struct.GooseAttack.html#method.test_stop), and during testing.
+        if let Some(metrics_channel) = self.metrics_channel.clone() {
+            if let Err(e) = metrics_channel.send(GooseMetric::Timer(timer_metric)) {
+                return Err(Box::new(e.into()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Increments a custom counter by `value`.
+    ///
+    /// Counters track domain specific totals, such as how many items were added to carts
+    /// during a load test. Counters are aggregated by name in
+    /// [`GooseMetrics::counters`](../metrics/struct.GooseMetrics.html#structfield.counters).
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(add_to_cart);
+    ///
+    /// /// Count how many items are added to carts.
+    /// async fn add_to_cart(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.post("cart", "item=1&quantity=3").await?;
+    ///     user.increment_counter("items in cart", 3)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn increment_counter(&self, name: &str, value: u64) -> TransactionResult {
+        self.record_custom_metric(name, CustomMetricValue::Counter(value))
+    }
+
+    /// Sets a custom gauge to `value`.
+    ///
+    /// Gauges track a value that goes up and down, such as the queue depth reported by
+    /// an API. Gauges are aggregated by name in
+    /// [`GooseMetrics::gauges`](../metrics/struct.GooseMetrics.html#structfield.gauges),
+    /// tracking the most recent, lowest and highest values.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(queue_status);
+    ///
+    /// /// Track the queue depth reported by the server.
+    /// async fn queue_status(user: &mut GooseUser) -> TransactionResult {
+    ///     let goose = user.get("queue/depth").await?;
+    ///     if let Ok(response) = goose.response {
+    ///         if let Ok(text) = response.text().await {
+    ///             if let Ok(depth) = text.trim().parse::<f64>() {
+    ///                 user.set_gauge("queue depth", depth)?;
+    ///             }
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_gauge(&self, name: &str, value: f64) -> TransactionResult {
+        self.record_custom_metric(name, CustomMetricValue::Gauge(value))
+    }
+
+    /// Records `value` in a custom histogram.
+    ///
+    /// Histograms track the distribution of a value, such as how many results a search
+    /// returns. Histograms are aggregated by name in
+    /// [`GooseMetrics::histograms`](../metrics/struct.GooseMetrics.html#structfield.histograms).
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(search);
+    ///
+    /// /// Track how many results each search returns.
+    /// async fn search(user: &mut GooseUser) -> TransactionResult {
+    ///     let goose = user.get("search?q=goose").await?;
+    ///     if let Ok(response) = goose.response {
+    ///         if let Ok(text) = response.text().await {
+    ///             user.record_histogram("search results", text.lines().count() as u64)?;
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn record_histogram(&self, name: &str, value: u64) -> TransactionResult {
+        self.record_custom_metric(name, CustomMetricValue::Histogram(value))
+    }
+
+    /// Internal helper that sends a custom metric to the parent when enabled.
+    fn record_custom_metric(&self, name: &str, value: CustomMetricValue) -> TransactionResult {
+        if self.config.no_metrics {
+            return Ok(());
+        }
+
+        // Parent is not defined when running
+        // [`test_start`](../struct.GooseAttack.html#method.test_start),
+        // [`test_stop`](../struct.GooseAttack.html#method.test_stop), and during testing.
+        if let Some(metrics_channel) = self.metrics_channel.clone() {
+            if let Err(e) = metrics_channel.send(GooseMetric::Custom(CustomMetric::new(
+                self.started.elapsed().as_millis(),
+                name,
+                value,
+                self.weighted_users_index,
+            ))) {
+                return Err(Box::new(e.into()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Manually build a
+    /// [`reqwest::Client`](https://docs.rs/reqwest/*/reqwest/struct.Client.html).
+    ///
+    /// By default, Goose configures the following options when building a
+    /// [`reqwest::Client`](https://docs.rs/reqwest/*/reqwest/struct.Client.html):
+    ///  - reports itself as the
+    ///    [`user_agent`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.user_agent)
+    ///    requesting web pages (ie `goose/0.15.2`);
+    ///  - [stores cookies](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.cookie_store),
+    ///    generally necessary if you aim to simulate logged in users;
+    ///  - enables
+    ///    [`gzip`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.gzip) compression;
+    ///  - sets a 60 second [`timeout`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.timeout) all
+    ///    on all requests.
+    ///
+    /// # Default configuration:
+    ///
+    /// ```rust
+    /// use reqwest::Client;
+    /// use core::time::Duration;
+    ///
+    /// static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+    ///
+    /// let builder = Client::builder()
+    ///   .user_agent(APP_USER_AGENT)
+    ///   .cookie_store(true)
+    ///   .gzip(true)
+    ///   .timeout(Duration::from_secs(60));
+    /// ```
+    ///
+    /// Alternatively, you can use this function to manually build a
+    /// [`reqwest::Client`](https://docs.rs/reqwest/*/reqwest/struct.Client.html).
+    /// with custom configuration. Available options are found in the
+    /// [`reqwest::ClientBuilder`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html)
+    /// documentation.
+    ///
+    /// When manually building a
+    /// [`reqwest::Client`](https://docs.rs/reqwest/*/reqwest/struct.Client.html),
+    /// there are a few things to be aware of:
+    ///  - Manually building a client in [`test_start`](../struct.GooseAttack.html#method.test_start)
+    ///    will only affect requests made during test setup;
+    ///  - Manually building a client in [`test_stop`](../struct.GooseAttack.html#method.test_stop)
+    ///    will only affect requests made during test teardown;
+    ///  - A manually built client is specific to a single Goose thread -- if you are
+    ///    generating a large load test with many users, each will need to manually build their
+    ///    own client (typically you'd do this in a Transaction that is registered with
+    ///   [`Transaction::set_on_start()`] in each Scenario requiring a custom client;
+    ///  - Manually building a client will completely replace the automatically built client
+    ///    with a brand new one, so any configuration, cookies or headers set in the previously
+    ///    built client will be gone;
+    ///  - You must include all desired configuration, as you are completely replacing Goose
+    ///    defaults. For example, if you want Goose clients to store cookies, you will have to
+    ///    include
+    ///    [`.cookie_store(true)`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.cookie_store);
+    ///  - Goose no longer decompresses responses itself, so if the client is configured to
+    ///    decompress them the size of response bodies is recorded after decompression;
+    ///  - DNS lookups are only timed if the client is configured to use the [`GooseDnsResolver`],
+    ///    and new connections are only timed if it is configured to use the
+    ///    [`GooseConnectLayer`];
+    ///  - The [`--proxy`](../config/enum.GooseDefault.html#variant.Proxy),
+    ///    [`--resolve`](../config/enum.GooseDefault.html#variant.Resolve) and
+    ///    [`--local-address`](../config/enum.GooseDefault.html#variant.LocalAddress) options
+    ///    are not applied to custom clients, nor are the TLS options such as
+    ///    [`--ca-cert`](../config/enum.GooseDefault.html#variant.CaCert) and
+    ///    [`--insecure`](../config/enum.GooseDefault.html#variant.Insecure);
+    ///  - The [`GooseClientOptions`] of the [`Scenario`], such as its HTTP version, are not
+    ///    applied to custom clients.
+    ///
+    /// In the following example, the Goose client is configured with a different user agent,
+    /// sets a default header on every request, stores cookies, supports gzip compression, and
+    /// times out requests after 30 seconds.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// transaction!(setup_custom_client).set_on_start();
+    ///
+    /// async fn setup_custom_client(user: &mut GooseUser) -> TransactionResult {
+    ///     use reqwest::{Client, header};
+    ///
+    ///     // Build a custom HeaderMap to include with all requests made by this client.
+    ///     let mut headers = header::HeaderMap::new();
+    ///     headers.insert("X-Custom-Header", header::HeaderValue::from_str("custom value").unwrap());
+    ///
+    ///     // Build a custom client.
+    ///     let builder = Client::builder()
+    ///         .default_headers(headers)
+    ///         .user_agent("custom user agent")
+    ///         .cookie_store(true)
+    ///         .gzip(true)
+    ///         .timeout(Duration::from_secs(30));
+    ///
+    ///     // Assign the custom client to this GooseUser.
+    ///     user.set_client_builder(builder).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Alternative Compression Algorithms
+    /// Reqwest also supports
+    /// [`brotli`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.brotli) and
+    /// [`deflate`](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.deflate) compression.
+    ///
+    /// To enable either, you must enable the features in your load test's `Cargo.toml`, for example:
+    /// ```text
+    /// reqwest = { version = "^0.11.4",  default-features = false, features = [
+    ///     "brotli",
+    ///     "cookies",
+    ///     "deflate",
+    ///     "gzip",
+    ///     "json",
+    /// ] }
+    /// ```
+    ///
+    /// Once enabled, you can add `.brotli(true)` and/or `.deflate(true)` to your custom
+    /// [`reqwest::Client::builder()`], following the documentation above.
+    ///
+    /// # Custom Cookies
+    /// Custom cookies can also be manually set when building a custom [`reqwest::Client`]. This requires
+    /// loading the [`GooseUser::base_url`] being load tested in order to properly build the cookie. Then
+    /// a custom [`reqwest::cookie::Jar`] is created and the custom cookie is added with
+    /// [`reqwest::cookie::Jar::add_cookie_str`]. Finally, the new cookie jar must be specified as the
+    /// [`reqwest::ClientBuilder::cookie_provider`] for the custom client.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reqwest::{cookie::Jar, Client};
+    /// use std::sync::Arc;
+    ///
+    /// use goose::prelude::*;
+    ///
+    /// transaction!(custom_cookie_with_custom_client).set_on_start();
+    ///
+    /// async fn custom_cookie_with_custom_client(user: &mut GooseUser) -> TransactionResult {
+    ///     // Prepare the contents of a custom cookie.
+    ///     let cookie = "my-custom-cookie=custom-value";
+    ///
+    ///     // Pre-load one or more cookies into a custom cookie jar to use with this client.
+    ///     let jar = Jar::default();
+    ///     jar.add_cookie_str(
+    ///         cookie,
+    ///         &user.base_url,
+    ///     );
+    ///
+    ///     // Build a custom client.
+    ///     let builder = Client::builder()
+    ///         .user_agent("example-loadtest")
+    ///         .cookie_store(true)
+    ///         .cookie_provider(Arc::new(jar))
+    ///         .gzip(true);
+    ///
+    ///     // Assign the custom client to this GooseUser.
+    ///     user.set_client_builder(builder).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_client_builder(
+        &mut self,
+        builder: ClientBuilder,
+    ) -> Result<(), TransactionError> {
+        self.client = builder.build()?;
+        // Custom clients decompress responses themselves, if configured to.
+        self.decompress_gzip = false;
+
+        Ok(())
+    }
+
+    /// Some websites use multiple domains to serve traffic, redirecting depending on
+    /// the user's roll. For this reason, Goose needs to respect a redirect of the
+    /// `base_url` and subsequent paths should be built from the redirect domain.
+    ///
+    /// For example, if the `base_url` (ie `--host`) is set to `foo.example.com` and the
+    /// load test requests `/login`, thereby loading `http://foo.example.com/login` and
+    /// this request gets redirected by the server to `http://foo-secure.example.com/`,
+    /// subsequent requests made by this user need to be against the new
+    /// `foo-secure.example.com domain`. (Further, if the `base_url` is again redirected,
+    /// such as when loading `http://foo-secure.example.com/logout`, the user should
+    /// again follow for subsequent requests, perhaps in this case back to
+    /// `foo.example.com`.)
+    ///
+    /// Load tests can also request absolute URLs, and if these URLs are redirected
+    /// it does not affect the `base_url` of the load test. For example, if
+    /// `foo.example.com` is the base url, and the load test requests
+    /// `http://bar.example.com` (a different domain) and this request gets redirected
+    /// to `http://other.example.com`, subsequent relative requests would still be made
+    /// against `foo.example.com`.
+    ///
+    /// This functionality is used internally by Goose to follow redirects of the
+    /// `base_url` when `--sticky-follow` is specified at run time, or
+    /// [`set_default`](../struct.GooseAttack.html#method.set_default)
+    /// `(`[`GooseDefault::StickyFollow`](../config/enum.GooseDefault.html#variant.StickyFollow)
+    /// `, true)` is enabled. It is also
+    /// available to be manually invoked from a load test such as in the following
+    /// example.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let _goose_metrics = GooseAttack::initialize()?
+    ///         .register_scenario(scenario!("LoadtestTransactions")
+    ///             .set_host("http://foo.example.com/")
+    ///             .set_wait_time(Duration::from_secs(0), Duration::from_secs(3))?
+    ///             .register_transaction(transaction!(transaction_foo).set_weight(10)?)
+    ///             .register_transaction(transaction!(transaction_bar))
+    ///         )
+    ///         // Set a default run time so this test runs to completion.
+    ///         .set_default(GooseDefault::RunTime, 1)?
+    ///         .execute()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn transaction_foo(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.get("").await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn transaction_bar(user: &mut GooseUser) -> TransactionResult {
+    ///     // Before this transaction runs, all requests are being made against
+    ///     // http://foo.example.com, after this transaction runs all subsequent
+    ///     // requests are made against http://bar.example.com/.
+    ///     user.set_base_url("http://bar.example.com/");
+    ///     let _goose = user.get("").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_base_url(&mut self, host: &str) -> Result<(), Box<TransactionError>> {
+        self.base_url = match Url::parse(host) {
+            Ok(u) => u,
+            Err(e) => return Err(Box::new(e.into())),
+        };
+        Ok(())
+    }
+
+    /// Returns the cookies this `GooseUser` sends with requests to the
+    /// [`GooseUser::base_url`].
+    ///
+    /// Only cookies stored by the default client can be inspected and changed: a client
+    /// built with [`GooseUser::set_client_builder`] manages its own cookies.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(show_cookies);
+    ///
+    /// async fn show_cookies(user: &mut GooseUser) -> TransactionResult {
+    ///     for cookie in user.get_cookies() {
+    ///         println!("{}={}", cookie.name, cookie.value);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_cookies(&self) -> Vec<GooseCookie> {
+        self.cookie_jar
+            .0
+            .read()
+            .unwrap()
+            .matches(&self.base_url)
+            .into_iter()
+            .map(GooseCookie::new)
+            .collect()
+    }
+
+    /// Returns the cookie named `name` this `GooseUser` sends with requests to the
+    /// [`GooseUser::base_url`], if any.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(check_session);
+    ///
+    /// async fn check_session(user: &mut GooseUser) -> TransactionResult {
+    ///     if user.get_cookie("session").is_none() {
+    ///         let _goose = user.post_form("/login", &[("name", "foo"), ("pass", "bar")]).await?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_cookie(&self, name: &str) -> Option<GooseCookie> {
+        self.get_cookies()
+            .into_iter()
+            .find(|cookie| cookie.name == name)
+    }
+
+    /// Stores a cookie as if it was set by a response from the [`GooseUser::base_url`].
+    /// The cookie uses the `Set-Cookie` header syntax, so can include attributes such as
+    /// `Path`, `Domain` and `Max-Age`. Replaces any stored cookie with the same name,
+    /// domain and path.
+    ///
+    /// Returns [`TransactionError::InvalidCookie`] if the cookie can't be parsed or isn't
+    /// allowed to be set by the [`GooseUser::base_url`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(accept_cookies).set_on_start();
+    ///
+    /// async fn accept_cookies(user: &mut GooseUser) -> TransactionResult {
+    ///     user.set_cookie("consent=accepted; Path=/")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_cookie(&self, cookie: &str) -> TransactionResult {
+        match self
+            .cookie_jar
+            .0
+            .write()
+            .unwrap()
+            .parse(cookie, &self.base_url)
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(Box::new(TransactionError::InvalidCookie {
+                cookie: format!("{}: {}", cookie, e),
+            })),
+        }
+    }
+
+    /// Removes the cookie named `name` this `GooseUser` sends with requests to the
+    /// [`GooseUser::base_url`]. Returns `true` if a cookie was removed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(expire_session);
+    ///
+    /// async fn expire_session(user: &mut GooseUser) -> TransactionResult {
+    ///     user.delete_cookie("session");
+    ///     let _goose = user.get("/").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn delete_cookie(&self, name: &str) -> bool {
+        let mut cookie_store = self.cookie_jar.0.write().unwrap();
+        // Several cookies with the same name can be sent, for different domains or paths.
+        let cookies: Vec<(String, String)> = cookie_store
+            .matches(&self.base_url)
+            .into_iter()
+            .filter(|cookie| cookie.name() == name)
+            .map(|cookie| (String::from(&cookie.domain), String::from(&cookie.path)))
+            .collect();
+        for (domain, path) in &cookies {
+            cookie_store.remove(domain, path, name);
+        }
+        !cookies.is_empty()
+    }
+
+    /// Removes all cookies stored by this `GooseUser`, for all hosts, so its next request
+    /// starts a new session like a first-time visitor. To do this at the start of every
+    /// iteration, use [`Scenario::set_clear_cookies_each_iteration`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(logout);
+    ///
+    /// async fn logout(user: &mut GooseUser) -> TransactionResult {
+    ///     user.clear_cookies();
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn clear_cookies(&self) {
+        self.cookie_jar.0.write().unwrap().clear();
+    }
+
+    /// Enables an HTTP cache for this `GooseUser`, so repeat visits behave like a browser.
+    ///
+    /// Once enabled, successful `GET` responses are stored in memory as allowed by their
+    /// `Cache-Control`, `Expires` and `Last-Modified` headers. While a stored response is
+    /// fresh, requests for the same URL are answered from the cache without contacting the
+    /// server. When it becomes stale, the next request for it is sent with the
+    /// `If-None-Match` and `If-Modified-Since` headers, and if the server answers
+    /// `304 Not Modified` the stored response is returned.
+    ///
+    /// Cache hits are counted in
+    /// [`GooseRequestMetricAggregate::cache_hits`](../metrics/struct.GooseRequestMetricAggregate.html#structfield.cache_hits)
+    /// instead of as requests, and `304 Not Modified` responses in
+    /// [`GooseRequestMetricAggregate::not_modified`](../metrics/struct.GooseRequestMetricAggregate.html#structfield.not_modified).
+    ///
+    /// Requests that set their own `Cache-Control`, `If-None-Match` or `If-Modified-Since`
+    /// headers, and responses read with [`GooseUser::request_stream`], bypass the cache.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(setup_cache).set_on_start();
+    ///
+    /// /// Cache responses like a returning visitor's browser.
+    /// async fn setup_cache(user: &mut GooseUser) -> TransactionResult {
+    ///     user.enable_http_cache();
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn enable_http_cache(&mut self) {
+        if self.http_cache.is_none() {
+            self.http_cache = Some(Mutex::new(HashMap::new()));
+        }
+    }
+
+    /// Disables the HTTP cache enabled with [`GooseUser::enable_http_cache`], discarding
+    /// all stored responses.
+    pub fn disable_http_cache(&mut self) {
+        self.http_cache = None;
+    }
+
+    /// Discards all responses stored in the HTTP cache enabled with
+    /// [`GooseUser::enable_http_cache`], as if the user cleared their browser cache.
+    pub fn clear_http_cache(&mut self) {
+        if let Some(http_cache) = self.http_cache.as_mut() {
+            http_cache.get_mut().unwrap().clear();
+        }
+    }
+
+    /// Internal helper that returns the key of a request in the HTTP cache, or [`None`] if
+    /// the cache is disabled or doesn't apply to the request.
+    fn http_cache_key(&self, request: &reqwest::Request, stream: bool) -> Option<String> {
+        if self.http_cache.is_none() || stream || request.method() != Method::GET {
+            return None;
+        }
+        // Leave requests that manage caching themselves alone.
+        let headers = request.headers();
+        if headers.contains_key(header::CACHE_CONTROL)
+            || headers.contains_key(header::IF_NONE_MATCH)
+            || headers.contains_key(header::IF_MODIFIED_SINCE)
+        {
+            return None;
+        }
+        Some(request.url().to_string())
+    }
+
+    /// Internal helper that returns a copy of a response stored in the HTTP cache.
+    fn get_cached_response(&self, key: &str) -> Option<GooseCachedResponse> {
+        let http_cache = self.http_cache.as_ref()?.lock().unwrap();
+        http_cache.get(key).cloned()
+    }
+
+    /// Internal helper that updates the HTTP cache after downloading a response. Returns
+    /// the response to use, and whether it was loaded from the cache because the server
+    /// confirmed the stored copy is still valid.
+    fn update_http_cache(
+        &self,
+        key: &str,
+        cached_response: Option<GooseCachedResponse>,
+        response: Response,
+        body: &[u8],
+    ) -> (Response, bool) {
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            // Validators are only sent when a stale copy is stored.
+            if let Some(mut cached_response) = cached_response {
+                let cacheable = cached_response.revalidate(response.headers());
+                let response = cached_response.to_response();
+                self.set_cached_response(key, Some(cached_response).filter(|_| cacheable));
+                return (response, true);
+            }
+        } else if response.url().as_str() == key {
+            self.set_cached_response(key, GooseCachedResponse::new(&response, body));
+        }
+        (response, false)
+    }
+
+    /// Internal helper that stores a response in the HTTP cache if allowed, otherwise
+    /// removing any stale copy.
+    fn set_cached_response(&self, key: &str, cached_response: Option<GooseCachedResponse>) {
+        if let Some(http_cache) = self.http_cache.as_ref() {
+            let mut http_cache = http_cache.lock().unwrap();
+            match cached_response {
+                Some(cached_response) => http_cache.insert(key.to_string(), cached_response),
+                None => http_cache.remove(key),
+            };
+        }
+    }
+
+    /// Configure this user to authenticate its requests as described by the [`GooseAuth`],
+    /// replacing any authentication configured for its [`Scenario`] with
+    /// [`Scenario::set_auth`]. Any OAuth2 access token already obtained is discarded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(login).set_on_start();
+    ///
+    /// async fn login(user: &mut GooseUser) -> TransactionResult {
+    ///     // Authenticate each user as a different account.
+    ///     let username = format!("user{}", user.weighted_users_index);
+    ///     user.set_auth(GooseAuth::oauth2_password(
+    ///         "https://auth.example.com/oauth/token",
+    ///         "load-test",
+    ///         "",
+    ///         &username,
+    ///         "password",
+    ///     ));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_auth(&mut self, auth: GooseAuth) {
+        trace!("set_auth: {}", auth.describe());
+        self.auth = Some(auth);
+        *self.auth_token.get_mut() = None;
+    }
+
+    /// Stop authenticating the requests made by this user, discarding any OAuth2 access
+    /// token already obtained.
+    pub fn remove_auth(&mut self) {
+        self.auth = None;
+        *self.auth_token.get_mut() = None;
+    }
+
+    /// Internal helper that adds an `Authorization` header to a request if this user is
+    /// configured to authenticate, obtaining an OAuth2 access token first if needed.
+    async fn authorize(&self, request: &mut reqwest::Request) -> TransactionResult {
+        let auth = match self.auth.as_ref() {
+            Some(auth) => auth,
+            None => return Ok(()),
+        };
+        // Leave requests that set their own authorization alone.
+        if request.headers().contains_key(header::AUTHORIZATION) {
+            return Ok(());
+        }
+
+        let authorization = match &auth.strategy {
+            GooseAuthStrategy::Basic { username, password } => format!(
+                "Basic {}",
+                general_purpose::STANDARD.encode(format!("{}:{}", username, password))
+            ),
+            GooseAuthStrategy::Bearer { token } => format!("Bearer {}", token),
+            GooseAuthStrategy::OAuth2 { token_url, .. } => {
+                // Token requests can't require a token.
+                if Url::parse(token_url).ok().as_ref() == Some(request.url()) {
+                    return Ok(());
+                }
+                format!("Bearer {}", self.get_auth_token(auth).await?)
+            }
+        };
+        match header::HeaderValue::from_str(&authorization) {
+            Ok(mut value) => {
+                value.set_sensitive(true);
+                request.headers_mut().insert(header::AUTHORIZATION, value);
+            }
+            Err(_) => warn!(
+                "{} credentials are not a valid header, request sent without authorization",
+                auth.describe()
+            ),
+        }
+        Ok(())
+    }
+
+    /// Internal helper that returns this user's OAuth2 access token, requesting a new one
+    /// if there is none yet or it is about to expire. Concurrent requests wait for the
+    /// same token.
+    async fn get_auth_token(&self, auth: &GooseAuth) -> Result<String, Box<TransactionError>> {
+        let (token_url, client_id, client_secret, user) = match &auth.strategy {
+            GooseAuthStrategy::OAuth2 {
+                token_url,
+                client_id,
+                client_secret,
+                user,
+            } => (token_url, client_id, client_secret, user),
+            _ => unreachable!("only OAuth2 uses access tokens"),
+        };
+
+        let mut auth_token = self.auth_token.lock().await;
+        // Reuse the current token unless it's about to expire.
+        let refresh_token = match auth_token.as_ref() {
+            Some(token) => match token.expires {
+                Some(expires)
+                    if expires.saturating_duration_since(Instant::now()) <= auth.refresh_margin =>
+                {
+                    token.refresh_token.clone()
+                }
+                _ => return Ok(token.access_token.clone()),
+            },
+            None => None,
+        };
+        // The token is replaced, or discarded if a new one can't be obtained.
+        *auth_token = None;
+
+        let mut params = Vec::new();
+        match (&refresh_token, user) {
+            (Some(refresh_token), _) => {
+                params.push(("grant_type", "refresh_token"));
+                params.push(("refresh_token", refresh_token));
+            }
+            (None, Some((username, password))) => {
+                params.push(("grant_type", "password"));
+                params.push(("username", username));
+                params.push(("password", password));
+            }
+            (None, None) => params.push(("grant_type", "client_credentials")),
+        }
+        if let Some(scope) = auth.scope.as_ref() {
+            params.push(("scope", scope));
+        }
+        let mut request_builder = self.client.post(token_url.as_str());
+        if client_secret.is_empty() {
+            params.push(("client_id", client_id));
+        } else {
+            request_builder = request_builder.basic_auth(client_id, Some(client_secret));
+        }
+
+        let goose_request = GooseRequest::builder()
+            .method(GooseMethod::Post)
+            .path(token_url.as_str())
+            .name(auth.token_request_name.as_str())
+            .set_request_builder(request_builder.form(&params))
+            .build();
+        // Token requests are made while making another request.
+        let mut goose = Box::pin(self.execute_request(goose_request)).await?;
+        let response = match goose.response {
+            Ok(response) if goose.request.success => response,
+            _ => {
+                return Err(Box::new(TransactionError::RequestFailed {
+                    raw_request: goose.request,
+                }))
+            }
+        };
+        let token = match response.json::<GooseAuthTokenResponse>().await {
+            Ok(token)
+                if header::HeaderValue::from_str(&format!("Bearer {}", token.access_token))
+                    .is_ok() =>
+            {
+                token
+            }
+            _ => {
+                self.set_failure("invalid oauth2 token", &mut goose.request, None, None)?;
+                unreachable!("set_failure always returns an error");
+            }
+        };
+
+        let access_token = token.access_token.clone();
+        *auth_token = Some(GooseAuthToken {
+            access_token: token.access_token,
+            expires: token
+                .expires_in
+                .map(|expires_in| Instant::now() + Duration::from_secs(expires_in)),
+            // Keep using the previous refresh token if the server didn't issue a new one.
+            refresh_token: token.refresh_token.or(refresh_token),
+        });
+        Ok(access_token)
+    }
+}
+
+/// Defines the HTTP requests that Goose makes.
+///
+/// Can be manually created and configured with [`GooseRequest::builder`], but it's typically
+/// more convenient to use the [`GooseUser::get`], [`GooseUser::get_named`], [`GooseUser::post`],
+/// [`GooseUser::post_form`], [`GooseUser::post_json`], [`GooseUser::post_multipart`],
+/// [`GooseUser::put`], [`GooseUser::patch`], [`GooseUser::head`], [`GooseUser::delete`],
+/// [`GooseUser::options`] and [`GooseUser::trace`] helpers.
+///
+/// For complete instructions review [`GooseRequestBuilder`].
+#[derive(Debug)]
+pub struct GooseRequest<'a> {
+    // Defaults to `""`.
+    path: &'a str,
+    // Defaults to [`GooseMethod::Get`].
+    method: GooseMethod,
+    // Defaults to [`None`].
+    name: Option<&'a str>,
+    // Defaults to [`None`].
+    expect_status_code: Option<u16>,
+    // Defaults to [`false`].
+    error_on_fail: bool,
+    // Defaults to [`None`].
+    request_builder: Option<RequestBuilder>,
+    // Defaults to [`None`].
+    resources: Option<GooseResources>,
+    // Defaults to [`None`].
+    multipart: Option<GooseMultipartForm>,
+    // Defaults to [`false`], set by [`GooseUser::request_stream`] so the body isn't downloaded
+    // before it's returned.
+    stream: bool,
+    // Defaults to [`None`], set by [`GooseUser::request_stream`] to override the timeout of
+    // the client, so streams can stay open for longer.
+    timeout: Option<Duration>,
+}
+impl<'a> GooseRequest<'a> {
+    /// Convenience function to bring [`GooseRequestBuilder`] into scope.
+    pub fn builder() -> GooseRequestBuilder<'a> {
+        GooseRequestBuilder::new()
+    }
+}
+
+/// Used to build a [`GooseRequest`] object, necessary to make a request with Goose.
+///
+/// It's only necessary to build manually if the [`GooseUser::get`], [`GooseUser::get_named`],
+/// [`GooseUser::post`], [`GooseUser::post_form`], [`GooseUser::post_json`],
+/// [`GooseUser::post_multipart`], [`GooseUser::put`], [`GooseUser::patch`], [`GooseUser::head`],
+/// [`GooseUser::delete`], [`GooseUser::options`] and [`GooseUser::trace`] helpers don't
+/// provide you with enough flexibility, for example to use a [`GooseMethod::Extension`]
+/// method.
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+///
+/// let mut a_transaction = transaction!(transaction_function);
+///
+/// // A simple transaction that loads a relative path.
+/// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
+///     // Manually create a GooseRequestBuilder object.
+///     let goose_request = GooseRequest::builder()
+///         // Set a relative path to request.
+///         .path("about")
+///         // Name the request in the metircs.
+///         .name("About page")
+///         // Build the GooseRequest object.
+///         .build();
+///
+///     // Make the configured request.
+///     let _goose = user.request(goose_request).await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct GooseRequestBuilder<'a> {
+    path: &'a str,
+    method: GooseMethod,
+    name: Option<&'a str>,
+    expect_status_code: Option<u16>,
+    error_on_fail: bool,
+    request_builder: Option<RequestBuilder>,
+    resources: Option<GooseResources>,
+    multipart: Option<GooseMultipartForm>,
+}
+impl<'a> GooseRequestBuilder<'a> {
+    // Internal method to build a [`GooseRequest`] from a [`GooseRequestBuilder`].
+    fn new() -> Self {
+        Self {
+            path: "",
+            method: GooseMethod::Get,
+            name: None,
+            expect_status_code: None,
+            error_on_fail: false,
+            request_builder: None,
+            resources: None,
+            multipart: None,
+        }
+    }
+
+    /// Set the path to request.
+    ///
+    /// Typically is a relative path allowing Goose to append a configurable base_url.
+    ///
+    /// Defaults to `""` (the main index).
+    ///
+    /// # Example
+    /// This can be implemented in a simpler way using the [`GooseUser::get`] helper function.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_transaction = transaction!(transaction_function);
+    ///
+    /// // A simple transaction that loads a relative path.
+    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("a/relative/path")
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn path(mut self, path: impl Into<&'a str>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Set the method of the request.
+    ///
+    /// Must be set to a [`GooseMethod`].
+    ///
+    /// Defaults to [`GooseMethod::Get`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_transaction = transaction!(transaction_function);
+    ///
+    /// // Make a DELETE request.
+    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("path/to/delete")
+    ///         // Set the method to DELETE.
+    ///         .method(GooseMethod::Delete)
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured DELETE request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn method(mut self, method: GooseMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Set a name for the request, affecting how it shows up in metrics.
+    ///
+    /// Must be set to a [`GooseMethod`].
+    ///
+    /// Defaults to [`GooseMethod::Get`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_transaction = transaction!(transaction_function);
+    ///
+    /// // Make a named request.
+    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("path/to/request")
+    ///         // Name the request in the metrics.
+    ///         .name("custom name")
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn name(mut self, name: impl Into<&'a str>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Manually configure the expected HTTP response status code.
+    ///
+    /// Defaults to [`reqwest::StatusCode::is_success`].
+    ///
+    /// # Example
+    /// Intentionally request a 404 page, and do not trigger an error.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_transaction = transaction!(transaction_function);
+    ///
+    /// // Make a named request.
+    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("no/such/path")
+    ///         // Tell Goose to expect a 404 HTTP response status code.
+    ///         .expect_status_code(404)
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn expect_status_code(mut self, status_code: u16) -> Self {
+        self.expect_status_code = Some(status_code);
+        self
+    }
+
+    /// Configure whether the request should return on error when it
+    /// fails
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Example
+    /// Intentionally request a 404 page, and do not trigger an error.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_transaction = transaction!(transaction_function);
+    ///
+    /// // Make a named request.
+    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("no/such/path")
+    ///         // Tell Goose to expect a 404 HTTP response status code.
+    ///         .expect_status_code(404)
+    ///         // Tell Goose to return an error if the status code is
+    ///         // not a 404
+    ///         .error_on_fail()
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn error_on_fail(mut self) -> Self {
+        self.error_on_fail = true;
+        self
+    }
+
+    /// Manually create the [`reqwest::RequestBuilder`] used to make a request.
+    ///
+    /// # Example
+    /// Manually create a `RequestBuilder` in order to set a timeout.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_transaction = transaction!(transaction_function);
+    ///
+    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     use std::time::Duration;
+    ///
+    ///     // Manually interact with the Reqwest RequestBuilder object.
+    ///     let request_builder = user.get_request_builder(&GooseMethod::Get, "no/such/path")?
+    ///         // Configure the request to timeout if it takes longer than 500 milliseconds.
+    ///         .timeout(Duration::from_millis(500));
+    ///
+    ///     // Manually build a GooseRequest in order to set our custom RequestBuilder.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Manually add our custom RequestBuilder object.
+    ///         .set_request_builder(request_builder)
+    ///         // Turn the GooseRequestBuilder object into a GooseRequest.
+    ///         .build();
+    ///
+    ///     // Finally make the actual request with our custom GooseRequest object.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_request_builder(mut self, request_builder: RequestBuilder) -> Self {
+        self.request_builder = Some(request_builder);
+        self
+    }
+
+    /// Load the static resources embedded in the returned HTML page, as a browser would.
+    ///
+    /// If the request succeeds and returns an HTML page, Goose parses the page and
+    /// concurrently requests the images, stylesheets, scripts and icons it references.
+    /// Which resources are loaded and how many connections are opened to each host is
+    /// configured with [`GooseResources`]. Each resource is named after the parent page
+    /// in the metrics, for example `/about > css`. The body of the page is preserved, so
+    /// it can still be read from the returned [`GooseResponse`].
+    ///
+    /// Defaults to [`None`], no resources are loaded.
+    ///
+    /// # Example
+    /// Load the front page and all of its images, stylesheets and scripts.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_transaction = transaction!(transaction_function);
+    ///
+    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("/")
+    ///         // Also load static resources, including those served by a CDN.
+    ///         .load_resources(GooseResources::new().allow_domain("cdn.example.com"))
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request, loading all static resources on the page.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn load_resources(mut self, resources: GooseResources) -> Self {
+        self.resources = Some(resources);
+        self
+    }
+
+    /// Send a `multipart/form-data` body, replacing any body set on a custom
+    /// [`RequestBuilder`] provided with [`GooseRequestBuilder::set_request_builder`].
+    ///
+    /// Defaults to [`None`], no multipart form is sent.
+    ///
+    /// # Example
+    /// Upload a file with a `PUT` request.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_transaction = transaction!(transaction_function);
+    ///
+    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let form = GooseMultipartForm::new().bytes("file", "notes.txt", "text/plain", "notes");
+    ///
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set the method and relative path to request.
+    ///         .method(GooseMethod::Put)
+    ///         .path("files/notes")
+    ///         // Upload the form.
+    ///         .multipart(form)
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn multipart(mut self, form: GooseMultipartForm) -> Self {
+        self.multipart = Some(form);
+        self
+    }
+
+    /// Build the [`GooseRequest`] object which is then passed to [`GooseUser::request`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// // Build the default "GET /".
+    /// let goose_request = GooseRequest::builder().build();
+    /// ```
+    pub fn build(self) -> GooseRequest<'a> {
+        let Self {
+            path,
+            method,
+            name,
+            expect_status_code,
+            error_on_fail,
+            request_builder,
+            resources,
+            multipart,
+        } = self;
+        GooseRequest {
+            path,
+            method,
+            name,
+            expect_status_code,
+            error_on_fail,
+            request_builder,
+            resources,
+            multipart,
+            stream: false,
+            timeout: None,
+        }
+    }
+}
+
+/// A `multipart/form-data` request body, sent with [`GooseUser::post_multipart`] or
+/// [`GooseRequestBuilder::multipart`].
+///
+/// The form is made of text fields and file parts, each named after the form field it
+/// fills. File parts are read from disk with [`GooseMultipartForm::file`], or provided from
+/// memory with [`GooseMultipartForm::bytes`]. The form is encoded by reqwest, which sets the
+/// `Content-Length` of the body, so its size is recorded in
+/// [`GooseRequestMetric::request_body_size`](../metrics/struct.GooseRequestMetric.html#structfield.request_body_size).
+///
+/// When the request body is logged with `--request-body`, a description of each part is
+/// logged instead of the encoded body: its name, the file name and content type of file
+/// parts, and its size.
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+///
+/// let mut transaction = transaction!(upload_avatar);
+///
+/// async fn upload_avatar(user: &mut GooseUser) -> TransactionResult {
+///     let form = GooseMultipartForm::new()
+///         .text("description", "My avatar")
+///         .bytes("avatar", "avatar.png", "image/png", vec![0x89, 0x50, 0x4e, 0x47]);
+///     let _goose = user.post_multipart("profile/avatar", form).await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GooseMultipartForm {
+    // The parts of the form, in the order they're sent.
+    parts: Vec<GooseMultipartPart>,
+}
+/// A single text field or file in a [`GooseMultipartForm`].
+#[derive(Clone, Debug)]
+struct GooseMultipartPart {
+    name: String,
+    // Only set for file parts.
+    filename: Option<String>,
+    // Only set for file parts.
+    content_type: Option<String>,
+    data: Vec<u8>,
+}
+impl GooseMultipartForm {
+    /// Creates an empty form.
+    pub fn new() -> Self {
+        GooseMultipartForm::default()
+    }
+
+    /// Adds a text field.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let form = GooseMultipartForm::new().text("title", "Quarterly report");
+    /// ```
+    pub fn text(mut self, name: &str, value: &str) -> Self {
+        self.parts.push(GooseMultipartPart {
+            name: name.to_string(),
+            filename: None,
+            content_type: None,
+            data: value.as_bytes().to_vec(),
+        });
+        self
+    }
+
+    /// Adds a file part with the provided contents, uploaded with the provided file name
+    /// and content type.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let form = GooseMultipartForm::new().bytes("data", "data.csv", "text/csv", "id,name\n1,foo\n");
+    /// ```
+    pub fn bytes(
+        mut self,
+        name: &str,
+        filename: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.parts.push(GooseMultipartPart {
+            name: name.to_string(),
+            filename: Some(filename.to_string()),
+            content_type: Some(content_type.to_string()),
+            data: data.into(),
+        });
+        self
+    }
+
+    /// Adds a file part read from `path`, uploaded with the name of the file and the
+    /// provided content type. The file is read without blocking when it's added to the
+    /// form, returning [`TransactionError::Io`] if it can't be read.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut transaction = transaction!(upload_report);
+    ///
+    /// async fn upload_report(user: &mut GooseUser) -> TransactionResult {
+    ///     let form = GooseMultipartForm::new()
+    ///         .file("report", "Cargo.toml", "text/plain")
+    ///         .await?;
+    ///     let _goose = user.post_multipart("reports", form).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn file(
+        self,
+        name: &str,
+        path: impl AsRef<std::path::Path>,
+        content_type: &str,
+    ) -> Result<Self, Box<TransactionError>> {
+        let path = path.as_ref();
+        let data = match tokio::fs::read(path).await {
+            Ok(d) => d,
+            Err(e) => return Err(Box::new(e.into())),
+        };
+        let filename = match path.file_name() {
+            Some(f) => f.to_string_lossy().to_string(),
+            None => String::new(),
+        };
+        Ok(self.bytes(name, &filename, content_type, data))
+    }
+
+    // Internal helper that sets the form as the `multipart/form-data` body of a request,
+    // failing if the content type of a file part isn't valid.
+    fn apply(self, request_builder: RequestBuilder) -> Result<RequestBuilder, reqwest::Error> {
+        let mut form = reqwest::multipart::Form::new();
+        for part in self.parts {
+            let mut form_part = reqwest::multipart::Part::bytes(part.data);
+            if let Some(filename) = part.filename {
+                form_part = form_part.file_name(filename);
+            }
+            if let Some(content_type) = part.content_type {
+                form_part = form_part.mime_str(&content_type)?;
+            }
+            form = form.part(part.name, form_part);
+        }
+        Ok(request_builder.multipart(form))
+    }
+
+    // Internal helper that describes the parts of the form in the request log, instead of
+    // logging file contents.
+    fn describe(&self) -> String {
+        let parts: Vec<String> = self
+            .parts
+            .iter()
+            .map(|part| match (&part.filename, &part.content_type) {
+                (Some(filename), Some(content_type)) => format!(
+                    "{} (file {}, {}, {} bytes)",
+                    part.name,
+                    filename,
+                    content_type,
+                    part.data.len()
+                ),
+                _ => format!("{} (text, {} bytes)", part.name, part.data.len()),
+            })
+            .collect();
+        format!("multipart/form-data: {}", parts.join(", "))
+    }
+}
+
+/// Configures how the static resources embedded in an HTML page are loaded, used with
+/// [`GooseRequestBuilder::load_resources`].
+///
+/// Images, stylesheets, scripts and icons referenced by the page are loaded. By default
+/// only resources served from the same host as the page are loaded, and up to 6
+/// connections are opened to each host.
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), GooseError> {
+///     // Also load resources from a CDN, opening at most 2 connections per host.
+///     let resources = GooseResources::new()
+///         .allow_domain("cdn.example.com")
+///         .set_connections_per_host(2)?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GooseResources {
+    // Defaults to `6`.
+    connections_per_host: usize,
+    // Defaults to an empty vector, only the host of the page is allowed.
+    allowed_domains: Vec<String>,
+}
+impl Default for GooseResources {
+    fn default() -> Self {
+        GooseResources::new()
+    }
+}
+impl GooseResources {
+    /// Create a new [`GooseResources`] object with the default configuration.
+    pub fn new() -> Self {
+        GooseResources {
+            connections_per_host: GOOSE_RESOURCE_CONNECTIONS_PER_HOST,
+            allowed_domains: Vec::new(),
+        }
+    }
+
+    /// Set how many concurrent connections can be opened to each host when loading
+    /// resources. Must be set to at least 1.
+    ///
+    /// Defaults to `6`.
+    pub fn set_connections_per_host(mut self, connections: usize) -> Result<Self, GooseError> {
+        if connections == 0 {
+            return Err(GooseError::InvalidOption {
+                option: "GooseResources::set_connections_per_host".to_string(),
+                value: connections.to_string(),
+                detail: "At least 1 connection per host is required.".to_string(),
+            });
+        }
+        self.connections_per_host = connections;
+
+        Ok(self)
+    }
+
+    /// Allow loading resources from a domain other than the host of the page, such as a CDN.
+    /// Subdomains of the allowed domain are also allowed. Can be invoked multiple times to
+    /// allow multiple domains.
+    pub fn allow_domain(mut self, domain: &str) -> Self {
+        self.allowed_domains.push(domain.to_lowercase());
+        self
+    }
+
+    /// Internal helper to determine if a resource can be loaded by a page.
+    fn is_allowed(&self, page_url: &Url, url: &Url) -> bool {
+        let host = match url.host_str() {
+            Some(h) => h,
+            None => return false,
+        };
+        // Resources from the same host as the page are always allowed.
+        if page_url.host_str() == Some(host) {
+            return true;
+        }
+        self.allowed_domains
+            .iter()
+            .any(|domain| host == domain || host.ends_with(&format!(".{}", domain)))
+    }
+
+    /// Internal helper to find all allowed resources embedded in an HTML page, returning
+    /// each resource's absolute URL and the type of resource.
+    fn parse(&self, html: &str, page_url: &Url) -> Vec<(Url, &'static str)> {
+        let mut resources = Vec::new();
+        let mut found = HashSet::new();
+        for tag in RESOURCE_TAG.captures_iter(html) {
+            let tag_name = tag[1].to_lowercase();
+            // Collect the relevant attributes of this tag.
+            let mut attributes = HashMap::new();
+            for attribute in RESOURCE_ATTRIBUTE.captures_iter(&tag[2]) {
+                let value = attribute
+                    .get(2)
+                    .or_else(|| attribute.get(3))
+                    .map_or("", |v| v.as_str());
+                attributes.insert(attribute[1].to_lowercase(), value.replace("&amp;", "&"));
+            }
+
+            // Determine which attributes reference resources, and what type they are.
+            let mut paths = Vec::new();
+            let kind = match tag_name.as_str() {
+                "img" | "source" => {
+                    // The `src` of a `<source>` tag is audio or video, which isn't preloaded.
+                    if tag_name == "img" {
+                        paths.extend(attributes.get("src").cloned());
+                    }
+                    // Each candidate in a `srcset` is a URL optionally followed by a descriptor.
+                    if let Some(srcset) = attributes.get("srcset") {
+                        for candidate in srcset.split(',') {
+                            paths.extend(candidate.split_whitespace().next().map(String::from));
+                        }
+                    }
+                    "img"
+                }
+                "script" => {
+                    paths.extend(attributes.get("src").cloned());
+                    "js"
+                }
+                _ => {
+                    let rel = attributes
+                        .get("rel")
+                        .map_or("".to_string(), |r| r.to_lowercase());
+                    if rel.split_whitespace().any(|r| r == "stylesheet") {
+                        paths.extend(attributes.get("href").cloned());
+                        "css"
+                    } else if rel.split_whitespace().any(|r| r == "icon") {
+                        paths.extend(attributes.get("href").cloned());
+                        "img"
+                    } else {
+                        continue;
+                    }
+                }
+            };
+
+            for path in paths {
+                let path = path.trim();
+                if path.is_empty() {
+                    continue;
+                }
+                let url = match page_url.join(path) {
+                    Ok(u) => u,
+                    Err(_) => continue,
+                };
+                // Only load http(s) resources from allowed domains, and only load each once.
+                if (url.scheme() == "http" || url.scheme() == "https")
+                    && self.is_allowed(page_url, &url)
+                    && found.insert(url.to_string())
+                {
+                    resources.push((url, kind));
+                }
+            }
+        }
+        resources
+    }
+}
+
+/// A DNS resolver that records how long each lookup takes, included in the
+/// [`GooseRequestTimings`](../metrics/struct.GooseRequestTimings.html) of the request that
+/// triggered the lookup.
+///
+/// Goose clients use this resolver by default. To also record DNS timings when building a
+/// custom client with [`GooseUser::set_client_builder`], configure it with
+/// [`ClientBuilder::dns_resolver`].
+///
+/// # Example
+/// ```rust
+/// use goose::goose::GooseDnsResolver;
+/// use reqwest::Client;
+/// use std::sync::Arc;
+///
+/// let builder = Client::builder()
+///     .cookie_store(true)
+///     .dns_resolver(Arc::new(GooseDnsResolver));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GooseDnsResolver;
+impl Resolve for GooseDnsResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        // Lookups are only timed when triggered by a request made by a GooseUser.
+        let dns_time = DNS_TIME.try_with(|dns_time| dns_time.clone()).ok();
+        Box::pin(async move {
+            let started = Instant::now();
+            // The port is replaced by the client once the host name is resolved.
+            let addrs = tokio::net::lookup_host(format!("{}:0", name.as_str())).await?;
+            if let Some(dns_time) = dns_time {
+                let elapsed = started.elapsed().as_millis() as u64;
+                let mut dns_time = dns_time.lock().unwrap();
+                // A request that is redirected to another host can trigger multiple lookups.
+                *dns_time = Some(dns_time.unwrap_or(0) + elapsed);
+            }
+            let addrs: Addrs = Box::new(addrs);
+            Ok(addrs)
+        })
+    }
+}
+
+/// A connector layer that records how long opening each new connection takes, including
+/// the TLS handshake of `https` connections, in the
+/// [`GooseRequestTimings`](../metrics/struct.GooseRequestTimings.html) of the request that
+/// opened the connection.
+///
+/// Goose clients use this layer by default. To also record connection timings when building
+/// a custom client with [`GooseUser::set_client_builder`], configure it with
+/// [`ClientBuilder::connector_layer`].
+///
+/// # Example
+/// ```rust
+/// use goose::goose::{GooseConnectLayer, GooseDnsResolver};
+/// use reqwest::Client;
+/// use std::sync::Arc;
+///
+/// let builder = Client::builder()
+///     .cookie_store(true)
+///     .dns_resolver(Arc::new(GooseDnsResolver))
+///     .connector_layer(GooseConnectLayer);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GooseConnectLayer;
+impl<S> Layer<S> for GooseConnectLayer {
+    type Service = GooseConnectService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GooseConnectService { inner }
+    }
+}
+
+/// The connector service created by the [`GooseConnectLayer`], timing each connection
+/// opened by the wrapped connector.
+#[derive(Clone, Debug)]
+pub struct GooseConnectService<S> {
+    inner: S,
+}
+impl<S, R> Service<R> for GooseConnectService<S>
+where
+    S: Service<R>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        // Connections are only timed when opened by a request made by a GooseUser.
+        let connect_time = CONNECT_TIME
+            .try_with(|connect_time| connect_time.clone())
+            .ok();
+        let started = Instant::now();
+        let connecting = self.inner.call(request);
+        Box::pin(async move {
+            let connection = connecting.await;
+            // Only connections that were opened successfully are timed.
+            if let (Some(connect_time), Ok(_)) = (connect_time, connection.as_ref()) {
+                let elapsed = started.elapsed().as_millis() as u64;
+                let mut connect_time = connect_time.lock().unwrap();
+                // A request that is redirected to another host can open multiple connections.
+                *connect_time = Some(connect_time.unwrap_or(0) + elapsed);
+            }
+            connection
+        })
+    }
+}
+
+/// Internal helper to read the body of a response, returning the body as text along with
+/// a copy of the response so the body can be read again.
+async fn read_response_body(response: Response) -> Result<(String, Response), reqwest::Error> {
+    let (body, copy) = read_response_bytes(response, None).await?;
+    Ok((String::from_utf8_lossy(body.as_ref()).to_string(), copy))
+}
+
+/// Internal helper to read the body of a response into memory, no faster than the
+/// optional `download_bandwidth` in bytes per second, returning the body along with a copy
+/// of the response so the body can be read again.
+async fn read_response_bytes(
+    mut response: Response,
+    download_bandwidth: Option<u64>,
+) -> Result<(Bytes, Response), reqwest::Error> {
+    let url = response.url().clone();
+    let status = response.status();
+    let version = response.version();
+    let headers = response.headers().clone();
+    // Keep the extensions, such as the address of the server, with the copy.
+    let extensions = std::mem::take(response.extensions_mut());
+    let body = match download_bandwidth {
+        None => response.bytes().await?,
+        Some(bandwidth) => {
+            let started = Instant::now();
+            let mut body = Vec::new();
+            while let Some(chunk) = response.chunk().await? {
+                body.extend_from_slice(&chunk);
+                throttle_transfer(started, body.len(), bandwidth).await;
+            }
+            body.into()
+        }
+    };
+
+    let copy = build_response(url, status, version, headers, extensions, body.clone());
+    Ok((body, copy))
+}
+
+/// Gzip encoded response bodies larger than this many bytes are decompressed on a blocking
+/// thread, so they don't hold up other users running on the same worker thread.
+const BLOCKING_DECOMPRESS_SIZE: usize = 64 * 1024;
+
+/// Internal helper to decompress a gzip encoded response body, returning the decompressed
+/// body along with a copy of the response without the `Content-Encoding` and
+/// `Content-Length` headers, as the client does when it decompresses responses itself.
+/// Returns `None` if the body isn't gzip encoded, and an error if it can't be decoded.
+async fn decompress_response(
+    body: &Bytes,
+    response: &mut Response,
+) -> Option<std::io::Result<(Bytes, Response)>> {
+    let gzip_encoded = response
+        .headers()
+        .get(header::CONTENT_ENCODING)
+        .is_some_and(|encoding| encoding.as_bytes().eq_ignore_ascii_case(b"gzip"));
+    if !gzip_encoded || body.is_empty() {
+        return None;
+    }
+
+    let decompressed = if body.len() > BLOCKING_DECOMPRESS_SIZE {
+        let body = body.clone();
+        match tokio::task::spawn_blocking(move || gunzip(&body)).await {
+            Ok(decompressed) => decompressed,
+            Err(e) => Err(std::io::Error::other(e)),
+        }
+    } else {
+        gunzip(body)
+    };
+    let body = match decompressed {
+        Ok(body) => body,
+        Err(e) => return Some(Err(e)),
+    };
+
+    let mut headers = response.headers().clone();
+    headers.remove(header::CONTENT_ENCODING);
+    headers.remove(header::CONTENT_LENGTH);
+    let extensions = std::mem::take(response.extensions_mut());
+    let copy = build_response(
+        response.url().clone(),
+        response.status(),
+        response.version(),
+        headers,
+        extensions,
+        body.clone(),
+    );
+    Some(Ok((body, copy)))
+}
+
+/// Internal helper to decode a gzip encoded body.
+fn gunzip(body: &[u8]) -> std::io::Result<Bytes> {
+    let mut decompressed = Vec::new();
+    GzDecoder::new(body).read_to_end(&mut decompressed)?;
+    Ok(decompressed.into())
+}
+
+/// Internal helper to build a response from parts copied from another response.
+fn build_response<T: Into<reqwest::Body>>(
+    url: Url,
+    status: reqwest::StatusCode,
+    version: reqwest::Version,
+    headers: header::HeaderMap,
+    extensions: http::Extensions,
+    body: T,
+) -> Response {
+    let mut builder = http::Response::builder().status(status).version(version);
+    if let Some(builder_headers) = builder.headers_mut() {
+        *builder_headers = headers;
+    }
+    if let Some(builder_extensions) = builder.extensions_mut() {
+        *builder_extensions = extensions;
+    }
+    let builder = builder.url(url);
+    let response = builder
+        .body(body)
+        .expect("parts copied from a valid response are always valid");
+
+    Response::from(response)
+}
+
+/// Remove path from Reqwest error to avoid having a lot of distincts error
+/// when path parameters are used.
+fn clean_reqwest_error(e: &reqwest::Error, request_name: &str) -> String {
+    let kind = if e.is_builder() {
+        "builder error"
+    } else if e.is_request() {
+        "error sending request"
+    } else if e.is_body() {
+        "request or response body error"
+    } else if e.is_decode() {
+        "error decoding response body"
+    } else if e.is_redirect() {
+        "error following redirect"
+    } else {
+        "Http status"
+    };
+
+    if let Some(ref e) = std::error::Error::source(e) {
+        format!("{} {}: {}", kind, request_name, e)
+    } else {
+        format!("{} {}", kind, request_name)
+    }
+}
+
+/// A helper to determine which host should be prepended to relative load test
+/// paths in this Scenario.
+///
+/// The first of these defined will be returned as the prepended host:
+///  1. `--host` (host specified on the command line when running load test)
+///  2. [`Scenario`](./struct.Scenario.html)`.host` (default host defined
+///     for the current scenario)
+///  3. [`GooseDefault::Host`](../config/enum.GooseDefault.html#variant.Host) (default
+///     host defined for the current load test)
+pub fn get_base_url(
+    config_host: Option<String>,
+    scenario_host: Option<String>,
+    default_host: Option<String>,
+) -> Result<Url, GooseError> {
+    // If the `--host` CLI option is set, build the URL with it.
+    match config_host {
+        Some(host) => Ok(
+            Url::parse(&host).map_err(|parse_error| GooseError::InvalidHost {
+                host,
+                detail: "There was a failure parsing the host specified with --host.".to_string(),
+                parse_error,
+            })?,
+        ),
+        None => {
+            match scenario_host {
+                // Otherwise, if `Scenario.host` is defined, usee this
+                Some(host) => {
+                    Ok(
+                        Url::parse(&host).map_err(|parse_error| GooseError::InvalidHost {
+                            host,
+                            detail: "There was a failure parsing the host specified with the Scenario.set_host() function.".to_string(),
+                            parse_error,
+                        })?,
+                    )
+                }
+                // Otherwise, use global `GooseAttack.host`. `unwrap` okay as host validation was done at startup.
+                None => {
+                    // Host is required, if we get here it's safe to unwrap this variable.
+                    let default_host = default_host.unwrap();
+                    Ok(
+                        Url::parse(&default_host).map_err(|parse_error| GooseError::InvalidHost {
+                            host: default_host.to_string(),
+                            detail: "There was a failure parsing the host specified globally with the GooseAttack.set_default() function.".to_string(),
+                            parse_error,
+                        })?,
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// The function type of a goose transaction function.
+pub type TransactionFunction = Arc<
+    dyn for<'r> Fn(
+            &'r mut GooseUser,
+        ) -> Pin<Box<dyn Future<Output = TransactionResult> + Send + 'r>>
+        + Send
+        + Sync,
+>;
+
+/// An individual transaction within a [`Scenario`](./struct.Scenario.html).
+#[derive(Clone)]
+pub struct Transaction {
+    /// An index into [`Scenario`](./struct.Scenario.html)`.transaction`, indicating which
+    /// transaction this is.
+    pub transactions_index: usize,
+    /// An optional name for the transaction, used when displaying metrics.
+    pub name: String,
+    /// An integer value that controls the frequency that this transaction will be run.
+    pub weight: usize,
+    /// An integer value that controls when this transaction runs compared to other transactions in the same
+    /// [`Scenario`](./struct.Scenario.html).
+    pub sequence: usize,
+    /// A flag indicating that this transaction runs when the user starts.
+    pub on_start: bool,
+    /// A flag indicating that this transaction runs when the user stops.
+    pub on_stop: bool,
+    /// A required function that is executed each time this transaction runs.
+    pub function: TransactionFunction,
+}
+impl Transaction {
+    pub fn new(function: TransactionFunction) -> Self {
+        trace!("new transaction");
+        Transaction {
+            transactions_index: usize::max_value(),
+            name: "".to_string(),
+            weight: 1,
+            sequence: 0,
+            on_start: false,
+            on_stop: false,
+            function,
+        }
+    }
+
+    /// Set an optional name for the transaction, used when displaying metrics.
+    ///
+    /// Individual requests can also be named using [`GooseRequestBuilder`], or for GET
+    /// requests with the [`GooseUser::get_named`] helper.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// transaction!(my_transaction_function).set_name("foo");
+    ///
+    /// async fn my_transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.get("").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_name(mut self, name: &str) -> Self {
+        trace!("[{}] set_name: {}", self.transactions_index, self.name);
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set an optional flag indicating that this transaction should be run when
+    /// a user first starts. This could be used to log the user in, and
+    /// so all subsequent transaction are done as a logged in user. A transaction
+    /// with this flag set will only run at start time (and optionally at stop
+    /// time as well, if that flag is also set).
+    ///
+    /// On-start transactions can be sequenced and weighted. Sequences allow
+    /// multiple on-start transactions to run in a controlled order. Weights allow
+    /// on-start transactions to run multiple times when a user starts.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// transaction!(my_on_start_function).set_on_start();
+    ///
+    /// async fn my_on_start_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.get("").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_on_start(mut self) -> Self {
+        trace!(
+            "{} [{}] set_on_start transaction",
+            self.name,
+            self.transactions_index
+        );
+        self.on_start = true;
+        self
+    }
+
+    /// Set an optional flag indicating that this transaction should be run when
+    /// a user stops. This could be used to log a user out when the user
+    /// finishes its load test. A transaction with this flag set will only run at
+    /// stop time (and optionally at start time as well, if that flag is
+    /// also set).
+    ///
+    /// On-stop transactions can be sequenced and weighted. Sequences allow
+    /// multiple on-stop transactions to run in a controlled order. Weights allow
+    /// on-stop transactions to run multiple times when a user stops.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// transaction!(my_on_stop_function).set_on_stop();
+    ///
+    /// async fn my_on_stop_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.get("").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_on_stop(mut self) -> Self {
+        trace!(
+            "{} [{}] set_on_stop transaction",
+            self.name,
+            self.transactions_index
+        );
+        self.on_stop = true;
+        self
+    }
+
+    /// Sets a weight on an individual transaction. The larger the value of weight, the more often it will be run
+    /// in the Scenario. For example, if one transaction has a weight of 3 and another transaction has a weight of
+    /// 1, the first transaction will run 3 times as often.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     transaction!(transaction_function).set_weight(3)?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.get("").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_weight(mut self, weight: usize) -> Result<Self, GooseError> {
+        trace!(
+            "{} [{}] set_weight: {}",
+            self.name,
+            self.transactions_index,
+            weight
+        );
+        if weight == 0 {
+            return Err(GooseError::InvalidWeight {
+                weight,
+                detail: "Weight must be set to at least 1.".to_string(),
+            });
+        }
+        self.weight = weight;
+
+        Ok(self)
+    }
+
+    /// Defines the sequence value of an individual transactions. Transactions are run in order of their
+    /// sequence value, so a transaction with a sequence value of 1 will run before a transaction with a
+    /// sequence value of 2. Transactions with no sequence value (or a sequence value of 0) will run last,
+    /// after all transactions with positive sequence values.
+    ///
+    /// All transactions with the same sequence value will run in a random order. Transactions can be
+    /// assigned both squence values and weights.
+    ///
+    /// # Examples
+    /// In this first example, the variable names indicate the order the transactions will be run in:
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let runs_first = transaction!(first_transaction_function).set_sequence(3);
+    /// let runs_second = transaction!(second_transaction_function).set_sequence(5835);
+    /// let runs_last = transaction!(third_transaction_function);
+    ///
+    /// async fn first_transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.get("1").await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn second_transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.get("2").await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn third_transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.get("3").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// In the following example, the `runs_first` transactions runs two times, then one instance of `runs_second`
+    /// and two instances of `also_runs_second` are all three run. The user will do this over and over
+    /// the entire time it runs, with `runs_first` always running first, then the other transactions being
+    /// run in a random and weighted order:
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let runs_first = transaction!(first_transaction_function).set_sequence(1).set_weight(2)?;
+    ///     let runs_second = transaction!(second_transaction_function_a).set_sequence(2);
+    ///     let also_runs_second = transaction!(second_transaction_function_b).set_sequence(2).set_weight(2)?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn first_transaction_function(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.get("1").await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn second_transaction_function_a(user: &mut GooseUser) -> TransactionResult {
+    ///     let _goose = user.get("2a").await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    ///     async fn second_transaction_function_b(user: &mut GooseUser) -> TransactionResult {
+    ///       let _goose = user.get("2b").await?;
+    ///
+    ///       Ok(())
+    ///     }
+    /// ```
+    pub fn set_sequence(mut self, sequence: usize) -> Self {
+        trace!(
+            "{} [{}] set_sequence: {}",
+            self.name,
+            self.transactions_index,
+            sequence
+        );
+        if sequence < 1 {
+            info!(
+                "setting sequence to 0 for transaction {} is unnecessary, sequence disabled",
+                self.name
+            );
+        }
+        self.sequence = sequence;
+        self
+    }
+}
+impl Hash for Transaction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.transactions_index.hash(state);
+        self.name.hash(state);
+        self.weight.hash(state);
+        self.sequence.hash(state);
+        self.on_start.hash(state);
+        self.on_stop.hash(state);
+    }
+}
+
+/// Returns the path of `method` of the gRPC `service`.
+#[cfg(feature = "grpc")]
+fn grpc_path(
+    service: &str,
+    method: &str,
+) -> Result<tonic::codegen::http::uri::PathAndQuery, Box<TransactionError>> {
+    let path = format!("/{}/{}", service, method);
+    match path.parse() {
+        Ok(path_and_query) => Ok(path_and_query),
+        Err(_) => Err(Box::new(TransactionError::InvalidGrpcPath { path })),
+    }
+}
+
+/// Returns the type and optional name of the first operation defined in a GraphQL `query`.
+/// Queries using the shorthand syntax are anonymous queries.
+fn graphql_operation(query: &str) -> (&str, Option<&str>) {
+    match GRAPHQL_OPERATION.captures(query) {
+        Some(captures) => (
+            captures.get(1).map_or("query", |m| m.as_str()),
+            captures.get(2).map(|m| m.as_str()),
+        ),
+        None => ("query", None),
+    }
+}
+
+/// Removes all complete Server-Sent Events from `buffer`, which must not contain any carriage
+/// returns, and returns those that contain data. Incomplete events are left in the buffer.
+fn parse_server_sent_events(buffer: &mut Vec<u8>, elapsed: u64) -> Vec<GooseStreamEvent> {
+    let mut events = Vec::new();
+    // Each event ends with a blank line.
+    while let Some(end) = buffer.windows(2).position(|window| window == b"\n\n") {
+        let block: Vec<u8> = buffer.drain(..end + 2).collect();
+        let mut event = GooseStreamEvent {
+            elapsed,
+            event: None,
+            id: None,
+            data: String::new(),
+        };
+        let mut has_data = false;
+        for line in String::from_utf8_lossy(&block).lines() {
+            // Lines starting with a colon are comments, often used to keep the stream open.
+            if line.starts_with(':') {
+                continue;
+            }
+            let (field, value) = match line.split_once(':') {
+                Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+                None => (line, ""),
+            };
+            match field {
+                "data" => {
+                    if has_data {
+                        event.data.push('\n');
+                    }
+                    event.data.push_str(value);
+                    has_data = true;
+                }
+                "event" => event.event = Some(value.to_string()),
+                "id" => event.id = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        if has_data {
+            events.push(event);
+        }
+    }
+    events
+}
+
+/// Returns how long to wait for a request to complete, configured with `--timeout`.
+fn request_timeout(configuration: &GooseConfiguration) -> Duration {
+    // Either use manually configured timeout, or default.
+    let timeout = if configuration.timeout.is_some() {
+        match crate::util::get_float_from_string(configuration.timeout.clone()) {
+            Some(f) => f as u64 * 1_000,
+            None => GOOSE_REQUEST_TIMEOUT,
+        }
+    } else {
+        GOOSE_REQUEST_TIMEOUT
+    };
+    Duration::from_millis(timeout)
+}
+
+/// Waits for WebSocket activity to complete, returning an error if it takes longer than
+/// `timeout`.
+async fn with_websocket_timeout<T>(
+    timeout: Duration,
+    future: impl Future<Output = Result<T, tokio_tungstenite::tungstenite::Error>>,
+) -> Result<T, tokio_tungstenite::tungstenite::Error> {
+    match tokio::time::timeout(timeout, future).await {
+        Ok(result) => result,
+        Err(_) => Err(tokio_tungstenite::tungstenite::Error::Io(
+            std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("timed out after {} seconds", timeout.as_secs_f32()),
+            ),
+        )),
+    }
+}
+
+/// Returns the next text, binary or close message received on a WebSocket connection,
+/// skipping ping and pong messages. Returns an error if the connection was lost.
+async fn next_websocket_message(
+    stream: &mut WebSocketStream<MaybeTlsStream<TcpStream>>,
+) -> Result<GooseWebSocketMessage, tokio_tungstenite::tungstenite::Error> {
+    loop {
+        match stream.next().await {
+            Some(Ok(GooseWebSocketMessage::Ping(_)))
+            | Some(Ok(GooseWebSocketMessage::Pong(_)))
+            | Some(Ok(GooseWebSocketMessage::Frame(_))) => continue,
+            Some(result) => return result,
+            None => return Err(tokio_tungstenite::tungstenite::Error::ConnectionClosed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use gumdrop::Options;
+    use httpmock::{
+        Method::{GET, POST},
+        MockServer,
+    };
+
+    const EMPTY_ARGS: Vec<&str> = vec![];
+
+    fn setup_user(server: &MockServer) -> Result<GooseUser, GooseError> {
+        let mut configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        configuration.co_mitigation = Some(GooseCoordinatedOmissionMitigation::Average);
+        let base_url = get_base_url(Some(server.url("/")), None, None).unwrap();
+        GooseUser::single(base_url, &configuration)
+    }
+
+    #[test]
+    fn goose_scenario() {
+        // Simplistic test transaction functions.
+        async fn test_function_a(user: &mut GooseUser) -> TransactionResult {
+            let _goose = user.get("/a/").await?;
+
+            Ok(())
+        }
+
+        async fn test_function_b(user: &mut GooseUser) -> TransactionResult {
+            let _goose = user.get("/b/").await?;
+
+            Ok(())
+        }
+
+        let mut scenario = scenario!("foo");
+        assert_eq!(scenario.name, "foo");
+        assert_eq!(scenario.scenarios_index, usize::max_value());
+        assert_eq!(scenario.weight, 1);
+        assert_eq!(scenario.transaction_wait, None);
+        assert!(scenario.host.is_none());
+        assert_eq!(scenario.transactions.len(), 0);
+        assert_eq!(scenario.weighted_transactions.len(), 0);
+        assert_eq!(scenario.weighted_on_start_transactions.len(), 0);
+        assert_eq!(scenario.weighted_on_stop_transactions.len(), 0);
+
+        // Registering a transaction adds it to transactions, but doesn't update weighted_transactions.
+        scenario = scenario.register_transaction(transaction!(test_function_a));
+        assert_eq!(scenario.transactions.len(), 1);
+        assert_eq!(scenario.weighted_transactions.len(), 0);
+        assert_eq!(scenario.scenarios_index, usize::max_value());
+        assert_eq!(scenario.weight, 1);
+        assert_eq!(scenario.transaction_wait, None);
+        assert!(scenario.host.is_none());
+
+        // Different transactions can be registered.
+        scenario = scenario.register_transaction(transaction!(test_function_b));
+        assert_eq!(scenario.transactions.len(), 2);
+        assert_eq!(scenario.weighted_transactions.len(), 0);
+        assert_eq!(scenario.scenarios_index, usize::max_value());
+        assert_eq!(scenario.weight, 1);
+        assert_eq!(scenario.transaction_wait, None);
+        assert!(scenario.host.is_none());
+
+        // Same transactions can be registered again.
+        scenario = scenario.register_transaction(transaction!(test_function_a));
+        assert_eq!(scenario.transactions.len(), 3);
+        assert_eq!(scenario.weighted_transactions.len(), 0);
+        assert_eq!(scenario.scenarios_index, usize::max_value());
+        assert_eq!(scenario.weight, 1);
+        assert_eq!(scenario.transaction_wait, None);
+        assert!(scenario.host.is_none());
+
+        // Setting weight only affects weight field.
+        scenario = scenario.set_weight(50).unwrap();
+        assert_eq!(scenario.weight, 50);
+        assert_eq!(scenario.transactions.len(), 3);
+        assert_eq!(scenario.weighted_transactions.len(), 0);
+        assert_eq!(scenario.scenarios_index, usize::max_value());
+        assert_eq!(scenario.transaction_wait, None);
+        assert!(scenario.host.is_none());
+
+        // Weight can be changed.
+        scenario = scenario.set_weight(5).unwrap();
+        assert_eq!(scenario.weight, 5);
+
+        // Setting host only affects host field.
+        scenario = scenario.set_host("http://foo.example.com/");
+        assert_eq!(scenario.host, Some("http://foo.example.com/".to_string()));
+        assert_eq!(scenario.weight, 5);
+        assert_eq!(scenario.transactions.len(), 3);
+        assert_eq!(scenario.weighted_transactions.len(), 0);
+        assert_eq!(scenario.scenarios_index, usize::max_value());
+        assert_eq!(scenario.transaction_wait, None);
+
+        // Host field can be changed.
+        scenario = scenario.set_host("https://bar.example.com/");
+        assert_eq!(scenario.host, Some("https://bar.example.com/".to_string()));
+
+        // Wait time only affects wait time fields.
+        scenario = scenario
+            .set_wait_time(Duration::from_secs(1), Duration::from_secs(10))
+            .unwrap();
+        assert_eq!(
+            scenario.transaction_wait,
+            Some((Duration::from_secs(1), Duration::from_secs(10)))
+        );
+        assert_eq!(scenario.host, Some("https://bar.example.com/".to_string()));
+        assert_eq!(scenario.weight, 5);
+        assert_eq!(scenario.transactions.len(), 3);
+        assert_eq!(scenario.weighted_transactions.len(), 0);
+        assert_eq!(scenario.scenarios_index, usize::max_value());
+
+        // Wait time can be changed.
+        scenario = scenario
+            .set_wait_time(Duration::from_secs(3), Duration::from_secs(9))
+            .unwrap();
+        assert_eq!(
+            scenario.transaction_wait,
+            Some((Duration::from_secs(3), Duration::from_secs(9)))
+        );
+    }
+
+    #[test]
+    fn goose_transaction() {
+        // Simplistic test transaction functions.
+        async fn test_function_a(user: &mut GooseUser) -> TransactionResult {
+            let _goose = user.get("/a/").await?;
+
+            Ok(())
+        }
+
+        // Initialize scenario.
+        let mut transaction = transaction!(test_function_a);
+        assert_eq!(transaction.transactions_index, usize::max_value());
+        assert_eq!(transaction.name, "".to_string());
+        assert_eq!(transaction.weight, 1);
+        assert_eq!(transaction.sequence, 0);
+        assert!(!transaction.on_start);
+        assert!(!transaction.on_stop);
+
+        // Name can be set, without affecting other fields.
+        transaction = transaction.set_name("foo");
+        assert_eq!(transaction.name, "foo".to_string());
+        assert_eq!(transaction.weight, 1);
+        assert_eq!(transaction.sequence, 0);
+        assert!(!transaction.on_start);
+        assert!(!transaction.on_stop);
+
+        // Name can be set multiple times.
+        transaction = transaction.set_name("bar");
+        assert_eq!(transaction.name, "bar".to_string());
+
+        // On start flag can be set, without affecting other fields.
+        transaction = transaction.set_on_start();
+        assert!(transaction.on_start);
+        assert_eq!(transaction.name, "bar".to_string());
+        assert_eq!(transaction.weight, 1);
+        assert_eq!(transaction.sequence, 0);
+        assert!(!transaction.on_stop);
+
+        // Setting on start flag twice doesn't change anything.
+        transaction = transaction.set_on_start();
+        assert!(transaction.on_start);
+
+        // On stop flag can be set, without affecting other fields.
+        // It's possible to set both on_start and on_stop for same transaction.
+        transaction = transaction.set_on_stop();
+        assert!(transaction.on_stop);
+        assert!(transaction.on_start);
+        assert_eq!(transaction.name, "bar".to_string());
+        assert_eq!(transaction.weight, 1);
+        assert_eq!(transaction.sequence, 0);
+
+        // Setting on stop flag twice doesn't change anything.
+        transaction = transaction.set_on_stop();
+        assert!(transaction.on_stop);
+
+        // Setting weight doesn't change anything else.
+        transaction = transaction.set_weight(2).unwrap();
+        assert_eq!(transaction.weight, 2);
+        assert!(transaction.on_stop);
+        assert!(transaction.on_start);
+        assert_eq!(transaction.name, "bar".to_string());
+        assert_eq!(transaction.sequence, 0);
+
+        // Weight field can be changed multiple times.
+        transaction = transaction.set_weight(3).unwrap();
+        assert_eq!(transaction.weight, 3);
+
+        // Setting sequence doesn't change anything else.
+        transaction = transaction.set_sequence(4);
+        assert_eq!(transaction.sequence, 4);
+        assert_eq!(transaction.weight, 3);
+        assert!(transaction.on_stop);
+        assert!(transaction.on_start);
+        assert_eq!(transaction.name, "bar".to_string());
+
+        // Sequence field can be changed multiple times.
+        transaction = transaction.set_sequence(8);
+        assert_eq!(transaction.sequence, 8);
+    }
+
+    #[tokio::test]
+    async fn goose_user() {
+        const HOST: &str = "http://example.com/";
+        let configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        let base_url = get_base_url(Some(HOST.to_string()), None, None).unwrap();
+        let user = GooseUser::new(0, base_url, &configuration, 0).unwrap();
+        assert_eq!(user.scenarios_index, 0);
+        assert_eq!(user.weighted_users_index, usize::max_value());
+
+        // Confirm the URLs are correctly built using the default_host.
+        let url = user.build_url("/foo").unwrap();
+        assert_eq!(&url, &[HOST, "foo"].concat());
+        let url = user.build_url("bar/").unwrap();
+        assert_eq!(&url, &[HOST, "bar/"].concat());
+        let url = user.build_url("/foo/bar").unwrap();
+        assert_eq!(&url, &[HOST, "foo/bar"].concat());
+
+        // Confirm the URLs are built with their own specified host.
+        let url = user.build_url("https://example.com/foo").unwrap();
+        assert_eq!(url, "https://example.com/foo");
+        let url = user
+            .build_url("https://www.example.com/path/to/resource")
+            .unwrap();
+        assert_eq!(url, "https://www.example.com/path/to/resource");
+
+        // Create a second user, this time setting a scenario_host.
+        let base_url = get_base_url(
+            None,
+            Some("http://www2.example.com/".to_string()),
+            Some("http://www.example.com/".to_string()),
+        )
+        .unwrap();
+        let user2 = GooseUser::new(0, base_url, &configuration, 0).unwrap();
+
+        // Confirm the URLs are correctly built using the scenario_host.
+        let url = user2.build_url("/foo").unwrap();
+        assert_eq!(url, "http://www2.example.com/foo");
+
+        // Confirm URLs are still built with their own specified host.
+        let url = user2.build_url("https://example.com/foo").unwrap();
+        assert_eq!(url, "https://example.com/foo");
+
+        // Confirm Goose can build a base_url that includes a path.
+        const HOST_WITH_PATH: &str = "http://example.com/with/path/";
+        let base_url = get_base_url(Some(HOST_WITH_PATH.to_string()), None, None).unwrap();
+        let user = GooseUser::new(0, base_url, &configuration, 0).unwrap();
+
+        // Confirm the URLs are correctly built using the default_host that includes a path.
+        let url = user.build_url("foo").unwrap();
+        assert_eq!(&url, &[HOST_WITH_PATH, "foo"].concat());
+        let url = user.build_url("bar/").unwrap();
+        assert_eq!(&url, &[HOST_WITH_PATH, "bar/"].concat());
+        let url = user.build_url("foo/bar").unwrap();
+        assert_eq!(&url, &[HOST_WITH_PATH, "foo/bar"].concat());
+
+        // Confirm that URLs are correctly re-written if an absolute path is used.
+        let url = user.build_url("/foo").unwrap();
+        assert_eq!(&url, &[HOST, "foo"].concat());
+    }
+
+    #[tokio::test]
+    async fn manual_requests() {
+        let server = MockServer::start();
+
+        let mut user = setup_user(&server).unwrap();
+
+        // Set up a mock http server endpoint.
+        const INDEX_PATH: &str = "/";
+        let index = server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        });
+
+        // Make a GET request to the mock http server and confirm we get a 200 response.
+        assert_eq!(index.hits(), 0);
+        let goose = user
+            .get(INDEX_PATH)
+            .await
+            .expect("get returned unexpected error");
+        let status = goose.response.unwrap().status();
+        assert_eq!(status, 200);
+        assert_eq!(goose.request.raw.method, GooseMethod::Get);
+        assert_eq!(goose.request.name, INDEX_PATH);
+        assert!(goose.request.success);
+        assert!(!goose.request.update);
+        assert_eq!(goose.request.status_code, 200);
+        assert_eq!(index.hits(), 1);
+
+        const NO_SUCH_PATH: &str = "/no/such/path";
+        // Set up a mock http server endpoint.
+        let not_found = server.mock(|when, then| {
+            when.method(GET).path(NO_SUCH_PATH);
+            then.status(404);
+        });
+
+        // Make an invalid GET request to the mock http server and confirm we get a 404 response.
+        assert_eq!(not_found.hits(), 0);
+        let goose = user
+            .get(NO_SUCH_PATH)
+            .await
+            .expect("get returned unexpected error");
+        let status = goose.response.unwrap().status();
+        assert_eq!(status, 404);
+        assert_eq!(goose.request.raw.method, GooseMethod::Get);
+        assert_eq!(goose.request.name, NO_SUCH_PATH);
+        assert!(!goose.request.success);
+        assert!(!goose.request.update);
+        assert_eq!(goose.request.status_code, 404,);
+        not_found.assert_hits(1);
+
+        // Set up a mock http server endpoint.
+        const COMMENT_PATH: &str = "/comment";
+        let comment = server.mock(|when, then| {
+            when.method(POST).path(COMMENT_PATH).body("foo");
+            then.status(200).body("foo");
+        });
+
+        // Make a POST request to the mock http server and confirm we get a 200 OK response.
+        assert_eq!(comment.hits(), 0);
+        let goose = user
+            .post(COMMENT_PATH, "foo")
+            .await
+            .expect("post returned unexpected error");
+        let unwrapped_response = goose.response.unwrap();
+        let status = unwrapped_response.status();
+        assert_eq!(status, 200);
+        let body = unwrapped_response.text().await.unwrap();
+        assert_eq!(body, "foo");
+        assert_eq!(goose.request.raw.method, GooseMethod::Post);
+        assert!(goose.request.success);
+        assert!(!goose.request.update);
+        assert_eq!(goose.request.status_code, 200);
+        comment.assert_hits(1);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[tokio::test]
+    async fn load_resources() {
+        let server = MockServer::start();
 
-    use gumdrop::Options;
-    use httpmock::{
-        Method::{GET, POST},
-        MockServer,
-    };
+        let mut user = setup_user(&server).unwrap();
 
-    const EMPTY_ARGS: Vec<&str> = vec![];
+        // Capture the request metrics sent by the user.
+        let (metrics_tx, metrics_rx) = flume::unbounded();
+        user.metrics_channel = Some(metrics_tx);
+
+        // Set up a mock http server endpoint returning a page with embedded resources.
+        const PAGE_PATH: &str = "/page";
+        const PAGE_HTML: &str = r#"<html><head>
+            <link rel="stylesheet" href="/style.css">
+            <link rel="canonical" href="/page">
+            <script src='/script.js'></script>
+            <script src="https://other.example.com/tracker.js"></script>
+        </head><body>
+            <img src="/image.png" alt="image">
+            <IMG SRCSET="/image.png 1x, /image-2x.png 2x">
+        </body></html>"#;
+        let page = server.mock(|when, then| {
+            when.method(GET).path(PAGE_PATH);
+            then.status(200)
+                .header("content-type", "text/html; charset=utf-8")
+                .body(PAGE_HTML);
+        });
 
-    fn setup_user(server: &MockServer) -> Result<GooseUser, GooseError> {
-        let mut configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
-        configuration.co_mitigation = Some(GooseCoordinatedOmissionMitigation::Average);
-        let base_url = get_base_url(Some(server.url("/")), None, None).unwrap();
-        GooseUser::single(base_url, &configuration)
+        // Set up mock http server endpoints for each resource.
+        let resources: Vec<_> = ["/style.css", "/script.js", "/image.png", "/image-2x.png"]
+            .iter()
+            .map(|path| {
+                server.mock(|when, then| {
+                    when.method(GET).path(*path);
+                    then.status(200).body("resource");
+                })
+            })
+            .collect();
+
+        // Load the page and its resources.
+        let goose_request = GooseRequest::builder()
+            .path(PAGE_PATH)
+            .load_resources(GooseResources::new())
+            .build();
+        let goose = user
+            .request(goose_request)
+            .await
+            .expect("request returned unexpected error");
+        assert!(goose.request.success);
+        page.assert_hits(1);
+
+        // The body of the page can still be read.
+        let body = goose.response.unwrap().text().await.unwrap();
+        assert_eq!(body, PAGE_HTML);
+
+        // Each resource was loaded one time, even if referenced multiple times.
+        for resource in &resources {
+            resource.assert_hits(1);
+        }
+
+        // Resources are named after the parent page in the metrics.
+        let mut names: Vec<String> = metrics_rx
+            .drain()
+            .map(|metric| match metric {
+                GooseMetric::Request(request) => request.name,
+                _ => unreachable!(),
+            })
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "/page",
+                "/page > css",
+                "/page > img",
+                "/page > img",
+                "/page > js"
+            ]
+        );
     }
 
     #[test]
-    fn goose_scenario() {
-        // Simplistic test transaction functions.
-        async fn test_function_a(user: &mut GooseUser) -> TransactionResult {
-            let _goose = user.get("/a/").await?;
+    fn parse_resources() {
+        let page_url = Url::parse("http://example.com/path/page").unwrap();
+        let html = r#"
+            <img src="image.png">
+            <img src="data:image/png;base64,AAAA">
+            <img src="http://cdn.example.com/a.png?foo=1&amp;bar=2">
+            <img src="http://static.cdn.example.com/b.png">
+            <img src="http://other.example.com/c.png">
+            <link rel="shortcut icon" href="/favicon.ico">
+            <link rel="preconnect" href="http://cdn.example.com/">
+            <source src="/video.mp4">
+        "#;
+
+        // By default only resources from the same host are loaded.
+        let resources = GooseResources::new().parse(html, &page_url);
+        assert_eq!(
+            resources,
+            vec![
+                (
+                    Url::parse("http://example.com/path/image.png").unwrap(),
+                    "img"
+                ),
+                (Url::parse("http://example.com/favicon.ico").unwrap(), "img"),
+            ]
+        );
 
-            Ok(())
+        // Allowed domains and their subdomains are also loaded.
+        let resources = GooseResources::new()
+            .allow_domain("cdn.example.com")
+            .parse(html, &page_url);
+        assert_eq!(resources.len(), 4);
+        assert_eq!(
+            resources[1].0.as_str(),
+            "http://cdn.example.com/a.png?foo=1&bar=2"
+        );
+        assert_eq!(
+            resources[2].0.as_str(),
+            "http://static.cdn.example.com/b.png"
+        );
+
+        // At least one connection per host is required.
+        assert!(GooseResources::new().set_connections_per_host(0).is_err());
+        assert!(GooseResources::new().set_connections_per_host(1).is_ok());
+    }
+
+    #[tokio::test]
+    async fn request_batch() {
+        let server = MockServer::start();
+
+        let mut user = setup_user(&server).unwrap();
+
+        // Capture the request metrics sent by the user.
+        let (metrics_tx, metrics_rx) = flume::unbounded();
+        user.metrics_channel = Some(metrics_tx);
+
+        // Set up mock http server endpoints that are slow to respond.
+        const DELAY: u64 = 500;
+        let paths = ["/one", "/two", "/three"];
+        let endpoints: Vec<_> = paths
+            .iter()
+            .map(|path| {
+                server.mock(|when, then| {
+                    when.method(GET).path(*path);
+                    then.status(200)
+                        .body(*path)
+                        .delay(Duration::from_millis(DELAY));
+                })
+            })
+            .collect();
+        let missing = server.mock(|when, then| {
+            when.method(GET).path("/missing");
+            then.status(404);
+        });
+
+        // Make all requests in parallel.
+        let requests = paths
+            .iter()
+            .map(|path| GooseRequest::builder().path(*path).build())
+            .collect();
+        let batch = user
+            .request_batch("batch", requests)
+            .await
+            .expect("request_batch returned unexpected error");
+        for endpoint in &endpoints {
+            endpoint.assert_hits(1);
         }
 
-        async fn test_function_b(user: &mut GooseUser) -> TransactionResult {
-            let _goose = user.get("/b/").await?;
+        // Responses are returned in the order requests were provided.
+        assert_eq!(batch.responses.len(), 3);
+        for (goose, path) in batch.responses.into_iter().zip(paths.iter()) {
+            assert!(goose.request.success);
+            let body = goose.response.unwrap().text().await.unwrap();
+            assert_eq!(&body, path);
+        }
 
-            Ok(())
+        // The requests were made in parallel, not one after the other.
+        assert!(batch.elapsed >= DELAY);
+        assert!(batch.elapsed < DELAY * paths.len() as u64);
+
+        // Each request is recorded in the metrics, followed by the batch timer.
+        let metrics: Vec<GooseMetric> = metrics_rx.drain().collect();
+        assert_eq!(metrics.len(), 4);
+        match &metrics[3] {
+            GooseMetric::Timer(timer) => {
+                assert_eq!(timer.name, "batch");
+                assert_eq!(timer.run_time, batch.elapsed);
+                assert!(timer.success);
+            }
+            _ => panic!("expected batch timer"),
         }
 
-        let mut scenario = scenario!("foo");
-        assert_eq!(scenario.name, "foo");
-        assert_eq!(scenario.scenarios_index, usize::max_value());
-        assert_eq!(scenario.weight, 1);
-        assert_eq!(scenario.transaction_wait, None);
-        assert!(scenario.host.is_none());
-        assert_eq!(scenario.transactions.len(), 0);
-        assert_eq!(scenario.weighted_transactions.len(), 0);
-        assert_eq!(scenario.weighted_on_start_transactions.len(), 0);
-        assert_eq!(scenario.weighted_on_stop_transactions.len(), 0);
+        // A failed request only returns an error if configured to.
+        let requests = vec![
+            GooseRequest::builder().path("/missing").build(),
+            GooseRequest::builder().path("/one").build(),
+        ];
+        let batch = user
+            .request_batch("batch", requests)
+            .await
+            .expect("request_batch returned unexpected error");
+        assert!(!batch.responses[0].request.success);
+        assert!(batch.responses[1].request.success);
+        let requests = vec![
+            GooseRequest::builder()
+                .path("/missing")
+                .error_on_fail()
+                .build(),
+            GooseRequest::builder().path("/one").build(),
+        ];
+        assert!(user.request_batch("batch", requests).await.is_err());
+        missing.assert_hits(2);
+    }
 
-        // Registering a transaction adds it to transactions, but doesn't update weighted_transactions.
-        scenario = scenario.register_transaction(transaction!(test_function_a));
-        assert_eq!(scenario.transactions.len(), 1);
-        assert_eq!(scenario.weighted_transactions.len(), 0);
-        assert_eq!(scenario.scenarios_index, usize::max_value());
-        assert_eq!(scenario.weight, 1);
-        assert_eq!(scenario.transaction_wait, None);
-        assert!(scenario.host.is_none());
+    #[test]
+    fn custom_timers() {
+        let configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        let mut user =
+            GooseUser::single("http://localhost:8080".parse().unwrap(), &configuration).unwrap();
 
-        // Different transactions can be registered.
-        scenario = scenario.register_transaction(transaction!(test_function_b));
-        assert_eq!(scenario.transactions.len(), 2);
-        assert_eq!(scenario.weighted_transactions.len(), 0);
-        assert_eq!(scenario.scenarios_index, usize::max_value());
-        assert_eq!(scenario.weight, 1);
-        assert_eq!(scenario.transaction_wait, None);
-        assert!(scenario.host.is_none());
+        // Capture the timer metrics sent by the user.
+        let (metrics_tx, metrics_rx) = flume::unbounded();
+        user.metrics_channel = Some(metrics_tx);
+
+        // Stopping a timer that was never started is an error.
+        match user.stop_timer("checkout", true) {
+            Err(e) => match *e {
+                TransactionError::TimerNotStarted { name } => assert_eq!(name, "checkout"),
+                _ => panic!("unexpected error"),
+            },
+            Ok(_) => panic!("stopped a timer that was never started"),
+        }
+        assert!(metrics_rx.is_empty());
+
+        // A started timer is recorded when stopped.
+        user.start_timer("checkout");
+        std::thread::sleep(Duration::from_millis(10));
+        let elapsed = user.stop_timer("checkout", false).unwrap();
+        assert!(elapsed >= 10);
+        let metrics: Vec<GooseMetric> = metrics_rx.drain().collect();
+        assert_eq!(metrics.len(), 1);
+        match &metrics[0] {
+            GooseMetric::Timer(timer) => {
+                assert_eq!(timer.name, "checkout");
+                assert_eq!(timer.run_time, elapsed);
+                assert!(!timer.success);
+            }
+            _ => panic!("expected checkout timer"),
+        }
 
-        // Same transactions can be registered again.
-        scenario = scenario.register_transaction(transaction!(test_function_a));
-        assert_eq!(scenario.transactions.len(), 3);
-        assert_eq!(scenario.weighted_transactions.len(), 0);
-        assert_eq!(scenario.scenarios_index, usize::max_value());
-        assert_eq!(scenario.weight, 1);
-        assert_eq!(scenario.transaction_wait, None);
-        assert!(scenario.host.is_none());
+        // A timer can only be stopped once.
+        assert!(user.stop_timer("checkout", true).is_err());
+    }
 
-        // Setting weight only affects weight field.
-        scenario = scenario.set_weight(50).unwrap();
-        assert_eq!(scenario.weight, 50);
-        assert_eq!(scenario.transactions.len(), 3);
-        assert_eq!(scenario.weighted_transactions.len(), 0);
-        assert_eq!(scenario.scenarios_index, usize::max_value());
-        assert_eq!(scenario.transaction_wait, None);
-        assert!(scenario.host.is_none());
+    #[tokio::test]
+    async fn websocket_session() {
+        let server = MockServer::start();
 
-        // Weight can be changed.
-        scenario = scenario.set_weight(5).unwrap();
-        assert_eq!(scenario.weight, 5);
+        // Logging in sets a session cookie.
+        let login = server.mock(|when, then| {
+            when.method(GET).path("/login");
+            then.status(200)
+                .header("Set-Cookie", "session=abc123; HttpOnly");
+        });
 
-        // Setting host only affects host field.
-        scenario = scenario.set_host("http://foo.example.com/");
-        assert_eq!(scenario.host, Some("http://foo.example.com/".to_string()));
-        assert_eq!(scenario.weight, 5);
-        assert_eq!(scenario.transactions.len(), 3);
-        assert_eq!(scenario.weighted_transactions.len(), 0);
-        assert_eq!(scenario.scenarios_index, usize::max_value());
-        assert_eq!(scenario.transaction_wait, None);
+        // Start a WebSocket server that echoes text messages, and closes the connection
+        // when asked to.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (cookie_tx, cookie_rx) = flume::unbounded();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            // The error response is defined by tungstenite, and can't be boxed.
+            #[allow(clippy::result_large_err)]
+            let callback =
+                |request: &tokio_tungstenite::tungstenite::handshake::server::Request, response| {
+                    let cookie = request
+                        .headers()
+                        .get("cookie")
+                        .map(|c| c.to_str().unwrap().to_string());
+                    cookie_tx.send(cookie).unwrap();
+                    Ok(response)
+                };
+            let mut stream = tokio_tungstenite::accept_hdr_async(socket, callback)
+                .await
+                .unwrap();
+            while let Some(Ok(message)) = stream.next().await {
+                match message {
+                    GooseWebSocketMessage::Text(text) if text == "bye" => {
+                        stream.close(None).await.unwrap();
+                    }
+                    GooseWebSocketMessage::Text(text) => {
+                        stream
+                            .send(GooseWebSocketMessage::Text(format!("echo: {}", text)))
+                            .await
+                            .unwrap();
+                    }
+                    _ => (),
+                }
+            }
+        });
 
-        // Host field can be changed.
-        scenario = scenario.set_host("https://bar.example.com/");
-        assert_eq!(scenario.host, Some("https://bar.example.com/".to_string()));
+        let mut user = setup_user(&server).unwrap();
 
-        // Wait time only affects wait time fields.
-        scenario = scenario
-            .set_wait_time(Duration::from_secs(1), Duration::from_secs(10))
+        // Capture the request metrics sent by the user.
+        let (metrics_tx, metrics_rx) = flume::unbounded();
+        user.metrics_channel = Some(metrics_tx);
+
+        // Using a WebSocket that isn't open is an error.
+        match user.websocket_send("chat", "hello").await {
+            Err(e) => match *e {
+                TransactionError::WebSocketNotConnected { name } => assert_eq!(name, "chat"),
+                _ => panic!("unexpected error"),
+            },
+            Ok(_) => panic!("sent a message on a WebSocket that isn't open"),
+        }
+
+        let goose = user.get("/login").await.unwrap();
+        assert!(goose.request.success);
+        login.assert_hits(1);
+
+        // The session cookie is sent when connecting.
+        let connect = user
+            .websocket_connect("chat", &format!("ws://127.0.0.1:{}/chat", port))
+            .await
             .unwrap();
+        assert!(connect.success);
+        assert_eq!(connect.status_code, 101);
         assert_eq!(
-            scenario.transaction_wait,
-            Some((Duration::from_secs(1), Duration::from_secs(10)))
+            cookie_rx.recv_async().await.unwrap(),
+            Some("session=abc123".to_string())
         );
-        assert_eq!(scenario.host, Some("https://bar.example.com/".to_string()));
-        assert_eq!(scenario.weight, 5);
-        assert_eq!(scenario.transactions.len(), 3);
-        assert_eq!(scenario.weighted_transactions.len(), 0);
-        assert_eq!(scenario.scenarios_index, usize::max_value());
 
-        // Wait time can be changed.
-        scenario = scenario
-            .set_wait_time(Duration::from_secs(3), Duration::from_secs(9))
-            .unwrap();
+        // Send a message and wait for the reply.
+        let reply = user.websocket_request("chat", "hello").await.unwrap();
         assert_eq!(
-            scenario.transaction_wait,
-            Some((Duration::from_secs(3), Duration::from_secs(9)))
+            reply,
+            GooseWebSocketMessage::Text("echo: hello".to_string())
         );
+
+        // Send and receive separately.
+        user.websocket_send("chat", "again").await.unwrap();
+        let reply = user.websocket_receive("chat").await.unwrap();
+        assert_eq!(
+            reply,
+            GooseWebSocketMessage::Text("echo: again".to_string())
+        );
+
+        // The server closing the connection instead of replying is an error, and closes the
+        // WebSocket.
+        assert!(user.websocket_request("chat", "bye").await.is_err());
+        assert!(user.websocket_close("chat").await.is_err());
+
+        // Skip the login request, then check all WebSocket activity was recorded.
+        let metrics: Vec<GooseMetric> = metrics_rx.drain().collect();
+        let recorded: Vec<(String, bool)> = metrics
+            .iter()
+            .skip(1)
+            .map(|metric| match metric {
+                GooseMetric::Request(request) => (
+                    format!("{} {}", request.raw.method, request.name),
+                    request.success,
+                ),
+                _ => panic!("expected request metric"),
+            })
+            .collect();
+        assert_eq!(
+            recorded,
+            vec![
+                ("WS chat connect".to_string(), true),
+                ("WS chat message".to_string(), true),
+                ("WS chat send".to_string(), true),
+                ("WS chat receive".to_string(), true),
+                ("WS chat message".to_string(), false),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn record_operation() {
+        let server = MockServer::start();
+
+        let mut user = setup_user(&server).unwrap();
+
+        // Capture the request metrics sent by the user.
+        let (metrics_tx, metrics_rx) = flume::unbounded();
+        user.metrics_channel = Some(metrics_tx);
+
+        // A successful operation is recorded like a successful request.
+        let operation = GooseOperation::new("GET session", Duration::from_millis(7))
+            .protocol("redis")
+            .target("redis://127.0.0.1:6379")
+            .status_code(1);
+        let request = user.record_operation(operation).await.unwrap();
+        assert_eq!(request.raw.method, GooseMethod::Custom("REDIS".to_string()));
+        assert_eq!(request.raw.url, "redis://127.0.0.1:6379");
+        assert_eq!(request.name, "GET session");
+        assert_eq!(request.response_time, 7);
+        assert_eq!(request.status_code, 1);
+        assert!(request.success);
+        assert!(request.error.is_empty());
+
+        // A failed operation is recorded like a failed request.
+        let result: Result<(), &str> = Err("connection reset");
+        let operation = GooseOperation::new("GET session", Duration::from_millis(3))
+            .protocol("redis")
+            .result(&result);
+        let request = user.record_operation(operation).await.unwrap();
+        assert!(!request.success);
+        assert_eq!(request.error, "connection reset: GET session");
+
+        let metrics: Vec<GooseMetric> = metrics_rx.drain().collect();
+        assert_eq!(metrics.len(), 2);
+        for metric in &metrics {
+            match metric {
+                GooseMetric::Request(request) => {
+                    assert_eq!(
+                        format!("{} {}", request.raw.method, request.name),
+                        "REDIS GET session"
+                    );
+                }
+                _ => panic!("expected request metric"),
+            }
+        }
+
+        // Custom methods can't be used to make HTTP requests.
+        match user.get_request_builder(&GooseMethod::Custom("REDIS".to_string()), "/") {
+            Err(e) => match *e {
+                TransactionError::CustomMethod { method } => assert_eq!(method, "REDIS"),
+                _ => panic!("unexpected error"),
+            },
+            Ok(_) => panic!("built an HTTP request with a custom method"),
+        }
     }
 
     #[test]
-    fn goose_transaction() {
-        // Simplistic test transaction functions.
-        async fn test_function_a(user: &mut GooseUser) -> TransactionResult {
-            let _goose = user.get("/a/").await?;
+    fn convert_methods() {
+        // Every standard method and extension methods convert in both directions.
+        for (method, goose_method) in [
+            (Method::CONNECT, GooseMethod::Connect),
+            (Method::DELETE, GooseMethod::Delete),
+            (Method::GET, GooseMethod::Get),
+            (Method::HEAD, GooseMethod::Head),
+            (Method::OPTIONS, GooseMethod::Options),
+            (Method::PATCH, GooseMethod::Patch),
+            (Method::POST, GooseMethod::Post),
+            (Method::PUT, GooseMethod::Put),
+            (Method::TRACE, GooseMethod::Trace),
+            (
+                Method::from_bytes(b"PROPFIND").unwrap(),
+                GooseMethod::Extension("PROPFIND".to_string()),
+            ),
+        ] {
+            assert_eq!(goose_method_from_method(method.clone()), goose_method);
+            assert_eq!(method_from_goose_method(&goose_method).unwrap(), method);
+        }
 
-            Ok(())
+        // Extension methods are displayed so they can't be confused with custom methods.
+        assert_eq!(GooseMethod::Get.to_string(), "GET");
+        let extension = GooseMethod::Extension("PROPFIND".to_string());
+        let custom = GooseMethod::Custom("PROPFIND".to_string());
+        assert_eq!(extension.to_string(), "HTTP PROPFIND");
+        assert_ne!(extension.to_string(), custom.to_string());
+
+        // Extension methods must be valid HTTP methods.
+        match method_from_goose_method(&GooseMethod::Extension("NOT VALID".to_string())) {
+            Err(e) => match *e {
+                TransactionError::InvalidExtensionMethod { method } => {
+                    assert_eq!(method, "NOT VALID")
+                }
+                _ => panic!("unexpected error"),
+            },
+            Ok(_) => panic!("converted an invalid extension method"),
+        }
+    }
+
+    #[test]
+    fn normalize_paths() {
+        // Numeric and UUID segments are replaced, the query string is left unchanged.
+        assert_eq!(normalize_path("/node/1"), "/node/{id}");
+        assert_eq!(
+            normalize_path("/node/12/edit?page=2"),
+            "/node/{id}/edit?page=2"
+        );
+        assert_eq!(
+            normalize_path("/file/0b8e5c8a-6a3e-4c1f-9d8b-6c5e2f0a1b3c/download"),
+            "/file/{uuid}/download"
+        );
+        assert!(matches!(
+            normalize_path("/node/1a/"),
+            Cow::Borrowed("/node/1a/")
+        ));
+        assert!(matches!(normalize_path("/"), Cow::Borrowed("/")));
+
+        // Path rules are a semicolon separated list of REGEX=NAME.
+        let rules =
+            parse_path_rules(r"^/node/\d+=/node/{id}; ^/user/\d+/(\w+)$=/user/{id}/$1").unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].name, "/node/{id}");
+        assert_eq!(
+            rules[1]
+                .pattern
+                .replace_all("/user/3/edit", rules[1].name.as_str()),
+            "/user/{id}/edit"
+        );
+        // Patterns may contain `=`, as names follow the last `=`.
+        let rules = parse_path_rules(r"^/search\?q=\w+$=/search").unwrap();
+        assert_eq!(rules[0].name, "/search");
+        assert!(rules[0].pattern.is_match("/search?q=goose"));
+        assert!(parse_path_rules("").unwrap().is_empty());
+        assert!(parse_path_rules("/node/{id}").is_err());
+        assert!(parse_path_rules("/node/(=/node/{id}").is_err());
+    }
+
+    #[tokio::test]
+    async fn request_stream() {
+        const EVENTS: &str = "event: price\ndata: 1\n\n: keep-alive\n\ndata: 2\ndata: 3\nid: 7\n\n";
+
+        let server = MockServer::start();
+        let events = server.mock(|when, then| {
+            when.method(GET).path("/events");
+            then.status(200)
+                .header("content-type", "text/event-stream")
+                .body(EVENTS);
+        });
+        let missing = server.mock(|when, then| {
+            when.method(GET).path("/missing");
+            then.status(404);
+        });
+
+        let mut user = setup_user(&server).unwrap();
+
+        // Capture the metrics sent by the user.
+        let (metrics_tx, metrics_rx) = flume::unbounded();
+        user.metrics_channel = Some(metrics_tx);
+
+        // Server-Sent Events are split into events, skipping comments.
+        let request = GooseRequest::builder().path("/events").build();
+        let sse = user
+            .request_stream(request, GooseStream::server_sent_events())
+            .await
+            .unwrap();
+        assert!(sse.request.success);
+        assert_eq!(sse.events.len(), 2);
+        assert_eq!(sse.events[0].event, Some("price".to_string()));
+        assert_eq!(sse.events[0].data, "1");
+        assert_eq!(sse.events[1].id, Some("7".to_string()));
+        assert_eq!(sse.events[1].data, "2\n3");
+        assert_eq!(sse.stream.events, 2);
+        assert_eq!(sse.stream.time_between_events.len(), 1);
+        assert!(sse.stream.time_to_first_byte.is_some());
+        assert!(sse.stream.time_to_first_event >= sse.stream.time_to_first_byte);
+        assert!(sse.stream.duration >= sse.request.response_time);
+        assert!(sse.stream.success);
+
+        // The stream can be closed after a maximum number of events.
+        let request = GooseRequest::builder().path("/events").build();
+        let limited = user
+            .request_stream(request, GooseStream::server_sent_events().max_events(1))
+            .await
+            .unwrap();
+        assert_eq!(limited.events.len(), 1);
+        assert!(limited.stream.time_between_events.is_empty());
+
+        // Other streams are read one event per chunk.
+        let request = GooseRequest::builder().path("/events").build();
+        let chunks = user
+            .request_stream(request, GooseStream::chunks())
+            .await
+            .unwrap();
+        let body: String = chunks
+            .events
+            .iter()
+            .map(|event| event.data.as_str())
+            .collect();
+        assert_eq!(body, EVENTS);
+        events.assert_hits(3);
+
+        // There is nothing to read if the request fails.
+        let request = GooseRequest::builder().path("/missing").build();
+        let failed = user
+            .request_stream(request, GooseStream::server_sent_events())
+            .await
+            .unwrap();
+        assert!(!failed.request.success);
+        assert!(failed.events.is_empty());
+        assert!(failed.stream.time_to_first_byte.is_none());
+        assert!(!failed.stream.success);
+        missing.assert_hits(1);
+
+        // Each stream is recorded after the request that opened it.
+        let metrics: Vec<GooseMetric> = metrics_rx.drain().collect();
+        assert_eq!(metrics.len(), 8);
+        for pair in metrics.chunks(2) {
+            match (&pair[0], &pair[1]) {
+                (GooseMetric::Request(request), GooseMetric::Stream(stream)) => {
+                    assert_eq!(request.name, stream.name);
+                    assert_eq!(request.success, stream.success);
+                }
+                _ => panic!("expected request and stream metrics"),
+            }
         }
+    }
+
+    #[tokio::test]
+    async fn request_stream_timeout() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Start a server that streams an event every 400 milliseconds, for longer than the
+        // configured timeout.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut request = [0; 1024];
+                    let _ = socket.read(&mut request).await.unwrap();
+                    socket
+                        .write_all(b"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\ntransfer-encoding: chunked\r\n\r\n")
+                        .await
+                        .unwrap();
+                    for i in 0..4 {
+                        tokio::time::sleep(Duration::from_millis(400)).await;
+                        let event = format!("data: {}\n\n", i);
+                        let chunk = format!("{:x}\r\n{}\r\n", event.len(), event);
+                        if socket.write_all(chunk.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                    let _ = socket.write_all(b"0\r\n\r\n").await;
+                });
+            }
+        });
+
+        let mut configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        configuration.co_mitigation = Some(GooseCoordinatedOmissionMitigation::Average);
+        configuration.timeout = Some("1".to_string());
+        let base_url = get_base_url(Some(format!("http://127.0.0.1:{}/", port)), None, None);
+        let mut user = GooseUser::single(base_url.unwrap(), &configuration).unwrap();
+
+        // Regular requests time out.
+        let goose = user.get("/events").await.unwrap();
+        assert!(!goose.request.success);
+
+        // Streams are read until the server closes them, even when that's longer than the
+        // timeout.
+        let request = GooseRequest::builder().path("/events").build();
+        let events = user
+            .request_stream(request, GooseStream::server_sent_events())
+            .await
+            .unwrap();
+        assert!(events.stream.success);
+        assert_eq!(events.events.len(), 4);
+        assert!(events.stream.duration >= 1_600);
+
+        // Streams with a maximum duration are closed when it expires, rather than timing out.
+        let request = GooseRequest::builder().path("/events").build();
+        let events = user
+            .request_stream(
+                request,
+                GooseStream::server_sent_events().max_duration(Duration::from_millis(1_000)),
+            )
+            .await
+            .unwrap();
+        assert!(events.stream.success);
+        assert_eq!(events.events.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn request_timings() {
+        const BODY: &str = "<html><body>timed</body></html>";
+
+        let server = MockServer::start();
+        let page = server.mock(|when, then| {
+            when.method(GET).path("/page");
+            then.status(200).body(BODY);
+        });
+
+        // Connect to the server by host name so DNS is queried.
+        let mut configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        configuration.co_mitigation = Some(GooseCoordinatedOmissionMitigation::Average);
+        let base_url = Url::parse(&format!("http://localhost:{}/", server.port())).unwrap();
+        let mut user = GooseUser::single(base_url, &configuration).unwrap();
+
+        // The first request opens a connection, so the host name is resolved.
+        let goose = user.get("page").await.unwrap();
+        let timings = goose.request.timings.clone();
+        assert!(timings.dns.is_some());
+        assert!(timings.connect.is_some());
+        let time_to_first_byte = timings.time_to_first_byte.unwrap();
+        let download = timings.download.unwrap();
+        assert!(goose.request.response_time >= time_to_first_byte + download);
+        // The body can still be read after it was downloaded, and the copy of the response
+        // still knows which server it came from.
+        let response = goose.response.unwrap();
+        assert_eq!(response.remote_addr().unwrap().port(), server.port());
+        assert_eq!(response.text().await.unwrap(), BODY);
+
+        // The connection is reused, so there is nothing to resolve or connect.
+        let goose = user.get("page").await.unwrap();
+        assert!(goose.request.timings.dns.is_none());
+        assert!(goose.request.timings.connect.is_none());
+        assert!(goose.request.timings.download.is_some());
+        page.assert_hits(2);
+
+        // Streamed bodies aren't downloaded up front.
+        let request = GooseRequest::builder().path("page").build();
+        let stream = user
+            .request_stream(request, GooseStream::chunks())
+            .await
+            .unwrap();
+        assert!(stream.request.timings.time_to_first_byte.is_some());
+        assert!(stream.request.timings.download.is_none());
+        assert_eq!(stream.events[0].data, BODY);
+
+        // Nothing is timed if no response is received.
+        let base_url = Url::parse("http://127.0.0.1:1/").unwrap();
+        let mut user = GooseUser::single(base_url, &configuration).unwrap();
+        let goose = user.get("page").await.unwrap();
+        assert!(!goose.request.success);
+        assert_eq!(
+            goose.request.timings,
+            crate::metrics::GooseRequestTimings::default()
+        );
+    }
 
-        // Initialize scenario.
-        let mut transaction = transaction!(test_function_a);
-        assert_eq!(transaction.transactions_index, usize::max_value());
-        assert_eq!(transaction.name, "".to_string());
-        assert_eq!(transaction.weight, 1);
-        assert_eq!(transaction.sequence, 0);
-        assert!(!transaction.on_start);
-        assert!(!transaction.on_stop);
+    #[tokio::test]
+    async fn request_body_sizes() {
+        const FORM: &str = "name=goose";
+        const BODY: &str = "<html><body>saved</body></html>";
 
-        // Name can be set, without affecting other fields.
-        transaction = transaction.set_name("foo");
-        assert_eq!(transaction.name, "foo".to_string());
-        assert_eq!(transaction.weight, 1);
-        assert_eq!(transaction.sequence, 0);
-        assert!(!transaction.on_start);
-        assert!(!transaction.on_stop);
+        let server = MockServer::start();
+        let form = server.mock(|when, then| {
+            when.method(POST).path("/form").body(FORM);
+            then.status(200).body(BODY);
+        });
 
-        // Name can be set multiple times.
-        transaction = transaction.set_name("bar");
-        assert_eq!(transaction.name, "bar".to_string());
+        let mut user = setup_user(&server).unwrap();
 
-        // On start flag can be set, without affecting other fields.
-        transaction = transaction.set_on_start();
-        assert!(transaction.on_start);
-        assert_eq!(transaction.name, "bar".to_string());
-        assert_eq!(transaction.weight, 1);
-        assert_eq!(transaction.sequence, 0);
-        assert!(!transaction.on_stop);
+        // The sizes of the request and response bodies are recorded.
+        let goose = user.post("form", FORM).await.unwrap();
+        assert_eq!(goose.request.request_body_size, FORM.len() as u64);
+        assert_eq!(goose.request.response_body_size, BODY.len() as u64);
 
-        // Setting on start flag twice doesn't change anything.
-        transaction = transaction.set_on_start();
-        assert!(transaction.on_start);
+        // Requests without a body, and streamed responses, don't count any bytes.
+        let request = GooseRequest::builder().path("form").build();
+        let stream = user
+            .request_stream(request, GooseStream::chunks())
+            .await
+            .unwrap();
+        assert_eq!(stream.request.request_body_size, 0);
+        assert_eq!(stream.request.response_body_size, 0);
+        form.assert_hits(1);
+    }
 
-        // On stop flag can be set, without affecting other fields.
-        // It's possible to set both on_start and on_stop for same transaction.
-        transaction = transaction.set_on_stop();
-        assert!(transaction.on_stop);
-        assert!(transaction.on_start);
-        assert_eq!(transaction.name, "bar".to_string());
-        assert_eq!(transaction.weight, 1);
-        assert_eq!(transaction.sequence, 0);
+    #[tokio::test]
+    async fn compressed_body_sizes() {
+        use flate2::write::GzEncoder;
+        use std::io::Write;
 
-        // Setting on stop flag twice doesn't change anything.
-        transaction = transaction.set_on_stop();
-        assert!(transaction.on_stop);
+        const BODY: &str = "<html><body>compressed compressed compressed</body></html>";
 
-        // Setting weight doesn't change anything else.
-        transaction = transaction.set_weight(2).unwrap();
-        assert_eq!(transaction.weight, 2);
-        assert!(transaction.on_stop);
-        assert!(transaction.on_start);
-        assert_eq!(transaction.name, "bar".to_string());
-        assert_eq!(transaction.sequence, 0);
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(BODY.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
 
-        // Weight field can be changed multiple times.
-        transaction = transaction.set_weight(3).unwrap();
-        assert_eq!(transaction.weight, 3);
+        let server = MockServer::start();
+        let page = server.mock(|when, then| {
+            when.method(GET)
+                .path("/compressed")
+                .header("accept-encoding", "gzip");
+            then.status(200)
+                .header("content-encoding", "gzip")
+                .body(&compressed);
+        });
 
-        // Setting sequence doesn't change anything else.
-        transaction = transaction.set_sequence(4);
-        assert_eq!(transaction.sequence, 4);
-        assert_eq!(transaction.weight, 3);
-        assert!(transaction.on_stop);
-        assert!(transaction.on_start);
-        assert_eq!(transaction.name, "bar".to_string());
+        let mut user = setup_user(&server).unwrap();
 
-        // Sequence field can be changed multiple times.
-        transaction = transaction.set_sequence(8);
-        assert_eq!(transaction.sequence, 8);
+        // The compressed size of the response body is recorded, and it is decompressed.
+        let goose = user.get("compressed").await.unwrap();
+        assert_eq!(goose.request.response_body_size, compressed.len() as u64);
+        let response = goose.response.unwrap();
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+        assert_eq!(response.text().await.unwrap(), BODY);
+        page.assert_hits(1);
+
+        // Large bodies are also decompressed.
+        let large_body = BODY.repeat(BLOCKING_DECOMPRESS_SIZE / BODY.len() + 1);
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::none());
+        encoder.write_all(large_body.as_bytes()).unwrap();
+        let large_compressed = encoder.finish().unwrap();
+        assert!(large_compressed.len() > BLOCKING_DECOMPRESS_SIZE);
+        server.mock(|when, then| {
+            when.method(GET).path("/large");
+            then.status(200)
+                .header("content-encoding", "gzip")
+                .body(&large_compressed);
+        });
+        let goose = user.get("large").await.unwrap();
+        assert!(goose.request.success);
+        assert_eq!(goose.response.unwrap().text().await.unwrap(), large_body);
+
+        // Bodies that can't be decoded fail the request, and are returned as received.
+        server.mock(|when, then| {
+            when.method(GET).path("/corrupt");
+            then.status(200)
+                .header("content-encoding", "gzip")
+                .body(&compressed[..compressed.len() / 2]);
+        });
+        let goose = user.get("corrupt").await.unwrap();
+        assert!(!goose.request.success);
+        assert!(goose
+            .request
+            .error
+            .starts_with("error decoding response body"));
+        let response = goose.response.unwrap();
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_some());
+        assert_eq!(
+            response.bytes().await.unwrap(),
+            &compressed[..compressed.len() / 2]
+        );
     }
 
     #[tokio::test]
-    async fn goose_user() {
-        const HOST: &str = "http://example.com/";
-        let configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
-        let base_url = get_base_url(Some(HOST.to_string()), None, None).unwrap();
-        let user = GooseUser::new(0, base_url, &configuration, 0).unwrap();
-        assert_eq!(user.scenarios_index, 0);
-        assert_eq!(user.weighted_users_index, usize::max_value());
+    async fn network_profile() {
+        const FORM: &str = "name=goose";
+        const BODY: &str = "<html><body>slow</body></html>";
+
+        // Bandwidth must be at least 1 byte per second.
+        let profile = GooseNetworkProfile::new("slow");
+        assert!(profile.clone().set_download_bandwidth(0).is_err());
+        assert!(profile.clone().set_upload_bandwidth(0).is_err());
+
+        // Uploading the form and downloading the body each take at least 100 milliseconds.
+        let profile = profile
+            .set_latency(Duration::from_millis(50))
+            .set_upload_bandwidth(FORM.len() as u64 * 10)
+            .unwrap()
+            .set_download_bandwidth(BODY.len() as u64 * 10)
+            .unwrap();
+        assert_eq!(profile.name(), "slow");
+        assert_eq!(
+            profile.request_delay(FORM.len() as u64),
+            Duration::from_millis(150)
+        );
 
-        // Confirm the URLs are correctly built using the default_host.
-        let url = user.build_url("/foo").unwrap();
-        assert_eq!(&url, &[HOST, "foo"].concat());
-        let url = user.build_url("bar/").unwrap();
-        assert_eq!(&url, &[HOST, "bar/"].concat());
-        let url = user.build_url("/foo/bar").unwrap();
-        assert_eq!(&url, &[HOST, "foo/bar"].concat());
+        let server = MockServer::start();
+        let form = server.mock(|when, then| {
+            when.method(POST).path("/form").body(FORM);
+            then.status(200).body(BODY);
+        });
+        let page = server.mock(|when, then| {
+            when.method(GET).path("/page");
+            then.status(200).body(BODY);
+        });
 
-        // Confirm the URLs are built with their own specified host.
-        let url = user.build_url("https://example.com/foo").unwrap();
-        assert_eq!(url, "https://example.com/foo");
-        let url = user
-            .build_url("https://www.example.com/path/to/resource")
+        let mut user = setup_user(&server).unwrap();
+        let goose = user.post("form", FORM).await.unwrap();
+        assert!(goose.request.network_profile.is_none());
+
+        // The network profile slows down the request, and is recorded in its metrics.
+        user.network_profile = Some(profile);
+        let goose = user.post("form", FORM).await.unwrap();
+        assert_eq!(goose.request.network_profile.as_deref(), Some("slow"));
+        assert!(goose.request.timings.download.unwrap() >= 100);
+        assert!(goose.request.response_time >= 250);
+        assert_eq!(goose.response.unwrap().text().await.unwrap(), BODY);
+
+        // Streamed bodies are also downloaded no faster than the download bandwidth.
+        let request = GooseRequest::builder().path("page").build();
+        let started = Instant::now();
+        let stream = user
+            .request_stream(request, GooseStream::chunks())
+            .await
             .unwrap();
-        assert_eq!(url, "https://www.example.com/path/to/resource");
+        assert!(started.elapsed() >= Duration::from_millis(150));
+        assert_eq!(stream.events[0].data, BODY);
+        form.assert_hits(2);
+        page.assert_hits(1);
+    }
 
-        // Create a second user, this time setting a scenario_host.
-        let base_url = get_base_url(
-            None,
-            Some("http://www2.example.com/".to_string()),
-            Some("http://www.example.com/".to_string()),
-        )
-        .unwrap();
-        let user2 = GooseUser::new(0, base_url, &configuration, 0).unwrap();
+    #[test]
+    fn connection_options() {
+        // Proxies can be HTTP or SOCKS.
+        assert!(parse_proxy("http://proxy.example.com:3128").is_ok());
+        assert!(parse_proxy("socks5://127.0.0.1:1080").is_ok());
+        assert!(parse_proxy("ftp://proxy.example.com").is_err());
+
+        // DNS overrides are a comma separated list of HOST:PORT:ADDR, grouped by host name.
+        // The port is ignored, so requests connect to the port of the requested URL.
+        let overrides =
+            parse_resolve("example.com:443:10.0.0.1, example.org:80:::1, Example.com:8080:[::1]")
+                .unwrap();
+        assert_eq!(
+            overrides,
+            vec![
+                (
+                    "example.com".to_string(),
+                    vec!["10.0.0.1:0".parse().unwrap(), "[::1]:0".parse().unwrap()]
+                ),
+                ("example.org".to_string(), vec!["[::1]:0".parse().unwrap()]),
+            ]
+        );
+        assert!(parse_resolve("").unwrap().is_empty());
+        assert!(parse_resolve("example.com").is_err());
+        assert!(parse_resolve("example.com:10.0.0.1").is_err());
+        assert!(parse_resolve("example.com:https:10.0.0.1").is_err());
+        assert!(parse_resolve(":443:10.0.0.1").is_err());
+
+        // Local addresses are a comma separated list of IP addresses.
+        let local_addresses = parse_local_address("10.0.0.2,::1").unwrap();
+        assert_eq!(
+            local_addresses,
+            vec![
+                "10.0.0.2".parse::<IpAddr>().unwrap(),
+                "::1".parse::<IpAddr>().unwrap()
+            ]
+        );
+        assert!(parse_local_address("").unwrap().is_empty());
+        assert!(parse_local_address("localhost").is_err());
+    }
 
-        // Confirm the URLs are correctly built using the scenario_host.
-        let url = user2.build_url("/foo").unwrap();
-        assert_eq!(url, "http://www2.example.com/foo");
+    #[test]
+    fn http_cache_freshness() {
+        let headers = |pairs: &[(header::HeaderName, &str)]| {
+            let mut headers = header::HeaderMap::new();
+            for (name, value) in pairs {
+                headers.append(name, header::HeaderValue::from_str(value).unwrap());
+            }
+            headers
+        };
 
-        // Confirm URLs are still built with their own specified host.
-        let url = user2.build_url("https://example.com/foo").unwrap();
-        assert_eq!(url, "https://example.com/foo");
+        // Explicit directives take precedence.
+        let fresh = headers(&[(header::CACHE_CONTROL, "public, max-age=60")]);
+        assert_eq!(cache_freshness(&fresh), Some(Duration::from_secs(60)));
+        let revalidate = headers(&[
+            (header::CACHE_CONTROL, "no-cache"),
+            (header::EXPIRES, "Wed, 21 Oct 2015 07:28:00 GMT"),
+        ]);
+        assert_eq!(cache_freshness(&revalidate), Some(Duration::ZERO));
+        let no_store = headers(&[(header::CACHE_CONTROL, "max-age=60, no-store")]);
+        assert_eq!(cache_freshness(&no_store), None);
+        let vary = headers(&[(header::CACHE_CONTROL, "max-age=60"), (header::VARY, "*")]);
+        assert_eq!(cache_freshness(&vary), None);
+
+        // Expiration is relative to when the response was sent.
+        let expires = headers(&[
+            (header::DATE, "Wed, 21 Oct 2015 07:28:00 GMT"),
+            (header::EXPIRES, "Wed, 21 Oct 2015 08:28:00 GMT"),
+        ]);
+        assert_eq!(cache_freshness(&expires), Some(Duration::from_secs(3_600)));
+        let invalid = headers(&[(header::EXPIRES, "0")]);
+        assert_eq!(cache_freshness(&invalid), Some(Duration::ZERO));
+
+        // Otherwise responses are fresh for a tenth of the time since they were modified.
+        let heuristic = headers(&[
+            (header::DATE, "Wed, 21 Oct 2015 10:00:00 GMT"),
+            (header::LAST_MODIFIED, "Wed, 21 Oct 2015 00:00:00 GMT"),
+        ]);
+        assert_eq!(
+            cache_freshness(&heuristic),
+            Some(Duration::from_secs(3_600))
+        );
+        assert_eq!(cache_freshness(&headers(&[])), Some(Duration::ZERO));
+    }
 
-        // Confirm Goose can build a base_url that includes a path.
-        const HOST_WITH_PATH: &str = "http://example.com/with/path/";
-        let base_url = get_base_url(Some(HOST_WITH_PATH.to_string()), None, None).unwrap();
-        let user = GooseUser::new(0, base_url, &configuration, 0).unwrap();
+    #[tokio::test]
+    async fn http_cache() {
+        const BODY: &str = "<html><body>cached</body></html>";
 
-        // Confirm the URLs are correctly built using the default_host that includes a path.
-        let url = user.build_url("foo").unwrap();
-        assert_eq!(&url, &[HOST_WITH_PATH, "foo"].concat());
-        let url = user.build_url("bar/").unwrap();
-        assert_eq!(&url, &[HOST_WITH_PATH, "bar/"].concat());
-        let url = user.build_url("foo/bar").unwrap();
-        assert_eq!(&url, &[HOST_WITH_PATH, "foo/bar"].concat());
+        let server = MockServer::start();
+        let fresh = server.mock(|when, then| {
+            when.method(GET).path("/fresh");
+            then.status(200)
+                .header("Cache-Control", "max-age=60")
+                .body(BODY);
+        });
+        // Mocks are matched in the order they're defined, so revalidation is matched first.
+        let not_modified = server.mock(|when, then| {
+            when.method(GET)
+                .path("/stale")
+                .header("If-None-Match", "\"v1\"");
+            then.status(304).header("ETag", "\"v1\"");
+        });
+        let stale = server.mock(|when, then| {
+            when.method(GET).path("/stale");
+            then.status(200)
+                .header("Cache-Control", "no-cache")
+                .header("ETag", "\"v1\"")
+                .body(BODY);
+        });
+        let no_store = server.mock(|when, then| {
+            when.method(GET).path("/no-store");
+            then.status(200)
+                .header("Cache-Control", "no-store")
+                .body(BODY);
+        });
 
-        // Confirm that URLs are correctly re-written if an absolute path is used.
-        let url = user.build_url("/foo").unwrap();
-        assert_eq!(&url, &[HOST, "foo"].concat());
+        // Without the cache, every request is sent to the server.
+        let mut user = setup_user(&server).unwrap();
+        for _ in 0..2 {
+            let goose = user.get("fresh").await.unwrap();
+            assert!(!goose.request.cache_hit);
+        }
+        fresh.assert_hits(2);
+
+        // Fresh responses are loaded from the cache.
+        user.enable_http_cache();
+        user.get("fresh").await.unwrap();
+        let goose = user.get("fresh").await.unwrap();
+        assert!(goose.request.cache_hit);
+        assert!(goose.request.success);
+        assert_eq!(goose.response.unwrap().text().await.unwrap(), BODY);
+        fresh.assert_hits(3);
+
+        // Stale responses are revalidated, returning the cached copy.
+        user.get("stale").await.unwrap();
+        let goose = user.get("stale").await.unwrap();
+        assert!(!goose.request.cache_hit);
+        assert!(goose.request.success);
+        assert_eq!(goose.request.status_code, 304);
+        let response = goose.response.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.text().await.unwrap(), BODY);
+        stale.assert_hits(1);
+        not_modified.assert_hits(1);
+
+        // Responses that must not be stored never are.
+        user.get("no-store").await.unwrap();
+        let goose = user.get("no-store").await.unwrap();
+        assert!(!goose.request.cache_hit);
+        no_store.assert_hits(2);
+
+        // Clearing the cache discards all stored responses.
+        user.clear_http_cache();
+        let goose = user.get("fresh").await.unwrap();
+        assert!(!goose.request.cache_hit);
+        fresh.assert_hits(4);
+    }
+
+    #[cfg(feature = "grpc")]
+    #[test]
+    fn grpc_paths() {
+        let path = grpc_path("helloworld.Greeter", "SayHello").unwrap();
+        assert_eq!(path.as_str(), "/helloworld.Greeter/SayHello");
+
+        // Services and methods must form a valid path.
+        match grpc_path("helloworld.Greeter", "Say Hello") {
+            Err(e) => match *e {
+                TransactionError::InvalidGrpcPath { path } => {
+                    assert_eq!(path, "/helloworld.Greeter/Say Hello")
+                }
+                _ => panic!("unexpected error"),
+            },
+            Ok(_) => panic!("built an invalid gRPC path"),
+        }
     }
 
     #[tokio::test]
-    async fn manual_requests() {
+    async fn graphql() {
+        #[derive(Debug, Deserialize)]
+        struct UserData {
+            user: User,
+        }
+        #[derive(Debug, Deserialize)]
+        struct User {
+            name: String,
+        }
+
         let server = MockServer::start();
+        let get_user = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .json_body_partial(r#"{"operationName": "GetUser", "variables": {"id": 1}}"#);
+            then.status(200)
+                .body(r#"{"data": {"user": {"name": "Goose"}}}"#);
+        });
+        let add_user = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .json_body_partial(r#"{"operationName": "AddUser"}"#);
+            then.status(200).body(
+                r#"{"data": null, "errors": [{"message": "not allowed", "path": ["addUser"]}]}"#,
+            );
+        });
 
         let mut user = setup_user(&server).unwrap();
 
-        // Set up a mock http server endpoint.
-        const INDEX_PATH: &str = "/";
-        let index = server.mock(|when, then| {
-            when.method(GET).path(INDEX_PATH);
-            then.status(200);
-        });
+        // Capture the metrics sent by the user.
+        let (metrics_tx, metrics_rx) = flume::unbounded();
+        user.metrics_channel = Some(metrics_tx);
 
-        // Make a GET request to the mock http server and confirm we get a 200 response.
-        assert_eq!(index.hits(), 0);
+        // Data is deserialized into the requested type.
         let goose = user
-            .get(INDEX_PATH)
+            .graphql::<_, UserData>(
+                "query GetUser($id: ID!) { user(id: $id) { name } }",
+                serde_json::json!({ "id": 1 }),
+            )
             .await
-            .expect("get returned unexpected error");
-        let status = goose.response.unwrap().status();
-        assert_eq!(status, 200);
-        assert_eq!(goose.request.raw.method, GooseMethod::Get);
-        assert_eq!(goose.request.name, INDEX_PATH);
+            .unwrap();
         assert!(goose.request.success);
-        assert!(!goose.request.update);
-        assert_eq!(goose.request.status_code, 200);
-        assert_eq!(index.hits(), 1);
-
-        const NO_SUCH_PATH: &str = "/no/such/path";
-        // Set up a mock http server endpoint.
-        let not_found = server.mock(|when, then| {
-            when.method(GET).path(NO_SUCH_PATH);
-            then.status(404);
-        });
+        assert_eq!(goose.request.name, "query GetUser");
+        assert_eq!(goose.data.unwrap().user.name, "Goose");
+        assert!(goose.errors.is_empty());
+        get_user.assert_hits(1);
 
-        // Make an invalid GET request to the mock http server and confirm we get a 404 response.
-        assert_eq!(not_found.hits(), 0);
+        // A response with errors is a failure, even though the status code is 200.
         let goose = user
-            .get(NO_SUCH_PATH)
+            .graphql::<_, UserData>("mutation AddUser { addUser { name } }", ())
             .await
-            .expect("get returned unexpected error");
-        let status = goose.response.unwrap().status();
-        assert_eq!(status, 404);
-        assert_eq!(goose.request.raw.method, GooseMethod::Get);
-        assert_eq!(goose.request.name, NO_SUCH_PATH);
+            .unwrap();
         assert!(!goose.request.success);
-        assert!(!goose.request.update);
-        assert_eq!(goose.request.status_code, 404,);
-        not_found.assert_hits(1);
+        assert_eq!(goose.request.status_code, 200);
+        assert_eq!(goose.request.name, "mutation AddUser");
+        assert_eq!(goose.request.error, "GraphQL error: not allowed");
+        assert!(goose.data.is_none());
+        assert_eq!(goose.errors.len(), 1);
+        assert_eq!(goose.errors[0].path, vec![serde_json::json!("addUser")]);
+        add_user.assert_hits(1);
+
+        // Both requests were recorded, followed by an update marking the second as failed.
+        let metrics: Vec<GooseMetric> = metrics_rx.drain().collect();
+        assert_eq!(metrics.len(), 3);
+        match &metrics[2] {
+            GooseMetric::Request(request) => {
+                assert!(request.update);
+                assert!(!request.success);
+                assert_eq!(request.name, "mutation AddUser");
+            }
+            _ => panic!("expected request metric"),
+        }
+    }
 
-        // Set up a mock http server endpoint.
-        const COMMENT_PATH: &str = "/comment";
-        let comment = server.mock(|when, then| {
-            when.method(POST).path(COMMENT_PATH).body("foo");
-            then.status(200).body("foo");
-        });
+    #[test]
+    fn graphql_operations() {
+        assert_eq!(
+            graphql_operation("query GetUser($id: ID!) { user(id: $id) { name } }"),
+            ("query", Some("GetUser"))
+        );
+        assert_eq!(
+            graphql_operation("# Add an item.\nmutation AddItem { add { id } }"),
+            ("mutation", Some("AddItem"))
+        );
+        assert_eq!(
+            graphql_operation("subscription($id: ID!) { prices(id: $id) }"),
+            ("subscription", None)
+        );
+        // Fragments defined before the operation are skipped.
+        assert_eq!(
+            graphql_operation("fragment Name on User { name }\nquery Users { users { ...Name } }"),
+            ("query", Some("Users"))
+        );
+        // Queries using the shorthand syntax are anonymous.
+        assert_eq!(graphql_operation("{ users { name } }"), ("query", None));
+    }
 
-        // Make a POST request to the mock http server and confirm we get a 200 OK response.
-        assert_eq!(comment.hits(), 0);
-        let goose = user
-            .post(COMMENT_PATH, "foo")
-            .await
-            .expect("post returned unexpected error");
-        let unwrapped_response = goose.response.unwrap();
-        let status = unwrapped_response.status();
-        assert_eq!(status, 200);
-        let body = unwrapped_response.text().await.unwrap();
-        assert_eq!(body, "foo");
-        assert_eq!(goose.request.raw.method, GooseMethod::Post);
-        assert!(goose.request.success);
-        assert!(!goose.request.update);
-        assert_eq!(goose.request.status_code, 200);
-        comment.assert_hits(1);
+    #[test]
+    fn server_sent_events() {
+        // Incomplete events are left in the buffer until the rest arrives.
+        let mut buffer = b"data: first\n\ndata: sec".to_vec();
+        let events = parse_server_sent_events(&mut buffer, 5);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "first");
+        assert_eq!(events[0].elapsed, 5);
+        assert_eq!(buffer, b"data: sec");
+
+        // Fields without a value, or without a space after the colon, are supported.
+        buffer.extend_from_slice(b"ond\n\nevent:ping\ndata\n\nretry: 10\n\n");
+        let events = parse_server_sent_events(&mut buffer, 9);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].data, "second");
+        assert_eq!(events[1].event, Some("ping".to_string()));
+        assert_eq!(events[1].data, "");
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn custom_metrics() {
+        let configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        let mut user =
+            GooseUser::single("http://localhost:8080".parse().unwrap(), &configuration).unwrap();
+
+        // Capture the custom metrics sent by the user.
+        let (metrics_tx, metrics_rx) = flume::unbounded();
+        user.metrics_channel = Some(metrics_tx);
+
+        user.increment_counter("items", 3).unwrap();
+        user.set_gauge("queue", 1.5).unwrap();
+        user.record_histogram("results", 25).unwrap();
+
+        let metrics: Vec<GooseMetric> = metrics_rx.drain().collect();
+        assert_eq!(metrics.len(), 3);
+        let expected = [
+            ("items", CustomMetricValue::Counter(3)),
+            ("queue", CustomMetricValue::Gauge(1.5)),
+            ("results", CustomMetricValue::Histogram(25)),
+        ];
+        for (metric, (name, value)) in metrics.iter().zip(expected.iter()) {
+            match metric {
+                GooseMetric::Custom(custom) => {
+                    assert_eq!(&custom.name, name);
+                    assert_eq!(&custom.value, value);
+                }
+                _ => panic!("expected custom metric"),
+            }
+        }
     }
 
     #[test]
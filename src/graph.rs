@@ -21,6 +21,8 @@ pub(crate) struct GraphData {
     requests_per_second: HashMap<String, TimeSeries<u32, u32>>,
     /// Counts errors per second.
     errors_per_second: HashMap<String, TimeSeries<u32, u32>>,
+    /// Counts bytes received in response bodies per second for each request type.
+    response_bytes_per_second: HashMap<String, TimeSeries<usize, usize>>,
     /// Maintains average response time per second.
     average_response_time_per_second: HashMap<String, TimeSeries<MovingAverage, f32>>,
     /// Number of transactions at the end of each second of the test.
@@ -38,6 +40,7 @@ impl GraphData {
         GraphData {
             requests_per_second: HashMap::new(),
             errors_per_second: HashMap::new(),
+            response_bytes_per_second: HashMap::new(),
             average_response_time_per_second: HashMap::new(),
             transactions_per_second: TimeSeries::new(),
             scenarios_per_second: TimeSeries::new(),
@@ -77,6 +80,27 @@ impl GraphData {
         );
     }
 
+    /// Record response bytes per second metric.
+    pub(crate) fn record_response_bytes_per_second(
+        &mut self,
+        key: &str,
+        second: usize,
+        bytes: usize,
+    ) {
+        if !self.response_bytes_per_second.contains_key(key) {
+            self.response_bytes_per_second
+                .insert(key.to_string(), TimeSeries::new());
+        }
+        let data = self.response_bytes_per_second.get_mut(key).unwrap();
+        data.increase_value(second, bytes);
+
+        debug!(
+            "increased second {} for response bytes per second counter: {}",
+            second,
+            data.get(second)
+        );
+    }
+
     /// Record average response time per second metric.
     pub(crate) fn record_average_response_time_per_second(
         &mut self,
@@ -145,6 +169,19 @@ impl GraphData {
         )
     }
 
+    /// Generate response bytes per second graph.
+    pub(crate) fn get_response_bytes_per_second_graph(
+        &self,
+        granular_data: bool,
+    ) -> Graph<'_, usize, usize> {
+        self.create_graph_from_data(
+            "graph-response-bytes",
+            "Response bytes #",
+            granular_data,
+            self.response_bytes_per_second.clone(),
+        )
+    }
+
     /// Generate average response time graph.
     pub(crate) fn get_average_response_time_graph(
         &self,
@@ -1182,6 +1219,27 @@ mod test {
         assert_eq!(graph.errors_per_second.get("GET /").unwrap().total(), 16);
     }
 
+    #[test]
+    fn test_record_response_bytes_per_second() {
+        // Should be initialized with empty response bytes per second vector.
+        let mut graph = GraphData::new();
+        assert_eq!(graph.response_bytes_per_second.len(), 0);
+
+        graph.record_response_bytes_per_second("GET /", 0, 1_024);
+        graph.record_response_bytes_per_second("GET /", 0, 512);
+        graph.record_response_bytes_per_second("GET /", 2, 100);
+        graph.record_response_bytes_per_second("GET /about", 1, 7);
+        let index = graph.response_bytes_per_second.get("GET /").unwrap();
+        assert_eq!(index.data.len(), 3);
+        assert_eq!(index.data[0], 1_536);
+        assert_eq!(index.data[1], 0);
+        assert_eq!(index.data[2], 100);
+        assert_eq!(index.total(), 1_636);
+        let about = graph.response_bytes_per_second.get("GET /about").unwrap();
+        assert_eq!(about.data[1], 7);
+        assert_eq!(about.total(), 7);
+    }
+
     #[test]
     #[allow(clippy::float_cmp)]
     fn test_record_average_response_time_per_second() {
@@ -1825,21 +1883,25 @@ mod test {
                 action: TestPlanStepAction::Increasing,
                 timestamp: Utc.with_ymd_and_hms(2021, 11, 21, 21, 20, 32).unwrap(),
                 users: 123,
+                reason: None,
             },
             TestPlanHistory {
                 action: TestPlanStepAction::Decreasing,
                 timestamp: Utc.with_ymd_and_hms(2021, 11, 21, 21, 20, 33).unwrap(),
                 users: 123,
+                reason: None,
             },
             TestPlanHistory {
                 action: TestPlanStepAction::Canceling,
                 timestamp: Utc.with_ymd_and_hms(2021, 11, 21, 21, 20, 34).unwrap(),
                 users: 123,
+                reason: None,
             },
             TestPlanHistory {
                 action: TestPlanStepAction::Finished,
                 timestamp: Utc.with_ymd_and_hms(2021, 11, 21, 21, 20, 35).unwrap(),
                 users: 123,
+                reason: None,
             },
         ];
 
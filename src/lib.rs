@@ -65,10 +65,12 @@ use tokio::fs::File;
 
 use crate::config::{GooseConfiguration, GooseDefaults};
 use crate::controller::{ControllerProtocol, ControllerRequest};
-use crate::goose::{GooseUser, GooseUserCommand, Scenario, Transaction};
+use crate::goose::{GoosePathRule, GooseUser, GooseUserCommand, Scenario, Transaction};
 use crate::graph::GraphData;
 use crate::logger::{GooseLoggerJoinHandle, GooseLoggerTx};
-use crate::metrics::{GooseMetric, GooseMetrics};
+use crate::metrics::{
+    GooseAbortCondition, GooseAbortConditionState, GooseMetric, GooseMetrics, GooseThreshold,
+};
 use crate::test_plan::{TestPlan, TestPlanHistory, TestPlanStepAction};
 
 /// Constant defining Goose's default telnet Controller port.
@@ -153,6 +155,20 @@ pub enum GooseError {
         /// An optional explanation of the error.
         detail: String,
     },
+    /// An abort condition was met, canceling the load test early.
+    Aborted {
+        /// The metrics collected before the load test was aborted.
+        metrics: Box<GooseMetrics>,
+        /// An optional explanation of the error.
+        detail: String,
+    },
+    /// One or more thresholds were not met by the final metrics of the load test.
+    ThresholdsFailed {
+        /// The final metrics, including the result of checking each threshold.
+        metrics: Box<GooseMetrics>,
+        /// An optional explanation of the error.
+        detail: String,
+    },
 }
 /// Implement a helper to provide a text description of all possible types of errors.
 impl GooseError {
@@ -168,6 +184,8 @@ impl GooseError {
             GooseError::InvalidWeight { .. } => "invalid weight specified",
             GooseError::InvalidControllerCommand { .. } => "invalid controller command",
             GooseError::NoScenarios { .. } => "no scenarios defined",
+            GooseError::Aborted { .. } => "load test aborted",
+            GooseError::ThresholdsFailed { .. } => "thresholds not met",
         }
     }
 }
@@ -187,6 +205,10 @@ impl fmt::Display for GooseError {
             GooseError::InvalidHost {
                 ref parse_error, ..
             } => write!(f, "GooseError: {} ({})", self.describe(), parse_error),
+            GooseError::Aborted { ref detail, .. }
+            | GooseError::ThresholdsFailed { ref detail, .. } => {
+                write!(f, "GooseError: {} ({})", self.describe(), detail)
+            }
             _ => write!(f, "GooseError: {}", self.describe()),
         }
     }
@@ -336,6 +358,8 @@ struct GooseAttackRunState {
     shutdown_after_stop: bool,
     /// Whether or not the load test is currently canceling.
     canceling: bool,
+    /// Abort conditions checked while the load test is running.
+    abort_conditions: Vec<GooseAbortConditionState>,
 }
 
 /// Global internal state for the load test.
@@ -371,6 +395,12 @@ pub struct GooseAttack {
     metrics: GooseMetrics,
     /// All data for report graphs.
     graph_data: GraphData,
+    /// Rules grouping the paths of unnamed requests under a single name.
+    path_rules: Vec<GoosePathRule>,
+    /// Thresholds checked against the final metrics.
+    thresholds: Vec<GooseThreshold>,
+    /// Conditions that abort the load test early.
+    abort_conditions: Vec<GooseAbortCondition>,
 }
 
 /// Goose's internal global state.
@@ -401,6 +431,9 @@ impl GooseAttack {
             step_started: None,
             metrics: GooseMetrics::default(),
             graph_data: GraphData::new(),
+            path_rules: Vec::new(),
+            thresholds: Vec::new(),
+            abort_conditions: Vec::new(),
         })
     }
 
@@ -437,6 +470,9 @@ impl GooseAttack {
             step_started: None,
             metrics: GooseMetrics::default(),
             graph_data: GraphData::new(),
+            path_rules: Vec::new(),
+            thresholds: Vec::new(),
+            abort_conditions: Vec::new(),
         })
     }
 
@@ -619,6 +655,104 @@ impl GooseAttack {
         self
     }
 
+    /// Group the paths of requests that don't have a name under a single name, so
+    /// dynamic paths such as `/node/1` and `/node/2` share one row in the metrics and
+    /// the HTML report.
+    ///
+    /// The path of each unnamed request is compared against each rule in the order they
+    /// were added, after any rules configured with `--path-rules`. Every match of the first
+    /// matching rule's regular expression is replaced with `name`, which can refer to
+    /// capture groups with `$1` or `${group}`. Paths that don't match any rule are grouped
+    /// by `--normalize-paths` if enabled. Returns [`GooseError::InvalidOption`] if the
+    /// regular expression is invalid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     GooseAttack::initialize()?
+    ///         // Requests for `/node/1`, `/node/2` etc are all named `/node/{id}`.
+    ///         .add_path_rule(r"^/node/\d+$", "/node/{id}")?
+    ///         // Requests for `/user/1/edit` are named `/user/{id}/edit`.
+    ///         .add_path_rule(r"^/user/\d+/(\w+)$", "/user/{id}/$1")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn add_path_rule(mut self, pattern: &str, name: &str) -> Result<Self, GooseError> {
+        self.path_rules.push(GoosePathRule::new(pattern, name)?);
+        Ok(self)
+    }
+
+    /// Add a threshold, or service level objective, that the final metrics of the load
+    /// test must meet.
+    ///
+    /// Thresholds are defined as `[TARGET] METRIC OPERATOR LIMIT`, as documented in
+    /// [`GooseThreshold`](./metrics/struct.GooseThreshold.html), and are checked in addition
+    /// to any thresholds configured with `--thresholds`. The result of each threshold is
+    /// displayed with the final metrics and in the HTML report. If any threshold isn't met,
+    /// [`GooseAttack::execute`] returns [`GooseError::ThresholdsFailed`] so the load test
+    /// exits with a non-zero exit code. Returns [`GooseError::InvalidOption`] if the
+    /// threshold can't be parsed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     GooseAttack::initialize()?
+    ///         // 95% of requests for `/api` must complete in less than 300 milliseconds.
+    ///         .add_threshold("GET /api p95 < 300ms")?
+    ///         // Less than 1% of all requests may fail.
+    ///         .add_threshold("error rate < 1%")?
+    ///         // The checkout transaction must take less than 2 seconds on average.
+    ///         .add_threshold("transaction checkout avg < 2s")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn add_threshold(mut self, threshold: &str) -> Result<Self, GooseError> {
+        self.thresholds.push(threshold.parse()?);
+        Ok(self)
+    }
+
+    /// Add a condition that aborts the load test early, for example when a broken deploy
+    /// makes every request fail.
+    ///
+    /// Abort conditions are defined like thresholds, but cancel the running load test as
+    /// soon as the comparison is true. They can be followed by `over DURATION` to only
+    /// measure a sliding window of the most recent seconds, and by `for DURATION` to only
+    /// abort once the comparison has been true for that long, as documented in
+    /// [`GooseAbortCondition`](./metrics/struct.GooseAbortCondition.html). They're checked in
+    /// addition to any abort conditions configured with `--abort-on`. The reason the load
+    /// test was aborted is recorded in the metrics history, displayed with the final metrics
+    /// and in the HTML report, and [`GooseAttack::execute`] returns [`GooseError::Aborted`]
+    /// so the load test exits with a non-zero exit code. Returns
+    /// [`GooseError::InvalidOption`] if the abort condition can't be parsed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     GooseAttack::initialize()?
+    ///         // Abort if more than 5% of requests failed in the last 30 seconds.
+    ///         .add_abort_condition("error rate > 5% over 30s")?
+    ///         // Abort if `/api` has been slower than 2 seconds for a full minute.
+    ///         .add_abort_condition("GET /api p95 > 2000ms over 10s for 1m")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn add_abort_condition(mut self, abort_condition: &str) -> Result<Self, GooseError> {
+        self.abort_conditions.push(abort_condition.parse()?);
+        Ok(self)
+    }
+
     /// Internal helper to determine if the scenario is currently active.
     fn scenario_is_active(&self, scenario: &Scenario) -> bool {
         // All scenarios are enabled by default.
@@ -745,6 +879,15 @@ impl GooseAttack {
 
         let weighted_scenarios = self.allocate_scenarios();
 
+        // Rules configured with `--path-rules` take precedence over those added with
+        // add_path_rule(), and are shared by all users.
+        let mut path_rules = goose::parse_path_rules(&self.configuration.path_rules)?;
+        path_rules.extend(self.path_rules.iter().cloned());
+        let path_rules = Arc::new(path_rules);
+
+        // Certificates and keys are loaded once, and shared by all users.
+        let tls_options = goose::GooseTlsOptions::load(&self.configuration)?;
+
         // Allocate a state for each user that will be launched.
         info!(
             "initializing {} user states...",
@@ -764,12 +907,19 @@ impl GooseAttack {
                     self.scenarios[*scenarios_index].host.clone(),
                     self.defaults.host.clone(),
                 )?;
-                weighted_users.push(GooseUser::new(
+                let mut user = GooseUser::new_with_client_options(
                     self.scenarios[*scenarios_index].scenarios_index,
                     base_url,
                     &self.configuration,
                     self.metrics.hash,
-                )?);
+                    &self.scenarios[*scenarios_index].client_options,
+                    &tls_options,
+                    weighted_users.len(),
+                )?;
+                user.network_profile = self.scenarios[*scenarios_index].network_profile.clone();
+                user.auth = self.scenarios[*scenarios_index].auth.clone();
+                user.path_rules = path_rules.clone();
+                weighted_users.push(user);
                 user_count += 1;
                 if user_count == total_users {
                     debug!("created {} weighted_users", user_count);
@@ -941,6 +1091,11 @@ impl GooseAttack {
 
         self = self.start_attack().await?;
 
+        // Check the final metrics against all configured thresholds.
+        let mut thresholds = metrics::parse_thresholds(&self.configuration.thresholds)?;
+        thresholds.extend(self.thresholds.iter().cloned());
+        self.metrics.check_thresholds(&thresholds);
+
         if self.metrics.display_metrics {
             info!(
                 "printing final metrics after {} seconds...",
@@ -952,6 +1107,35 @@ impl GooseAttack {
             self.write_html_report().await?;
         }
 
+        // Exit with an error if an abort condition canceled the load test.
+        if let Some(reason) = self
+            .metrics
+            .history
+            .iter()
+            .find_map(|step| step.reason.clone())
+        {
+            return Err(GooseError::Aborted {
+                metrics: Box::new(self.metrics),
+                detail: reason,
+            });
+        }
+
+        // Exit with an error if any thresholds weren't met.
+        let failed = self
+            .metrics
+            .thresholds
+            .iter()
+            .filter(|result| !result.passed)
+            .map(|result| result.threshold.definition.as_str())
+            .collect::<Vec<&str>>();
+        if !failed.is_empty() {
+            let detail = format!("failed thresholds: {}", failed.join("; "));
+            return Err(GooseError::ThresholdsFailed {
+                metrics: Box::new(self.metrics),
+                detail,
+            });
+        }
+
         Ok(self.metrics)
     }
 
@@ -1265,6 +1449,7 @@ impl GooseAttack {
             all_users_spawned: false,
             shutdown_after_stop: !self.configuration.no_autostart,
             canceling: false,
+            abort_conditions: Vec::new(),
         };
 
         // Catch ctrl-c to allow clean shutdown to display metrics.
@@ -1700,8 +1885,18 @@ impl GooseAttack {
             }
             // Only display status codes if not disaled.
             self.metrics.display_status_codes = !self.configuration.no_status_codes;
+            // Display the configured percentiles of response times.
+            self.metrics.percentiles = metrics::parse_percentiles(&self.configuration.percentiles)?;
         }
 
+        // Track all abort conditions, configured at run-time and in the load test.
+        goose_attack_run_state.abort_conditions =
+            metrics::parse_abort_conditions(&self.configuration.abort_on)?
+                .into_iter()
+                .chain(self.abort_conditions.iter().cloned())
+                .map(GooseAbortConditionState::new)
+                .collect();
+
         // Reset the run state.
         let std_now = std::time::Instant::now();
         goose_attack_run_state.adjust_user_timer = std_now;
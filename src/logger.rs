@@ -2,11 +2,12 @@
 //!
 //! Goose can generate a number of log files during a load test, enabled through any combination of
 //! the following run time options:
-//!  - `--debug-log`, `--request-log`, `--transaction-log`, `--scenario-log`
+//!  - `--debug-log`, `--request-log`, `--transaction-log`, `--scenario-log`, `--timer-log`
 //!
 //! It's also possible to configure the format of any of thse logs to be `json`, `csv`, or `raw`
 //! (the standard debug output of a Rust structure), using the following run time optios:
-//!  - `--debug-format`, `--request-format`, `--transaction-format`, `--scenario-format`
+//!  - `--debug-format`, `--request-format`, `--transaction-format`, `--scenario-format`,
+//!    `--timer-format`
 //!
 //! All of these loggers use a single shared logger thread, with
 //! [`GooseUser`](../goose/struct.GooseUser.html)s sending log messages through the same shared
@@ -52,6 +53,17 @@
 //! Each [`ScenarioMetric`] object generated by all [`GooseUser`](../goose/struct.GooseUser.html)
 //! threads during a load test is written to this log file.
 //!
+//! ## Timer File logger
+//! The Goose timers logger is enabled with the `--timer-log` command-line option, or the
+//! [`GooseDefault::TimerLog`](../config/enum.GooseDefault.html#variant.TimerLog) default
+//! configuration option. The format of the log is configured with the `--timer-format`
+//! command-line option, or the
+//! [`GooseDefault::TimerFormat`](../config/enum.GooseDefault.html#variant.TimerFormat) default
+//! configuration option.
+//!
+//! Each [`TimerMetric`] object generated when a [`GooseUser`](../goose/struct.GooseUser.html)
+//! stops a custom timer during a load test is written to this log file.
+//!
 //! ## Debug File logger
 //! The Goose debug logger is enabled with the `--debug-log` command-line option, or the
 //! [`GooseDefault::DebugLog`](../config/enum.GooseDefault.html#variant.DebugLog) default
@@ -151,7 +163,9 @@ use tokio::io::{AsyncWriteExt, BufWriter};
 
 use crate::config::{GooseConfigure, GooseValue};
 use crate::goose::GooseDebug;
-use crate::metrics::{GooseErrorMetric, GooseRequestMetric, ScenarioMetric, TransactionMetric};
+use crate::metrics::{
+    GooseErrorMetric, GooseRequestMetric, ScenarioMetric, TimerMetric, TransactionMetric,
+};
 use crate::{GooseConfiguration, GooseDefaults, GooseError};
 
 /// Optional unbounded receiver for logger thread, if debug logger is enabled.
@@ -195,6 +209,7 @@ pub enum GooseLog {
     Request(GooseRequestMetric),
     Transaction(TransactionMetric),
     Scenario(ScenarioMetric),
+    Timer(TimerMetric),
 }
 
 /// Defines the formats logs can be written to file.
@@ -238,6 +253,11 @@ impl FromStr for GooseLogFormat {
     }
 }
 
+// Phase timings that weren't measured are left empty in the csv log.
+fn format_optional_time(time: Option<u64>) -> String {
+    time.map(|time| time.to_string()).unwrap_or_default()
+}
+
 // @TODO this should be automatically derived from the structure.
 fn debug_csv_header() -> String {
     format_csv_row!("tag", "request", "header", "body")
@@ -268,12 +288,21 @@ fn requests_csv_header() -> String {
         "redirected",
         "response_time",
         "status_code",
+        "http_version",
         "success",
         "update",
         "user",
         "error",
         "coordinated_omission_elapsed",
         "user_cadence",
+        "dns",
+        "connect",
+        "time_to_first_byte",
+        "download",
+        "request_body_size",
+        "response_body_size",
+        "network_profile",
+        "cache_hit",
     )
 }
 
@@ -295,6 +324,11 @@ fn scenarios_csv_header() -> String {
     format_csv_row!("elapsed", "name", "index", "run_time", "user",)
 }
 
+// @TODO this should be automatically derived from the structure.
+fn timers_csv_header() -> String {
+    format_csv_row!("elapsed", "name", "run_time", "success", "user",)
+}
+
 /// Two traits that must be implemented by all loggers provided through this thread.
 pub(crate) trait GooseLogger<T> {
     /// Converts a rust structure to a formatted string.
@@ -385,12 +419,21 @@ impl GooseLogger<GooseRequestMetric> for GooseConfiguration {
                         message.redirected,
                         message.response_time,
                         message.status_code,
+                        message.http_version.unwrap_or_default(),
                         message.success,
                         message.update,
                         message.user,
                         message.error,
                         message.coordinated_omission_elapsed,
                         message.user_cadence,
+                        format_optional_time(message.timings.dns),
+                        format_optional_time(message.timings.connect),
+                        format_optional_time(message.timings.time_to_first_byte),
+                        format_optional_time(message.timings.download),
+                        message.request_body_size,
+                        message.response_body_size,
+                        message.network_profile.unwrap_or_default(),
+                        message.cache_hit,
                     )
                 }
             }
@@ -462,6 +505,36 @@ impl GooseLogger<ScenarioMetric> for GooseConfiguration {
     }
 }
 
+/// Traits for TimerMetric logs.
+impl GooseLogger<TimerMetric> for GooseConfiguration {
+    /// Converts a TimerMetric structure to a formatted string.
+    fn format_message(&self, message: TimerMetric) -> String {
+        if let Some(timer_format) = self.timer_format.as_ref() {
+            match timer_format {
+                // Use serde_json to create JSON.
+                GooseLogFormat::Json => json!(message).to_string(),
+                // Raw format is Debug output for TimerMetric structure.
+                GooseLogFormat::Raw => format!("{:?}", message),
+                // Pretty format is Debug Pretty output for TimerMetric structure.
+                GooseLogFormat::Pretty => format!("{:#?}", message),
+                // Csv format with `,` separator and `"` quotes.
+                GooseLogFormat::Csv => {
+                    format_csv_row!(
+                        message.elapsed,
+                        message.name,
+                        message.run_time,
+                        message.success,
+                        message.user,
+                    )
+                }
+            }
+        } else {
+            // A log format is required.
+            unreachable!()
+        }
+    }
+}
+
 /// Helpers to launch and control configured loggers.
 impl GooseConfiguration {
     /// Makes sure the GooseConfiguration has any/all configured log files (loading from defaults
@@ -684,6 +757,46 @@ impl GooseConfiguration {
                 message: "",
             },
         ]);
+
+        // Configure timer_log path if enabled.
+        self.timer_log = self
+            .get_value(vec![
+                // Use --timer-log if set.
+                GooseValue {
+                    value: Some(self.timer_log.to_string()),
+                    filter: self.timer_log.is_empty(),
+                    message: "",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.timer_log.clone(),
+                    filter: defaults.timer_log.is_none(),
+                    message: "",
+                },
+            ])
+            .unwrap_or_default();
+
+        // Set `timer_format`.
+        self.timer_format = self.get_value(vec![
+            // Use --timer-format if set.
+            GooseValue {
+                value: self.timer_format.clone(),
+                filter: self.timer_format.is_none(),
+                message: "",
+            },
+            // Otherwise use GooseDefault if set.
+            GooseValue {
+                value: defaults.timer_format.clone(),
+                filter: defaults.timer_format.is_none(),
+                message: "",
+            },
+            // Otherwise default to GooseLogFormat::Json.
+            GooseValue {
+                value: Some(GooseLogFormat::Json),
+                filter: false,
+                message: "",
+            },
+        ]);
     }
 
     /// Spawns the logger thread if one or more loggers are enabled.
@@ -699,6 +812,7 @@ impl GooseConfiguration {
             && self.request_log.is_empty()
             && self.transaction_log.is_empty()
             && self.scenario_log.is_empty()
+            && self.timer_log.is_empty()
             && self.error_log.is_empty()
         {
             return Ok((None, None));
@@ -847,6 +961,18 @@ impl GooseConfiguration {
             }
         }
 
+        // If the timer_log is enabled, allocate a buffer and open the file.
+        let mut timer_log = self
+            .open_log_file(&self.timer_log, "timer log", 64 * 1024)
+            .await;
+        // If the timer_log is a CSV, write the header.
+        if self.timer_format == Some(GooseLogFormat::Csv) {
+            if let Some(log_file) = timer_log.as_mut() {
+                // This will generate a warning if it fails to write to log file.
+                self.write_to_log_file(log_file, timers_csv_header()).await;
+            }
+        }
+
         // Loop waiting for and writing error logs from GooseUser threads.
         while let Ok(received_message) = receiver.recv_async().await {
             if let Some(message) = received_message {
@@ -872,6 +998,10 @@ impl GooseConfiguration {
                         formatted_message = self.format_message(scenario_message).to_string();
                         scenario_log.as_mut()
                     }
+                    GooseLog::Timer(timer_message) => {
+                        formatted_message = self.format_message(timer_message).to_string();
+                        timer_log.as_mut()
+                    }
                 } {
                     // This will generate a warning if it fails to write to log file.
                     self.write_to_log_file(log_file, formatted_message).await;
@@ -906,6 +1036,12 @@ impl GooseConfiguration {
             let _ = scenarios_log_file.flush().await;
         }
 
+        // Flush timer log to disk if enabled.
+        if let Some(timers_log_file) = timer_log.as_mut() {
+            info!("flushing timer_log: {}", &self.timer_log);
+            let _ = timers_log_file.flush().await;
+        }
+
         // Flush error logs to disk if enabled.
         if let Some(error_log_file) = error_log.as_mut() {
             info!("flushing error_log: {}", &self.error_log);
@@ -14,7 +14,7 @@ use crate::logger::GooseLog;
 use crate::report;
 use crate::test_plan::{TestPlanHistory, TestPlanStepAction};
 use crate::util;
-use crate::{GooseAttack, GooseAttackRunState, GooseConfiguration, GooseError};
+use crate::{AttackPhase, GooseAttack, GooseAttackRunState, GooseConfiguration, GooseError};
 use chrono::prelude::*;
 use http::StatusCode;
 use itertools::Itertools;
@@ -23,12 +23,19 @@ use regex::RegexSet;
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Serialize, Serializer};
 use std::cmp::Ordering;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::fmt::Write;
 use std::str::FromStr;
 use std::{f32, fmt};
 use tokio::io::AsyncWriteExt;
 
+/// The default number of significant digits recorded times are accurate to, configurable
+/// with `--significant-digits`.
+pub(crate) const DEFAULT_SIGNIFICANT_DIGITS: usize = 3;
+
+/// The default percentiles of response times to display, configurable with `--percentiles`.
+pub(crate) const DEFAULT_PERCENTILES: &str = "50,75,98,99,99.9,99.99";
+
 /// Used to send metrics from [`GooseUser`](../goose/struct.GooseUser.html) threads
 /// to the parent Goose process.
 ///
@@ -47,6 +54,9 @@ pub enum GooseMetric {
     Request(GooseRequestMetric),
     Transaction(TransactionMetric),
     Scenario(ScenarioMetric),
+    Timer(TimerMetric),
+    Custom(CustomMetric),
+    Stream(StreamMetric),
 }
 
 /// THIS IS AN EXPERIMENTAL FEATURE, DISABLED BY DEFAULT. Optionally mitigate the loss of data
@@ -259,6 +269,139 @@ pub type TransactionMetrics = Vec<Vec<TransactionMetricAggregate>>;
 /// ```
 pub type ScenarioMetrics = Vec<ScenarioMetricAggregate>;
 
+/// All custom timers recorded during a load test.
+///
+/// Goose tracks metrics about custom timers started with
+/// [`GooseUser::start_timer`](../goose/struct.GooseUser.html#method.start_timer) and stopped
+/// with [`GooseUser::stop_timer`](../goose/struct.GooseUser.html#method.stop_timer). A timer
+/// can measure any part of a load test, such as a business flow that spans multiple requests
+/// or multiple transactions. The metrics can be disabled with the `--no-metrics` run-time
+/// option, or with [`GooseDefault::NoMetrics`](../config/enum.GooseDefault.html#variant.NoMetrics).
+///
+/// Aggregated timers ([`TimerMetricAggregate`]) are stored in a `BTreeMap` keyed by the name
+/// of the timer.
+///
+/// # Example
+/// When viewed with [`std::fmt::Display`], [`TimerMetrics`] are displayed in
+/// a table:
+/// ```text
+///  === PER TIMER METRICS ===
+/// ------------------------------------------------------------------------------
+/// Name                     |   # times run |        # fails |  timer/s |  fail/s
+/// ------------------------------------------------------------------------------
+/// checkout                 |            85 |       3 (3.5%) |     8.50 |    0.30
+/// login                    |           102 |         0 (0%) |    10.20 |    0.00
+/// -------------------------+---------------+----------------+----------+--------
+/// Aggregated               |           187 |       3 (1.6%) |    18.70 |    0.30
+/// ------------------------------------------------------------------------------
+/// Name                     |    Avg (ms) |        Min |         Max |     Median
+/// ------------------------------------------------------------------------------
+/// checkout                 |      612.40 |        410 |       1,300 |        600
+/// login                    |      201.18 |        150 |         330 |        200
+/// -------------------------+-------------+------------+-------------+-----------
+/// Aggregated               |      388.20 |        150 |       1,300 |        300
+/// ```
+pub type TimerMetrics = BTreeMap<String, TimerMetricAggregate>;
+
+/// All custom counters recorded during a load test.
+///
+/// Counters are incremented with
+/// [`GooseUser::increment_counter`](../goose/struct.GooseUser.html#method.increment_counter),
+/// for example to count how many items have been added to carts. The metrics can be disabled
+/// with the `--no-metrics` run-time option, or with
+/// [`GooseDefault::NoMetrics`](../config/enum.GooseDefault.html#variant.NoMetrics).
+///
+/// Aggregated counters ([`CounterMetricAggregate`]) are stored in a `BTreeMap` keyed by the
+/// name of the counter.
+///
+/// # Example
+/// When viewed with [`std::fmt::Display`], [`CounterMetrics`] are displayed in
+/// a table:
+/// ```text
+///  === PER COUNTER METRICS ===
+///  ------------------------------------------------------------------------------
+///  Name                     |            Total |      # updates |        total/s
+///  ------------------------------------------------------------------------------
+///  items in cart            |              412 |            206 |          41.20
+///  orders placed            |               37 |             37 |           3.70
+/// ```
+pub type CounterMetrics = BTreeMap<String, CounterMetricAggregate>;
+
+/// All custom gauges recorded during a load test.
+///
+/// Gauges are set with
+/// [`GooseUser::set_gauge`](../goose/struct.GooseUser.html#method.set_gauge), for example
+/// to track the queue depth reported by an API. The metrics can be disabled with the
+/// `--no-metrics` run-time option, or with
+/// [`GooseDefault::NoMetrics`](../config/enum.GooseDefault.html#variant.NoMetrics).
+///
+/// Aggregated gauges ([`GaugeMetricAggregate`]) are stored in a `BTreeMap` keyed by the
+/// name of the gauge.
+///
+/// # Example
+/// When viewed with [`std::fmt::Display`], [`GaugeMetrics`] are displayed in
+/// a table:
+/// ```text
+///  === PER GAUGE METRICS ===
+///  ------------------------------------------------------------------------------
+///  Name                     |       Last |        Min |        Max |   # updates
+///  ------------------------------------------------------------------------------
+///  queue depth              |      12.00 |       0.00 |      57.00 |         103
+/// ```
+pub type GaugeMetrics = BTreeMap<String, GaugeMetricAggregate>;
+
+/// All custom histograms recorded during a load test.
+///
+/// Values are recorded in histograms with
+/// [`GooseUser::record_histogram`](../goose/struct.GooseUser.html#method.record_histogram),
+/// for example to track how many search results are returned. The metrics can be disabled
+/// with the `--no-metrics` run-time option, or with
+/// [`GooseDefault::NoMetrics`](../config/enum.GooseDefault.html#variant.NoMetrics).
+///
+/// Aggregated histograms ([`HistogramMetricAggregate`]) are stored in a `BTreeMap` keyed by
+/// the name of the histogram.
+///
+/// # Example
+/// When viewed with [`std::fmt::Display`], [`HistogramMetrics`] are displayed in
+/// a table:
+/// ```text
+///  === PER HISTOGRAM METRICS ===
+///  ------------------------------------------------------------------------------
+///  Name                     |        # |       Avg |      Min |      Max |  Median
+///  ------------------------------------------------------------------------------
+///  search results           |      305 |     18.42 |        0 |      100 |      20
+/// ```
+pub type HistogramMetrics = BTreeMap<String, HistogramMetricAggregate>;
+
+/// All streaming responses read during a load test.
+///
+/// Goose tracks metrics about Server-Sent Events and other streaming responses read with
+/// [`GooseUser::request_stream`](../goose/struct.GooseUser.html#method.request_stream). The
+/// request that opens each stream is also tracked in [`GooseRequestMetrics`]. The metrics can
+/// be disabled with the `--no-metrics` run-time option, or with
+/// [`GooseDefault::NoMetrics`](../config/enum.GooseDefault.html#variant.NoMetrics).
+///
+/// Aggregated streams ([`StreamMetricAggregate`]) are stored in a `BTreeMap` with the key
+/// `method request-name`, for example `GET /events`.
+///
+/// # Example
+/// When viewed with [`std::fmt::Display`], [`StreamMetrics`] are displayed in
+/// tables:
+/// ```text
+///  === PER STREAM METRICS ===
+///  ------------------------------------------------------------------------------
+///  Name                     |     # streams |        # fails | stream/s |  fail/s
+///  ------------------------------------------------------------------------------
+///  GET prices               |            40 |         0 (0%) |     4.00 |    0.00
+///  ------------------------------------------------------------------------------
+///  Average stream times (in ms):
+///  ------------------------------------------------------------------------------
+///  Name                     |  Events |     TTFB |  1st evt |  Between |   Total
+///  ------------------------------------------------------------------------------
+///  GET prices               |   10.00 |     2.05 |     2.40 |   101.32 |    1,004
+/// ```
+pub type StreamMetrics = BTreeMap<String, StreamMetricAggregate>;
+
 /// All errors detected during a load test.
 ///
 /// By default Goose tracks all errors detected during the load test. Each error is stored
@@ -334,10 +477,14 @@ pub struct GooseRequestMetric {
     pub final_url: String,
     /// Whether or not the request was redirected.
     pub redirected: bool,
-    /// How many milliseconds the request took.
+    /// How many milliseconds the request took, including downloading the response body
+    /// unless it was read as a stream.
     pub response_time: u64,
     /// The HTTP response code (optional).
     pub status_code: u16,
+    /// The HTTP version the response was received with, such as `HTTP/1.1` or `HTTP/2.0`,
+    /// if a response was received from the server.
+    pub http_version: Option<String>,
     /// Whether or not the request was successful.
     pub success: bool,
     /// Whether or not we're updating a previous request, modifies how the parent thread records it.
@@ -353,6 +500,21 @@ pub struct GooseRequestMetric {
     /// [`Transaction`](../goose/struct.Transaction.html)s by this
     /// [`GooseUser`](../goose/struct.GooseUser.html) thread.
     pub user_cadence: u64,
+    /// How long each phase of the request took.
+    pub timings: GooseRequestTimings,
+    /// How many bytes were sent in the request body. Bodies that are streamed are only
+    /// counted if they set a `Content-Length` header, as multipart forms do.
+    pub request_body_size: u64,
+    /// How many bytes were received in the response body, before it was decompressed. Bodies
+    /// read with [`GooseUser::request_stream`](../goose/struct.GooseUser.html#method.request_stream)
+    /// aren't counted.
+    pub response_body_size: u64,
+    /// The name of the network profile emulated while making the request, if any, configured
+    /// with [`Scenario::set_network_profile`](../goose/struct.Scenario.html#method.set_network_profile).
+    pub network_profile: Option<String>,
+    /// Whether the response was loaded from the HTTP cache without contacting the server,
+    /// enabled with [`GooseUser::enable_http_cache`](../goose/struct.GooseUser.html#method.enable_http_cache).
+    pub cache_hit: bool,
 }
 impl GooseRequestMetric {
     pub(crate) fn new(raw: GooseRawRequest, name: &str, elapsed: u128, user: usize) -> Self {
@@ -364,12 +526,18 @@ impl GooseRequestMetric {
             redirected: false,
             response_time: 0,
             status_code: 0,
+            http_version: None,
             success: true,
             update: false,
             user,
             error: "".to_string(),
             coordinated_omission_elapsed: 0,
             user_cadence: 0,
+            timings: GooseRequestTimings::default(),
+            request_body_size: 0,
+            response_body_size: 0,
+            network_profile: None,
+            cache_hit: false,
         }
     }
 
@@ -395,6 +563,77 @@ impl GooseRequestMetric {
     }
 }
 
+/// How long each phase of a request took, in milliseconds.
+///
+/// The `response_time` of a [`GooseRequestMetric`] is the sum of these phases: the time
+/// to the first byte of the response includes the DNS lookup and establishing the
+/// connection, and the body download starts once the response headers are received.
+///
+/// The HTTP client negotiates TLS as part of opening a connection, so the TLS handshake
+/// can't be timed separately and is included in the time it took to connect.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GooseRequestTimings {
+    /// How long it took to resolve the host name. Only set if the request opened a new
+    /// connection to a host name, as DNS isn't queried when a connection is reused or for
+    /// IP addresses.
+    pub dns: Option<u64>,
+    /// How long it took to open a new connection once the host name was resolved, including
+    /// the TLS handshake for `https` requests. Only set if the request opened a new
+    /// connection.
+    pub connect: Option<u64>,
+    /// How long it took from sending the request until the response headers were received.
+    /// Not set if no response was received, or for operations recorded with
+    /// [`GooseUser::record_operation`](../goose/struct.GooseUser.html#method.record_operation).
+    pub time_to_first_byte: Option<u64>,
+    /// How long it took to download the response body once the headers were received. Not
+    /// set if the request failed before a response was received, or if the body was read as
+    /// a stream with [`GooseUser::request_stream`](../goose/struct.GooseUser.html#method.request_stream).
+    pub download: Option<u64>,
+}
+
+/// Timings of each phase of all requests made to a method-path pair, aggregated from
+/// [`GooseRequestTimings`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GooseRequestTimingsAggregate {
+    /// How long it took to resolve host names, for requests that opened a new connection.
+    pub dns: GooseRequestMetricTimingData,
+    /// How long it took to open new connections, including TLS handshakes.
+    pub connect: GooseRequestMetricTimingData,
+    /// How long it took from sending requests until the response headers were received.
+    pub time_to_first_byte: GooseRequestMetricTimingData,
+    /// How long it took to download response bodies.
+    pub download: GooseRequestMetricTimingData,
+}
+impl GooseRequestTimingsAggregate {
+    /// Create a new GooseRequestTimingsAggregate object.
+    pub(crate) fn new() -> Self {
+        GooseRequestTimingsAggregate {
+            dns: GooseRequestMetricTimingData::new(None),
+            connect: GooseRequestMetricTimingData::new(None),
+            time_to_first_byte: GooseRequestMetricTimingData::new(None),
+            download: GooseRequestMetricTimingData::new(None),
+        }
+    }
+
+    /// Record the timings of a single request, accurate to the given number of significant
+    /// digits.
+    pub(crate) fn record(&mut self, timings: &GooseRequestTimings, significant_digits: usize) {
+        if let Some(dns) = timings.dns {
+            self.dns.record_time(dns, significant_digits);
+        }
+        if let Some(connect) = timings.connect {
+            self.connect.record_time(connect, significant_digits);
+        }
+        if let Some(time_to_first_byte) = timings.time_to_first_byte {
+            self.time_to_first_byte
+                .record_time(time_to_first_byte, significant_digits);
+        }
+        if let Some(download) = timings.download {
+            self.download.record_time(download, significant_digits);
+        }
+    }
+}
+
 /// Metrics collected about a method-path pair, (for example `GET /index`).
 ///
 /// [`GooseRequestMetric`]s are sent by [`GooseUser`](../goose/struct.GooseUser.html)
@@ -424,6 +663,19 @@ pub struct GooseRequestMetricAggregate {
     ///
     /// A count of how many requests resulted in a non-2xx status code.
     pub fail_count: usize,
+    /// How long each phase of these requests took, not including times generated by
+    /// Coordinated Omission Mitigation.
+    pub timings: GooseRequestTimingsAggregate,
+    /// Total number of bytes sent in request bodies.
+    pub request_body_bytes: u64,
+    /// Total number of bytes received in response bodies, before they were decompressed.
+    pub response_body_bytes: u64,
+    /// Total number of times the response was loaded from the HTTP cache without contacting
+    /// the server. Cache hits aren't counted as requests.
+    pub cache_hits: usize,
+    /// Total number of times the server returned `304 Not Modified`, confirming a cached
+    /// response is still valid.
+    pub not_modified: usize,
     /// Load test hash.
     ///
     /// The hash is primarily used when running a distributed Gaggle, allowing the Manager to confirm
@@ -442,27 +694,37 @@ impl GooseRequestMetricAggregate {
             status_code_counts: HashMap::new(),
             success_count: 0,
             fail_count: 0,
+            timings: GooseRequestTimingsAggregate::new(),
+            request_body_bytes: 0,
+            response_body_bytes: 0,
+            cache_hits: 0,
+            not_modified: 0,
             load_test_hash,
         }
     }
 
-    pub(crate) fn record_time(&mut self, time_elapsed: u64, coordinated_omission_mitigation: bool) {
+    pub(crate) fn record_time(
+        &mut self,
+        time_elapsed: u64,
+        coordinated_omission_mitigation: bool,
+        significant_digits: usize,
+    ) {
         // Only add time_elapsed to raw_data if the time wasn't generated by Coordinated
         // Omission Mitigation.
         if !coordinated_omission_mitigation {
-            self.raw_data.record_time(time_elapsed);
+            self.raw_data.record_time(time_elapsed, significant_digits);
         }
 
         // A Coordinated Omission data object already exists, add a new time into the data.
         if let Some(coordinated_omission_data) = self.coordinated_omission_data.as_mut() {
-            coordinated_omission_data.record_time(time_elapsed);
+            coordinated_omission_data.record_time(time_elapsed, significant_digits);
         }
         // Create a new Coordinated Omission data object by cloning the raw data.
         else if coordinated_omission_mitigation {
             // If this time_elapsed was generated by Coordinated Omission Mitigation, it doesn't
             // exist in the raw_data, so add it.
             let mut coordinated_omission_data = self.raw_data.clone();
-            coordinated_omission_data.record_time(time_elapsed);
+            coordinated_omission_data.record_time(time_elapsed, significant_digits);
             self.coordinated_omission_data = Some(coordinated_omission_data);
         }
     }
@@ -513,10 +775,11 @@ impl PartialOrd for GooseRequestMetricAggregate {
 pub struct GooseRequestMetricTimingData {
     /// Per-response-time counters, tracking how often pages are returned with this response time.
     ///
-    /// All response times between 1 and 100ms are stored without any rounding. Response times between
-    /// 100 and 500ms are rounded to the nearest 10ms and then stored. Response times betwee 500 and
-    /// 1000ms are rounded to the nearest 100ms. Response times larger than 1000ms are rounded to the
-    /// nearest 1000ms.
+    /// Response times are stored in the buckets of a sparse histogram, keyed by the time in the
+    /// middle of each bucket. Times are accurate to the number of significant digits configured
+    /// with `--significant-digits` (3 by default): with 3 digits all response times up to
+    /// 2047ms are stored without any rounding, times up to 4095ms are stored in buckets 2ms
+    /// wide, times up to 8191ms in buckets 4ms wide, and so on.
     pub times: BTreeMap<usize, usize>,
     /// The shortest response time seen so far.
     ///
@@ -556,8 +819,8 @@ impl GooseRequestMetricTimingData {
         }
     }
 
-    /// Record a new time.
-    pub(crate) fn record_time(&mut self, time_elapsed: u64) {
+    /// Record a new time, accurate to the given number of significant digits.
+    pub(crate) fn record_time(&mut self, time_elapsed: u64, significant_digits: usize) {
         // Perform this conversin only once, then re-use throughout this funciton.
         let time = time_elapsed as usize;
 
@@ -577,39 +840,9 @@ impl GooseRequestMetricTimingData {
         // Each time we store a new time, increment counter by one.
         self.counter += 1;
 
-        // Round the time so we can combine similar times together and
-        // minimize required memory to store and push upstream to the parent.
-        // No rounding for 1-100ms times.
-        let rounded_time = if time_elapsed < 100 {
-            time
-        }
-        // Round to nearest 10 for 100-500ms times.
-        else if time_elapsed < 500 {
-            ((time_elapsed as f64 / 10.0).round() * 10.0) as usize
-        }
-        // Round to nearest 100 for 500-1000ms times.
-        else if time_elapsed < 1000 {
-            ((time_elapsed as f64 / 100.0).round() * 100.0) as usize
-        }
-        // Round to nearest 1000 for all larger times.
-        else {
-            ((time_elapsed as f64 / 1000.0).round() * 1000.0) as usize
-        };
-
-        let counter = match self.times.get(&rounded_time) {
-            // We've seen this elapsed time before, increment counter.
-            Some(c) => {
-                debug!("got {:?} counter: {}", rounded_time, c);
-                *c + 1
-            }
-            // First time we've seen this elapsed time, initialize counter.
-            None => {
-                debug!("no match for counter: {}", rounded_time);
-                1
-            }
-        };
-        debug!("incremented {} counter: {}", rounded_time, counter);
-        self.times.insert(rounded_time, counter);
+        // Store the time in its histogram bucket so we can combine similar times together
+        // and minimize required memory to store and push upstream to the parent.
+        record_histogram_time(&mut self.times, time, significant_digits);
     }
 }
 /// The per-scenario metrics collected each time a scenario is run.
@@ -708,6 +941,9 @@ pub struct TransactionMetricAggregate {
     /// An optional name for the transaction.
     pub transaction_name: String,
     /// Per-run-time counters, tracking how often transactions take a given time to complete.
+    ///
+    /// Run-times are stored in the buckets of a sparse histogram, as described in
+    /// [`GooseRequestMetricTimingData::times`].
     pub times: BTreeMap<usize, usize>,
     /// The shortest run-time for this transaction.
     pub min_time: usize,
@@ -745,8 +981,9 @@ impl TransactionMetricAggregate {
         }
     }
 
-    /// Track transaction function elapsed time in milliseconds.
-    pub(crate) fn set_time(&mut self, time: u64, success: bool) {
+    /// Track transaction function elapsed time in milliseconds, accurate to the given number
+    /// of significant digits.
+    pub(crate) fn set_time(&mut self, time: u64, success: bool, significant_digits: usize) {
         // Perform this conversion only once, then re-use throughout this function.
         let time_usize = time as usize;
 
@@ -772,27 +1009,9 @@ impl TransactionMetricAggregate {
             self.fail_count += 1;
         }
 
-        // Round the time so we can combine similar times together and
-        // minimize required memory to store and push upstream to the parent.
-        let rounded_time = match time {
-            // No rounding for times 0-100 ms.
-            0..=100 => time_usize,
-            // Round to nearest 10 for times 100-500 ms.
-            101..=500 => ((time as f64 / 10.0).round() * 10.0) as usize,
-            // Round to nearest 100 for times 500-1000 ms.
-            501..=1000 => ((time as f64 / 100.0).round() * 10.0) as usize,
-            // Round to nearest 1000 for larger times.
-            _ => ((time as f64 / 1000.0).round() * 10.0) as usize,
-        };
-
-        let counter = match self.times.get(&rounded_time) {
-            // We've seen this time before, increment counter.
-            Some(c) => *c + 1,
-            // First time we've seen this time, initialize counter.
-            None => 1,
-        };
-        self.times.insert(rounded_time, counter);
-        debug!("incremented {} counter: {}", rounded_time, counter);
+        // Store the time in its histogram bucket so we can combine similar times together
+        // and minimize required memory to store and push upstream to the parent.
+        record_histogram_time(&mut self.times, time_usize, significant_digits);
     }
 }
 /// Aggregated per-scenario metrics updated each time a scenario is run.
@@ -810,6 +1029,9 @@ pub struct ScenarioMetricAggregate {
     /// List of users running this scenario.
     pub users: HashSet<usize>,
     /// Per-run-time counters, tracking how often scenario takes a given time to complete.
+    ///
+    /// Run-times are stored in the buckets of a sparse histogram, as described in
+    /// [`GooseRequestMetricTimingData::times`].
     pub times: BTreeMap<usize, usize>,
     /// The shortest run-time for this scenario.
     pub min_time: usize,
@@ -835,8 +1057,9 @@ impl ScenarioMetricAggregate {
         }
     }
 
-    /// Track scenario function elapsed time in milliseconds.
-    pub(crate) fn update(&mut self, time: u64, user: usize) {
+    /// Track scenario function elapsed time in milliseconds, accurate to the given number
+    /// of significant digits.
+    pub(crate) fn update(&mut self, time: u64, user: usize, significant_digits: usize) {
         // Record each different user running this scenario.
         self.users.insert(user);
 
@@ -859,59 +1082,413 @@ impl ScenarioMetricAggregate {
         // Each time we store a new time, increment counter by one.
         self.counter += 1;
 
-        // Round the time so we can combine similar times together and
-        // minimize required memory to store and push upstream to the parent.
-        let rounded_time = match time {
-            // No rounding for times 0-100 ms.
-            0..=100 => time_usize,
-            // Round to nearest 10 for times 100-500 ms.
-            101..=500 => ((time as f64 / 10.0).round() * 10.0) as usize,
-            // Round to nearest 100 for times 500-1000 ms.
-            501..=1000 => ((time as f64 / 100.0).round() * 10.0) as usize,
-            // Round to nearest 1000 for larger times.
-            _ => ((time as f64 / 1000.0).round() * 10.0) as usize,
-        };
+        // Store the time in its histogram bucket so we can combine similar times together
+        // and minimize required memory to store and push upstream to the parent.
+        record_histogram_time(&mut self.times, time_usize, significant_digits);
+    }
+}
 
-        let counter = match self.times.get(&rounded_time) {
-            // We've seen this time before, increment counter.
-            Some(c) => *c + 1,
-            // First time we've seen this time, initialize counter.
-            None => 1,
-        };
-        self.times.insert(rounded_time, counter);
-        debug!("incremented {} counter: {}", rounded_time, counter);
+/// The metrics collected each time a custom timer is stopped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimerMetric {
+    /// How many milliseconds the load test has been running.
+    pub elapsed: u64,
+    /// The name of the timer.
+    pub name: String,
+    /// How many milliseconds passed between starting and stopping the timer.
+    pub run_time: u64,
+    /// Whether or not the timed operation was successful.
+    pub success: bool,
+    /// Which GooseUser thread started and stopped the timer.
+    pub user: usize,
+}
+impl TimerMetric {
+    /// Create a new TimerMetric metric.
+    pub(crate) fn new(
+        elapsed: u128,
+        name: &str,
+        run_time: u128,
+        success: bool,
+        user: usize,
+    ) -> Self {
+        TimerMetric {
+            elapsed: elapsed as u64,
+            name: name.to_string(),
+            run_time: run_time as u64,
+            success,
+            user,
+        }
     }
 }
-/// All metrics optionally collected during a Goose load test.
+
+/// Aggregated per-timer metrics updated each time a custom timer is stopped.
 ///
-/// By default, Goose collects metrics during a load test in a `GooseMetrics` object
-/// that is returned by
-/// [`GooseAttack::execute()`](../struct.GooseAttack.html#method.execute) when a load
-/// test finishes.
+/// [`TimerMetric`]s are sent by [`GooseUser`](../goose/struct.GooseUser.html)
+/// threads to the Goose parent process where they are aggregated together into this
+/// structure, and stored in [`GooseMetrics::timers`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TimerMetricAggregate {
+    /// The name of the timer.
+    pub name: String,
+    /// Per-run-time counters, tracking how often timers take a given time to complete.
+    ///
+    /// Run-times are stored in the buckets of a sparse histogram, as described in
+    /// [`GooseRequestMetricTimingData::times`].
+    pub times: BTreeMap<usize, usize>,
+    /// The shortest run-time for this timer.
+    pub min_time: usize,
+    /// The longest run-time for this timer.
+    pub max_time: usize,
+    /// Total combined run-times for this timer.
+    pub total_time: usize,
+    /// Total number of times timer has been stopped.
+    pub counter: usize,
+    /// Total number of times the timed operation was successful.
+    pub success_count: usize,
+    /// Total number of times the timed operation failed.
+    pub fail_count: usize,
+}
+impl TimerMetricAggregate {
+    /// Create a new TimerMetricAggregate.
+    pub(crate) fn new(name: &str) -> Self {
+        TimerMetricAggregate {
+            name: name.to_string(),
+            times: BTreeMap::new(),
+            min_time: 0,
+            max_time: 0,
+            total_time: 0,
+            counter: 0,
+            success_count: 0,
+            fail_count: 0,
+        }
+    }
+
+    /// Track timer elapsed time in milliseconds, accurate to the given number of significant
+    /// digits.
+    pub(crate) fn set_time(&mut self, time: u64, success: bool, significant_digits: usize) {
+        // Perform this conversion only once, then re-use throughout this function.
+        let time_usize = time as usize;
+
+        // Update minimum if this one is fastest yet.
+        if self.min_time == 0 || time_usize < self.min_time {
+            self.min_time = time_usize;
+        }
+
+        // Update maximum if this one is slowest yet.
+        if time_usize > self.max_time {
+            self.max_time = time_usize;
+        }
+
+        // Update total_time, adding in this one.
+        self.total_time += time_usize;
+
+        // Each time we store a new time, increment counter by one.
+        self.counter += 1;
+
+        if success {
+            self.success_count += 1;
+        } else {
+            self.fail_count += 1;
+        }
+
+        // Store the time in its histogram bucket so we can combine similar times together
+        // and minimize required memory to store and push upstream to the parent.
+        record_histogram_time(&mut self.times, time_usize, significant_digits);
+    }
+}
+
+/// The value of a custom metric sent by a [`GooseUser`](../goose/struct.GooseUser.html).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CustomMetricValue {
+    /// Increment a counter by this amount.
+    Counter(u64),
+    /// Set a gauge to this value.
+    Gauge(f64),
+    /// Record this value in a histogram.
+    Histogram(u64),
+}
+
+/// The metrics collected each time a custom counter, gauge or histogram is updated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomMetric {
+    /// How many milliseconds the load test has been running.
+    pub elapsed: u64,
+    /// The name of the custom metric.
+    pub name: String,
+    /// The type and value of the custom metric.
+    pub value: CustomMetricValue,
+    /// Which GooseUser thread updated the custom metric.
+    pub user: usize,
+}
+impl CustomMetric {
+    /// Create a new CustomMetric metric.
+    pub(crate) fn new(elapsed: u128, name: &str, value: CustomMetricValue, user: usize) -> Self {
+        CustomMetric {
+            elapsed: elapsed as u64,
+            name: name.to_string(),
+            value,
+            user,
+        }
+    }
+}
+
+/// Aggregated per-counter metrics updated each time a custom counter is incremented.
 ///
-/// # Example
-/// ```rust
-/// use goose::prelude::*;
+/// Stored in [`GooseMetrics::counters`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CounterMetricAggregate {
+    /// The name of the counter.
+    pub name: String,
+    /// The sum of all increments.
+    pub total: u64,
+    /// Total number of times the counter was incremented.
+    pub updates: usize,
+}
+impl CounterMetricAggregate {
+    /// Create a new CounterMetricAggregate.
+    pub(crate) fn new(name: &str) -> Self {
+        CounterMetricAggregate {
+            name: name.to_string(),
+            total: 0,
+            updates: 0,
+        }
+    }
+
+    /// Increment the counter.
+    pub(crate) fn increment(&mut self, value: u64) {
+        self.total += value;
+        self.updates += 1;
+    }
+}
+
+/// Aggregated per-gauge metrics updated each time a custom gauge is set.
 ///
-/// #[tokio::main]
-/// async fn main() -> Result<(), GooseError> {
-///     let goose_metrics: GooseMetrics = GooseAttack::initialize()?
-///         .register_scenario(scenario!("ExampleUsers")
-///             .register_transaction(transaction!(example_transaction))
-///         )
-///         // Set a default host so the load test will start.
-///         .set_default(GooseDefault::Host, "http://localhost/")?
-///         // Set a default run time so this test runs to completion.
-///         .set_default(GooseDefault::RunTime, 1)?
-///         .execute()
-///         .await?;
+/// Stored in [`GooseMetrics::gauges`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GaugeMetricAggregate {
+    /// The name of the gauge.
+    pub name: String,
+    /// The most recent value of the gauge.
+    pub value: f64,
+    /// The lowest value the gauge has been set to.
+    pub minimum: f64,
+    /// The highest value the gauge has been set to.
+    pub maximum: f64,
+    /// Total number of times the gauge was set.
+    pub updates: usize,
+}
+impl GaugeMetricAggregate {
+    /// Create a new GaugeMetricAggregate.
+    pub(crate) fn new(name: &str) -> Self {
+        GaugeMetricAggregate {
+            name: name.to_string(),
+            value: 0.0,
+            minimum: 0.0,
+            maximum: 0.0,
+            updates: 0,
+        }
+    }
+
+    /// Set the gauge to a new value.
+    pub(crate) fn set(&mut self, value: f64) {
+        if self.updates == 0 || value < self.minimum {
+            self.minimum = value;
+        }
+        if self.updates == 0 || value > self.maximum {
+            self.maximum = value;
+        }
+        self.value = value;
+        self.updates += 1;
+    }
+}
+
+/// Aggregated per-histogram metrics updated each time a value is recorded in a custom
+/// histogram.
 ///
-///     // It is now possible to do something with the metrics collected by Goose.
-///     // For now, we'll just pretty-print the entire object.
-///     println!("{:#?}", goose_metrics);
+/// Stored in [`GooseMetrics::histograms`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HistogramMetricAggregate {
+    /// The name of the histogram.
+    pub name: String,
+    /// Per-value counters, tracking how often each value was recorded. Values are stored in
+    /// histogram buckets with the configured number of significant digits, the same as
+    /// request times.
+    pub values: BTreeMap<usize, usize>,
+    /// The smallest value recorded.
+    pub minimum: usize,
+    /// The largest value recorded.
+    pub maximum: usize,
+    /// The sum of all values recorded.
+    pub total: usize,
+    /// Total number of values recorded.
+    pub counter: usize,
+}
+impl HistogramMetricAggregate {
+    /// Create a new HistogramMetricAggregate.
+    pub(crate) fn new(name: &str) -> Self {
+        HistogramMetricAggregate {
+            name: name.to_string(),
+            values: BTreeMap::new(),
+            minimum: 0,
+            maximum: 0,
+            total: 0,
+            counter: 0,
+        }
+    }
+
+    /// Record a value in the histogram.
+    pub(crate) fn record(&mut self, value: u64, significant_digits: usize) {
+        let value = value as usize;
+        if self.counter == 0 || value < self.minimum {
+            self.minimum = value;
+        }
+        self.maximum = update_max_time(self.maximum, value);
+        self.total += value;
+        self.counter += 1;
+        record_histogram_time(&mut self.values, value, significant_digits);
+    }
+}
+
+/// The metrics collected each time a streaming response is read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamMetric {
+    /// How many milliseconds the load test has been running.
+    pub elapsed: u64,
+    /// The method of the request that opened the stream.
+    pub method: GooseMethod,
+    /// The name of the request that opened the stream.
+    pub name: String,
+    /// How many milliseconds passed before the first byte of the body was received, if any.
+    pub time_to_first_byte: Option<u64>,
+    /// How many milliseconds passed before the first event was received, if any.
+    pub time_to_first_event: Option<u64>,
+    /// How many events were received.
+    pub events: usize,
+    /// How many milliseconds passed between each event and the previous event.
+    pub time_between_events: Vec<u64>,
+    /// How many milliseconds passed between sending the request and closing the stream.
+    pub duration: u64,
+    /// Whether or not the stream was read successfully.
+    pub success: bool,
+    /// Which GooseUser thread read the stream.
+    pub user: usize,
+}
+
+impl StreamMetric {
+    /// Create a new StreamMetric metric.
+    pub(crate) fn new(elapsed: u64, method: GooseMethod, name: &str, user: usize) -> Self {
+        StreamMetric {
+            elapsed,
+            method,
+            name: name.to_string(),
+            time_to_first_byte: None,
+            time_to_first_event: None,
+            events: 0,
+            time_between_events: Vec::new(),
+            duration: 0,
+            success: true,
+            user,
+        }
+    }
+}
+
+/// Aggregated per-stream metrics updated each time a streaming response is read.
 ///
-///     /**
-///     // For example:
+/// [`StreamMetric`]s are sent by [`GooseUser`](../goose/struct.GooseUser.html)
+/// threads to the Goose parent process where they are aggregated together into this
+/// structure, and stored in [`GooseMetrics::streams`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StreamMetricAggregate {
+    /// The method of the request that opened the stream.
+    pub method: GooseMethod,
+    /// The name of the request that opened the stream.
+    pub name: String,
+    /// Total number of streams read.
+    pub counter: usize,
+    /// Total number of streams that failed.
+    pub fail_count: usize,
+    /// Total number of events received on all streams.
+    pub events: usize,
+    /// Time to the first byte of the body of each stream.
+    pub time_to_first_byte: GooseRequestMetricTimingData,
+    /// Time to the first event of each stream.
+    pub time_to_first_event: GooseRequestMetricTimingData,
+    /// Time between consecutive events on each stream.
+    pub time_between_events: GooseRequestMetricTimingData,
+    /// Total duration of each stream.
+    pub duration: GooseRequestMetricTimingData,
+}
+impl StreamMetricAggregate {
+    /// Create a new StreamMetricAggregate.
+    pub(crate) fn new(method: GooseMethod, name: &str) -> Self {
+        StreamMetricAggregate {
+            method,
+            name: name.to_string(),
+            counter: 0,
+            fail_count: 0,
+            events: 0,
+            time_to_first_byte: GooseRequestMetricTimingData::new(None),
+            time_to_first_event: GooseRequestMetricTimingData::new(None),
+            time_between_events: GooseRequestMetricTimingData::new(None),
+            duration: GooseRequestMetricTimingData::new(None),
+        }
+    }
+
+    /// Merge a [`StreamMetric`] into the aggregate, with times accurate to the given number
+    /// of significant digits.
+    pub(crate) fn record(&mut self, stream: &StreamMetric, significant_digits: usize) {
+        self.counter += 1;
+        if !stream.success {
+            self.fail_count += 1;
+        }
+        self.events += stream.events;
+        if let Some(time) = stream.time_to_first_byte {
+            self.time_to_first_byte
+                .record_time(time, significant_digits);
+        }
+        if let Some(time) = stream.time_to_first_event {
+            self.time_to_first_event
+                .record_time(time, significant_digits);
+        }
+        for time in &stream.time_between_events {
+            self.time_between_events
+                .record_time(*time, significant_digits);
+        }
+        self.duration
+            .record_time(stream.duration, significant_digits);
+    }
+}
+
+/// All metrics optionally collected during a Goose load test.
+///
+/// By default, Goose collects metrics during a load test in a `GooseMetrics` object
+/// that is returned by
+/// [`GooseAttack::execute()`](../struct.GooseAttack.html#method.execute) when a load
+/// test finishes.
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), GooseError> {
+///     let goose_metrics: GooseMetrics = GooseAttack::initialize()?
+///         .register_scenario(scenario!("ExampleUsers")
+///             .register_transaction(transaction!(example_transaction))
+///         )
+///         // Set a default host so the load test will start.
+///         .set_default(GooseDefault::Host, "http://localhost/")?
+///         // Set a default run time so this test runs to completion.
+///         .set_default(GooseDefault::RunTime, 1)?
+///         .execute()
+///         .await?;
+///
+///     // It is now possible to do something with the metrics collected by Goose.
+///     // For now, we'll just pretty-print the entire object.
+///     println!("{:#?}", goose_metrics);
+///
+///     /**
+///     // For example:
 ///     $ cargo run -- -H http://example.com -u1 -t1
 ///     GooseMetrics {
 ///         hash: 0,
@@ -972,6 +1549,15 @@ impl ScenarioMetricAggregate {
 ///         },
 ///         final_metrics: true,
 ///         display_status_codes: false,
+///         percentiles: [
+///             50.0,
+///             75.0,
+///             98.0,
+///             99.0,
+///             99.9,
+///             99.99,
+///         ],
+///         thresholds: [],
 ///         display_metrics: true,
 ///     }
 ///     **/
@@ -1020,6 +1606,31 @@ pub struct GooseMetrics {
     /// [GooseDefault::NoTransactionMetrics](../config/enum.GooseDefault.html#variant.NoTransactionMetrics) or
     /// [GooseDefault::NoMetrics](../config/enum.GooseDefault.html#variant.NoMetrics).
     pub scenarios: ScenarioMetrics,
+    /// Details about each custom timer that is stopped during the load test.
+    ///
+    /// Can be disabled with the `--no-metrics` run-time option, or with
+    /// [GooseDefault::NoMetrics](../config/enum.GooseDefault.html#variant.NoMetrics).
+    pub timers: TimerMetrics,
+    /// Details about each custom counter that is incremented during the load test.
+    ///
+    /// Can be disabled with the `--no-metrics` run-time option, or with
+    /// [GooseDefault::NoMetrics](../config/enum.GooseDefault.html#variant.NoMetrics).
+    pub counters: CounterMetrics,
+    /// Details about each custom gauge that is set during the load test.
+    ///
+    /// Can be disabled with the `--no-metrics` run-time option, or with
+    /// [GooseDefault::NoMetrics](../config/enum.GooseDefault.html#variant.NoMetrics).
+    pub gauges: GaugeMetrics,
+    /// Details about each custom histogram that values are recorded in during the load test.
+    ///
+    /// Can be disabled with the `--no-metrics` run-time option, or with
+    /// [GooseDefault::NoMetrics](../config/enum.GooseDefault.html#variant.NoMetrics).
+    pub histograms: HistogramMetrics,
+    /// Details about each streaming response that is read during the load test.
+    ///
+    /// Can be disabled with the `--no-metrics` run-time option, or with
+    /// [GooseDefault::NoMetrics](../config/enum.GooseDefault.html#variant.NoMetrics).
+    pub streams: StreamMetrics,
     /// Tracks and counts each time an error is detected during the load test.
     ///
     /// Can be disabled with either the `--no-error-summary` or `--no-metrics` run-time options,
@@ -1034,6 +1645,13 @@ pub struct GooseMetrics {
     pub(crate) final_metrics: bool,
     /// Flag indicating whether or not to display status_codes. Defaults to false.
     pub(crate) display_status_codes: bool,
+    /// The percentiles of response times to display, configured with `--percentiles`.
+    pub(crate) percentiles: Vec<f64>,
+    /// The result of checking each threshold against the final metrics.
+    ///
+    /// Thresholds are configured with the `--thresholds` run-time option, or with
+    /// [`GooseAttack::add_threshold`](../struct.GooseAttack.html#method.add_threshold).
+    pub thresholds: Vec<GooseThresholdResult>,
     /// Flag indicating whether or not to display metrics. This defaults to false on
     /// Workers, otherwise true.
     pub(crate) display_metrics: bool,
@@ -1250,6 +1868,162 @@ impl GooseMetrics {
             }
         }
 
+        // Request and response bodies are only displayed if any were sent or received.
+        if self
+            .requests
+            .values()
+            .any(|request| request.request_body_bytes > 0)
+        {
+            self.fmt_body_bytes(fmt, "Request body bytes sent:", |request| {
+                request.request_body_bytes
+            })?;
+        }
+        if self
+            .requests
+            .values()
+            .any(|request| request.response_body_bytes > 0)
+        {
+            self.fmt_body_bytes(fmt, "Response body bytes received:", |request| {
+                request.response_body_bytes
+            })?;
+        }
+
+        // The HTTP cache is only displayed if it was used.
+        if self
+            .requests
+            .values()
+            .any(|request| request.cache_hits > 0 || request.not_modified > 0)
+        {
+            self.fmt_cache(fmt)?;
+        }
+
+        Ok(())
+    }
+
+    /// Prepares a table of how often responses were loaded from the HTTP cache, or
+    /// revalidated with a `304 Not Modified` response.
+    ///
+    /// This function is invoked by `GooseMetrics::fmt_requests()`.
+    fn fmt_cache(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        writeln!(fmt, " HTTP cache:")?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>15} | {:>15} | {:>15}",
+            "Name", "Cache hits", "Not modified", "Hit rate"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        let mut aggregate_cache_hits = 0;
+        let mut aggregate_not_modified = 0;
+        let mut aggregate_total_count = 0;
+        for (request_key, request) in self.requests.iter().sorted() {
+            let total_count = request.success_count + request.fail_count;
+            writeln!(
+                fmt,
+                " {:<24} | {:>15} | {:>15} | {:>14.1}%",
+                util::truncate_string(request_key, 24),
+                request.cache_hits.to_formatted_string(&Locale::en),
+                request.not_modified.to_formatted_string(&Locale::en),
+                cache_hit_rate(request.cache_hits, total_count),
+            )?;
+            aggregate_cache_hits += request.cache_hits;
+            aggregate_not_modified += request.not_modified;
+            aggregate_total_count += total_count;
+        }
+        if self.requests.len() > 1 {
+            writeln!(
+                fmt,
+                " -------------------------+-----------------+-----------------+-----------------"
+            )?;
+            writeln!(
+                fmt,
+                " {:<24} | {:>15} | {:>15} | {:>14.1}%",
+                "Aggregated",
+                aggregate_cache_hits.to_formatted_string(&Locale::en),
+                aggregate_not_modified.to_formatted_string(&Locale::en),
+                cache_hit_rate(aggregate_cache_hits, aggregate_total_count),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Prepares a table of the total, average and per-second bytes in request or response
+    /// bodies, as returned by `bytes`.
+    ///
+    /// This function is invoked by `GooseMetrics::fmt_requests()`.
+    fn fmt_body_bytes(
+        &self,
+        fmt: &mut fmt::Formatter<'_>,
+        title: &str,
+        bytes: impl Fn(&GooseRequestMetricAggregate) -> u64,
+    ) -> fmt::Result {
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        writeln!(fmt, " {}", title)?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>15} | {:>15} | {:>15}",
+            "Name", "Total", "Average", "Per second"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        let mut aggregate_bytes = 0;
+        let mut aggregate_counter = 0;
+        for (request_key, request) in self.requests.iter().sorted() {
+            let total = bytes(request);
+            let (average, per_second) =
+                bytes_calculations(self.duration, total, request.raw_data.counter);
+            writeln!(
+                fmt,
+                " {:<24} | {:>15} | {:>15.avg_p$} | {:>15.per_second_p$}",
+                util::truncate_string(request_key, 24),
+                format_number(total as usize),
+                average,
+                per_second,
+                avg_p = determine_precision(average),
+                per_second_p = determine_precision(per_second),
+            )?;
+            aggregate_bytes += total;
+            aggregate_counter += request.raw_data.counter;
+        }
+        if self.requests.len() > 1 {
+            writeln!(
+                fmt,
+                " -------------------------+-----------------+-----------------+-----------------"
+            )?;
+            let (average, per_second) =
+                bytes_calculations(self.duration, aggregate_bytes, aggregate_counter);
+            writeln!(
+                fmt,
+                " {:<24} | {:>15} | {:>15.avg_p$} | {:>15.per_second_p$}",
+                "Aggregated",
+                format_number(aggregate_bytes as usize),
+                average,
+                per_second,
+                avg_p = determine_precision(average),
+                per_second_p = determine_precision(per_second),
+            )?;
+        }
+
         Ok(())
     }
 
@@ -1553,93 +2327,77 @@ impl GooseMetrics {
         Ok(())
     }
 
-    /// Optionally prepares a table of scenarios.
+    /// Optionally prepares a table of custom timers.
     ///
     /// This function is invoked by `GooseMetrics::print()` and
     /// `GooseMetrics::print_running()`.
-    pub(crate) fn fmt_scenarios(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    pub(crate) fn fmt_timers(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         // If there's nothing to display, exit immediately.
-        if self.scenarios.is_empty() || !self.display_metrics {
+        if self.timers.is_empty() || !self.display_metrics {
             return Ok(());
         }
 
-        // Display metrics from scenarios Vector
+        // Display metrics from timers BTreeMap
         writeln!(
             fmt,
-            "\n === PER SCENARIO METRICS ===\n ------------------------------------------------------------------------------"
+            "\n === PER TIMER METRICS ===\n ------------------------------------------------------------------------------"
         )?;
         writeln!(
             fmt,
-            " {:<24} | {:>8} | {:>12} | {:>11} | {:10}",
-            "Name", "# users", "# times run", "scenarios/s", "iterations"
+            " {:<24} | {:>13} | {:>14} | {:>8} | {:>7}",
+            "Name", "# times run", "# fails", "timer/s", "fail/s"
         )?;
         writeln!(
             fmt,
             " ------------------------------------------------------------------------------"
         )?;
-        let mut aggregate_users = 0;
-        let mut aggregate_counter = 0;
-        for scenario in &self.scenarios {
-            aggregate_users += scenario.users.len();
-            aggregate_counter += scenario.counter;
-            let (runs, _fails) = per_second_calculations(self.duration, scenario.counter, 0);
-            let runs_precision = determine_precision(runs);
-            let iterations = scenario.counter as f32 / scenario.users.len() as f32;
-            let iterations_precision = determine_precision(iterations);
+        let mut aggregate_fail_count = 0;
+        let mut aggregate_total_count = 0;
+        for timer in self.timers.values() {
+            let total_count = timer.success_count + timer.fail_count;
             writeln!(
                 fmt,
-                " {:24 } | {:>8} | {:>12} | {:>11.runs_p$} | {:>10.iterations_p$}",
-                util::truncate_string(&format!("{}: {}", scenario.index + 1, &scenario.name,), 24),
-                scenario.users.len(),
-                scenario.counter,
-                runs,
-                iterations,
-                runs_p = runs_precision,
-                iterations_p = iterations_precision,
+                "{}",
+                format_timer_counts(&timer.name, self.duration, total_count, timer.fail_count)
             )?;
+            aggregate_total_count += total_count;
+            aggregate_fail_count += timer.fail_count;
         }
-        // If more than 1 scenario is defined, also display aggregated metrics.
-        if self.scenarios.len() > 1 {
-            let (aggregate_runs, _fails) =
-                per_second_calculations(self.duration, aggregate_counter, 0);
-            let aggregate_runs_precision = determine_precision(aggregate_runs);
-            let aggregate_iterations = aggregate_counter as f32 / aggregate_users as f32;
-            let aggregate_iterations_precision = determine_precision(aggregate_iterations);
+        if self.timers.len() > 1 {
             writeln!(
                 fmt,
-                " -------------------------+----------+--------------+-------------+------------"
+                " -------------------------+---------------+----------------+----------+--------"
             )?;
             writeln!(
                 fmt,
-                " {:24 } | {:>8} | {:>12} | {:>11.runs_p$} | {:>10.iterations_p$}",
-                "Aggregated",
-                aggregate_users,
-                aggregate_counter,
-                aggregate_runs,
-                aggregate_iterations,
-                runs_p = aggregate_runs_precision,
-                iterations_p = aggregate_iterations_precision,
+                "{}",
+                format_timer_counts(
+                    "Aggregated",
+                    self.duration,
+                    aggregate_total_count,
+                    aggregate_fail_count
+                )
             )?;
         }
 
         Ok(())
     }
 
-    /// Optionally prepares a table of scenario times.
+    /// Optionally prepares a table of custom timer times.
     ///
     /// This function is invoked by `GooseMetrics::print()` and
     /// `GooseMetrics::print_running()`.
-    pub(crate) fn fmt_scenario_times(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    pub(crate) fn fmt_timer_times(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         // If there's nothing to display, exit immediately.
-        if self.scenarios.is_empty() || !self.display_metrics {
+        if self.timers.is_empty() || !self.display_metrics {
             return Ok(());
         }
 
-        let mut aggregate_scenario_times: BTreeMap<usize, usize> = BTreeMap::new();
-        let mut aggregate_total_scenario_time: usize = 0;
-        let mut aggregate_scenario_time_counter: usize = 0;
-        let mut aggregate_min_scenario_time: usize = 0;
-        let mut aggregate_max_scenario_time: usize = 0;
+        let mut aggregate_timer_times: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut aggregate_total_timer_time: usize = 0;
+        let mut aggregate_timer_time_counter: usize = 0;
+        let mut aggregate_min_timer_time: usize = 0;
+        let mut aggregate_max_timer_time: usize = 0;
         writeln!(
             fmt,
             " ------------------------------------------------------------------------------"
@@ -1653,51 +2411,40 @@ impl GooseMetrics {
             fmt,
             " ------------------------------------------------------------------------------"
         )?;
-        for scenario in &self.scenarios {
-            // Iterate over user transaction times, and merge into global transaction times.
-            aggregate_scenario_times =
-                merge_times(aggregate_scenario_times, scenario.times.clone());
-
-            // Increment total scenario time counter.
-            aggregate_total_scenario_time += &scenario.total_time;
-
-            // Increment counter tracking individual scenario times seen.
-            aggregate_scenario_time_counter += &scenario.counter;
-
-            // If user had new fastest scenario time, update global fastest scenario time.
-            aggregate_min_scenario_time =
-                update_min_time(aggregate_min_scenario_time, scenario.min_time);
-
-            // If user had new slowest scenario time, update global slowest scenario time.
-            aggregate_max_scenario_time =
-                update_max_time(aggregate_max_scenario_time, scenario.max_time);
-
-            let average = match scenario.counter {
+        for timer in self.timers.values() {
+            // Iterate over timer times, and merge into global timer times.
+            aggregate_timer_times = merge_times(aggregate_timer_times, timer.times.clone());
+            aggregate_total_timer_time += &timer.total_time;
+            aggregate_timer_time_counter += &timer.counter;
+            aggregate_min_timer_time = update_min_time(aggregate_min_timer_time, timer.min_time);
+            aggregate_max_timer_time = update_max_time(aggregate_max_timer_time, timer.max_time);
+
+            let average = match timer.counter {
                 0 => 0.00,
-                _ => scenario.total_time as f32 / scenario.counter as f32,
+                _ => timer.total_time as f32 / timer.counter as f32,
             };
             let average_precision = determine_precision(average);
 
             writeln!(
                 fmt,
                 " {:<24} | {:>11.avg_precision$} | {:>10} | {:>11} | {:>10}",
-                util::truncate_string(&format!("  {}: {}", scenario.index + 1, scenario.name), 24),
+                util::truncate_string(&timer.name, 24),
                 average,
-                format_number(scenario.min_time),
-                format_number(scenario.max_time),
+                format_number(timer.min_time),
+                format_number(timer.max_time),
                 format_number(util::median(
-                    &scenario.times,
-                    scenario.counter,
-                    scenario.min_time,
-                    scenario.max_time
+                    &timer.times,
+                    timer.counter,
+                    timer.min_time,
+                    timer.max_time
                 )),
                 avg_precision = average_precision,
             )?;
         }
-        if self.scenarios.len() > 1 {
-            let average = match aggregate_scenario_time_counter {
+        if self.timers.len() > 1 {
+            let average = match aggregate_timer_time_counter {
                 0 => 0.00,
-                _ => aggregate_total_scenario_time as f32 / aggregate_scenario_time_counter as f32,
+                _ => aggregate_total_timer_time as f32 / aggregate_timer_time_counter as f32,
             };
             let average_precision = determine_precision(average);
 
@@ -1710,13 +2457,13 @@ impl GooseMetrics {
                 " {:<24} | {:>11.avg_precision$} | {:>10} | {:>11} | {:>10}",
                 "Aggregated",
                 average,
-                format_number(aggregate_min_scenario_time),
-                format_number(aggregate_max_scenario_time),
+                format_number(aggregate_min_timer_time),
+                format_number(aggregate_max_timer_time),
                 format_number(util::median(
-                    &aggregate_scenario_times,
-                    aggregate_scenario_time_counter,
-                    aggregate_min_scenario_time,
-                    aggregate_max_scenario_time
+                    &aggregate_timer_times,
+                    aggregate_timer_time_counter,
+                    aggregate_min_timer_time,
+                    aggregate_max_timer_time
                 )),
                 avg_precision = average_precision,
             )?;
@@ -1725,35 +2472,401 @@ impl GooseMetrics {
         Ok(())
     }
 
-    /// Optionally prepares a table of response times.
+    /// Optionally prepares a table of custom counters.
     ///
     /// This function is invoked by `GooseMetrics::print()` and
     /// `GooseMetrics::print_running()`.
-    pub(crate) fn fmt_response_times(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    pub(crate) fn fmt_counters(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         // If there's nothing to display, exit immediately.
-        if self.requests.is_empty() {
+        if self.counters.is_empty() || !self.display_metrics {
             return Ok(());
         }
 
-        let mut aggregate_raw_times: BTreeMap<usize, usize> = BTreeMap::new();
-        let mut aggregate_raw_total_time: usize = 0;
-        let mut aggregate_raw_counter: usize = 0;
-        let mut aggregate_raw_min_time: usize = 0;
-        let mut aggregate_raw_max_time: usize = 0;
         writeln!(
             fmt,
-            " ------------------------------------------------------------------------------"
+            "\n === PER COUNTER METRICS ===\n ------------------------------------------------------------------------------"
         )?;
         writeln!(
             fmt,
-            " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
-            "Name", "Avg (ms)", "Min", "Max", "Median"
+            " {:<24} | {:>16} | {:>14} | {:>14}",
+            "Name", "Total", "# updates", "total/s"
         )?;
         writeln!(
             fmt,
             " ------------------------------------------------------------------------------"
         )?;
-
+        for counter in self.counters.values() {
+            let per_second = match self.duration {
+                0 => 0.0,
+                _ => counter.total as f32 / self.duration as f32,
+            };
+            writeln!(
+                fmt,
+                " {:<24} | {:>16} | {:>14} | {:>14.2}",
+                util::truncate_string(&counter.name, 24),
+                counter.total.to_formatted_string(&Locale::en),
+                format_number(counter.updates),
+                per_second,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of custom gauges.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_gauges(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if self.gauges.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === PER GAUGE METRICS ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>10} | {:>10} | {:>10} | {:>11}",
+            "Name", "Last", "Min", "Max", "# updates"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for gauge in self.gauges.values() {
+            writeln!(
+                fmt,
+                " {:<24} | {:>10.2} | {:>10.2} | {:>10.2} | {:>11}",
+                util::truncate_string(&gauge.name, 24),
+                gauge.value,
+                gauge.minimum,
+                gauge.maximum,
+                format_number(gauge.updates),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of custom histograms.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_histograms(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if self.histograms.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === PER HISTOGRAM METRICS ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>8} | {:>9} | {:>8} | {:>8} | {:>7}",
+            "Name", "#", "Avg", "Min", "Max", "Median"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for histogram in self.histograms.values() {
+            let average = match histogram.counter {
+                0 => 0.00,
+                _ => histogram.total as f32 / histogram.counter as f32,
+            };
+            let average_precision = determine_precision(average);
+
+            writeln!(
+                fmt,
+                " {:<24} | {:>8} | {:>9.avg_precision$} | {:>8} | {:>8} | {:>7}",
+                util::truncate_string(&histogram.name, 24),
+                format_number(histogram.counter),
+                average,
+                format_number(histogram.minimum),
+                format_number(histogram.maximum),
+                format_number(util::median(
+                    &histogram.values,
+                    histogram.counter,
+                    histogram.minimum,
+                    histogram.maximum
+                )),
+                avg_precision = average_precision,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares tables of streaming responses.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_streams(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if self.streams.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === PER STREAM METRICS ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>13} | {:>14} | {:>8} | {:>7}",
+            "Name", "# streams", "# fails", "stream/s", "fail/s"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for (name, stream) in &self.streams {
+            writeln!(
+                fmt,
+                "{}",
+                format_timer_counts(name, self.duration, stream.counter, stream.fail_count)
+            )?;
+        }
+
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------\n Average stream times (in ms):\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>7} | {:>8} | {:>8} | {:>8} | {:>7}",
+            "Name", "Events", "TTFB", "1st evt", "Between", "Total"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for (name, stream) in &self.streams {
+            let events = match stream.counter {
+                0 => 0.00,
+                _ => stream.events as f32 / stream.counter as f32,
+            };
+            writeln!(
+                fmt,
+                " {:<24} | {:>7.2} | {:>8.2} | {:>8.2} | {:>8.2} | {:>7}",
+                util::truncate_string(name, 24),
+                events,
+                average_time(&stream.time_to_first_byte),
+                average_time(&stream.time_to_first_event),
+                average_time(&stream.time_between_events),
+                format_number(average_time(&stream.duration).round() as usize),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of scenarios.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_scenarios(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if self.scenarios.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        // Display metrics from scenarios Vector
+        writeln!(
+            fmt,
+            "\n === PER SCENARIO METRICS ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>8} | {:>12} | {:>11} | {:10}",
+            "Name", "# users", "# times run", "scenarios/s", "iterations"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        let mut aggregate_users = 0;
+        let mut aggregate_counter = 0;
+        for scenario in &self.scenarios {
+            aggregate_users += scenario.users.len();
+            aggregate_counter += scenario.counter;
+            let (runs, _fails) = per_second_calculations(self.duration, scenario.counter, 0);
+            let runs_precision = determine_precision(runs);
+            let iterations = scenario.counter as f32 / scenario.users.len() as f32;
+            let iterations_precision = determine_precision(iterations);
+            writeln!(
+                fmt,
+                " {:24 } | {:>8} | {:>12} | {:>11.runs_p$} | {:>10.iterations_p$}",
+                util::truncate_string(&format!("{}: {}", scenario.index + 1, &scenario.name,), 24),
+                scenario.users.len(),
+                scenario.counter,
+                runs,
+                iterations,
+                runs_p = runs_precision,
+                iterations_p = iterations_precision,
+            )?;
+        }
+        // If more than 1 scenario is defined, also display aggregated metrics.
+        if self.scenarios.len() > 1 {
+            let (aggregate_runs, _fails) =
+                per_second_calculations(self.duration, aggregate_counter, 0);
+            let aggregate_runs_precision = determine_precision(aggregate_runs);
+            let aggregate_iterations = aggregate_counter as f32 / aggregate_users as f32;
+            let aggregate_iterations_precision = determine_precision(aggregate_iterations);
+            writeln!(
+                fmt,
+                " -------------------------+----------+--------------+-------------+------------"
+            )?;
+            writeln!(
+                fmt,
+                " {:24 } | {:>8} | {:>12} | {:>11.runs_p$} | {:>10.iterations_p$}",
+                "Aggregated",
+                aggregate_users,
+                aggregate_counter,
+                aggregate_runs,
+                aggregate_iterations,
+                runs_p = aggregate_runs_precision,
+                iterations_p = aggregate_iterations_precision,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of scenario times.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_scenario_times(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if self.scenarios.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        let mut aggregate_scenario_times: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut aggregate_total_scenario_time: usize = 0;
+        let mut aggregate_scenario_time_counter: usize = 0;
+        let mut aggregate_min_scenario_time: usize = 0;
+        let mut aggregate_max_scenario_time: usize = 0;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
+            "Name", "Avg (ms)", "Min", "Max", "Median"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for scenario in &self.scenarios {
+            // Iterate over user transaction times, and merge into global transaction times.
+            aggregate_scenario_times =
+                merge_times(aggregate_scenario_times, scenario.times.clone());
+
+            // Increment total scenario time counter.
+            aggregate_total_scenario_time += &scenario.total_time;
+
+            // Increment counter tracking individual scenario times seen.
+            aggregate_scenario_time_counter += &scenario.counter;
+
+            // If user had new fastest scenario time, update global fastest scenario time.
+            aggregate_min_scenario_time =
+                update_min_time(aggregate_min_scenario_time, scenario.min_time);
+
+            // If user had new slowest scenario time, update global slowest scenario time.
+            aggregate_max_scenario_time =
+                update_max_time(aggregate_max_scenario_time, scenario.max_time);
+
+            let average = match scenario.counter {
+                0 => 0.00,
+                _ => scenario.total_time as f32 / scenario.counter as f32,
+            };
+            let average_precision = determine_precision(average);
+
+            writeln!(
+                fmt,
+                " {:<24} | {:>11.avg_precision$} | {:>10} | {:>11} | {:>10}",
+                util::truncate_string(&format!("  {}: {}", scenario.index + 1, scenario.name), 24),
+                average,
+                format_number(scenario.min_time),
+                format_number(scenario.max_time),
+                format_number(util::median(
+                    &scenario.times,
+                    scenario.counter,
+                    scenario.min_time,
+                    scenario.max_time
+                )),
+                avg_precision = average_precision,
+            )?;
+        }
+        if self.scenarios.len() > 1 {
+            let average = match aggregate_scenario_time_counter {
+                0 => 0.00,
+                _ => aggregate_total_scenario_time as f32 / aggregate_scenario_time_counter as f32,
+            };
+            let average_precision = determine_precision(average);
+
+            writeln!(
+                fmt,
+                " -------------------------+-------------+------------+-------------+-----------"
+            )?;
+            writeln!(
+                fmt,
+                " {:<24} | {:>11.avg_precision$} | {:>10} | {:>11} | {:>10}",
+                "Aggregated",
+                average,
+                format_number(aggregate_min_scenario_time),
+                format_number(aggregate_max_scenario_time),
+                format_number(util::median(
+                    &aggregate_scenario_times,
+                    aggregate_scenario_time_counter,
+                    aggregate_min_scenario_time,
+                    aggregate_max_scenario_time
+                )),
+                avg_precision = average_precision,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of response times.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_response_times(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if self.requests.is_empty() {
+            return Ok(());
+        }
+
+        let mut aggregate_raw_times: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut aggregate_raw_total_time: usize = 0;
+        let mut aggregate_raw_counter: usize = 0;
+        let mut aggregate_raw_min_time: usize = 0;
+        let mut aggregate_raw_max_time: usize = 0;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
+            "Name", "Avg (ms)", "Min", "Max", "Median"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+
         // First display the raw data, as it always exists.
         let mut co_data = false;
         for (request_key, request) in self.requests.iter().sorted() {
@@ -1959,41 +3072,24 @@ impl GooseMetrics {
         Ok(())
     }
 
-    /// Optionally prepares a table of slowest response times within several percentiles.
+    /// Optionally prepares a table of slowest response times within the configured
+    /// percentiles.
     ///
     /// This function is invoked by `GooseMetrics::print()` and
     /// `GooseMetrics::print_running()`.
     pub(crate) fn fmt_percentiles(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Only include percentiles when displaying the final metrics report.
-        if !self.final_metrics {
+        if !self.final_metrics || self.requests.is_empty() {
             return Ok(());
         }
 
         let mut raw_aggregate_response_times: BTreeMap<usize, usize> = BTreeMap::new();
-        let mut raw_aggregate_total_response_time: usize = 0;
         let mut raw_aggregate_response_time_counter: usize = 0;
         let mut raw_aggregate_min_response_time: usize = 0;
         let mut raw_aggregate_max_response_time: usize = 0;
-        writeln!(
-            fmt,
-            " ------------------------------------------------------------------------------"
-        )?;
-        writeln!(
+        self.fmt_percentiles_header(
             fmt,
-            " Slowest page load within specified percentile of requests (in ms):"
-        )?;
-        writeln!(
-            fmt,
-            " ------------------------------------------------------------------------------"
-        )?;
-        writeln!(
-            fmt,
-            " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
-            "Name", "50%", "75%", "98%", "99%", "99.9%", "99.99%"
-        )?;
-        writeln!(
-            fmt,
-            " ------------------------------------------------------------------------------"
+            "Slowest page load within specified percentile of requests (in ms):",
         )?;
         // Track whether or not Coordinated Omission Mitigation kicked in.
         let mut co_data = false;
@@ -2006,9 +3102,6 @@ impl GooseMetrics {
             raw_aggregate_response_times =
                 merge_times(raw_aggregate_response_times, request.raw_data.times.clone());
 
-            // Increment total response time counter.
-            raw_aggregate_total_response_time += &request.raw_data.total_time;
-
             // Increment counter tracking individual response times seen.
             raw_aggregate_response_time_counter += &request.raw_data.counter;
 
@@ -2023,105 +3116,28 @@ impl GooseMetrics {
                 raw_aggregate_max_response_time,
                 request.raw_data.maximum_time,
             );
-            // Sort response times so we can calculate a mean.
-            writeln!(
+
+            self.fmt_percentiles_row(
                 fmt,
-                " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
-                util::truncate_string(request_key, 24),
-                calculate_response_time_percentile(
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                    0.5
-                ),
-                calculate_response_time_percentile(
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                    0.75
-                ),
-                calculate_response_time_percentile(
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                    0.98
-                ),
-                calculate_response_time_percentile(
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                    0.99
-                ),
-                calculate_response_time_percentile(
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                    0.999
-                ),
-                calculate_response_time_percentile(
+                request_key,
+                self.calculate_percentiles(
                     &request.raw_data.times,
                     request.raw_data.counter,
                     request.raw_data.minimum_time,
                     request.raw_data.maximum_time,
-                    0.9999
                 ),
             )?;
         }
         if self.requests.len() > 1 {
-            writeln!(
+            self.fmt_percentiles_separator(fmt)?;
+            self.fmt_percentiles_row(
                 fmt,
-                " -------------------------+--------+--------+--------+--------+--------+-------"
-            )?;
-            writeln!(
-                fmt,
-                " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
                 "Aggregated",
-                calculate_response_time_percentile(
-                    &raw_aggregate_response_times,
-                    raw_aggregate_response_time_counter,
-                    raw_aggregate_min_response_time,
-                    raw_aggregate_max_response_time,
-                    0.5
-                ),
-                calculate_response_time_percentile(
-                    &raw_aggregate_response_times,
-                    raw_aggregate_response_time_counter,
-                    raw_aggregate_min_response_time,
-                    raw_aggregate_max_response_time,
-                    0.75
-                ),
-                calculate_response_time_percentile(
+                self.calculate_percentiles(
                     &raw_aggregate_response_times,
                     raw_aggregate_response_time_counter,
                     raw_aggregate_min_response_time,
                     raw_aggregate_max_response_time,
-                    0.98
-                ),
-                calculate_response_time_percentile(
-                    &raw_aggregate_response_times,
-                    raw_aggregate_response_time_counter,
-                    raw_aggregate_min_response_time,
-                    raw_aggregate_max_response_time,
-                    0.99
-                ),
-                calculate_response_time_percentile(
-                    &raw_aggregate_response_times,
-                    raw_aggregate_response_time_counter,
-                    raw_aggregate_min_response_time,
-                    raw_aggregate_max_response_time,
-                    0.999
-                ),
-                calculate_response_time_percentile(
-                    &raw_aggregate_response_times,
-                    raw_aggregate_response_time_counter,
-                    raw_aggregate_min_response_time,
-                    raw_aggregate_max_response_time,
-                    0.9999
                 ),
             )?;
         }
@@ -2132,29 +3148,11 @@ impl GooseMetrics {
         }
 
         let mut co_aggregate_response_times: BTreeMap<usize, usize> = BTreeMap::new();
-        let mut co_aggregate_total_response_time: usize = 0;
         let mut co_aggregate_response_time_counter: usize = 0;
         let mut co_aggregate_min_response_time: usize = 0;
         let mut co_aggregate_max_response_time: usize = 0;
 
-        writeln!(
-            fmt,
-            " ------------------------------------------------------------------------------"
-        )?;
-        writeln!(fmt, " Adjusted for Coordinated Omission:")?;
-        writeln!(
-            fmt,
-            " ------------------------------------------------------------------------------"
-        )?;
-        writeln!(
-            fmt,
-            " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
-            "Name", "50%", "75%", "98%", "99%", "99.9%", "99.99%"
-        )?;
-        writeln!(
-            fmt,
-            " ------------------------------------------------------------------------------"
-        )?;
+        self.fmt_percentiles_header(fmt, "Adjusted for Coordinated Omission:")?;
         for (request_key, request) in self.requests.iter().sorted() {
             if let Some(coordinated_omission_data) = request.coordinated_omission_data.as_ref() {
                 // Iterate over user response times, and merge into global response times.
@@ -2163,9 +3161,6 @@ impl GooseMetrics {
                     coordinated_omission_data.times.clone(),
                 );
 
-                // Increment total response time counter.
-                co_aggregate_total_response_time += &coordinated_omission_data.total_time;
-
                 // Increment counter tracking individual response times seen.
                 co_aggregate_response_time_counter += &coordinated_omission_data.counter;
 
@@ -2181,118 +3176,186 @@ impl GooseMetrics {
                     coordinated_omission_data.maximum_time,
                 );
 
-                // Sort response times so we can calculate a mean.
-                writeln!(
+                self.fmt_percentiles_row(
                     fmt,
-                    " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
-                    util::truncate_string(request_key, 24),
-                    calculate_response_time_percentile(
-                        &coordinated_omission_data.times,
-                        coordinated_omission_data.counter,
-                        coordinated_omission_data.minimum_time,
-                        coordinated_omission_data.maximum_time,
-                        0.5
-                    ),
-                    calculate_response_time_percentile(
-                        &coordinated_omission_data.times,
-                        coordinated_omission_data.counter,
-                        coordinated_omission_data.minimum_time,
-                        coordinated_omission_data.maximum_time,
-                        0.75
-                    ),
-                    calculate_response_time_percentile(
-                        &coordinated_omission_data.times,
-                        coordinated_omission_data.counter,
-                        coordinated_omission_data.minimum_time,
-                        coordinated_omission_data.maximum_time,
-                        0.98
-                    ),
-                    calculate_response_time_percentile(
-                        &coordinated_omission_data.times,
-                        coordinated_omission_data.counter,
-                        coordinated_omission_data.minimum_time,
-                        coordinated_omission_data.maximum_time,
-                        0.99
-                    ),
-                    calculate_response_time_percentile(
-                        &coordinated_omission_data.times,
-                        coordinated_omission_data.counter,
-                        coordinated_omission_data.minimum_time,
-                        coordinated_omission_data.maximum_time,
-                        0.999
-                    ),
-                    calculate_response_time_percentile(
+                    request_key,
+                    self.calculate_percentiles(
                         &coordinated_omission_data.times,
                         coordinated_omission_data.counter,
                         coordinated_omission_data.minimum_time,
                         coordinated_omission_data.maximum_time,
-                        0.9999
                     ),
                 )?;
             } else {
-                writeln!(
+                self.fmt_percentiles_row(
                     fmt,
-                    " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
-                    util::truncate_string(request_key, 24),
-                    "-",
-                    "-",
-                    "-",
-                    "-",
-                    "-",
-                    "-"
+                    request_key,
+                    vec!["-".to_string(); self.percentiles.len()],
+                )?;
+            }
+        }
+        if self.requests.len() > 1 {
+            self.fmt_percentiles_separator(fmt)?;
+            self.fmt_percentiles_row(
+                fmt,
+                "Aggregated",
+                self.calculate_percentiles(
+                    &co_aggregate_response_times,
+                    co_aggregate_response_time_counter,
+                    co_aggregate_min_response_time,
+                    co_aggregate_max_response_time,
+                ),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of slowest transaction times within the configured
+    /// percentiles.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_transaction_percentiles(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only include percentiles when displaying the final metrics report.
+        if !self.final_metrics || self.transactions.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        let mut aggregate_transaction_times: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut aggregate_transaction_time_counter: usize = 0;
+        let mut aggregate_min_transaction_time: usize = 0;
+        let mut aggregate_max_transaction_time: usize = 0;
+        self.fmt_percentiles_header(
+            fmt,
+            "Slowest transaction within specified percentile of transactions (in ms):",
+        )?;
+        let mut transaction_count = 0;
+        for scenario in &self.transactions {
+            let mut displayed_scenario = false;
+            for transaction in scenario {
+                transaction_count += 1;
+                // First time through display name of scenario.
+                if !displayed_scenario {
+                    writeln!(
+                        fmt,
+                        " {:24}",
+                        util::truncate_string(
+                            &format!(
+                                "{}: {}",
+                                transaction.scenario_index + 1,
+                                &transaction.scenario_name
+                            ),
+                            60
+                        ),
+                    )?;
+                    displayed_scenario = true;
+                }
+
+                // Iterate over user transaction times, and merge into global transaction times.
+                aggregate_transaction_times =
+                    merge_times(aggregate_transaction_times, transaction.times.clone());
+
+                // Increment counter tracking individual transaction times seen.
+                aggregate_transaction_time_counter += &transaction.counter;
+
+                // If user had new fastest transaction time, update global fastest transaction time.
+                aggregate_min_transaction_time =
+                    update_min_time(aggregate_min_transaction_time, transaction.min_time);
+
+                // If user had new slowest transaction time, update global slowest transaction time.
+                aggregate_max_transaction_time =
+                    update_max_time(aggregate_max_transaction_time, transaction.max_time);
+
+                self.fmt_percentiles_row(
+                    fmt,
+                    &format!(
+                        "  {}: {}",
+                        transaction.transaction_index + 1,
+                        transaction.transaction_name
+                    ),
+                    self.calculate_percentiles(
+                        &transaction.times,
+                        transaction.counter,
+                        transaction.min_time,
+                        transaction.max_time,
+                    ),
                 )?;
             }
         }
-        if self.requests.len() > 1 {
-            writeln!(
+        if transaction_count > 1 {
+            self.fmt_percentiles_separator(fmt)?;
+            self.fmt_percentiles_row(
+                fmt,
+                "Aggregated",
+                self.calculate_percentiles(
+                    &aggregate_transaction_times,
+                    aggregate_transaction_time_counter,
+                    aggregate_min_transaction_time,
+                    aggregate_max_transaction_time,
+                ),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of slowest scenario times within the configured
+    /// percentiles.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_scenario_percentiles(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only include percentiles when displaying the final metrics report.
+        if !self.final_metrics || self.scenarios.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        let mut aggregate_scenario_times: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut aggregate_scenario_time_counter: usize = 0;
+        let mut aggregate_min_scenario_time: usize = 0;
+        let mut aggregate_max_scenario_time: usize = 0;
+        self.fmt_percentiles_header(
+            fmt,
+            "Slowest scenario within specified percentile of scenarios (in ms):",
+        )?;
+        for scenario in &self.scenarios {
+            // Iterate over user scenario times, and merge into global scenario times.
+            aggregate_scenario_times =
+                merge_times(aggregate_scenario_times, scenario.times.clone());
+
+            // Increment counter tracking individual scenario times seen.
+            aggregate_scenario_time_counter += &scenario.counter;
+
+            // If user had new fastest scenario time, update global fastest scenario time.
+            aggregate_min_scenario_time =
+                update_min_time(aggregate_min_scenario_time, scenario.min_time);
+
+            // If user had new slowest scenario time, update global slowest scenario time.
+            aggregate_max_scenario_time =
+                update_max_time(aggregate_max_scenario_time, scenario.max_time);
+
+            self.fmt_percentiles_row(
                 fmt,
-                " -------------------------+--------+--------+--------+--------+--------+-------"
+                &format!("  {}: {}", scenario.index + 1, scenario.name),
+                self.calculate_percentiles(
+                    &scenario.times,
+                    scenario.counter,
+                    scenario.min_time,
+                    scenario.max_time,
+                ),
             )?;
-            writeln!(
+        }
+        if self.scenarios.len() > 1 {
+            self.fmt_percentiles_separator(fmt)?;
+            self.fmt_percentiles_row(
                 fmt,
-                " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
                 "Aggregated",
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.5
-                ),
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.75
-                ),
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.98
-                ),
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.99
-                ),
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.999
-                ),
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.9999
+                self.calculate_percentiles(
+                    &aggregate_scenario_times,
+                    aggregate_scenario_time_counter,
+                    aggregate_min_scenario_time,
+                    aggregate_max_scenario_time,
                 ),
             )?;
         }
@@ -2300,6 +3363,76 @@ impl GooseMetrics {
         Ok(())
     }
 
+    /// Calculates the slowest time within each of the configured percentiles, formatted
+    /// for display.
+    fn calculate_percentiles(
+        &self,
+        times: &BTreeMap<usize, usize>,
+        counter: usize,
+        min: usize,
+        max: usize,
+    ) -> Vec<String> {
+        self.percentiles
+            .iter()
+            .map(|percentile| {
+                calculate_response_time_percentile(
+                    times,
+                    counter,
+                    min,
+                    max,
+                    (percentile / 100.0) as f32,
+                )
+            })
+            .collect()
+    }
+
+    /// Writes the title and column headers of a table of percentiles.
+    fn fmt_percentiles_header(&self, fmt: &mut fmt::Formatter<'_>, title: &str) -> fmt::Result {
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        writeln!(fmt, " {}", title)?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        let headers = self
+            .percentiles
+            .iter()
+            .map(|percentile| format!("{:>6}", format!("{}%", percentile)))
+            .join(" | ");
+        writeln!(fmt, " {:<24} | {}", "Name", headers)?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )
+    }
+
+    /// Writes a single row of a table of percentiles.
+    fn fmt_percentiles_row(
+        &self,
+        fmt: &mut fmt::Formatter<'_>,
+        name: &str,
+        percentiles: Vec<String>,
+    ) -> fmt::Result {
+        let values = percentiles
+            .iter()
+            .map(|value| format!("{:>6}", value))
+            .join(" | ");
+        writeln!(fmt, " {:<24} | {}", util::truncate_string(name, 24), values)
+    }
+
+    /// Writes the line separating the aggregated row from the rest of a table of
+    /// percentiles.
+    fn fmt_percentiles_separator(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = format!(" {}", "-".repeat(25));
+        separator.push_str(&"+--------".repeat(self.percentiles.len()));
+        // Keep the default table the same width as the other tables.
+        separator.pop();
+        writeln!(fmt, "{}", separator)
+    }
+
     /// Optionally prepares a table of response status codes.
     ///
     /// This function is invoked by `GooseMetrics::print()` and
@@ -2386,6 +3519,63 @@ impl GooseMetrics {
         Ok(())
     }
 
+    /// Optionally prepares a table of thresholds and whether or not they were met.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_thresholds(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only include thresholds when displaying the final metrics report, and if there
+        // are thresholds to display.
+        if !self.final_metrics || self.thresholds.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === THRESHOLDS ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<50} | {:>13} | {:>7}",
+            "Threshold", "Value", "Result"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for result in &self.thresholds {
+            writeln!(
+                fmt,
+                " {:<50} | {:>13} | {:>7}",
+                util::truncate_string(&result.threshold.definition, 50),
+                result.formatted_value(),
+                result.formatted_result(),
+            )?;
+        }
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+
+        Ok(())
+    }
+
+    /// Check each threshold against the final metrics, storing the results.
+    pub(crate) fn check_thresholds(&mut self, thresholds: &[GooseThreshold]) {
+        self.thresholds = thresholds
+            .iter()
+            .map(|threshold| {
+                let value = threshold.measure(self);
+                GooseThresholdResult {
+                    threshold: threshold.clone(),
+                    value,
+                    // A target that wasn't seen during the load test fails its thresholds.
+                    passed: value.is_some_and(|value| threshold.check(value)),
+                }
+            })
+            .collect();
+    }
+
     // Determine the seconds, minutes and hours between two chrono:DateTimes.
     fn get_seconds_minutes_hours(
         &self,
@@ -2483,6 +3673,9 @@ impl GooseMetrics {
                         step[1].users,
                         step[0].users,
                     )?;
+                    if let Some(reason) = &step[0].reason {
+                        writeln!(fmt, " {:<12} {}", "Aborted:", reason)?;
+                    }
                 }
                 TestPlanStepAction::Finished => {
                     unreachable!("there shouldn't be a step after finished");
@@ -2530,21 +3723,103 @@ impl Serialize for GooseMetrics {
     where
         S: Serializer,
     {
-        let mut s = serializer.serialize_struct("GooseMetrics", 10)?;
+        let mut s = serializer.serialize_struct("GooseMetrics", 17)?;
         s.serialize_field("hash", &self.hash)?;
         s.serialize_field("duration", &self.duration)?;
         s.serialize_field("maximum_users", &self.maximum_users)?;
         s.serialize_field("total_users", &self.total_users)?;
         s.serialize_field("requests", &self.requests)?;
         s.serialize_field("transactions", &self.transactions)?;
+        s.serialize_field("timers", &self.timers)?;
+        s.serialize_field("counters", &self.counters)?;
+        s.serialize_field("gauges", &self.gauges)?;
+        s.serialize_field("histograms", &self.histograms)?;
+        s.serialize_field("streams", &self.streams)?;
         s.serialize_field("errors", &self.errors)?;
         s.serialize_field("final_metrics", &self.final_metrics)?;
         s.serialize_field("display_status_codes", &self.display_status_codes)?;
         s.serialize_field("display_metrics", &self.display_metrics)?;
+        s.serialize_field("percentiles", &GoosePercentiles::new(self))?;
+        s.serialize_field("thresholds", &self.thresholds)?;
         s.end()
     }
 }
 
+/// The slowest times within each of the configured percentiles, included when
+/// serializing [`GooseMetrics`] (ie for the `metrics-json` Controller command).
+#[derive(Debug, Serialize)]
+struct GoosePercentiles<'a> {
+    /// The configured percentiles.
+    percentiles: &'a [f64],
+    /// Per-request response times, in the same order as `percentiles`.
+    requests: BTreeMap<&'a str, Vec<usize>>,
+    /// Per-transaction run-times, in the same order as `percentiles`.
+    transactions: Vec<Vec<Vec<usize>>>,
+    /// Per-scenario run-times, in the same order as `percentiles`.
+    scenarios: Vec<Vec<usize>>,
+}
+impl<'a> GoosePercentiles<'a> {
+    /// Calculate the configured percentiles of all requests, transactions and scenarios.
+    fn new(metrics: &'a GooseMetrics) -> Self {
+        let calculate = |times: &BTreeMap<usize, usize>, counter: usize, min: usize, max: usize| {
+            metrics
+                .percentiles
+                .iter()
+                .map(|percentile| {
+                    response_time_percentile(times, counter, min, max, (percentile / 100.0) as f32)
+                })
+                .collect::<Vec<usize>>()
+        };
+        GoosePercentiles {
+            percentiles: &metrics.percentiles,
+            requests: metrics
+                .requests
+                .iter()
+                .map(|(request_key, request)| {
+                    (
+                        request_key.as_str(),
+                        calculate(
+                            &request.raw_data.times,
+                            request.raw_data.counter,
+                            request.raw_data.minimum_time,
+                            request.raw_data.maximum_time,
+                        ),
+                    )
+                })
+                .collect(),
+            transactions: metrics
+                .transactions
+                .iter()
+                .map(|scenario| {
+                    scenario
+                        .iter()
+                        .map(|transaction| {
+                            calculate(
+                                &transaction.times,
+                                transaction.counter,
+                                transaction.min_time,
+                                transaction.max_time,
+                            )
+                        })
+                        .collect()
+                })
+                .collect(),
+            scenarios: metrics
+                .scenarios
+                .iter()
+                .map(|scenario| {
+                    calculate(
+                        &scenario.times,
+                        scenario.counter,
+                        scenario.min_time,
+                        scenario.max_time,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
 /// Implement format trait to allow displaying metrics.
 impl fmt::Display for GooseMetrics {
     // Implement display of metrics with `{}` marker.
@@ -2553,17 +3828,540 @@ impl fmt::Display for GooseMetrics {
         // flags are set.
         self.fmt_scenarios(fmt)?;
         self.fmt_scenario_times(fmt)?;
+        self.fmt_scenario_percentiles(fmt)?;
         self.fmt_transactions(fmt)?;
         self.fmt_transaction_times(fmt)?;
+        self.fmt_transaction_percentiles(fmt)?;
+        self.fmt_timers(fmt)?;
+        self.fmt_timer_times(fmt)?;
+        self.fmt_counters(fmt)?;
+        self.fmt_gauges(fmt)?;
+        self.fmt_histograms(fmt)?;
         self.fmt_requests(fmt)?;
         self.fmt_response_times(fmt)?;
         self.fmt_percentiles(fmt)?;
         self.fmt_status_codes(fmt)?;
+        self.fmt_streams(fmt)?;
         self.fmt_errors(fmt)?;
+        self.fmt_thresholds(fmt)?;
         self.fmt_overview(fmt)
     }
 }
 
+/// A threshold, or service level objective, checked against the final metrics of a load
+/// test.
+///
+/// Thresholds are defined as `[TARGET] METRIC OPERATOR LIMIT`, for example
+/// `GET /api p95 < 300ms`, `error rate < 1%` or `transaction checkout avg < 2s`, either with
+/// the `--thresholds` run-time option or with
+/// [`GooseAttack::add_threshold`](../struct.GooseAttack.html#method.add_threshold).
+/// - `TARGET` is the name of a request (ie `GET /api`), `transaction NAME` or
+///   `scenario NAME`. If omitted, all requests are checked together.
+/// - `METRIC` is one of `avg`, `min`, `max`, `median`, a percentile such as `p95` or
+///   `p99.9`, `error rate` or `rps`.
+/// - `OPERATOR` is one of `<`, `<=`, `>` or `>=`.
+/// - `LIMIT` is a number, optionally followed by `ms` or `s` for times, `%` for error rates,
+///   or `/s` for rates per second.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GooseThreshold {
+    /// The threshold as it was defined.
+    pub definition: String,
+    /// What is checked.
+    pub target: GooseThresholdTarget,
+    /// Which metric of the target is checked.
+    pub metric: GooseThresholdMetric,
+    /// How the metric is compared with the limit.
+    pub operator: GooseThresholdOperator,
+    /// The limit, in milliseconds for times, in percent for error rates, or per second.
+    pub limit: f64,
+}
+impl GooseThreshold {
+    /// Returns the value of the threshold's metric in the final metrics, or `None` if the
+    /// target wasn't seen during the load test.
+    fn measure(&self, metrics: &GooseMetrics) -> Option<f64> {
+        self.collect(metrics)
+            .measure(&self.metric, metrics.duration)
+    }
+
+    /// Merges the times and counts of all requests, transactions or scenarios targeted by
+    /// the threshold.
+    fn collect(&self, metrics: &GooseMetrics) -> ThresholdData {
+        let mut data = ThresholdData::default();
+        match &self.target {
+            GooseThresholdTarget::Requests => {
+                for request in metrics.requests.values() {
+                    data.merge_request(request);
+                }
+            }
+            GooseThresholdTarget::Request(name) => {
+                if let Some(request) = metrics.requests.get(name) {
+                    data.merge_request(request);
+                }
+            }
+            GooseThresholdTarget::Transaction(name) => {
+                for transaction in metrics.transactions.iter().flatten() {
+                    if &transaction.transaction_name == name {
+                        data.merge(
+                            &transaction.times,
+                            transaction.counter,
+                            transaction.total_time,
+                            transaction.min_time,
+                            transaction.max_time,
+                            transaction.fail_count,
+                        );
+                    }
+                }
+            }
+            GooseThresholdTarget::Scenario(name) => {
+                for scenario in &metrics.scenarios {
+                    if &scenario.name == name {
+                        data.merge(
+                            &scenario.times,
+                            scenario.counter,
+                            scenario.total_time,
+                            scenario.min_time,
+                            scenario.max_time,
+                            // Scenarios don't fail, their error rate is rejected when parsing.
+                            0,
+                        );
+                    }
+                }
+            }
+        }
+        data
+    }
+
+    /// Returns `true` if the value is within the threshold.
+    fn check(&self, value: f64) -> bool {
+        match self.operator {
+            GooseThresholdOperator::Less => value < self.limit,
+            GooseThresholdOperator::LessOrEqual => value <= self.limit,
+            GooseThresholdOperator::Greater => value > self.limit,
+            GooseThresholdOperator::GreaterOrEqual => value >= self.limit,
+        }
+    }
+}
+impl FromStr for GooseThreshold {
+    type Err = GooseError;
+
+    fn from_str(definition: &str) -> Result<Self, Self::Err> {
+        GooseThreshold::parse(definition, "`configuration.thresholds`")
+    }
+}
+impl GooseThreshold {
+    /// Parses a threshold, returning an error for the named option if it's invalid.
+    fn parse(definition: &str, option: &str) -> Result<Self, GooseError> {
+        let definition = definition.trim();
+        let invalid = |detail: &str| GooseError::InvalidOption {
+            option: option.to_string(),
+            value: definition.to_string(),
+            detail: format!("{} {}", option, detail),
+        };
+
+        // Split the definition into the measurement and the limit.
+        let index = definition
+            .find(['<', '>'])
+            .ok_or_else(|| invalid("must compare a metric with <, <=, > or >=."))?;
+        let (measurement, comparison) = definition.split_at(index);
+        let (operator, limit) = match comparison.split_at(1) {
+            ("<", limit) if limit.starts_with('=') => {
+                (GooseThresholdOperator::LessOrEqual, &limit[1..])
+            }
+            ("<", limit) => (GooseThresholdOperator::Less, limit),
+            (_, limit) if limit.starts_with('=') => {
+                (GooseThresholdOperator::GreaterOrEqual, &limit[1..])
+            }
+            (_, limit) => (GooseThresholdOperator::Greater, limit),
+        };
+
+        // Split the measurement into the target and the metric.
+        let measurement = measurement.trim();
+        let error_rate = measurement
+            .len()
+            .checked_sub("error rate".len())
+            .filter(|index| measurement.is_char_boundary(*index))
+            .filter(|index| measurement[*index..].eq_ignore_ascii_case("error rate"));
+        let (target, metric) = if let Some(index) = error_rate {
+            (&measurement[..index], GooseThresholdMetric::ErrorRate)
+        } else {
+            let (target, metric) = measurement.rsplit_once(' ').unwrap_or(("", measurement));
+            let metric = match metric.to_lowercase().as_str() {
+                "avg" | "average" => GooseThresholdMetric::Average,
+                "min" => GooseThresholdMetric::Minimum,
+                "max" => GooseThresholdMetric::Maximum,
+                "median" => GooseThresholdMetric::Percentile(50.0),
+                "rps" => GooseThresholdMetric::PerSecond,
+                percentile => match percentile
+                    .strip_prefix('p')
+                    .and_then(|p| p.parse::<f64>().ok())
+                {
+                    Some(p) if p > 0.0 && p <= 100.0 => GooseThresholdMetric::Percentile(p),
+                    _ => return Err(invalid(
+                        "must check avg, min, max, median, a percentile such as p95, error rate or rps.",
+                    )),
+                },
+            };
+            (target, metric)
+        };
+
+        // Determine which requests, transactions or scenarios are checked.
+        let target = target.trim();
+        let target = if target.is_empty() {
+            GooseThresholdTarget::Requests
+        } else if let Some(name) = target.strip_prefix("transaction ") {
+            GooseThresholdTarget::Transaction(name.trim().to_string())
+        } else if let Some(name) = target.strip_prefix("scenario ") {
+            // Scenarios don't fail, only the requests and transactions they run.
+            if metric == GooseThresholdMetric::ErrorRate {
+                return Err(invalid("can't check the error rate of a scenario."));
+            }
+            GooseThresholdTarget::Scenario(name.trim().to_string())
+        } else {
+            GooseThresholdTarget::Request(target.to_string())
+        };
+
+        // Convert the limit to milliseconds, percent or per second.
+        let limit = limit.trim();
+        let (number, multiplier) = match metric {
+            GooseThresholdMetric::ErrorRate => (limit.trim_end_matches('%'), 1.0),
+            GooseThresholdMetric::PerSecond => (limit.trim_end_matches("/s"), 1.0),
+            _ => {
+                if let Some(number) = limit.strip_suffix("ms") {
+                    (number, 1.0)
+                } else if let Some(number) = limit.strip_suffix('s') {
+                    (number, 1000.0)
+                } else {
+                    (limit, 1.0)
+                }
+            }
+        };
+        let limit = number
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| invalid("must have a numeric limit, such as 300ms, 2s or 1%."))?
+            * multiplier;
+
+        Ok(GooseThreshold {
+            definition: definition.to_string(),
+            target,
+            metric,
+            operator,
+            limit,
+        })
+    }
+}
+
+/// What a [`GooseThreshold`] is checked against.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GooseThresholdTarget {
+    /// All requests together.
+    Requests,
+    /// A single request, named by method and name (ie `GET /api`).
+    Request(String),
+    /// All transactions with this name.
+    Transaction(String),
+    /// All scenarios with this name.
+    Scenario(String),
+}
+
+/// Which metric a [`GooseThreshold`] checks.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GooseThresholdMetric {
+    /// The average time, in milliseconds.
+    Average,
+    /// The shortest time, in milliseconds.
+    Minimum,
+    /// The longest time, in milliseconds.
+    Maximum,
+    /// The slowest time within a percentile, in milliseconds.
+    Percentile(f64),
+    /// The percentage of requests or transactions that failed. Scenarios don't fail, so their
+    /// error rate can't be checked.
+    ErrorRate,
+    /// How many times the target ran per second.
+    PerSecond,
+}
+
+impl GooseThresholdMetric {
+    /// Format a measured value for display, with the unit of the metric.
+    fn format_value(&self, value: Option<f64>) -> String {
+        match (value, self) {
+            (None, _) => "-".to_string(),
+            (Some(value), GooseThresholdMetric::ErrorRate) => format!("{:.2}%", value),
+            (Some(value), GooseThresholdMetric::PerSecond) => format!("{:.2}/s", value),
+            (Some(value), _) => format!("{:.2} ms", value),
+        }
+    }
+}
+
+/// How a [`GooseThreshold`] compares a metric with its limit.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GooseThresholdOperator {
+    /// `<`
+    Less,
+    /// `<=`
+    LessOrEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterOrEqual,
+}
+
+/// The result of checking a [`GooseThreshold`] against the final metrics of a load test.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GooseThresholdResult {
+    /// The threshold that was checked.
+    pub threshold: GooseThreshold,
+    /// The measured value, or `None` if the target wasn't seen during the load test.
+    pub value: Option<f64>,
+    /// Whether or not the threshold was met.
+    pub passed: bool,
+}
+impl GooseThresholdResult {
+    /// Format the measured value for display, with the unit of the metric.
+    pub(crate) fn formatted_value(&self) -> String {
+        self.threshold.metric.format_value(self.value)
+    }
+
+    /// Format whether or not the threshold was met for display.
+    pub(crate) fn formatted_result(&self) -> &'static str {
+        if self.passed {
+            "PASS"
+        } else {
+            "FAIL"
+        }
+    }
+}
+
+/// A condition that aborts a running load test early, for example when a broken deploy
+/// makes every request fail.
+///
+/// Abort conditions are defined like a [`GooseThreshold`], but abort the load test as soon as
+/// the comparison is true. They can be followed by `over DURATION` to only measure what
+/// happened in a sliding window of the most recent seconds, and by `for DURATION` to only
+/// abort once the comparison has been true for that long, for example
+/// `error rate > 5% over 30s` or `GET /api p95 > 2000ms for 1m`. Abort conditions are
+/// configured with the `--abort-on` run-time option or with
+/// [`GooseAttack::add_abort_condition`](../struct.GooseAttack.html#method.add_abort_condition).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GooseAbortCondition {
+    /// The abort condition as it was defined.
+    pub definition: String,
+    /// The comparison that aborts the load test when true.
+    pub condition: GooseThreshold,
+    /// Optionally only measure this many of the most recent seconds.
+    pub window: Option<usize>,
+    /// Optionally only abort once the comparison has been true for this many seconds.
+    pub duration: Option<usize>,
+}
+impl FromStr for GooseAbortCondition {
+    type Err = GooseError;
+
+    fn from_str(definition: &str) -> Result<Self, Self::Err> {
+        let definition = definition.trim();
+        let option = "`configuration.abort_on`";
+
+        // Strip the optional `over DURATION` and `for DURATION` from the end of the definition.
+        let mut words = definition.split_whitespace().collect::<Vec<&str>>();
+        let mut window = None;
+        let mut duration = None;
+        while words.len() > 2 {
+            let clause = match words[words.len() - 2].to_lowercase().as_str() {
+                "over" if window.is_none() => &mut window,
+                "for" if duration.is_none() => &mut duration,
+                _ => break,
+            };
+            match util::parse_timespan(words[words.len() - 1]) {
+                0 => {
+                    return Err(GooseError::InvalidOption {
+                        option: option.to_string(),
+                        value: definition.to_string(),
+                        detail: format!(
+                            "{} must be followed by a duration of at least 1 second, such as `over 30s` or `for 1m`.",
+                            option
+                        ),
+                    })
+                }
+                seconds => *clause = Some(seconds),
+            }
+            words.truncate(words.len() - 2);
+        }
+
+        Ok(GooseAbortCondition {
+            definition: definition.to_string(),
+            condition: GooseThreshold::parse(&words.join(" "), option)?,
+            window,
+            duration,
+        })
+    }
+}
+
+/// Tracks a [`GooseAbortCondition`] while the load test is running.
+#[derive(Debug)]
+pub(crate) struct GooseAbortConditionState {
+    /// The abort condition being tracked.
+    condition: GooseAbortCondition,
+    /// Copies of the measured data taken at most once a second, covering the window.
+    snapshots: VecDeque<(std::time::Instant, ThresholdData)>,
+    /// When the comparison most recently became true.
+    breached: Option<std::time::Instant>,
+}
+impl GooseAbortConditionState {
+    pub(crate) fn new(condition: GooseAbortCondition) -> Self {
+        GooseAbortConditionState {
+            condition,
+            snapshots: VecDeque::new(),
+            breached: None,
+        }
+    }
+
+    /// Checks the abort condition against the running metrics, returning why the load test
+    /// should be aborted if the condition is met.
+    fn check(&mut self, metrics: &GooseMetrics) -> Option<String> {
+        let now = std::time::Instant::now();
+        let threshold = &self.condition.condition;
+        let mut data = threshold.collect(metrics);
+        let mut duration = metrics.duration;
+
+        // Only measure what happened within the window.
+        if let Some(window) = self.condition.window {
+            // Start over if the metrics were reset.
+            if self
+                .snapshots
+                .back()
+                .is_some_and(|(_, snapshot)| snapshot.counter > data.counter)
+            {
+                self.snapshots.clear();
+            }
+            // Take at most one snapshot per second.
+            if !self
+                .snapshots
+                .back()
+                .is_some_and(|(taken, _)| now.duration_since(*taken).as_secs() < 1)
+            {
+                self.snapshots.push_back((now, data.clone()));
+            }
+            // Keep the most recent snapshot taken before the window started.
+            while self.snapshots.len() > 1
+                && now.duration_since(self.snapshots[1].0).as_secs() >= window as u64
+            {
+                self.snapshots.pop_front();
+            }
+            let (taken, earliest) = self.snapshots.front().expect("no snapshot taken");
+            data = data.since(earliest);
+            duration = now.duration_since(*taken).as_secs() as usize;
+        }
+
+        let value = data.measure(&threshold.metric, duration);
+        if !value.is_some_and(|value| threshold.check(value)) {
+            self.breached = None;
+            return None;
+        }
+
+        // Optionally wait until the comparison has been true for long enough.
+        let breached = *self.breached.get_or_insert(now);
+        if now.duration_since(breached).as_secs() < self.condition.duration.unwrap_or(0) as u64 {
+            return None;
+        }
+
+        Some(format!(
+            "{}, measured {}",
+            self.condition.definition,
+            threshold.metric.format_value(value)
+        ))
+    }
+}
+
+/// The times and counts of one or more requests, transactions or scenarios, merged together
+/// to check a [`GooseThreshold`].
+#[derive(Clone, Debug, Default)]
+struct ThresholdData {
+    times: BTreeMap<usize, usize>,
+    counter: usize,
+    total_time: usize,
+    min: usize,
+    max: usize,
+    fail_count: usize,
+}
+impl ThresholdData {
+    /// Merge the times and counts of a request.
+    fn merge_request(&mut self, request: &GooseRequestMetricAggregate) {
+        self.merge(
+            &request.raw_data.times,
+            request.raw_data.counter,
+            request.raw_data.total_time,
+            request.raw_data.minimum_time,
+            request.raw_data.maximum_time,
+            request.fail_count,
+        );
+    }
+
+    /// Merge the times and counts of a request, transaction or scenario.
+    fn merge(
+        &mut self,
+        times: &BTreeMap<usize, usize>,
+        counter: usize,
+        total_time: usize,
+        min: usize,
+        max: usize,
+        fail_count: usize,
+    ) {
+        self.times = merge_times(std::mem::take(&mut self.times), times.clone());
+        self.counter += counter;
+        self.total_time += total_time;
+        self.min = update_min_time(self.min, min);
+        self.max = update_max_time(self.max, max);
+        self.fail_count += fail_count;
+    }
+
+    /// Returns the times and counts recorded since an earlier copy of the same data.
+    fn since(&self, earlier: &ThresholdData) -> ThresholdData {
+        let times = self
+            .times
+            .iter()
+            .filter_map(|(time, count)| {
+                let count = count.saturating_sub(*earlier.times.get(time).unwrap_or(&0));
+                (count > 0).then_some((*time, count))
+            })
+            .collect::<BTreeMap<usize, usize>>();
+        // Exact minimum and maximum times can't be subtracted, so use the recorded times.
+        ThresholdData {
+            min: times.keys().next().copied().unwrap_or(0),
+            max: times.keys().next_back().copied().unwrap_or(0),
+            times,
+            counter: self.counter.saturating_sub(earlier.counter),
+            total_time: self.total_time.saturating_sub(earlier.total_time),
+            fail_count: self.fail_count.saturating_sub(earlier.fail_count),
+        }
+    }
+
+    /// Returns the value of a metric, or `None` if nothing was recorded. Rates per second
+    /// are calculated over `duration` seconds.
+    fn measure(&self, metric: &GooseThresholdMetric, duration: usize) -> Option<f64> {
+        // A target that was never seen can't be measured.
+        if self.counter == 0 {
+            return None;
+        }
+
+        Some(match metric {
+            GooseThresholdMetric::Average => self.total_time as f64 / self.counter as f64,
+            GooseThresholdMetric::Minimum => self.min as f64,
+            GooseThresholdMetric::Maximum => self.max as f64,
+            GooseThresholdMetric::Percentile(percentile) => response_time_percentile(
+                &self.times,
+                self.counter,
+                self.min,
+                self.max,
+                (percentile / 100.0) as f32,
+            ) as f64,
+            GooseThresholdMetric::ErrorRate => self.fail_count as f64 / self.counter as f64 * 100.0,
+            GooseThresholdMetric::PerSecond => {
+                per_second_calculations(duration, self.counter, 0).0 as f64
+            }
+        })
+    }
+}
+
 /// For tracking and counting requests made during a load test.
 ///
 /// The request that Goose is making. User threads send this data to the parent thread
@@ -2665,6 +4463,11 @@ impl GooseAttack {
             };
             // Load messages from user threads until the receiver queue is empty.
             self.receive_metrics(goose_attack_run_state, flush).await?;
+
+            // Regularly check if the load test should be aborted.
+            if !flush {
+                self.check_abort_conditions(goose_attack_run_state).await?;
+            }
         }
 
         // If enabled, display running metrics after sync
@@ -2677,6 +4480,52 @@ impl GooseAttack {
         Ok(())
     }
 
+    // Check abort conditions against the running metrics, canceling the load test if any
+    // of them is met.
+    async fn check_abort_conditions(
+        &mut self,
+        goose_attack_run_state: &mut GooseAttackRunState,
+    ) -> Result<(), GooseError> {
+        // Only check abort conditions while GooseUsers are running.
+        if goose_attack_run_state.canceling
+            || !matches!(
+                self.attack_phase,
+                AttackPhase::Increase | AttackPhase::Maintain
+            )
+        {
+            return Ok(());
+        }
+
+        self.update_duration();
+        let metrics = &self.metrics;
+        let reason = goose_attack_run_state
+            .abort_conditions
+            .iter_mut()
+            .find_map(|abort_condition| abort_condition.check(metrics));
+
+        if let Some(reason) = reason {
+            warn!("abort condition met, canceling load test: {}", reason);
+
+            // Shutdown after stopping as the load test was aborted.
+            goose_attack_run_state.shutdown_after_stop = true;
+
+            // Quickly stop the load test.
+            self.cancel_attack(goose_attack_run_state).await?;
+
+            // Load test is actively canceling.
+            goose_attack_run_state.canceling = true;
+
+            // Record why the load test was aborted.
+            self.metrics
+                .history
+                .last_mut()
+                .expect("tried to abort load test with no history")
+                .reason = Some(reason);
+        }
+
+        Ok(())
+    }
+
     // When the [`GooseAttack`](./struct.GooseAttack.html) goes from the `Increasing`
     // phase to the `Maintaining` phase, optionally flush metrics.
     pub(crate) async fn reset_metrics(
@@ -2714,6 +4563,11 @@ impl GooseAttack {
                     }
 
                     self.metrics.requests = HashMap::new();
+                    self.metrics.timers = BTreeMap::new();
+                    self.metrics.counters = BTreeMap::new();
+                    self.metrics.gauges = BTreeMap::new();
+                    self.metrics.histograms = BTreeMap::new();
+                    self.metrics.streams = BTreeMap::new();
                     self.metrics
                         .initialize_scenario_metrics(&self.scenarios, &self.configuration);
                     self.metrics.initialize_transaction_metrics(
@@ -2757,8 +4611,14 @@ impl GooseAttack {
             ),
         };
 
+        // Cache hits never reached the server, so are only counted.
+        if request_metric.cache_hit {
+            if !request_metric.update {
+                merge_request.cache_hits += 1;
+            }
+        }
         // Handle a metrics update.
-        if request_metric.update {
+        else if request_metric.update {
             if request_metric.success {
                 merge_request.success_count += 1;
                 merge_request.fail_count -= 1;
@@ -2772,7 +4632,20 @@ impl GooseAttack {
             merge_request.record_time(
                 request_metric.response_time,
                 request_metric.coordinated_omission_elapsed > 0,
+                self.configuration.significant_digits,
             );
+            // Phase timings and sizes are only known for requests that were actually made.
+            if request_metric.coordinated_omission_elapsed == 0 {
+                merge_request.timings.record(
+                    &request_metric.timings,
+                    self.configuration.significant_digits,
+                );
+                merge_request.request_body_bytes += request_metric.request_body_size;
+                merge_request.response_body_bytes += request_metric.response_body_size;
+                if request_metric.status_code == StatusCode::NOT_MODIFIED.as_u16() {
+                    merge_request.not_modified += 1;
+                }
+            }
             if !self.configuration.no_status_codes {
                 merge_request.set_status_code(request_metric.status_code);
             }
@@ -2842,7 +4715,8 @@ impl GooseAttack {
                         // `GooseMetrics.requests`, and write to the requests log if enabled.
                         self.record_request_metric(&request_metric).await;
 
-                        if !self.configuration.report_file.is_empty() {
+                        // Cache hits aren't graphed as they never reached the server.
+                        if !self.configuration.report_file.is_empty() && !request_metric.cache_hit {
                             let seconds_since_start = (request_metric.elapsed / 1000) as usize;
 
                             let key =
@@ -2855,6 +4729,15 @@ impl GooseAttack {
                                 request_metric.response_time,
                             );
 
+                            // Updates re-send a request that was already recorded.
+                            if !request_metric.update {
+                                self.graph_data.record_response_bytes_per_second(
+                                    &key,
+                                    seconds_since_start,
+                                    request_metric.response_body_size as usize,
+                                );
+                            }
+
                             if !request_metric.success {
                                 self.graph_data
                                     .record_errors_per_second(&key, seconds_since_start);
@@ -2866,7 +4749,11 @@ impl GooseAttack {
                     // Store a new metric.
                     self.metrics.transactions[raw_transaction.scenario_index]
                         [raw_transaction.transaction_index]
-                        .set_time(raw_transaction.run_time, raw_transaction.success);
+                        .set_time(
+                            raw_transaction.run_time,
+                            raw_transaction.success,
+                            self.configuration.significant_digits,
+                        );
 
                     if !self.configuration.report_file.is_empty() {
                         self.graph_data.record_transactions_per_second(
@@ -2876,14 +4763,67 @@ impl GooseAttack {
                 }
                 GooseMetric::Scenario(raw_scenario) => {
                     // Store a new metric.
-                    self.metrics.scenarios[raw_scenario.index]
-                        .update(raw_scenario.run_time, raw_scenario.user);
+                    self.metrics.scenarios[raw_scenario.index].update(
+                        raw_scenario.run_time,
+                        raw_scenario.user,
+                        self.configuration.significant_digits,
+                    );
 
                     if !self.configuration.report_file.is_empty() {
                         self.graph_data
                             .record_scenarios_per_second((raw_scenario.elapsed / 1000) as usize);
                     }
                 }
+                GooseMetric::Timer(raw_timer) => {
+                    // Merge the `TimerMetric` into a `TimerMetricAggregate`, creating it the
+                    // first time a timer with this name is stopped.
+                    self.metrics
+                        .timers
+                        .entry(raw_timer.name.clone())
+                        .or_insert_with(|| TimerMetricAggregate::new(&raw_timer.name))
+                        .set_time(
+                            raw_timer.run_time,
+                            raw_timer.success,
+                            self.configuration.significant_digits,
+                        );
+                }
+                GooseMetric::Custom(raw_custom) => {
+                    // Merge the `CustomMetric` into the matching aggregate, creating it the
+                    // first time a custom metric with this name is updated.
+                    let name = &raw_custom.name;
+                    match raw_custom.value {
+                        CustomMetricValue::Counter(value) => self
+                            .metrics
+                            .counters
+                            .entry(name.clone())
+                            .or_insert_with(|| CounterMetricAggregate::new(name))
+                            .increment(value),
+                        CustomMetricValue::Gauge(value) => self
+                            .metrics
+                            .gauges
+                            .entry(name.clone())
+                            .or_insert_with(|| GaugeMetricAggregate::new(name))
+                            .set(value),
+                        CustomMetricValue::Histogram(value) => self
+                            .metrics
+                            .histograms
+                            .entry(name.clone())
+                            .or_insert_with(|| HistogramMetricAggregate::new(name))
+                            .record(value, self.configuration.significant_digits),
+                    }
+                }
+                GooseMetric::Stream(raw_stream) => {
+                    // Merge the `StreamMetric` into a `StreamMetricAggregate`, creating it the
+                    // first time a stream with this method and name is read.
+                    let key = format!("{} {}", raw_stream.method, raw_stream.name);
+                    self.metrics
+                        .streams
+                        .entry(key)
+                        .or_insert_with(|| {
+                            StreamMetricAggregate::new(raw_stream.method.clone(), &raw_stream.name)
+                        })
+                        .record(&raw_stream, self.configuration.significant_digits);
+                }
             }
             // Unless flushing all metrics, break out of receive loop after timeout.
             if !flush && util::ms_timer_expired(receive_started, receive_timeout) {
@@ -3040,6 +4980,13 @@ impl GooseAttack {
                             step[1].users,
                             step[0].users,
                         );
+                        if let Some(reason) = &step[0].reason {
+                            let _ = write!(
+                                steps_overview,
+                                "<tr><td>Aborted</td><td colspan=\"4\">{}</td></tr>",
+                                reason,
+                            );
+                        }
                     }
                     TestPlanStepAction::Finished => {
                         unreachable!("there shouldn't be a step after finished");
@@ -3055,6 +5002,15 @@ impl GooseAttack {
             let mut co_request_metrics = Vec::new();
             let mut raw_response_metrics = Vec::new();
             let mut co_response_metrics = Vec::new();
+            let mut request_timings_metrics = Vec::new();
+            let mut aggregate_timings = GooseRequestTimingsAggregate::new();
+            let mut throughput_metrics = Vec::new();
+            let mut aggregate_request_body_bytes = 0;
+            let mut aggregate_response_body_bytes = 0;
+            let mut aggregate_body_counter = 0;
+            let mut cache_metrics = Vec::new();
+            let mut aggregate_cache_hits = 0;
+            let mut aggregate_not_modified = 0;
             let mut raw_aggregate_total_count = 0;
             let mut co_aggregate_total_count = 0;
             let mut raw_aggregate_fail_count = 0;
@@ -3104,12 +5060,57 @@ impl GooseAttack {
                 raw_response_metrics.push(report::get_response_metric(
                     &method,
                     &name,
+                    &self.metrics.percentiles,
                     &request.raw_data.times,
                     request.raw_data.counter,
                     request.raw_data.minimum_time,
                     request.raw_data.maximum_time,
                 ));
 
+                // Prepare per-request timing metrics.
+                request_timings_metrics.push(report::RequestTimingsMetric {
+                    method: method.to_string(),
+                    name: name.to_string(),
+                    dns_average: format!("{:.2}", average_time(&request.timings.dns)),
+                    connect_average: format!("{:.2}", average_time(&request.timings.connect)),
+                    time_to_first_byte_average: format!(
+                        "{:.2}",
+                        average_time(&request.timings.time_to_first_byte)
+                    ),
+                    download_average: format!("{:.2}", average_time(&request.timings.download)),
+                });
+                merge_timing_data(&mut aggregate_timings.dns, &request.timings.dns);
+                merge_timing_data(&mut aggregate_timings.connect, &request.timings.connect);
+                merge_timing_data(
+                    &mut aggregate_timings.time_to_first_byte,
+                    &request.timings.time_to_first_byte,
+                );
+                merge_timing_data(&mut aggregate_timings.download, &request.timings.download);
+
+                // Prepare per-request throughput metrics.
+                throughput_metrics.push(throughput_metric(
+                    &method,
+                    &name,
+                    self.metrics.duration,
+                    request.request_body_bytes,
+                    request.response_body_bytes,
+                    request.raw_data.counter,
+                ));
+                aggregate_request_body_bytes += request.request_body_bytes;
+                aggregate_response_body_bytes += request.response_body_bytes;
+                aggregate_body_counter += request.raw_data.counter;
+
+                // Prepare per-request HTTP cache metrics.
+                cache_metrics.push(cache_metric(
+                    &method,
+                    &name,
+                    request.cache_hits,
+                    request.not_modified,
+                    total_request_count,
+                ));
+                aggregate_cache_hits += request.cache_hits;
+                aggregate_not_modified += request.not_modified;
+
                 // Collect aggregated request and response metrics.
                 raw_aggregate_total_count += total_request_count;
                 raw_aggregate_fail_count += request.fail_count;
@@ -3152,18 +5153,81 @@ impl GooseAttack {
             raw_response_metrics.push(report::get_response_metric(
                 "",
                 "Aggregated",
+                &self.metrics.percentiles,
                 &raw_aggregate_response_times,
                 raw_aggregate_total_count,
                 raw_aggregate_response_time_minimum,
                 raw_aggregate_response_time_maximum,
             ));
 
+            // Prepare aggregate per-request timing metrics.
+            request_timings_metrics.push(report::RequestTimingsMetric {
+                method: "".to_string(),
+                name: "Aggregated".to_string(),
+                dns_average: format!("{:.2}", average_time(&aggregate_timings.dns)),
+                connect_average: format!("{:.2}", average_time(&aggregate_timings.connect)),
+                time_to_first_byte_average: format!(
+                    "{:.2}",
+                    average_time(&aggregate_timings.time_to_first_byte)
+                ),
+                download_average: format!("{:.2}", average_time(&aggregate_timings.download)),
+            });
+
+            // Compile the request timing metrics template.
+            let mut request_timings_rows = Vec::new();
+            for metric in request_timings_metrics {
+                request_timings_rows.push(report::request_timings_metrics_row(metric));
+            }
+            let request_timings_template =
+                report::request_timings_metrics_template(&request_timings_rows.join("\n"));
+
+            // Prepare aggregate throughput metrics, and compile the throughput template.
+            throughput_metrics.push(throughput_metric(
+                "",
+                "Aggregated",
+                self.metrics.duration,
+                aggregate_request_body_bytes,
+                aggregate_response_body_bytes,
+                aggregate_body_counter,
+            ));
+            let mut throughput_rows = Vec::new();
+            for metric in throughput_metrics {
+                throughput_rows.push(report::throughput_metrics_row(metric));
+            }
+            let throughput_template = report::throughput_metrics_template(
+                &throughput_rows.join("\n"),
+                self.graph_data
+                    .get_response_bytes_per_second_graph(!self.configuration.no_granular_report)
+                    .get_markup(&self.metrics.history, test_start_time),
+            );
+
+            // Compile the HTTP cache template, only if the cache was used.
+            let cache_template = if aggregate_cache_hits > 0 || aggregate_not_modified > 0 {
+                cache_metrics.push(cache_metric(
+                    "",
+                    "Aggregated",
+                    aggregate_cache_hits,
+                    aggregate_not_modified,
+                    raw_aggregate_total_count,
+                ));
+                let mut cache_rows = Vec::new();
+                for metric in cache_metrics {
+                    cache_rows.push(report::cache_metrics_row(metric));
+                }
+                report::cache_metrics_template(&cache_rows.join("\n"))
+            } else {
+                "".to_string()
+            };
+
             // Compile the request metrics template.
             let mut raw_requests_rows = Vec::new();
             for metric in raw_request_metrics {
                 raw_requests_rows.push(report::raw_request_metrics_row(metric));
             }
 
+            // The configured percentiles are displayed in all response time tables.
+            let percentile_headers = report::percentile_headers(&self.metrics.percentiles);
+
             // Compile the response metrics template.
             let mut raw_responses_rows = Vec::new();
             for metric in raw_response_metrics {
@@ -3204,6 +5268,7 @@ impl GooseAttack {
                         co_response_metrics.push(report::get_response_metric(
                             &method,
                             &name,
+                            &self.metrics.percentiles,
                             &coordinated_omission_data.times,
                             coordinated_omission_data.counter,
                             coordinated_omission_data.minimum_time,
@@ -3246,6 +5311,7 @@ impl GooseAttack {
                 co_response_metrics.push(report::get_response_metric(
                     "",
                     "Aggregated",
+                    &self.metrics.percentiles,
                     &co_aggregate_response_times,
                     co_aggregate_total_count,
                     raw_aggregate_response_time_minimum,
@@ -3273,6 +5339,7 @@ impl GooseAttack {
                 // Compile the status_code metrics template.
                 co_responses_template = report::coordinated_omission_response_metrics_template(
                     &co_response_rows.join("\n"),
+                    &percentile_headers,
                 );
             } else {
                 // If --status-codes is not enabled, return an empty template.
@@ -3305,6 +5372,7 @@ impl GooseAttack {
                                 response_time_maximum: 0,
                                 requests_per_second: "".to_string(),
                                 failures_per_second: "".to_string(),
+                                percentiles: vec![String::new(); self.metrics.percentiles.len()],
                             });
                         }
                         let total_run_count = transaction.success_count + transaction.fail_count;
@@ -3328,6 +5396,13 @@ impl GooseAttack {
                             response_time_maximum: transaction.max_time,
                             requests_per_second: format!("{:.2}", requests_per_second),
                             failures_per_second: format!("{:.2}", failures_per_second),
+                            percentiles: report::get_percentiles(
+                                &self.metrics.percentiles,
+                                &transaction.times,
+                                transaction.counter,
+                                transaction.min_time,
+                                transaction.max_time,
+                            ),
                         });
 
                         aggregate_total_count += total_run_count;
@@ -3366,6 +5441,13 @@ impl GooseAttack {
                     response_time_maximum: aggregate_transaction_time_maximum,
                     requests_per_second: format!("{:.2}", aggregate_requests_per_second),
                     failures_per_second: format!("{:.2}", aggregate_failures_per_second),
+                    percentiles: report::get_percentiles(
+                        &self.metrics.percentiles,
+                        &aggregate_transaction_times,
+                        aggregate_transaction_time_counter,
+                        aggregate_transaction_time_minimum,
+                        aggregate_transaction_time_maximum,
+                    ),
                 });
                 let mut transactions_rows = Vec::new();
                 // Compile the transaction metrics template.
@@ -3378,11 +5460,175 @@ impl GooseAttack {
                     self.graph_data
                         .get_transactions_per_second_graph(!self.configuration.no_granular_report)
                         .get_markup(&self.metrics.history, test_start_time),
+                    &percentile_headers,
                 );
             } else {
                 transactions_template = "".to_string();
             }
 
+            // Only build the timers template if custom timers were used.
+            let timers_template: String = if !self.metrics.timers.is_empty() {
+                let mut timer_metrics = Vec::new();
+                let mut aggregate_total_count = 0;
+                let mut aggregate_fail_count = 0;
+                let mut aggregate_timer_time_counter: usize = 0;
+                let mut aggregate_timer_time_minimum: usize = 0;
+                let mut aggregate_timer_time_maximum: usize = 0;
+                for timer in self.metrics.timers.values() {
+                    let total_run_count = timer.success_count + timer.fail_count;
+                    let (timers_per_second, failures_per_second) = per_second_calculations(
+                        self.metrics.duration,
+                        total_run_count,
+                        timer.fail_count,
+                    );
+                    let average = match timer.counter {
+                        0 => 0.00,
+                        _ => timer.total_time as f32 / timer.counter as f32,
+                    };
+                    timer_metrics.push(report::TimerMetric {
+                        name: timer.name.to_string(),
+                        number_of_runs: total_run_count,
+                        number_of_failures: timer.fail_count,
+                        time_average: format!("{:.2}", average),
+                        time_minimum: timer.min_time,
+                        time_maximum: timer.max_time,
+                        timers_per_second: format!("{:.2}", timers_per_second),
+                        failures_per_second: format!("{:.2}", failures_per_second),
+                    });
+
+                    aggregate_total_count += total_run_count;
+                    aggregate_fail_count += timer.fail_count;
+                    aggregate_timer_time_counter += timer.total_time;
+                    aggregate_timer_time_minimum =
+                        update_min_time(aggregate_timer_time_minimum, timer.min_time);
+                    aggregate_timer_time_maximum =
+                        update_max_time(aggregate_timer_time_maximum, timer.max_time);
+                }
+
+                let (aggregate_timers_per_second, aggregate_failures_per_second) =
+                    per_second_calculations(
+                        self.metrics.duration,
+                        aggregate_total_count,
+                        aggregate_fail_count,
+                    );
+                timer_metrics.push(report::TimerMetric {
+                    name: "Aggregated".to_string(),
+                    number_of_runs: aggregate_total_count,
+                    number_of_failures: aggregate_fail_count,
+                    time_average: format!(
+                        "{:.2}",
+                        aggregate_timer_time_counter as f32 / aggregate_total_count as f32
+                    ),
+                    time_minimum: aggregate_timer_time_minimum,
+                    time_maximum: aggregate_timer_time_maximum,
+                    timers_per_second: format!("{:.2}", aggregate_timers_per_second),
+                    failures_per_second: format!("{:.2}", aggregate_failures_per_second),
+                });
+
+                // Compile the timer metrics template.
+                let mut timers_rows = Vec::new();
+                for metric in timer_metrics {
+                    timers_rows.push(report::timer_metrics_row(metric));
+                }
+                report::timer_metrics_template(&timers_rows.join("\n"))
+            } else {
+                "".to_string()
+            };
+
+            // Only build the custom metrics template if custom metrics were used.
+            let custom_metrics_template: String = if !self.metrics.counters.is_empty()
+                || !self.metrics.gauges.is_empty()
+                || !self.metrics.histograms.is_empty()
+            {
+                let mut counter_rows = Vec::new();
+                for counter in self.metrics.counters.values() {
+                    let total_per_second = match self.metrics.duration {
+                        0 => 0.0,
+                        _ => counter.total as f32 / self.metrics.duration as f32,
+                    };
+                    counter_rows.push(report::counter_metrics_row(report::CounterMetric {
+                        name: counter.name.to_string(),
+                        total: counter.total,
+                        updates: counter.updates,
+                        total_per_second: format!("{:.2}", total_per_second),
+                    }));
+                }
+
+                let mut gauge_rows = Vec::new();
+                for gauge in self.metrics.gauges.values() {
+                    gauge_rows.push(report::gauge_metrics_row(report::GaugeMetric {
+                        name: gauge.name.to_string(),
+                        value: format!("{:.2}", gauge.value),
+                        minimum: format!("{:.2}", gauge.minimum),
+                        maximum: format!("{:.2}", gauge.maximum),
+                        updates: gauge.updates,
+                    }));
+                }
+
+                let mut histogram_rows = Vec::new();
+                for histogram in self.metrics.histograms.values() {
+                    let average = match histogram.counter {
+                        0 => 0.00,
+                        _ => histogram.total as f32 / histogram.counter as f32,
+                    };
+                    histogram_rows.push(report::histogram_metrics_row(report::HistogramMetric {
+                        name: histogram.name.to_string(),
+                        counter: histogram.counter,
+                        average: format!("{:.2}", average),
+                        minimum: histogram.minimum,
+                        maximum: histogram.maximum,
+                        median: util::median(
+                            &histogram.values,
+                            histogram.counter,
+                            histogram.minimum,
+                            histogram.maximum,
+                        ),
+                    }));
+                }
+
+                report::custom_metrics_template(
+                    &counter_rows.join("\n"),
+                    &gauge_rows.join("\n"),
+                    &histogram_rows.join("\n"),
+                )
+            } else {
+                "".to_string()
+            };
+
+            // Only build the streams template if streaming responses were read.
+            let streams_template: String = if !self.metrics.streams.is_empty() {
+                let mut stream_rows = Vec::new();
+                for stream in self.metrics.streams.values() {
+                    let events_average = match stream.counter {
+                        0 => 0.00,
+                        _ => stream.events as f32 / stream.counter as f32,
+                    };
+                    stream_rows.push(report::stream_metrics_row(report::StreamMetric {
+                        method: stream.method.to_string(),
+                        name: stream.name.to_string(),
+                        number_of_streams: stream.counter,
+                        number_of_failures: stream.fail_count,
+                        events_average: format!("{:.2}", events_average),
+                        time_to_first_byte_average: format!(
+                            "{:.2}",
+                            average_time(&stream.time_to_first_byte)
+                        ),
+                        time_to_first_event_average: format!(
+                            "{:.2}",
+                            average_time(&stream.time_to_first_event)
+                        ),
+                        time_between_events_average: format!(
+                            "{:.2}",
+                            average_time(&stream.time_between_events)
+                        ),
+                        duration_average: format!("{:.2}", average_time(&stream.duration)),
+                    }));
+                }
+                report::stream_metrics_template(&stream_rows.join("\n"))
+            } else {
+                "".to_string()
+            };
+
             // Only build the scenarios template if --no-senario-metrics isn't enabled.
             let scenarios_template: String;
             if !self.configuration.no_scenario_metrics {
@@ -3412,6 +5658,13 @@ impl GooseAttack {
                         response_time_maximum: scenario.max_time,
                         count_per_second: format!("{:.2}", count_per_second),
                         iterations: format!("{:.2}", iterations),
+                        percentiles: report::get_percentiles(
+                            &self.metrics.percentiles,
+                            &scenario.times,
+                            scenario.counter,
+                            scenario.min_time,
+                            scenario.max_time,
+                        ),
                     });
 
                     aggregate_users += scenario.users.len();
@@ -3441,6 +5694,13 @@ impl GooseAttack {
                     response_time_maximum: aggregate_scenario_time_maximum,
                     count_per_second: format!("{:.2}", aggregate_count_per_second),
                     iterations: format!("{:.2}", aggregate_iterations),
+                    percentiles: report::get_percentiles(
+                        &self.metrics.percentiles,
+                        &aggregate_scenario_times,
+                        aggregate_scenario_time_counter,
+                        aggregate_scenario_time_minimum,
+                        aggregate_scenario_time_maximum,
+                    ),
                 });
                 let mut scenarios_rows = Vec::new();
                 // Compile the scenario metrics template.
@@ -3453,6 +5713,7 @@ impl GooseAttack {
                     self.graph_data
                         .get_scenarios_per_second_graph(!self.configuration.no_granular_report)
                         .get_markup(&self.metrics.history, test_start_time),
+                    &percentile_headers,
                 );
             } else {
                 scenarios_template = "".to_string();
@@ -3475,6 +5736,20 @@ impl GooseAttack {
                 "".to_string()
             };
 
+            // Only build the thresholds template if thresholds were checked.
+            let thresholds_template: String = if !self.metrics.thresholds.is_empty() {
+                let threshold_rows = self
+                    .metrics
+                    .thresholds
+                    .iter()
+                    .map(report::threshold_row)
+                    .collect::<Vec<String>>();
+
+                report::thresholds_template(&threshold_rows.join("\n"))
+            } else {
+                "".to_string()
+            };
+
             // Only build the status_code template if --no-status-codes is not enabled.
             let status_code_template: String = if !self.configuration.no_status_codes {
                 let mut status_code_metrics = Vec::new();
@@ -3532,14 +5807,22 @@ impl GooseAttack {
                 &steps_overview,
                 hosts,
                 report::GooseReportTemplates {
+                    percentile_headers: &percentile_headers,
                     raw_requests_template: &raw_requests_rows.join("\n"),
                     raw_responses_template: &raw_responses_rows.join("\n"),
                     co_requests_template: &co_requests_template,
                     co_responses_template: &co_responses_template,
+                    request_timings_template: &request_timings_template,
+                    throughput_template: &throughput_template,
+                    cache_template: &cache_template,
                     transactions_template: &transactions_template,
+                    timers_template: &timers_template,
+                    custom_metrics_template: &custom_metrics_template,
+                    streams_template: &streams_template,
                     scenarios_template: &scenarios_template,
                     status_codes_template: &status_code_template,
                     errors_template: &errors_template,
+                    thresholds_template: &thresholds_template,
                     graph_rps_template: &self
                         .graph_data
                         .get_requests_per_second_graph(!self.configuration.no_granular_report)
@@ -3576,6 +5859,114 @@ impl GooseAttack {
     }
 }
 
+/// Returns the average of the times recorded in a [`GooseRequestMetricTimingData`].
+fn average_time(data: &GooseRequestMetricTimingData) -> f32 {
+    match data.counter {
+        0 => 0.00,
+        _ => data.total_time as f32 / data.counter as f32,
+    }
+}
+
+/// Builds a row of throughput metrics for the html report.
+fn throughput_metric(
+    method: &str,
+    name: &str,
+    duration: usize,
+    request_bytes: u64,
+    response_bytes: u64,
+    counter: usize,
+) -> report::ThroughputMetric {
+    let (request_bytes_average, _) = bytes_calculations(duration, request_bytes, counter);
+    let (response_bytes_average, response_bytes_per_second) =
+        bytes_calculations(duration, response_bytes, counter);
+    report::ThroughputMetric {
+        method: method.to_string(),
+        name: name.to_string(),
+        request_bytes: request_bytes as usize,
+        request_bytes_average: format!("{:.2}", request_bytes_average),
+        response_bytes: response_bytes as usize,
+        response_bytes_average: format!("{:.2}", response_bytes_average),
+        response_bytes_per_second: format!("{:.2}", response_bytes_per_second),
+    }
+}
+
+/// Returns the percentage of responses loaded from the HTTP cache, out of all cache hits
+/// and requests sent to the server.
+fn cache_hit_rate(cache_hits: usize, requests: usize) -> f32 {
+    match cache_hits + requests {
+        0 => 0.0,
+        total => cache_hits as f32 / total as f32 * 100.0,
+    }
+}
+
+/// Builds a row of HTTP cache metrics for the html report.
+fn cache_metric(
+    method: &str,
+    name: &str,
+    cache_hits: usize,
+    not_modified: usize,
+    requests: usize,
+) -> report::CacheMetric {
+    report::CacheMetric {
+        method: method.to_string(),
+        name: name.to_string(),
+        cache_hits,
+        not_modified,
+        hit_rate: format!("{:.1}", cache_hit_rate(cache_hits, requests)),
+    }
+}
+
+/// Merges the times recorded in one [`GooseRequestMetricTimingData`] into another.
+fn merge_timing_data(
+    merged: &mut GooseRequestMetricTimingData,
+    data: &GooseRequestMetricTimingData,
+) {
+    merged.times = merge_times(merged.times.clone(), data.times.clone());
+    merged.total_time += data.total_time;
+    merged.counter += data.counter;
+    merged.minimum_time = update_min_time(merged.minimum_time, data.minimum_time);
+    merged.maximum_time = update_max_time(merged.maximum_time, data.maximum_time);
+}
+
+/// Helper to format a row of timer counts, compressing 100.0% and 0.0% to 100% and 0%
+/// respectively to save width.
+fn format_timer_counts(
+    name: &str,
+    duration: usize,
+    total_count: usize,
+    fail_count: usize,
+) -> String {
+    let fail_percent = if fail_count > 0 {
+        fail_count as f32 / total_count as f32 * 100.0
+    } else {
+        0.0
+    };
+    let fail_and_percent = if fail_percent as usize == 100 || fail_percent as usize == 0 {
+        format!(
+            "{} ({}%)",
+            fail_count.to_formatted_string(&Locale::en),
+            fail_percent as usize
+        )
+    } else {
+        format!(
+            "{} ({:.1}%)",
+            fail_count.to_formatted_string(&Locale::en),
+            fail_percent
+        )
+    };
+    let (runs, fails) = per_second_calculations(duration, total_count, fail_count);
+    format!(
+        " {:<24} | {:>13} | {:>14} | {:>8.runs_p$} | {:>7.fails_p$}",
+        util::truncate_string(name, 24),
+        total_count.to_formatted_string(&Locale::en),
+        fail_and_percent,
+        runs,
+        fails,
+        runs_p = determine_precision(runs),
+        fails_p = determine_precision(fails),
+    )
+}
+
 /// Helper to calculate requests and fails per seconds.
 pub(crate) fn per_second_calculations(duration: usize, total: usize, fail: usize) -> (f32, f32) {
     let requests_per_second;
@@ -3590,6 +5981,20 @@ pub(crate) fn per_second_calculations(duration: usize, total: usize, fail: usize
     (requests_per_second, fails_per_second)
 }
 
+/// Returns the average bytes per request, and the bytes per second, given the total bytes
+/// transferred by `counter` requests during a load test that ran for `duration` seconds.
+pub(crate) fn bytes_calculations(duration: usize, total: u64, counter: usize) -> (f32, f32) {
+    let average = match counter {
+        0 => 0.0,
+        _ => total as f32 / counter as f32,
+    };
+    let per_second = match duration {
+        0 => 0.0,
+        _ => total as f32 / duration as f32,
+    };
+    (average, per_second)
+}
+
 fn determine_precision(value: f32) -> usize {
     if value < 1000.0 {
         2
@@ -3624,6 +6029,39 @@ pub(crate) fn merge_times(
     global_response_times
 }
 
+/// Returns the histogram bucket a time is stored in, identified by the time in the middle of
+/// the bucket.
+///
+/// Times are accurate to the given number of significant digits: times smaller than the
+/// smallest power of two larger than `2 * 10^significant_digits` are stored exactly, and
+/// the width of the buckets then doubles with each power of two.
+pub(crate) fn histogram_bucket(time: usize, significant_digits: usize) -> usize {
+    // The number of bits needed to store times with the requested precision.
+    let precision_bits =
+        usize::BITS - (2 * 10_usize.pow(significant_digits as u32) - 1).leading_zeros();
+    let time_bits = usize::BITS - time.leading_zeros();
+    if time_bits <= precision_bits {
+        time
+    } else {
+        // Drop the least significant bits beyond the requested precision.
+        let shift = time_bits - precision_bits;
+        ((time >> shift) << shift) + (1 << (shift - 1))
+    }
+}
+
+/// Counts a time in the histogram bucket it's stored in, accurate to the given number of
+/// significant digits.
+pub(crate) fn record_histogram_time(
+    times: &mut BTreeMap<usize, usize>,
+    time: usize,
+    significant_digits: usize,
+) {
+    let rounded_time = histogram_bucket(time, significant_digits);
+    let counter = times.entry(rounded_time).or_insert(0);
+    *counter += 1;
+    debug!("incremented {} counter: {}", rounded_time, counter);
+}
+
 /// A helper function to update the global minimum time based on local time.
 pub(crate) fn update_min_time(mut global_min: usize, min: usize) -> usize {
     if global_min == 0 || (min > 0 && min < global_min) {
@@ -3640,7 +6078,8 @@ pub(crate) fn update_max_time(mut global_max: usize, max: usize) -> usize {
     global_max
 }
 
-/// Get the response time that a certain number of percent of the requests finished within.
+/// Get the response time that a certain number of percent of the requests finished within,
+/// formatted for display.
 pub(crate) fn calculate_response_time_percentile(
     response_times: &BTreeMap<usize, usize>,
     total_requests: usize,
@@ -3648,6 +6087,23 @@ pub(crate) fn calculate_response_time_percentile(
     max: usize,
     percent: f32,
 ) -> String {
+    format_number(response_time_percentile(
+        response_times,
+        total_requests,
+        min,
+        max,
+        percent,
+    ))
+}
+
+/// Get the response time that a certain number of percent of the requests finished within.
+pub(crate) fn response_time_percentile(
+    response_times: &BTreeMap<usize, usize>,
+    total_requests: usize,
+    min: usize,
+    max: usize,
+    percent: f32,
+) -> usize {
     let percentile_request = (total_requests as f32 * percent).round() as usize;
     debug!(
         "percentile: {}, request {} of total {}",
@@ -3660,15 +6116,66 @@ pub(crate) fn calculate_response_time_percentile(
         total_count += counter;
         if total_count >= percentile_request {
             if *value < min {
-                return format_number(min);
+                return min;
             } else if *value > max {
-                return format_number(max);
+                return max;
             } else {
-                return format_number(*value);
+                return *value;
             }
         }
     }
-    format_number(0)
+    0
+}
+
+/// Parses the `--thresholds` option, a semicolon separated list of thresholds such as
+/// `GET /api p95 < 300ms; error rate < 1%`.
+pub(crate) fn parse_thresholds(thresholds: &str) -> Result<Vec<GooseThreshold>, GooseError> {
+    thresholds
+        .split(';')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(GooseThreshold::from_str)
+        .collect()
+}
+
+/// Parses the `--abort-on` option, a semicolon separated list of abort conditions such as
+/// `error rate > 5% over 30s; GET /api p95 > 2000ms for 1m`.
+pub(crate) fn parse_abort_conditions(
+    abort_on: &str,
+) -> Result<Vec<GooseAbortCondition>, GooseError> {
+    abort_on
+        .split(';')
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(GooseAbortCondition::from_str)
+        .collect()
+}
+
+/// Parses the `--percentiles` option, a comma separated list of percentiles such as
+/// `50,90,99.5`.
+pub(crate) fn parse_percentiles(percentiles: &str) -> Result<Vec<f64>, GooseError> {
+    let invalid = |value: &str| GooseError::InvalidOption {
+        option: "`configuration.percentiles`".to_string(),
+        value: value.to_string(),
+        detail: "`configuration.percentiles` must be a comma separated list of numbers greater \
+                 than 0 and at most 100."
+            .to_string(),
+    };
+    let parsed = percentiles
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(
+            |percentile| match percentile.trim_end_matches('%').parse::<f64>() {
+                Ok(p) if p > 0.0 && p <= 100.0 => Ok(p),
+                _ => Err(invalid(percentile)),
+            },
+        )
+        .collect::<Result<Vec<f64>, GooseError>>()?;
+    if parsed.is_empty() {
+        return Err(invalid(percentiles));
+    }
+    Ok(parsed)
 }
 
 /// Helper to count and aggregate seen status codes.
@@ -3851,8 +6358,9 @@ mod test {
         assert_eq!(request.success_count, 0);
         assert_eq!(request.fail_count, 0);
 
-        // Tracking a response time updates several fields.
-        request.record_time(1, false);
+        // Tracking a response time updates several fields. Times are stored accurate to a
+        // single significant digit, so rounding is easy to see.
+        request.record_time(1, false, 1);
         // We've seen only one response time so far.
         assert_eq!(request.raw_data.times.len(), 1);
         // We've seen one response time of length 1.
@@ -3873,7 +6381,7 @@ mod test {
         assert_eq!(request.fail_count, 0);
 
         // Tracking another response time updates all related fields.
-        request.record_time(10, false);
+        request.record_time(10, false, 1);
         // We've added a new unique response time.
         assert_eq!(request.raw_data.times.len(), 2);
         // We've seen the 10 ms response time 1 time.
@@ -3894,7 +6402,7 @@ mod test {
         assert_eq!(request.fail_count, 0);
 
         // Tracking another response time updates all related fields.
-        request.record_time(10, false);
+        request.record_time(10, false, 1);
         // We've incremented the counter of an existing response time.
         assert_eq!(request.raw_data.times.len(), 2);
         // We've seen the 10 ms response time 2 times.
@@ -3909,11 +6417,11 @@ mod test {
         assert_eq!(request.raw_data.counter, 3);
 
         // Tracking another response time updates all related fields.
-        request.record_time(101, false);
+        request.record_time(101, false, 1);
         // We've added a new response time for the first time.
         assert_eq!(request.raw_data.times.len(), 3);
-        // The response time was internally rounded to 100, which we've seen once.
-        assert_eq!(request.raw_data.times[&100], 1);
+        // The response time was internally rounded up to 102, which we've seen once.
+        assert_eq!(request.raw_data.times[&102], 1);
         // Minimum doesn't change.
         assert_eq!(request.raw_data.minimum_time, 1);
         // Maximum increases to actual maximum, not rounded maximum.
@@ -3924,11 +6432,11 @@ mod test {
         assert_eq!(request.raw_data.counter, 4);
 
         // Tracking another response time updates all related fields.
-        request.record_time(102, false);
-        // Due to rounding, this increments the existing 100 ms response time.
+        request.record_time(102, false, 1);
+        // Due to rounding, this increments the existing 102 ms response time.
         assert_eq!(request.raw_data.times.len(), 3);
-        // The response time was internally rounded to 100, which we've now seen twice.
-        assert_eq!(request.raw_data.times[&100], 2);
+        // The response time was stored as 102, which we've now seen twice.
+        assert_eq!(request.raw_data.times[&102], 2);
         // Minimum doesn't change.
         assert_eq!(request.raw_data.minimum_time, 1);
         // Maximum increases to actual maximum, not rounded maximum.
@@ -3939,11 +6447,11 @@ mod test {
         assert_eq!(request.raw_data.counter, 5);
 
         // Tracking another response time updates all related fields.
-        request.record_time(155, false);
+        request.record_time(155, false, 1);
         // Adds a new response time.
         assert_eq!(request.raw_data.times.len(), 4);
-        // The response time was internally rounded to 160, seen for the first time.
-        assert_eq!(request.raw_data.times[&160], 1);
+        // The response time was internally rounded up to 156, seen for the first time.
+        assert_eq!(request.raw_data.times[&156], 1);
         // Minimum doesn't change.
         assert_eq!(request.raw_data.minimum_time, 1);
         // Maximum increases to actual maximum, not rounded maximum.
@@ -3954,11 +6462,11 @@ mod test {
         assert_eq!(request.raw_data.counter, 6);
 
         // Tracking another response time updates all related fields.
-        request.record_time(2345, false);
+        request.record_time(2345, false, 1);
         // Adds a new response time.
         assert_eq!(request.raw_data.times.len(), 5);
-        // The response time was internally rounded to 2000, seen for the first time.
-        assert_eq!(request.raw_data.times[&2000], 1);
+        // The response time was internally rounded up to 2368, seen for the first time.
+        assert_eq!(request.raw_data.times[&2368], 1);
         // Minimum doesn't change.
         assert_eq!(request.raw_data.minimum_time, 1);
         // Maximum increases to actual maximum, not rounded maximum.
@@ -3969,11 +6477,11 @@ mod test {
         assert_eq!(request.raw_data.counter, 7);
 
         // Tracking another response time updates all related fields.
-        request.record_time(987654321, false);
+        request.record_time(987654321, false, 1);
         // Adds a new response time.
         assert_eq!(request.raw_data.times.len(), 6);
-        // The response time was internally rounded to 987654000, seen for the first time.
-        assert_eq!(request.raw_data.times[&987654000], 1);
+        // The response time was internally rounded up to 989855744, seen for the first time.
+        assert_eq!(request.raw_data.times[&989855744], 1);
         // Minimum doesn't change.
         assert_eq!(request.raw_data.minimum_time, 1);
         // Maximum increases to actual maximum, not rounded maximum.
@@ -4042,4 +6550,381 @@ mod test {
         assert_eq!(request.raw_data.total_time, 987657045);
         assert_eq!(request.raw_data.counter, 8);
     }
+
+    #[test]
+    fn percentiles() {
+        // Percentiles are a comma separated list, optionally with percent signs.
+        assert_eq!(
+            parse_percentiles(DEFAULT_PERCENTILES).unwrap(),
+            vec![50.0, 75.0, 98.0, 99.0, 99.9, 99.99]
+        );
+        assert_eq!(
+            parse_percentiles("90, 99.5%,99.99").unwrap(),
+            vec![90.0, 99.5, 99.99]
+        );
+        assert_eq!(parse_percentiles("100").unwrap(), vec![100.0]);
+
+        // At least one percentile between 0 and 100 is required.
+        assert!(parse_percentiles("").is_err());
+        assert!(parse_percentiles(",").is_err());
+        assert!(parse_percentiles("0").is_err());
+        assert!(parse_percentiles("50,100.1").is_err());
+        assert!(parse_percentiles("p99").is_err());
+
+        // Numeric percentiles are calculated like the displayed percentiles.
+        let mut response_times: BTreeMap<usize, usize> = BTreeMap::new();
+        response_times.insert(1, 1);
+        response_times.insert(2, 2);
+        response_times.insert(1_500, 1);
+        assert_eq!(
+            response_time_percentile(&response_times, 4, 1, 1_500, 0.5),
+            2
+        );
+        assert_eq!(
+            calculate_response_time_percentile(&response_times, 4, 1, 1_500, 0.995),
+            "1,500"
+        );
+    }
+
+    #[test]
+    fn thresholds() {
+        // Thresholds define an optional target, a metric, an operator and a limit.
+        let threshold: GooseThreshold = "GET /api p95 < 300ms".parse().unwrap();
+        assert_eq!(
+            threshold.target,
+            GooseThresholdTarget::Request("GET /api".to_string())
+        );
+        assert_eq!(threshold.metric, GooseThresholdMetric::Percentile(95.0));
+        assert_eq!(threshold.operator, GooseThresholdOperator::Less);
+        assert_eq!(threshold.limit, 300.0);
+        let threshold: GooseThreshold = "error rate <= 1%".parse().unwrap();
+        assert_eq!(threshold.target, GooseThresholdTarget::Requests);
+        assert_eq!(threshold.metric, GooseThresholdMetric::ErrorRate);
+        assert_eq!(threshold.operator, GooseThresholdOperator::LessOrEqual);
+        assert_eq!(threshold.limit, 1.0);
+        let threshold: GooseThreshold = "transaction checkout avg < 2s".parse().unwrap();
+        assert_eq!(
+            threshold.target,
+            GooseThresholdTarget::Transaction("checkout".to_string())
+        );
+        assert_eq!(threshold.metric, GooseThresholdMetric::Average);
+        assert_eq!(threshold.limit, 2_000.0);
+        let threshold: GooseThreshold = "scenario Anonymous rps >= 10/s".parse().unwrap();
+        assert_eq!(
+            threshold.target,
+            GooseThresholdTarget::Scenario("Anonymous".to_string())
+        );
+        assert_eq!(threshold.metric, GooseThresholdMetric::PerSecond);
+        assert_eq!(threshold.operator, GooseThresholdOperator::GreaterOrEqual);
+
+        // Thresholds are a semicolon separated list.
+        assert!(parse_thresholds("").unwrap().is_empty());
+        assert_eq!(
+            parse_thresholds("p99 < 1s; GET / max > 0").unwrap().len(),
+            2
+        );
+
+        // Invalid thresholds are rejected.
+        assert!(parse_thresholds("p95 300ms").is_err());
+        assert!(parse_thresholds("GET / p101 < 300ms").is_err());
+        assert!(parse_thresholds("GET / slow < 300ms").is_err());
+        assert!(parse_thresholds("GET / avg < fast").is_err());
+        assert!(matches!(
+            parse_thresholds("scenario Anonymous error rate < 1%"),
+            Err(GooseError::InvalidOption { .. })
+        ));
+
+        // Thresholds are checked against the final metrics.
+        let mut request = GooseRequestMetricAggregate::new("/", GooseMethod::Get, 0);
+        for time in [100, 200, 300, 400] {
+            request.record_time(time, false, 3);
+            request.success_count += 1;
+        }
+        request.record_time(500, false, 3);
+        request.fail_count += 1;
+        let mut metrics = GooseMetrics::default();
+        metrics.requests.insert("GET /".to_string(), request);
+        metrics.check_thresholds(&parse_thresholds("GET / avg < 300ms;GET / max <= 500ms;error rate < 10%;transaction checkout avg < 2s").unwrap());
+        assert_eq!(metrics.thresholds[0].value, Some(300.0));
+        assert!(!metrics.thresholds[0].passed);
+        assert!(metrics.thresholds[1].passed);
+        assert_eq!(metrics.thresholds[2].value, Some(20.0));
+        assert_eq!(metrics.thresholds[2].formatted_value(), "20.00%");
+        assert!(!metrics.thresholds[2].passed);
+        // Thresholds for targets that weren't seen fail.
+        assert_eq!(metrics.thresholds[3].value, None);
+        assert!(!metrics.thresholds[3].passed);
+    }
+
+    #[test]
+    fn abort_conditions() {
+        // Abort conditions are thresholds optionally followed by a window and a duration.
+        let abort_condition: GooseAbortCondition =
+            "GET /api p95 > 2000ms over 30s for 1m".parse().unwrap();
+        assert_eq!(
+            abort_condition.condition.target,
+            GooseThresholdTarget::Request("GET /api".to_string())
+        );
+        assert_eq!(abort_condition.condition.limit, 2_000.0);
+        assert_eq!(abort_condition.window, Some(30));
+        assert_eq!(abort_condition.duration, Some(60));
+        let abort_condition: GooseAbortCondition = "error rate > 5% for 10s".parse().unwrap();
+        assert_eq!(
+            abort_condition.condition.metric,
+            GooseThresholdMetric::ErrorRate
+        );
+        assert_eq!(abort_condition.window, None);
+        assert_eq!(abort_condition.duration, Some(10));
+        assert_eq!(
+            parse_abort_conditions("error rate > 5%; p99 > 1s over 1m")
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // Invalid abort conditions are rejected.
+        assert!(parse_abort_conditions("error rate > 5% over soon").is_err());
+        assert!(parse_abort_conditions("error rate > 5% over 30s over 1m").is_err());
+        assert!(parse_abort_conditions("error rate over 30s").is_err());
+
+        // Abort conditions without a window or duration are met immediately.
+        let mut request = GooseRequestMetricAggregate::new("/", GooseMethod::Get, 0);
+        request.record_time(100, false, 3);
+        request.fail_count += 1;
+        let mut metrics = GooseMetrics::default();
+        metrics.requests.insert("GET /".to_string(), request);
+        let mut state = GooseAbortConditionState::new("error rate > 5%".parse().unwrap());
+        assert_eq!(
+            state.check(&metrics),
+            Some("error rate > 5%, measured 100.00%".to_string())
+        );
+
+        // Abort conditions with a window only measure what happened since the window started.
+        let mut state = GooseAbortConditionState::new("error rate > 5% over 30s".parse().unwrap());
+        assert_eq!(state.check(&metrics), None);
+        let mut earlier = ThresholdData::default();
+        earlier.merge(&BTreeMap::from([(100, 2), (200, 1)]), 3, 400, 100, 200, 1);
+        let mut later = earlier.clone();
+        later.merge(&BTreeMap::from([(300, 1), (400, 1)]), 2, 700, 300, 400, 2);
+        let window = later.since(&earlier);
+        assert_eq!(window.counter, 2);
+        assert_eq!((window.min, window.max), (300, 400));
+        assert_eq!(
+            window.measure(&GooseThresholdMetric::Average, 1),
+            Some(350.0)
+        );
+        assert_eq!(
+            window.measure(&GooseThresholdMetric::ErrorRate, 1),
+            Some(100.0)
+        );
+
+        // Abort conditions with a duration must be met for long enough.
+        let mut state = GooseAbortConditionState::new("error rate > 5% for 1m".parse().unwrap());
+        assert_eq!(state.check(&metrics), None);
+        assert!(state.breached.is_some());
+    }
+
+    #[test]
+    fn histogram_buckets() {
+        // With 3 significant digits, times up to 2047ms are stored exactly.
+        assert_eq!(histogram_bucket(0, 3), 0);
+        assert_eq!(histogram_bucket(1437, 3), 1437);
+        assert_eq!(histogram_bucket(2047, 3), 2047);
+        // Larger times are stored as the middle of their bucket, and never off by more than
+        // 0.1%.
+        assert_eq!(histogram_bucket(2048, 3), 2049);
+        assert_eq!(histogram_bucket(2049, 3), 2049);
+        assert_eq!(histogram_bucket(4096, 3), 4098);
+        assert_eq!(histogram_bucket(4099, 3), 4098);
+        for time in [2_345, 98_765, 987_654_321] {
+            let bucket = histogram_bucket(time, 3);
+            assert!(bucket.abs_diff(time) < time / 1_000);
+        }
+        // More significant digits store larger times exactly.
+        assert_eq!(histogram_bucket(1437, 2), 1436);
+        assert_eq!(histogram_bucket(98_765, 5), 98_765);
+
+        // Percentiles of a slow endpoint are accurate, even after merging times.
+        let mut raw_data = GooseRequestMetricTimingData::new(None);
+        for time in 1_380..1_480 {
+            raw_data.record_time(time, 3);
+        }
+        let merged_times = merge_times(raw_data.times.clone(), raw_data.times.clone());
+        assert_eq!(
+            calculate_response_time_percentile(&merged_times, 200, 1_380, 1_479, 0.99),
+            "1,478"
+        );
+    }
+
+    #[test]
+    fn timer_metric_aggregate() {
+        let mut timer = TimerMetricAggregate::new("checkout");
+        assert_eq!(timer.name, "checkout");
+        assert_eq!(timer.counter, 0);
+
+        // Short times are not rounded.
+        timer.set_time(42, true, 2);
+        assert_eq!(timer.min_time, 42);
+        assert_eq!(timer.max_time, 42);
+        assert_eq!(timer.times[&42], 1);
+
+        // Longer times are rounded to two significant digits when stored, but not when
+        // tracking min, max and total.
+        timer.set_time(654, false, 2);
+        timer.set_time(1499, true, 2);
+        assert_eq!(timer.min_time, 42);
+        assert_eq!(timer.max_time, 1499);
+        assert_eq!(timer.total_time, 42 + 654 + 1499);
+        assert_eq!(timer.times[&654], 1);
+        assert_eq!(timer.times[&1500], 1);
+        assert_eq!(timer.counter, 3);
+        assert_eq!(timer.success_count, 2);
+        assert_eq!(timer.fail_count, 1);
+    }
+
+    #[test]
+    fn custom_metric_aggregates() {
+        let mut counter = CounterMetricAggregate::new("items");
+        counter.increment(3);
+        counter.increment(0);
+        counter.increment(2);
+        assert_eq!(counter.total, 5);
+        assert_eq!(counter.updates, 3);
+
+        // Gauges track negative values, and the most recent value is not always the largest.
+        let mut gauge = GaugeMetricAggregate::new("queue");
+        gauge.set(5.5);
+        assert_eq!(gauge.minimum, 5.5);
+        assert_eq!(gauge.maximum, 5.5);
+        gauge.set(-2.0);
+        gauge.set(3.0);
+        assert_eq!(gauge.value, 3.0);
+        assert_eq!(gauge.minimum, -2.0);
+        assert_eq!(gauge.maximum, 5.5);
+        assert_eq!(gauge.updates, 3);
+
+        // Histograms track each value that is recorded, including 0.
+        let mut histogram = HistogramMetricAggregate::new("results");
+        histogram.record(10, DEFAULT_SIGNIFICANT_DIGITS);
+        histogram.record(0, DEFAULT_SIGNIFICANT_DIGITS);
+        histogram.record(10, DEFAULT_SIGNIFICANT_DIGITS);
+        assert_eq!(histogram.minimum, 0);
+        assert_eq!(histogram.maximum, 10);
+        assert_eq!(histogram.total, 20);
+        assert_eq!(histogram.counter, 3);
+        assert_eq!(histogram.values[&10], 2);
+        assert_eq!(histogram.values[&0], 1);
+
+        // Large values are stored in the same buckets as request times, so similar values
+        // share a counter, while the minimum, maximum and total remain exact.
+        histogram.record(123_456, DEFAULT_SIGNIFICANT_DIGITS);
+        histogram.record(123_457, DEFAULT_SIGNIFICANT_DIGITS);
+        assert_eq!(histogram.maximum, 123_457);
+        assert_eq!(histogram.total, 20 + 123_456 + 123_457);
+        assert_eq!(
+            histogram.values[&histogram_bucket(123_456, DEFAULT_SIGNIFICANT_DIGITS)],
+            2
+        );
+        assert_eq!(histogram.values.len(), 3);
+    }
+
+    #[test]
+    fn stream_metric_aggregate() {
+        let mut aggregate = StreamMetricAggregate::new(GooseMethod::Get, "events");
+
+        let mut stream = StreamMetric::new(0, GooseMethod::Get, "events", 0);
+        stream.time_to_first_byte = Some(4);
+        stream.time_to_first_event = Some(6);
+        stream.events = 3;
+        stream.time_between_events = vec![100, 120];
+        stream.duration = 330;
+        aggregate.record(&stream, DEFAULT_SIGNIFICANT_DIGITS);
+
+        // A stream that failed before receiving anything only records its duration.
+        let mut failed = StreamMetric::new(500, GooseMethod::Get, "events", 1);
+        failed.success = false;
+        failed.duration = 12;
+        aggregate.record(&failed, DEFAULT_SIGNIFICANT_DIGITS);
+
+        assert_eq!(aggregate.counter, 2);
+        assert_eq!(aggregate.fail_count, 1);
+        assert_eq!(aggregate.events, 3);
+        assert_eq!(aggregate.time_to_first_byte.counter, 1);
+        assert_eq!(aggregate.time_to_first_byte.total_time, 4);
+        assert_eq!(aggregate.time_to_first_event.counter, 1);
+        assert_eq!(aggregate.time_to_first_event.minimum_time, 6);
+        assert_eq!(aggregate.time_between_events.counter, 2);
+        assert_eq!(aggregate.time_between_events.minimum_time, 100);
+        assert_eq!(aggregate.time_between_events.maximum_time, 120);
+        assert_eq!(aggregate.duration.counter, 2);
+        assert_eq!(aggregate.duration.total_time, 342);
+        assert_eq!(average_time(&aggregate.duration), 171.0);
+    }
+
+    #[test]
+    fn body_bytes() {
+        // 3,000 bytes received by 4 requests during a 2 second load test.
+        let (average, per_second) = bytes_calculations(2, 3_000, 4);
+        assert_eq!(average, 750.0);
+        assert_eq!(per_second, 1_500.0);
+
+        // Nothing to divide by.
+        assert_eq!(bytes_calculations(0, 3_000, 0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn cache_hits() {
+        // 3 of 4 responses were loaded from the cache.
+        assert_eq!(cache_hit_rate(3, 1), 75.0);
+        // Nothing to divide by.
+        assert_eq!(cache_hit_rate(0, 0), 0.0);
+    }
+
+    #[test]
+    fn request_timings_aggregate() {
+        let mut aggregate = GooseRequestTimingsAggregate::new();
+
+        // A request that opened a new connection.
+        aggregate.record(
+            &GooseRequestTimings {
+                dns: Some(3),
+                connect: Some(7),
+                time_to_first_byte: Some(20),
+                download: Some(5),
+            },
+            DEFAULT_SIGNIFICANT_DIGITS,
+        );
+        // A request that reused the connection.
+        aggregate.record(
+            &GooseRequestTimings {
+                dns: None,
+                connect: None,
+                time_to_first_byte: Some(10),
+                download: Some(1),
+            },
+            DEFAULT_SIGNIFICANT_DIGITS,
+        );
+        // A request that never received a response.
+        aggregate.record(&GooseRequestTimings::default(), DEFAULT_SIGNIFICANT_DIGITS);
+
+        assert_eq!(aggregate.dns.counter, 1);
+        assert_eq!(aggregate.dns.total_time, 3);
+        assert_eq!(aggregate.connect.counter, 1);
+        assert_eq!(aggregate.connect.total_time, 7);
+        assert_eq!(aggregate.time_to_first_byte.counter, 2);
+        assert_eq!(aggregate.time_to_first_byte.minimum_time, 10);
+        assert_eq!(aggregate.time_to_first_byte.maximum_time, 20);
+        assert_eq!(average_time(&aggregate.time_to_first_byte), 15.0);
+        assert_eq!(aggregate.download.counter, 2);
+        assert_eq!(aggregate.download.total_time, 6);
+
+        // Timings are merged when aggregating all requests.
+        let mut merged = GooseRequestTimingsAggregate::new();
+        merge_timing_data(&mut merged.download, &aggregate.download);
+        merge_timing_data(&mut merged.download, &aggregate.download);
+        assert_eq!(merged.download.counter, 4);
+        assert_eq!(merged.download.minimum_time, 1);
+        assert_eq!(merged.download.maximum_time, 5);
+        assert_eq!(average_time(&merged.download), 3.0);
+    }
 }
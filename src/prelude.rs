@@ -8,9 +8,12 @@
 //! ```
 
 pub use crate::config::{GooseDefault, GooseDefaultType};
+#[cfg(feature = "grpc")]
+pub use crate::goose::GooseGrpcResponse;
 pub use crate::goose::{
-    GooseMethod, GooseRequest, GooseUser, Scenario, Transaction, TransactionError,
-    TransactionFunction, TransactionResult,
+    GooseAuth, GooseCookie, GooseHttpVersion, GooseMethod, GooseMultipartForm, GooseNetworkProfile,
+    GooseOperation, GooseRequest, GooseResources, GooseStream, GooseUser, GooseWebSocketMessage,
+    Scenario, Transaction, TransactionError, TransactionFunction, TransactionResult,
 };
 pub use crate::metrics::{GooseCoordinatedOmissionMitigation, GooseMetrics};
 pub use crate::{scenario, transaction, GooseAttack, GooseError, GooseScheduler};
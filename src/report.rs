@@ -3,21 +3,28 @@
 use crate::metrics;
 
 use std::collections::BTreeMap;
-use std::mem;
 
 use serde::Serialize;
 
 /// The following templates are necessary to build an html-formatted summary report.
 #[derive(Debug)]
 pub(crate) struct GooseReportTemplates<'a> {
+    pub percentile_headers: &'a str,
     pub raw_requests_template: &'a str,
     pub raw_responses_template: &'a str,
     pub co_requests_template: &'a str,
     pub co_responses_template: &'a str,
+    pub request_timings_template: &'a str,
+    pub throughput_template: &'a str,
+    pub cache_template: &'a str,
     pub transactions_template: &'a str,
+    pub timers_template: &'a str,
+    pub custom_metrics_template: &'a str,
+    pub streams_template: &'a str,
     pub scenarios_template: &'a str,
     pub status_codes_template: &'a str,
     pub errors_template: &'a str,
+    pub thresholds_template: &'a str,
     pub graph_rps_template: &'a str,
     pub graph_average_response_time_template: &'a str,
     pub graph_users_per_second: &'a str,
@@ -52,14 +59,40 @@ pub(crate) struct CORequestMetric {
 pub(crate) struct ResponseMetric {
     pub method: String,
     pub name: String,
-    pub percentile_50: String,
-    pub percentile_60: String,
-    pub percentile_70: String,
-    pub percentile_80: String,
-    pub percentile_90: String,
-    pub percentile_95: String,
-    pub percentile_99: String,
-    pub percentile_100: String,
+    pub percentiles: Vec<String>,
+}
+
+/// Defines the metrics reported about the phases of requests.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct RequestTimingsMetric {
+    pub method: String,
+    pub name: String,
+    pub dns_average: String,
+    pub connect_average: String,
+    pub time_to_first_byte_average: String,
+    pub download_average: String,
+}
+
+/// Defines the metrics reported about bytes sent and received in bodies.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ThroughputMetric {
+    pub method: String,
+    pub name: String,
+    pub request_bytes: usize,
+    pub request_bytes_average: String,
+    pub response_bytes: usize,
+    pub response_bytes_average: String,
+    pub response_bytes_per_second: String,
+}
+
+/// Defines the metrics reported about the HTTP cache.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct CacheMetric {
+    pub method: String,
+    pub name: String,
+    pub cache_hits: usize,
+    pub not_modified: usize,
+    pub hit_rate: String,
 }
 
 /// Defines the metrics reported about transactions.
@@ -75,6 +108,64 @@ pub(crate) struct TransactionMetric {
     pub response_time_maximum: usize,
     pub requests_per_second: String,
     pub failures_per_second: String,
+    pub percentiles: Vec<String>,
+}
+
+/// Defines the metrics reported about custom timers.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct TimerMetric {
+    pub name: String,
+    pub number_of_runs: usize,
+    pub number_of_failures: usize,
+    pub time_average: String,
+    pub time_minimum: usize,
+    pub time_maximum: usize,
+    pub timers_per_second: String,
+    pub failures_per_second: String,
+}
+
+/// Defines the metrics reported about custom counters.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct CounterMetric {
+    pub name: String,
+    pub total: u64,
+    pub updates: usize,
+    pub total_per_second: String,
+}
+
+/// Defines the metrics reported about custom gauges.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct GaugeMetric {
+    pub name: String,
+    pub value: String,
+    pub minimum: String,
+    pub maximum: String,
+    pub updates: usize,
+}
+
+/// Defines the metrics reported about custom histograms.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct HistogramMetric {
+    pub name: String,
+    pub counter: usize,
+    pub average: String,
+    pub minimum: usize,
+    pub maximum: usize,
+    pub median: usize,
+}
+
+/// Defines the metrics reported about streaming responses.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct StreamMetric {
+    pub method: String,
+    pub name: String,
+    pub number_of_streams: usize,
+    pub number_of_failures: usize,
+    pub events_average: String,
+    pub time_to_first_byte_average: String,
+    pub time_to_first_event_average: String,
+    pub time_between_events_average: String,
+    pub duration_average: String,
 }
 
 /// Defines the metrics reported about scenarios.
@@ -88,6 +179,7 @@ pub(crate) struct ScenarioMetric {
     pub response_time_maximum: usize,
     pub count_per_second: String,
     pub iterations: String,
+    pub percentiles: Vec<String>,
 }
 
 /// Defines the metrics reported about status codes.
@@ -97,40 +189,67 @@ pub(crate) struct StatusCodeMetric {
     pub status_codes: String,
 }
 
+/// Helper to calculate the configured percentiles of a set of times.
+pub(crate) fn get_percentiles(
+    percentiles: &[f64],
+    times: &BTreeMap<usize, usize>,
+    total_count: usize,
+    time_minimum: usize,
+    time_maximum: usize,
+) -> Vec<String> {
+    percentiles
+        .iter()
+        .map(|percentile| {
+            metrics::calculate_response_time_percentile(
+                times,
+                total_count,
+                time_minimum,
+                time_maximum,
+                (percentile / 100.0) as f32,
+            )
+        })
+        .collect()
+}
+
 /// Helper to generate a single response metric.
 pub(crate) fn get_response_metric(
     method: &str,
     name: &str,
+    percentiles: &[f64],
     response_times: &BTreeMap<usize, usize>,
     total_request_count: usize,
     response_time_minimum: usize,
     response_time_maximum: usize,
 ) -> ResponseMetric {
-    // Calculate percentiles in a loop.
-    let mut percentiles = Vec::new();
-    for percent in &[0.5, 0.6, 0.7, 0.8, 0.9, 0.95, 0.99, 1.0] {
-        percentiles.push(metrics::calculate_response_time_percentile(
+    ResponseMetric {
+        method: method.to_string(),
+        name: name.to_string(),
+        percentiles: get_percentiles(
+            percentiles,
             response_times,
             total_request_count,
             response_time_minimum,
             response_time_maximum,
-            *percent,
-        ));
+        ),
     }
+}
 
-    // Now take the Strings out of the Vector and build a ResponseMetric object.
-    ResponseMetric {
-        method: method.to_string(),
-        name: name.to_string(),
-        percentile_50: mem::take(&mut percentiles[0]),
-        percentile_60: mem::take(&mut percentiles[1]),
-        percentile_70: mem::take(&mut percentiles[2]),
-        percentile_80: mem::take(&mut percentiles[3]),
-        percentile_90: mem::take(&mut percentiles[4]),
-        percentile_95: mem::take(&mut percentiles[5]),
-        percentile_99: mem::take(&mut percentiles[6]),
-        percentile_100: mem::take(&mut percentiles[7]),
-    }
+/// Build the table headers of the configured percentiles in the html report.
+pub(crate) fn percentile_headers(percentiles: &[f64]) -> String {
+    percentiles
+        .iter()
+        .map(|percentile| format!("<th>{}%ile (ms)</th>", percentile))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Build the table cells of the configured percentiles in the html report.
+fn percentile_cells(percentiles: &[String]) -> String {
+    percentiles
+        .iter()
+        .map(|percentile| format!("<td>{}</td>", percentile))
+        .collect::<Vec<String>>()
+        .join("\n")
 }
 
 /// Build an individual row of raw request metrics in the html report.
@@ -165,25 +284,11 @@ pub(crate) fn response_metrics_row(metric: ResponseMetric) -> String {
         r#"<tr>
             <td>{method}</td>
             <td>{name}</td>
-            <td>{percentile_50}</td>
-            <td>{percentile_60}</td>
-            <td>{percentile_70}</td>
-            <td>{percentile_80}</td>
-            <td>{percentile_90}</td>
-            <td>{percentile_95}</td>
-            <td>{percentile_99}</td>
-            <td>{percentile_100}</td>
+            {percentiles}
         </tr>"#,
         method = metric.method,
         name = metric.name,
-        percentile_50 = metric.percentile_50,
-        percentile_60 = metric.percentile_60,
-        percentile_70 = metric.percentile_70,
-        percentile_80 = metric.percentile_80,
-        percentile_90 = metric.percentile_90,
-        percentile_95 = metric.percentile_95,
-        percentile_99 = metric.percentile_99,
-        percentile_100 = metric.percentile_100,
+        percentiles = percentile_cells(&metric.percentiles),
     )
 }
 
@@ -233,7 +338,10 @@ pub(crate) fn coordinated_omission_request_metrics_row(metric: CORequestMetric)
 
 /// If Coordinated Omission Mitigation is triggered, add a relevant response table to the
 /// html report.
-pub(crate) fn coordinated_omission_response_metrics_template(co_responses_rows: &str) -> String {
+pub(crate) fn coordinated_omission_response_metrics_template(
+    co_responses_rows: &str,
+    percentile_headers: &str,
+) -> String {
     format!(
         r#"<div class="responses">
         <h2>Response Time Metrics With Coordinated Omission Mitigation</h2>
@@ -242,14 +350,7 @@ pub(crate) fn coordinated_omission_response_metrics_template(co_responses_rows:
                 <tr>
                     <th>Method</th>
                     <th>Name</th>
-                    <th>50%ile (ms)</th>
-                    <th>60%ile (ms)</th>
-                    <th>70%ile (ms)</th>
-                    <th>80%ile (ms)</th>
-                    <th>90%ile (ms)</th>
-                    <th>95%ile (ms)</th>
-                    <th>99%ile (ms)</th>
-                    <th>100%ile (ms)</th>
+                    {percentile_headers}
                 </tr>
             </thead>
             <tbody>
@@ -258,6 +359,7 @@ pub(crate) fn coordinated_omission_response_metrics_template(co_responses_rows:
         </table>
     </div>"#,
         co_responses_rows = co_responses_rows,
+        percentile_headers = percentile_headers,
     )
 }
 
@@ -268,25 +370,152 @@ pub(crate) fn coordinated_omission_response_metrics_row(metric: ResponseMetric)
         r#"<tr>
             <td>{method}</td>
             <td>{name}</td>
-            <td>{percentile_50}</td>
-            <td>{percentile_60}</td>
-            <td>{percentile_70}</td>
-            <td>{percentile_80}</td>
-            <td>{percentile_90}</td>
-            <td>{percentile_95}</td>
-            <td>{percentile_99}</td>
-            <td>{percentile_100}</td>
+            {percentiles}
         </tr>"#,
         method = metric.method,
         name = metric.name,
-        percentile_50 = metric.percentile_50,
-        percentile_60 = metric.percentile_60,
-        percentile_70 = metric.percentile_70,
-        percentile_80 = metric.percentile_80,
-        percentile_90 = metric.percentile_90,
-        percentile_95 = metric.percentile_95,
-        percentile_99 = metric.percentile_99,
-        percentile_100 = metric.percentile_100,
+        percentiles = percentile_cells(&metric.percentiles),
+    )
+}
+
+/// Add a table of how long each phase of requests took to the html report.
+pub(crate) fn request_timings_metrics_template(request_timings_rows: &str) -> String {
+    format!(
+        r#"<div class="request_timings">
+        <h2>Request Timing Metrics</h2>
+
+        <table>
+            <thead>
+                <tr>
+                    <th>Method</th>
+                    <th>Name</th>
+                    <th>DNS (ms)</th>
+                    <th>Connect (ms)</th>
+                    <th>Time To First Byte (ms)</th>
+                    <th>Download (ms)</th>
+                </tr>
+            </thead>
+            <tbody>
+                {request_timings_rows}
+            </tbody>
+        </table>
+    </div>"#,
+        request_timings_rows = request_timings_rows,
+    )
+}
+
+/// Build an individual row of request timing metrics in the html report.
+pub(crate) fn request_timings_metrics_row(metric: RequestTimingsMetric) -> String {
+    format!(
+        r#"<tr>
+            <td>{method}</td>
+            <td>{name}</td>
+            <td>{dns_average}</td>
+            <td>{connect_average}</td>
+            <td>{time_to_first_byte_average}</td>
+            <td>{download_average}</td>
+        </tr>"#,
+        method = metric.method,
+        name = metric.name,
+        dns_average = metric.dns_average,
+        connect_average = metric.connect_average,
+        time_to_first_byte_average = metric.time_to_first_byte_average,
+        download_average = metric.download_average,
+    )
+}
+
+/// Add a table and graph of the bytes sent and received in bodies to the html report.
+pub(crate) fn throughput_metrics_template(throughput_rows: &str, graph: String) -> String {
+    format!(
+        r#"<div class="throughput">
+        <h2>Throughput Metrics</h2>
+
+        {graph}
+
+        <table>
+            <thead>
+                <tr>
+                    <th>Method</th>
+                    <th>Name</th>
+                    <th>Sent (bytes)</th>
+                    <th>Average Sent (bytes)</th>
+                    <th>Received (bytes)</th>
+                    <th>Average Received (bytes)</th>
+                    <th>Received/s (bytes)</th>
+                </tr>
+            </thead>
+            <tbody>
+                {throughput_rows}
+            </tbody>
+        </table>
+    </div>"#,
+        throughput_rows = throughput_rows,
+        graph = graph,
+    )
+}
+
+/// Add a table of HTTP cache hits and revalidations to the html report.
+pub(crate) fn cache_metrics_template(cache_rows: &str) -> String {
+    format!(
+        r#"<div class="cache">
+        <h2>HTTP Cache Metrics</h2>
+
+        <table>
+            <thead>
+                <tr>
+                    <th>Method</th>
+                    <th>Name</th>
+                    <th>Cache Hits</th>
+                    <th>Not Modified</th>
+                    <th>Hit Rate (%)</th>
+                </tr>
+            </thead>
+            <tbody>
+                {cache_rows}
+            </tbody>
+        </table>
+    </div>"#,
+        cache_rows = cache_rows,
+    )
+}
+
+/// Build an individual row of HTTP cache metrics in the html report.
+pub(crate) fn cache_metrics_row(metric: CacheMetric) -> String {
+    format!(
+        r#"<tr>
+            <td>{method}</td>
+            <td>{name}</td>
+            <td>{cache_hits}</td>
+            <td>{not_modified}</td>
+            <td>{hit_rate}</td>
+        </tr>"#,
+        method = metric.method,
+        name = metric.name,
+        cache_hits = metrics::format_number(metric.cache_hits),
+        not_modified = metrics::format_number(metric.not_modified),
+        hit_rate = metric.hit_rate,
+    )
+}
+
+/// Build an individual row of throughput metrics in the html report.
+pub(crate) fn throughput_metrics_row(metric: ThroughputMetric) -> String {
+    format!(
+        r#"<tr>
+            <td>{method}</td>
+            <td>{name}</td>
+            <td>{request_bytes}</td>
+            <td>{request_bytes_average}</td>
+            <td>{response_bytes}</td>
+            <td>{response_bytes_average}</td>
+            <td>{response_bytes_per_second}</td>
+        </tr>"#,
+        method = metric.method,
+        name = metric.name,
+        request_bytes = metrics::format_number(metric.request_bytes),
+        request_bytes_average = metric.request_bytes_average,
+        response_bytes = metrics::format_number(metric.response_bytes),
+        response_bytes_average = metric.response_bytes_average,
+        response_bytes_per_second = metric.response_bytes_per_second,
     )
 }
 
@@ -328,7 +557,11 @@ pub(crate) fn status_code_metrics_row(metric: StatusCodeMetric) -> String {
 }
 
 /// If transaction metrics are enabled, add a transaction metrics table to the html report.
-pub(crate) fn transaction_metrics_template(transaction_rows: &str, graph: String) -> String {
+pub(crate) fn transaction_metrics_template(
+    transaction_rows: &str,
+    graph: String,
+    percentile_headers: &str,
+) -> String {
     format!(
         r#"<div class="transactions">
         <h2>Transaction Metrics</h2>
@@ -346,6 +579,7 @@ pub(crate) fn transaction_metrics_template(transaction_rows: &str, graph: String
                     <th>Max (ms)</th>
                     <th>RPS</th>
                     <th>Failures/s</th>
+                    {percentile_headers}
                 </tr>
             </thead>
             <tbody>
@@ -355,6 +589,7 @@ pub(crate) fn transaction_metrics_template(transaction_rows: &str, graph: String
     </div>"#,
         transaction_rows = transaction_rows,
         graph = graph,
+        percentile_headers = percentile_headers,
     )
 }
 
@@ -363,8 +598,9 @@ pub(crate) fn transaction_metrics_row(metric: TransactionMetric) -> String {
     if metric.is_scenario {
         format!(
             r#"<tr>
-            <td colspan="10" align="left"><strong>{name}</strong></td>
+            <td colspan="{columns}" align="left"><strong>{name}</strong></td>
         </tr>"#,
+            columns = 9 + metric.percentiles.len(),
             name = metric.name,
         )
     } else {
@@ -378,6 +614,7 @@ pub(crate) fn transaction_metrics_row(metric: TransactionMetric) -> String {
             <td>{response_time_maximum}</td>
             <td>{requests_per_second}</td>
             <td>{failures_per_second}</td>
+            {percentiles}
         </tr>"#,
             transaction = metric.transaction,
             name = metric.name,
@@ -388,12 +625,259 @@ pub(crate) fn transaction_metrics_row(metric: TransactionMetric) -> String {
             response_time_maximum = metric.response_time_maximum,
             requests_per_second = metric.requests_per_second,
             failures_per_second = metric.failures_per_second,
+            percentiles = percentile_cells(&metric.percentiles),
         )
     }
 }
 
+/// If custom timers were used, add a timer metrics table to the html report.
+pub(crate) fn timer_metrics_template(timer_rows: &str) -> String {
+    format!(
+        r#"<div class="timers">
+        <h2>Timer Metrics</h2>
+
+        <table>
+            <thead>
+                <tr>
+                    <th colspan="2">Timer</th>
+                    <th># Times Run</th>
+                    <th># Fails</th>
+                    <th>Average (ms)</th>
+                    <th>Min (ms)</th>
+                    <th>Max (ms)</th>
+                    <th>Timers/s</th>
+                    <th>Failures/s</th>
+                </tr>
+            </thead>
+            <tbody>
+                {timer_rows}
+            </tbody>
+        </table>
+    </div>"#,
+        timer_rows = timer_rows,
+    )
+}
+
+/// Build an individual row of timer metrics in the html report.
+pub(crate) fn timer_metrics_row(metric: TimerMetric) -> String {
+    format!(
+        r#"<tr>
+            <td colspan="2">{name}</td>
+            <td>{number_of_runs}</td>
+            <td>{number_of_failures}</td>
+            <td>{time_average}</td>
+            <td>{time_minimum}</td>
+            <td>{time_maximum}</td>
+            <td>{timers_per_second}</td>
+            <td>{failures_per_second}</td>
+        </tr>"#,
+        name = metric.name,
+        number_of_runs = metrics::format_number(metric.number_of_runs),
+        number_of_failures = metrics::format_number(metric.number_of_failures),
+        time_average = metric.time_average,
+        time_minimum = metric.time_minimum,
+        time_maximum = metric.time_maximum,
+        timers_per_second = metric.timers_per_second,
+        failures_per_second = metric.failures_per_second,
+    )
+}
+
+/// If streaming responses were read, add a stream metrics table to the html report.
+pub(crate) fn stream_metrics_template(stream_rows: &str) -> String {
+    format!(
+        r#"<div class="streams">
+        <h2>Stream Metrics</h2>
+
+        <table>
+            <thead>
+                <tr>
+                    <th>Method</th>
+                    <th>Name</th>
+                    <th># Streams</th>
+                    <th># Fails</th>
+                    <th>Events/Stream</th>
+                    <th>Time To First Byte (ms)</th>
+                    <th>Time To First Event (ms)</th>
+                    <th>Time Between Events (ms)</th>
+                    <th>Duration (ms)</th>
+                </tr>
+            </thead>
+            <tbody>
+                {stream_rows}
+            </tbody>
+        </table>
+    </div>"#,
+        stream_rows = stream_rows,
+    )
+}
+
+/// Build an individual row of stream metrics in the html report.
+pub(crate) fn stream_metrics_row(metric: StreamMetric) -> String {
+    format!(
+        r#"<tr>
+            <td>{method}</td>
+            <td>{name}</td>
+            <td>{number_of_streams}</td>
+            <td>{number_of_failures}</td>
+            <td>{events_average}</td>
+            <td>{time_to_first_byte_average}</td>
+            <td>{time_to_first_event_average}</td>
+            <td>{time_between_events_average}</td>
+            <td>{duration_average}</td>
+        </tr>"#,
+        method = metric.method,
+        name = metric.name,
+        number_of_streams = metrics::format_number(metric.number_of_streams),
+        number_of_failures = metrics::format_number(metric.number_of_failures),
+        events_average = metric.events_average,
+        time_to_first_byte_average = metric.time_to_first_byte_average,
+        time_to_first_event_average = metric.time_to_first_event_average,
+        time_between_events_average = metric.time_between_events_average,
+        duration_average = metric.duration_average,
+    )
+}
+
+/// If custom counters, gauges or histograms were used, add their tables to the html report.
+pub(crate) fn custom_metrics_template(
+    counter_rows: &str,
+    gauge_rows: &str,
+    histogram_rows: &str,
+) -> String {
+    let mut tables = Vec::new();
+    if !counter_rows.is_empty() {
+        tables.push(format!(
+            r#"<h3>Counters</h3>
+
+        <table>
+            <thead>
+                <tr>
+                    <th colspan="2">Counter</th>
+                    <th>Total</th>
+                    <th># Updates</th>
+                    <th>Total/s</th>
+                </tr>
+            </thead>
+            <tbody>
+                {counter_rows}
+            </tbody>
+        </table>"#,
+            counter_rows = counter_rows,
+        ));
+    }
+    if !gauge_rows.is_empty() {
+        tables.push(format!(
+            r#"<h3>Gauges</h3>
+
+        <table>
+            <thead>
+                <tr>
+                    <th colspan="2">Gauge</th>
+                    <th>Last</th>
+                    <th>Min</th>
+                    <th>Max</th>
+                    <th># Updates</th>
+                </tr>
+            </thead>
+            <tbody>
+                {gauge_rows}
+            </tbody>
+        </table>"#,
+            gauge_rows = gauge_rows,
+        ));
+    }
+    if !histogram_rows.is_empty() {
+        tables.push(format!(
+            r#"<h3>Histograms</h3>
+
+        <table>
+            <thead>
+                <tr>
+                    <th colspan="2">Histogram</th>
+                    <th># Values</th>
+                    <th>Average</th>
+                    <th>Min</th>
+                    <th>Max</th>
+                    <th>Median</th>
+                </tr>
+            </thead>
+            <tbody>
+                {histogram_rows}
+            </tbody>
+        </table>"#,
+            histogram_rows = histogram_rows,
+        ));
+    }
+
+    format!(
+        r#"<div class="custom-metrics">
+        <h2>Custom Metrics</h2>
+
+        {tables}
+    </div>"#,
+        tables = tables.join("\n\n        "),
+    )
+}
+
+/// Build an individual row of custom counter metrics in the html report.
+pub(crate) fn counter_metrics_row(metric: CounterMetric) -> String {
+    format!(
+        r#"<tr>
+            <td colspan="2">{name}</td>
+            <td>{total}</td>
+            <td>{updates}</td>
+            <td>{total_per_second}</td>
+        </tr>"#,
+        name = metric.name,
+        total = metrics::format_number(metric.total as usize),
+        updates = metrics::format_number(metric.updates),
+        total_per_second = metric.total_per_second,
+    )
+}
+
+/// Build an individual row of custom gauge metrics in the html report.
+pub(crate) fn gauge_metrics_row(metric: GaugeMetric) -> String {
+    format!(
+        r#"<tr>
+            <td colspan="2">{name}</td>
+            <td>{value}</td>
+            <td>{minimum}</td>
+            <td>{maximum}</td>
+            <td>{updates}</td>
+        </tr>"#,
+        name = metric.name,
+        value = metric.value,
+        minimum = metric.minimum,
+        maximum = metric.maximum,
+        updates = metrics::format_number(metric.updates),
+    )
+}
+
+/// Build an individual row of custom histogram metrics in the html report.
+pub(crate) fn histogram_metrics_row(metric: HistogramMetric) -> String {
+    format!(
+        r#"<tr>
+            <td colspan="2">{name}</td>
+            <td>{counter}</td>
+            <td>{average}</td>
+            <td>{minimum}</td>
+            <td>{maximum}</td>
+            <td>{median}</td>
+        </tr>"#,
+        name = metric.name,
+        counter = metrics::format_number(metric.counter),
+        average = metric.average,
+        minimum = metrics::format_number(metric.minimum),
+        maximum = metrics::format_number(metric.maximum),
+        median = metrics::format_number(metric.median),
+    )
+}
+
 /// If scenario metrics are enabled, add a scenario metrics table to the html report.
-pub(crate) fn scenario_metrics_template(scenario_rows: &str, graph: String) -> String {
+pub(crate) fn scenario_metrics_template(
+    scenario_rows: &str,
+    graph: String,
+    percentile_headers: &str,
+) -> String {
     format!(
         r#"<div class="scenarios">
         <h2>Scenario Metrics</h2>
@@ -411,6 +895,7 @@ pub(crate) fn scenario_metrics_template(scenario_rows: &str, graph: String) -> S
                     <th>Max (ms)</th>
                     <th>Scenarios/s</th>
                     <th>Iterations</th>
+                    {percentile_headers}
                 </tr>
             </thead>
             <tbody>
@@ -420,6 +905,7 @@ pub(crate) fn scenario_metrics_template(scenario_rows: &str, graph: String) -> S
     </div>"#,
         scenario_rows = scenario_rows,
         graph = graph,
+        percentile_headers = percentile_headers,
     )
 }
 
@@ -435,6 +921,7 @@ pub(crate) fn scenario_metrics_row(metric: ScenarioMetric) -> String {
             <td>{response_time_maximum}</td>
             <td>{count_per_second}</td>
             <td>{iterations}</td>
+            {percentiles}
         </tr>"#,
         name = metric.name,
         users = metrics::format_number(metric.users),
@@ -444,6 +931,7 @@ pub(crate) fn scenario_metrics_row(metric: ScenarioMetric) -> String {
         response_time_maximum = metric.response_time_maximum,
         count_per_second = metric.count_per_second,
         iterations = metric.iterations,
+        percentiles = percentile_cells(&metric.percentiles),
     )
 }
 
@@ -484,6 +972,43 @@ pub fn error_row(error: &metrics::GooseErrorMetricAggregate) -> String {
     )
 }
 
+/// If there are thresholds, add a thresholds table to the html report.
+pub(crate) fn thresholds_template(threshold_rows: &str) -> String {
+    format!(
+        r#"<div class="thresholds">
+        <h2>Thresholds</h2>
+
+        <table>
+            <thead>
+                <tr>
+                    <th colspan="2">Threshold</th>
+                    <th>Value</th>
+                    <th>Result</th>
+                </tr>
+            </thead>
+            <tbody>
+                {threshold_rows}
+            </tbody>
+        </table>
+    </div>"#,
+        threshold_rows = threshold_rows,
+    )
+}
+
+/// Build an individual threshold row in the html report.
+pub(crate) fn threshold_row(result: &metrics::GooseThresholdResult) -> String {
+    format!(
+        r#"<tr>
+        <td colspan="2">{threshold}</td>
+        <td>{value}</td>
+        <td>{result}</td>
+    </tr>"#,
+        threshold = result.threshold.definition,
+        value = result.formatted_value(),
+        result = result.formatted_result(),
+    )
+}
+
 /// Build the html report.
 pub(crate) fn build_report(
     users: &str,
@@ -584,6 +1109,8 @@ pub(crate) fn build_report(
                 </table>
         </div>
 
+        {thresholds_template}
+
         <div class="requests">
             <h2>Request Metrics</h2>
 
@@ -621,14 +1148,7 @@ pub(crate) fn build_report(
                     <tr>
                         <th>Method</th>
                         <th>Name</th>
-                        <th>50%ile (ms)</th>
-                        <th>60%ile (ms)</th>
-                        <th>70%ile (ms)</th>
-                        <th>80%ile (ms)</th>
-                        <th>90%ile (ms)</th>
-                        <th>95%ile (ms)</th>
-                        <th>99%ile (ms)</th>
-                        <th>100%ile (ms)</th>
+                        {percentile_headers}
                     </tr>
                 </thead>
                 <tbody>
@@ -639,10 +1159,22 @@ pub(crate) fn build_report(
 
         {co_responses_template}
 
+        {request_timings_template}
+
+        {throughput_template}
+
+        {cache_template}
+
         {status_codes_template}
 
         {transactions_template}
 
+        {timers_template}
+
+        {custom_metrics_template}
+
+        {streams_template}
+
         {scenarios_template}
 
         <div class="users">
@@ -661,13 +1193,21 @@ pub(crate) fn build_report(
         pkg_name = pkg_name,
         pkg_version = pkg_version,
         raw_requests_template = templates.raw_requests_template,
+        percentile_headers = templates.percentile_headers,
         raw_responses_template = templates.raw_responses_template,
         co_requests_template = templates.co_requests_template,
         co_responses_template = templates.co_responses_template,
+        request_timings_template = templates.request_timings_template,
+        throughput_template = templates.throughput_template,
+        cache_template = templates.cache_template,
         transactions_template = templates.transactions_template,
+        timers_template = templates.timers_template,
+        custom_metrics_template = templates.custom_metrics_template,
+        streams_template = templates.streams_template,
         scenarios_template = templates.scenarios_template,
         status_codes_template = templates.status_codes_template,
         errors_template = templates.errors_template,
+        thresholds_template = templates.thresholds_template,
         graph_rps_template = templates.graph_rps_template,
         graph_average_response_time_template = templates.graph_average_response_time_template,
         graph_users_per_second = templates.graph_users_per_second,
@@ -162,6 +162,8 @@ pub struct TestPlanHistory {
     pub timestamp: DateTime<Utc>,
     /// The number of users when the step started.
     pub users: usize,
+    /// Why the load test was aborted, if an abort condition canceled it in this step.
+    pub reason: Option<String>,
 }
 impl TestPlanHistory {
     /// A helper to record a new test plan step in the historical record.
@@ -170,6 +172,7 @@ impl TestPlanHistory {
             action,
             timestamp: Utc::now(),
             users,
+            reason: None,
         }
     }
 }
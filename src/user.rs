@@ -45,6 +45,10 @@ pub(crate) async fn user_main(
             // Tracks the time it takes to loop through all Transactions when Coordinated Omission
             // Mitigation is enabled.
             thread_user.update_request_cadence(thread_number).await;
+            // Start each iteration after the first with a new session, if configured.
+            if thread_scenario.clear_cookies_each_iteration && thread_user.iterations > 0 {
+                thread_user.clear_cookies();
+            }
             let scenario_started = time::Instant::now();
 
             for (thread_transaction_index, thread_transaction_name) in
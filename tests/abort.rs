@@ -0,0 +1,154 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::{Duration, Instant};
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const ERROR_PATH: &str = "/error";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const ERROR_KEY: usize = 1;
+
+// How long load tests run if they're not aborted.
+const RUN_TIME: usize = 30;
+
+// Test transaction, loads the front page.
+pub async fn get_index(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test transaction, loads a page that always fails.
+pub async fn get_error(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(ERROR_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+        // Set up ERROR_PATH, store in vector at ERROR_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(ERROR_PATH);
+            then.status(500);
+        }),
+    ]
+}
+
+// Build a load test where half of all requests fail.
+fn build_load_test(server: &MockServer, run_time: usize, custom: Vec<&str>) -> GooseAttack {
+    let run_time = run_time.to_string();
+    let mut args = vec!["--run-time", &run_time];
+    args.extend(custom);
+    common::build_load_test(
+        common::build_configuration(server, args),
+        vec![scenario!("LoadTest")
+            .register_transaction(transaction!(get_index))
+            .register_transaction(transaction!(get_error))],
+        None,
+        None,
+    )
+}
+
+#[tokio::test]
+#[serial]
+// Abort the load test as soon as the error rate within a sliding window is too high.
+async fn test_abort_on() {
+    let report_file = "abort-on-report.html";
+
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let started = Instant::now();
+    let goose_attack = build_load_test(
+        &server,
+        RUN_TIME,
+        vec![
+            "--abort-on",
+            "GET / error rate > 0%",
+            "--report-file",
+            report_file,
+        ],
+    )
+    .add_abort_condition("error rate > 25% over 2s")
+    .unwrap();
+
+    // The load test is aborted long before the run time expires.
+    let (metrics, detail) = match goose_attack.execute().await {
+        Err(GooseError::Aborted { metrics, detail }) => (metrics, detail),
+        _ => panic!("load test should be aborted"),
+    };
+    assert!(started.elapsed() < Duration::from_secs(RUN_TIME as u64 / 2));
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+    assert!(mock_endpoints[ERROR_KEY].hits() > 0);
+
+    // The reason the load test was aborted is recorded in the history.
+    assert!(detail.starts_with("error rate > 25% over 2s, measured "));
+    let canceling = metrics
+        .history
+        .iter()
+        .find(|step| step.reason.is_some())
+        .unwrap();
+    assert_eq!(format!("{:?}", canceling.action), "Canceling");
+    assert_eq!(canceling.reason.as_ref(), Some(&detail));
+
+    // The reason is displayed with the final metrics and in the html report.
+    assert!(metrics
+        .to_string()
+        .contains(&format!("Aborted:     {}", detail)));
+    let report = std::fs::read_to_string(report_file).unwrap();
+    assert!(report.contains(&format!(
+        "<td>Aborted</td><td colspan=\"4\">{}</td>",
+        detail
+    )));
+
+    // Cleanup from test.
+    common::cleanup_files(vec![report_file]);
+}
+
+#[tokio::test]
+#[serial]
+// Only abort the load test once a condition has been met for long enough.
+async fn test_abort_on_duration() {
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let _mock_endpoints = setup_mock_server_endpoints(&server);
+
+    // All requests for ERROR_PATH fail, but not for long enough to abort the load test.
+    let goose_metrics = common::run_load_test(
+        build_load_test(
+            &server,
+            2,
+            vec!["--abort-on", "GET /error error rate > 50% for 1m"],
+        ),
+        None,
+    )
+    .await;
+    assert!(goose_metrics
+        .history
+        .iter()
+        .all(|step| step.reason.is_none()));
+    assert!(goose_metrics.requests["GET /error"].fail_count > 0);
+
+    // Invalid abort conditions are rejected.
+    let goose_attack = build_load_test(&server, 2, vec!["--abort-on", "error rate > 5% for ever"]);
+    assert!(matches!(
+        goose_attack.execute().await,
+        Err(GooseError::InvalidOption { .. })
+    ));
+}
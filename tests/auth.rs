@@ -0,0 +1,238 @@
+use httpmock::{
+    Method::{GET, POST},
+    Mock, MockServer,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::config::GooseConfiguration;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const TOKEN_PATH: &str = "/oauth/token";
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 3;
+
+// Test transaction, loads the front page.
+pub async fn get_index(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test transaction, authenticates the user with HTTP basic authentication.
+pub async fn login(user: &mut GooseUser) -> TransactionResult {
+    user.set_auth(GooseAuth::basic("user", "secret"));
+    Ok(())
+}
+
+// Set up the front page, only answering requests with the expected authorization.
+fn setup_index<'a>(server: &'a MockServer, authorization: &str) -> Mock<'a> {
+    let authorization = authorization.to_string();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(INDEX_PATH)
+            .header("authorization", &authorization);
+        then.status(200).body("<html><body>index</body></html>");
+    })
+}
+
+// Set up the token endpoint, returning the token for requests containing `grant`.
+fn setup_token<'a>(server: &'a MockServer, grant: &str, status: u16, token: &str) -> Mock<'a> {
+    let grant = grant.to_string();
+    let token = token.to_string();
+    server.mock(|when, then| {
+        when.method(POST)
+            .path(TOKEN_PATH)
+            .header("authorization", "Basic aWQ6c2VjcmV0")
+            .body_contains(&grant);
+        then.status(status)
+            .header("content-type", "application/json")
+            .body(token);
+    })
+}
+
+// Build a configuration that runs each user for a few iterations.
+fn build_configuration(server: &MockServer) -> GooseConfiguration {
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        server,
+        vec![
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+    configuration
+}
+
+// Run a load test that loads the front page with the provided scenario.
+async fn run_load_test(server: &MockServer, scenario: Scenario) -> GooseMetrics {
+    common::run_load_test(
+        common::build_load_test(build_configuration(server), vec![scenario], None, None),
+        None,
+    )
+    .await
+}
+
+#[tokio::test]
+#[serial]
+// Authenticate all users of a scenario with HTTP basic authentication.
+async fn test_basic() {
+    let server = MockServer::start();
+    let index = setup_index(&server, "Basic dXNlcjpzZWNyZXQ=");
+
+    let scenario = scenario!("LoadTest")
+        .set_auth(GooseAuth::basic("user", "secret"))
+        .register_transaction(transaction!(get_index));
+    let goose_metrics = run_load_test(&server, scenario).await;
+
+    index.assert_hits(USERS * ITERATIONS);
+    assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+}
+
+#[tokio::test]
+#[serial]
+// Authenticate all users of a scenario with a static bearer token.
+async fn test_bearer() {
+    let server = MockServer::start();
+    let index = setup_index(&server, "Bearer static-token");
+
+    let scenario = scenario!("LoadTest")
+        .set_auth(GooseAuth::bearer("static-token"))
+        .register_transaction(transaction!(get_index));
+    let goose_metrics = run_load_test(&server, scenario).await;
+
+    index.assert_hits(USERS * ITERATIONS);
+    assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+}
+
+#[tokio::test]
+#[serial]
+// Authenticate a single user from a transaction.
+async fn test_user_auth() {
+    let server = MockServer::start();
+    let index = setup_index(&server, "Basic dXNlcjpzZWNyZXQ=");
+
+    let scenario = scenario!("LoadTest")
+        .register_transaction(transaction!(login).set_on_start())
+        .register_transaction(transaction!(get_index));
+    let goose_metrics = run_load_test(&server, scenario).await;
+
+    index.assert_hits(USERS * ITERATIONS);
+    assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+}
+
+#[tokio::test]
+#[serial]
+// Each user requests an OAuth2 access token once, and reuses it until it expires.
+async fn test_oauth2_client_credentials() {
+    let server = MockServer::start();
+    let token = setup_token(
+        &server,
+        "grant_type=client_credentials&scope=read",
+        200,
+        r#"{"access_token":"token","token_type":"Bearer","expires_in":3600}"#,
+    );
+    let index = setup_index(&server, "Bearer token");
+
+    let scenario = scenario!("LoadTest")
+        .set_auth(
+            GooseAuth::oauth2_client_credentials(&server.url(TOKEN_PATH), "id", "secret")
+                .set_scope("read"),
+        )
+        .register_transaction(transaction!(get_index));
+    let goose_metrics = run_load_test(&server, scenario).await;
+
+    token.assert_hits(USERS);
+    index.assert_hits(USERS * ITERATIONS);
+    // Token requests are recorded under their own name.
+    assert_eq!(
+        goose_metrics.requests["POST oauth2 token"].success_count,
+        USERS
+    );
+    assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+}
+
+#[tokio::test]
+#[serial]
+// Access tokens about to expire are refreshed with the refresh token.
+async fn test_oauth2_refresh() {
+    let server = MockServer::start();
+    let token = setup_token(
+        &server,
+        "grant_type=password&username=user&password=password",
+        200,
+        r#"{"access_token":"first","expires_in":10,"refresh_token":"refresh"}"#,
+    );
+    let refresh = setup_token(
+        &server,
+        "grant_type=refresh_token&refresh_token=refresh",
+        200,
+        r#"{"access_token":"refreshed","expires_in":10}"#,
+    );
+    let first_index = setup_index(&server, "Bearer first");
+    let refreshed_index = setup_index(&server, "Bearer refreshed");
+
+    // Tokens expire within the refresh margin, so are refreshed before every request.
+    let scenario = scenario!("LoadTest")
+        .set_auth(
+            GooseAuth::oauth2_password(&server.url(TOKEN_PATH), "id", "secret", "user", "password")
+                .set_refresh_margin(Duration::from_secs(60))
+                .set_token_request_name("login"),
+        )
+        .register_transaction(transaction!(get_index));
+    let goose_metrics = run_load_test(&server, scenario).await;
+
+    token.assert_hits(USERS);
+    refresh.assert_hits(USERS * (ITERATIONS - 1));
+    first_index.assert_hits(USERS);
+    refreshed_index.assert_hits(USERS * (ITERATIONS - 1));
+    assert_eq!(
+        goose_metrics.requests["POST login"].success_count,
+        USERS * ITERATIONS
+    );
+    assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+}
+
+#[tokio::test]
+#[serial]
+// Requests fail without being sent if an access token can't be obtained.
+async fn test_oauth2_failure() {
+    let server = MockServer::start();
+    let token = setup_token(
+        &server,
+        "grant_type=client_credentials",
+        401,
+        r#"{"error":"invalid_client"}"#,
+    );
+    let index = setup_index(&server, "Bearer token");
+
+    let scenario = scenario!("LoadTest")
+        .set_auth(GooseAuth::oauth2_client_credentials(
+            &server.url(TOKEN_PATH),
+            "id",
+            "secret",
+        ))
+        .register_transaction(transaction!(get_index));
+    let goose_metrics = run_load_test(&server, scenario).await;
+
+    // A token is requested for every request, as none could be obtained.
+    token.assert_hits(USERS * ITERATIONS);
+    index.assert_hits(0);
+    assert_eq!(
+        goose_metrics.requests["POST oauth2 token"].fail_count,
+        USERS * ITERATIONS
+    );
+    assert!(!goose_metrics.requests.contains_key("GET /"));
+}
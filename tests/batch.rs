@@ -0,0 +1,113 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const PROFILE_PATH: &str = "/api/profile";
+const MESSAGES_PATH: &str = "/api/messages";
+const NOTIFICATIONS_PATH: &str = "/api/notifications";
+
+// How long the server takes to respond to each request, in milliseconds.
+const DELAY: u64 = 300;
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 2;
+
+// Name of the batch.
+const BATCH_NAME: &str = "dashboard";
+
+// Test transaction, loads the dashboard with a batch of requests in parallel.
+pub async fn load_dashboard(user: &mut GooseUser) -> TransactionResult {
+    let requests = [PROFILE_PATH, MESSAGES_PATH, NOTIFICATIONS_PATH]
+        .iter()
+        .map(|path| GooseRequest::builder().path(*path).build())
+        .collect();
+    let _batch = user.request_batch(BATCH_NAME, requests).await?;
+
+    Ok(())
+}
+
+// All tests in this file run against common endpoints, which all respond slowly.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    [PROFILE_PATH, MESSAGES_PATH, NOTIFICATIONS_PATH]
+        .iter()
+        .map(|path| {
+            server.mock(|when, then| {
+                when.method(GET).path(*path);
+                then.status(200).delay(Duration::from_millis(DELAY));
+            })
+        })
+        .collect()
+}
+
+#[tokio::test]
+#[serial]
+// Make a batch of requests in parallel, recording how long the whole batch took.
+async fn test_request_batch() {
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest").register_transaction(transaction!(load_dashboard))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Every request in every batch was made, and recorded as a request.
+    let expected = USERS * ITERATIONS;
+    for (mock_endpoint, path) in
+        mock_endpoints
+            .iter()
+            .zip([PROFILE_PATH, MESSAGES_PATH, NOTIFICATIONS_PATH])
+    {
+        mock_endpoint.assert_hits(expected);
+        assert_eq!(
+            goose_metrics.requests[&format!("GET {}", path)].success_count,
+            expected
+        );
+    }
+
+    // The time each batch took was recorded under the name of the batch.
+    let batch = goose_metrics
+        .timers
+        .get(BATCH_NAME)
+        .expect("batch duration was not recorded");
+    assert_eq!(batch.counter, expected);
+    assert_eq!(batch.success_count, expected);
+    assert_eq!(batch.fail_count, 0);
+
+    // The requests ran concurrently: each batch took about as long as its slowest request,
+    // and not as long as all requests made one after the other.
+    assert!(batch.min_time as u64 >= DELAY);
+    assert!((batch.max_time as u64) < DELAY * 3);
+}
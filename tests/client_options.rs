@@ -0,0 +1,185 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+mod common;
+
+use goose::config::GooseConfiguration;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const ITERATIONS: usize = 3;
+
+// Test transaction, loads the front page.
+pub async fn get_index(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200).body("<html><body>index</body></html>");
+        }),
+    ]
+}
+
+// Build a configuration that runs each user for a few iterations.
+fn build_configuration(server: &MockServer, users: usize, custom: Vec<&str>) -> GooseConfiguration {
+    let users = users.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut args = vec![
+        "--users",
+        &users,
+        "--hatch-rate",
+        &users,
+        "--iterations",
+        &iterations,
+    ];
+    args.extend(custom);
+    let mut configuration = common::build_configuration(server, args);
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+    configuration
+}
+
+// Start an HTTP/1.1 server that keeps connections alive, returning its port and a count
+// of the connections it accepted.
+fn start_keepalive_server() -> (u16, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let connections = Arc::new(AtomicUsize::new(0));
+    let accepted_connections = connections.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(s) => s,
+                Err(_) => break,
+            };
+            accepted_connections.fetch_add(1, Ordering::SeqCst);
+            // Answer every request made on the connection until the client closes it.
+            std::thread::spawn(move || {
+                let mut request = Vec::new();
+                let mut buffer = [0; 1024];
+                while let Ok(read) = stream.read(&mut buffer) {
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buffer[..read]);
+                    while let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                        request.drain(..end + 4);
+                        if stream
+                            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok")
+                            .is_err()
+                        {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+    });
+    (port, connections)
+}
+
+#[tokio::test]
+#[serial]
+// Make requests with HTTP/1.1 and HTTP/2 in different scenarios.
+async fn test_http_version() {
+    let request_log = "client-options-requests.json";
+
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = build_configuration(&server, 2, vec!["--request-log", request_log]);
+
+    // Run the load test, with one user in each scenario.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![
+                scenario!("Http1")
+                    .set_http_version(GooseHttpVersion::Http1Only)
+                    .register_transaction(transaction!(get_index).set_name("http1")),
+                scenario!("Http2")
+                    .set_http_version(GooseHttpVersion::Http2PriorKnowledge)
+                    .register_transaction(transaction!(get_index).set_name("http2")),
+            ],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each user loaded the front page once per iteration.
+    mock_endpoints[INDEX_KEY].assert_hits(2 * ITERATIONS);
+    assert_eq!(goose_metrics.requests["GET http1"].fail_count, 0);
+    assert_eq!(goose_metrics.requests["GET http2"].fail_count, 0);
+
+    // The HTTP version of each response is recorded in the request log.
+    let log = std::fs::read_to_string(request_log).unwrap();
+    let mut versions: Vec<(String, String)> = log
+        .lines()
+        .map(|line| {
+            let request: serde_json::Value = serde_json::from_str(line).unwrap();
+            (
+                request["name"].as_str().unwrap().to_string(),
+                request["http_version"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    versions.sort();
+    let mut expected = vec![("http1".to_string(), "HTTP/1.1".to_string()); ITERATIONS];
+    expected.extend(vec![
+        ("http2".to_string(), "HTTP/2.0".to_string());
+        ITERATIONS
+    ]);
+    assert_eq!(versions, expected);
+
+    std::fs::remove_file(request_log).unwrap();
+}
+
+#[tokio::test]
+#[serial]
+// Don't keep idle connections open, opening a new connection for each request.
+async fn test_pool_max_idle_per_host() {
+    // The mock server isn't used, but provides the common configuration.
+    let server = MockServer::start();
+
+    for (max_idle, expected_connections) in [(None, 1), (Some(0), ITERATIONS)] {
+        let (port, connections) = start_keepalive_server();
+        let mut configuration = build_configuration(&server, 1, vec![]);
+        configuration.host = format!("http://127.0.0.1:{}/", port);
+
+        let mut scenario = scenario!("LoadTest").register_transaction(transaction!(get_index));
+        if let Some(max_idle) = max_idle {
+            scenario = scenario.set_pool_max_idle_per_host(max_idle);
+        }
+        let goose_metrics = common::run_load_test(
+            common::build_load_test(configuration, vec![scenario], None, None),
+            None,
+        )
+        .await;
+
+        // All requests succeeded, and reused the connection unless idle connections are
+        // closed.
+        assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+        assert_eq!(connections.load(Ordering::SeqCst), expected_connections);
+    }
+}
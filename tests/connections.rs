@@ -0,0 +1,193 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::collections::HashSet;
+use std::io::{Read, Write};
+use std::net::{IpAddr, TcpListener};
+use std::sync::{Arc, Mutex};
+
+mod common;
+
+use goose::config::GooseConfiguration;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 2;
+
+// A host name that only resolves through the configured proxy or DNS overrides.
+const HOST: &str = "goose.invalid";
+
+// Test transaction, loads the front page.
+pub async fn get_index(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200).body("<html><body>index</body></html>");
+        }),
+    ]
+}
+
+// Build a configuration that runs each user for a few iterations against the host.
+fn build_configuration(server: &MockServer, host: &str, custom: Vec<&str>) -> GooseConfiguration {
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut args = vec![
+        "--host",
+        host,
+        "--users",
+        &users,
+        "--hatch-rate",
+        &users,
+        "--iterations",
+        &iterations,
+    ];
+    args.extend(custom);
+    let mut configuration = common::build_configuration(server, args);
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+    configuration
+}
+
+// Run a load test that loads the front page.
+async fn run_load_test(configuration: GooseConfiguration) -> GooseMetrics {
+    common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest").register_transaction(transaction!(get_index))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await
+}
+
+#[tokio::test]
+#[serial]
+// Send requests through a proxy.
+async fn test_proxy() {
+    // Start the mock server, which also acts as the proxy.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let host = format!("http://{}/", HOST);
+    let proxy = server.url("");
+    let configuration = build_configuration(&server, &host, vec!["--proxy", &proxy]);
+    let goose_metrics = run_load_test(configuration).await;
+
+    // All requests were sent to the proxy.
+    mock_endpoints[INDEX_KEY].assert_hits(USERS * ITERATIONS);
+    assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+}
+
+#[tokio::test]
+#[serial]
+// Override DNS for a host name, with more than one address.
+async fn test_resolve() {
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let host = format!("http://{}:{}/", HOST, server.port());
+    // Nothing listens on the second address, so the first address must not be replaced.
+    let resolve = format!(
+        "{}:{}:127.0.0.1,{}:{}:127.0.0.2",
+        HOST,
+        server.port(),
+        HOST,
+        server.port()
+    );
+    let configuration = build_configuration(&server, &host, vec!["--resolve", &resolve]);
+    let goose_metrics = run_load_test(configuration).await;
+
+    // All requests were sent to the overridden address.
+    mock_endpoints[INDEX_KEY].assert_hits(USERS * ITERATIONS);
+    assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+}
+
+#[tokio::test]
+#[serial]
+// Bind users to local addresses in turn.
+async fn test_local_address() {
+    // Record the address each connection is made from, answering every request.
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let peers: Arc<Mutex<HashSet<IpAddr>>> = Arc::new(Mutex::new(HashSet::new()));
+    let recorded_peers = peers.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(s) => s,
+                Err(_) => break,
+            };
+            recorded_peers
+                .lock()
+                .unwrap()
+                .insert(stream.peer_addr().unwrap().ip());
+            let mut buffer = [0; 1024];
+            let _ = stream.read(&mut buffer);
+            let _ = stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok");
+        }
+    });
+
+    // The mock server isn't used, but provides the common configuration.
+    let server = MockServer::start();
+    let host = format!("http://127.0.0.1:{}/", port);
+    let configuration = build_configuration(
+        &server,
+        &host,
+        vec!["--local-address", "127.0.0.2,127.0.0.3"],
+    );
+    let goose_metrics = run_load_test(configuration).await;
+
+    // Each user connected from a different local address.
+    assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+    let expected: HashSet<IpAddr> = ["127.0.0.2", "127.0.0.3"]
+        .iter()
+        .map(|a| a.parse().unwrap())
+        .collect();
+    assert_eq!(*peers.lock().unwrap(), expected);
+}
+
+#[tokio::test]
+#[serial]
+// Invalid connection options are rejected before the load test starts.
+async fn test_invalid_options() {
+    let server = MockServer::start();
+    for (option, value) in [
+        ("--proxy", "ftp://proxy.invalid"),
+        ("--resolve", "goose.invalid:127.0.0.1"),
+        ("--local-address", "localhost"),
+    ] {
+        let host = format!("http://{}/", HOST);
+        let configuration = build_configuration(&server, &host, vec![option, value]);
+        let goose_attack = common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest").register_transaction(transaction!(get_index))],
+            None,
+            None,
+        );
+        assert!(matches!(
+            goose_attack.execute().await,
+            Err(GooseError::InvalidOption { .. })
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::config::GooseConfiguration;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const LOGIN_PATH: &str = "/login";
+const PAGE_PATH: &str = "/page";
+
+// Load test configuration.
+const ITERATIONS: usize = 3;
+
+// Test transaction, loads the page.
+pub async fn get_page(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(PAGE_PATH).await?;
+    Ok(())
+}
+
+// Test transaction, logs in, which sets a session cookie.
+pub async fn login(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(LOGIN_PATH).await?;
+    Ok(())
+}
+
+// Set up the login page, which sets a session cookie.
+fn setup_login(server: &MockServer) -> Mock {
+    server.mock(|when, then| {
+        when.method(GET).path(LOGIN_PATH);
+        then.status(200)
+            .header("set-cookie", "session=abc; Path=/; HttpOnly");
+    })
+}
+
+// Set up the page, only answering requests made with the provided cookie.
+fn setup_page<'a>(server: &'a MockServer, name: &str, value: &str) -> Mock<'a> {
+    server.mock(|when, then| {
+        when.method(GET).path(PAGE_PATH).cookie(name, value);
+        then.status(200).body("<html><body>page</body></html>");
+    })
+}
+
+// Build a configuration that runs a single user for a few iterations.
+fn build_configuration(server: &MockServer) -> GooseConfiguration {
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        server,
+        vec![
+            "--users",
+            "1",
+            "--hatch-rate",
+            "1",
+            "--iterations",
+            &iterations,
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+    configuration
+}
+
+#[tokio::test]
+#[serial]
+// List, get, set and delete the cookies of a user.
+async fn test_cookie_api() {
+    let server = MockServer::start();
+    let login_page = setup_login(&server);
+    // Only answer requests made with both cookies.
+    let both_cookies_page = server.mock(|when, then| {
+        when.method(GET)
+            .path(PAGE_PATH)
+            .cookie("session", "abc")
+            .cookie("consent", "yes");
+        then.status(200).body("<html><body>page</body></html>");
+    });
+    let consent_page = setup_page(&server, "consent", "yes");
+
+    let configuration = build_configuration(&server);
+    let mut user = GooseUser::single(server.url("/").parse().unwrap(), &configuration).unwrap();
+    assert!(user.get_cookies().is_empty());
+
+    // Cookies set by responses are stored.
+    let _goose = user.get(LOGIN_PATH).await.unwrap();
+    login_page.assert();
+    assert_eq!(
+        user.get_cookie("session"),
+        Some(GooseCookie {
+            name: "session".to_string(),
+            value: "abc".to_string(),
+            domain: None,
+            path: "/".to_string(),
+            secure: false,
+            http_only: true,
+        })
+    );
+
+    // Cookies set by the load test are sent along with stored cookies.
+    user.set_cookie("consent=yes; Max-Age=3600").unwrap();
+    assert!(user.set_cookie("not a cookie").is_err());
+    let mut names: Vec<String> = user.get_cookies().into_iter().map(|c| c.name).collect();
+    names.sort();
+    assert_eq!(names, vec!["consent", "session"]);
+    let goose = user.get(PAGE_PATH).await.unwrap();
+    assert!(goose.request.success);
+    both_cookies_page.assert_hits(1);
+    consent_page.assert_hits(0);
+
+    // Deleted cookies are no longer sent.
+    assert!(user.delete_cookie("session"));
+    assert!(!user.delete_cookie("session"));
+    assert!(user.get_cookie("session").is_none());
+    let _goose = user.get(PAGE_PATH).await.unwrap();
+    both_cookies_page.assert_hits(1);
+    consent_page.assert_hits(1);
+
+    // Clearing removes all cookies.
+    user.clear_cookies();
+    assert!(user.get_cookies().is_empty());
+}
+
+#[tokio::test]
+#[serial]
+// Start each iteration with a new session.
+async fn test_clear_cookies_each_iteration() {
+    for clear_cookies in [false, true] {
+        let server = MockServer::start();
+        let login_page = setup_login(&server);
+        let page = setup_page(&server, "session", "abc");
+
+        // Load the page before logging in, so it's only loaded with the session cookie
+        // set by a previous iteration.
+        let mut scenario = scenario!("LoadTest")
+            .register_transaction(transaction!(get_page).set_sequence(1))
+            .register_transaction(transaction!(login).set_sequence(2));
+        if clear_cookies {
+            scenario = scenario.set_clear_cookies_each_iteration();
+        }
+        let goose_metrics = common::run_load_test(
+            common::build_load_test(build_configuration(&server), vec![scenario], None, None),
+            None,
+        )
+        .await;
+
+        login_page.assert_hits(ITERATIONS);
+        let expected_hits = if clear_cookies { 0 } else { ITERATIONS - 1 };
+        page.assert_hits(expected_hits);
+        assert_eq!(
+            goose_metrics.requests["GET /page"].success_count,
+            expected_hits
+        );
+    }
+}
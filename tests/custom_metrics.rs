@@ -0,0 +1,172 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const SEARCH_PATH: &str = "/search";
+
+// Indexes to the above paths.
+const SEARCH_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 5;
+
+// The search endpoint always returns this many results, one per line.
+const SEARCH_RESULTS: u64 = 4;
+
+// Test transaction, records one of each type of custom metric.
+pub async fn search(user: &mut GooseUser) -> TransactionResult {
+    let goose = user.get(SEARCH_PATH).await?;
+    let results = match goose.response {
+        Ok(response) => response.text().await.unwrap().lines().count() as u64,
+        Err(_) => 0,
+    };
+
+    user.increment_counter("searches", 1)?;
+    user.increment_counter("search results", results)?;
+    user.set_gauge("user", user.weighted_users_index as f64)?;
+    user.record_histogram("results per search", results)?;
+
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up SEARCH_PATH, store in vector at SEARCH_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(SEARCH_PATH);
+            then.status(200).body("one\ntwo\nthree\nfour\n");
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+// Record custom counters, gauges and histograms during a load test.
+async fn test_custom_metrics() {
+    let report_file = "custom-metrics-report.html";
+
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+            "--report-file",
+            report_file,
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest").register_transaction(transaction!(search))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each user searched once per iteration.
+    let expected = USERS * ITERATIONS;
+    mock_endpoints[SEARCH_KEY].assert_hits(expected);
+
+    // Counters total all increments.
+    let searches = &goose_metrics.counters["searches"];
+    assert_eq!(searches.total, expected as u64);
+    assert_eq!(searches.updates, expected);
+    let results = &goose_metrics.counters["search results"];
+    assert_eq!(results.total, expected as u64 * SEARCH_RESULTS);
+    assert_eq!(results.updates, expected);
+
+    // Gauges track the lowest and highest values.
+    let user = &goose_metrics.gauges["user"];
+    assert_eq!(user.minimum, 0.0);
+    assert_eq!(user.maximum, (USERS - 1) as f64);
+    assert_eq!(user.updates, expected);
+
+    // Histograms track the distribution of values.
+    let histogram = &goose_metrics.histograms["results per search"];
+    assert_eq!(histogram.counter, expected);
+    assert_eq!(histogram.minimum, SEARCH_RESULTS as usize);
+    assert_eq!(histogram.maximum, SEARCH_RESULTS as usize);
+    assert_eq!(histogram.values[&(SEARCH_RESULTS as usize)], expected);
+
+    // Custom metrics are displayed, serialized, and included in the html report.
+    let displayed = goose_metrics.to_string();
+    assert!(displayed.contains("PER COUNTER METRICS"));
+    assert!(displayed.contains("PER GAUGE METRICS"));
+    assert!(displayed.contains("PER HISTOGRAM METRICS"));
+    let json = serde_json::to_value(&goose_metrics).unwrap();
+    assert_eq!(json["counters"]["searches"]["total"], expected);
+    assert_eq!(json["gauges"]["user"]["updates"], expected);
+    assert_eq!(
+        json["histograms"]["results per search"]["counter"],
+        expected
+    );
+    let report = std::fs::read_to_string(report_file).unwrap();
+    assert!(report.contains("<h2>Custom Metrics</h2>"));
+    assert!(report.contains("results per search"));
+
+    // Cleanup from test.
+    common::cleanup_files(vec![report_file]);
+}
+
+#[tokio::test]
+#[serial]
+// Custom metrics recorded while users are starting are reset with all other metrics.
+async fn test_custom_metrics_reset() {
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    // Start users slowly enough that they search before all users have started.
+    let users = USERS.to_string();
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--users", &users, "--hatch-rate", "4", "--run-time", "2"],
+    );
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest").register_transaction(transaction!(search))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Metrics were reset after all users were started, so fewer custom metrics were
+    // recorded than searches made.
+    let hits = mock_endpoints[SEARCH_KEY].hits();
+    assert!(hits > 0);
+    assert!((goose_metrics.counters["searches"].total as usize) < hits);
+    assert!(goose_metrics.counters["search results"].updates < hits);
+    assert!(goose_metrics.gauges["user"].updates < hits);
+    assert!(goose_metrics.histograms["results per search"].counter < hits);
+}
@@ -0,0 +1,138 @@
+use httpmock::{Method::POST, Mock, MockServer};
+use serde::Deserialize;
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const GRAPHQL_PATH: &str = "/graphql";
+
+// Indexes to the above paths.
+const GET_USER_KEY: usize = 0;
+const ADD_USER_KEY: usize = 1;
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 3;
+
+// GraphQL operations used in load tests performed during these tests.
+const GET_USER: &str = "query GetUser($id: ID!) { user(id: $id) { name } }";
+const ADD_USER: &str = "mutation AddUser($name: String!) { addUser(name: $name) { name } }";
+
+#[derive(Deserialize)]
+struct UserData {
+    user: User,
+}
+
+#[derive(Deserialize)]
+struct User {
+    name: String,
+}
+
+// Test transaction, loads a user.
+pub async fn get_user(user: &mut GooseUser) -> TransactionResult {
+    let goose = user
+        .graphql::<_, UserData>(GET_USER, serde_json::json!({ "id": 1 }))
+        .await?;
+    assert_eq!(goose.data.unwrap().user.name, "Goose");
+
+    Ok(())
+}
+
+// Test transaction, attempts to add a user which the server rejects.
+pub async fn add_user(user: &mut GooseUser) -> TransactionResult {
+    let goose = user
+        .graphql::<_, serde_json::Value>(ADD_USER, serde_json::json!({ "name": "Goose" }))
+        .await?;
+    assert_eq!(goose.errors[0].message, "not allowed");
+
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up the GetUser operation, store in vector at GET_USER_KEY.
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(GRAPHQL_PATH)
+                .json_body_partial(r#"{"operationName": "GetUser"}"#);
+            then.status(200)
+                .body(r#"{"data": {"user": {"name": "Goose"}}}"#);
+        }),
+        // Set up the AddUser operation, store in vector at ADD_USER_KEY.
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(GRAPHQL_PATH)
+                .json_body_partial(r#"{"operationName": "AddUser"}"#);
+            then.status(200)
+                .body(r#"{"data": null, "errors": [{"message": "not allowed"}]}"#);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+// Name GraphQL requests by operation, and fail responses that contain errors.
+async fn test_graphql() {
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest")
+                .register_transaction(transaction!(get_user))
+                .register_transaction(transaction!(add_user))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each user ran both operations once per iteration.
+    let expected = USERS * ITERATIONS;
+    mock_endpoints[GET_USER_KEY].assert_hits(expected);
+    mock_endpoints[ADD_USER_KEY].assert_hits(expected);
+
+    // Requests are named by operation instead of by path.
+    assert!(!goose_metrics.requests.contains_key("POST /graphql"));
+    let get_user = &goose_metrics.requests["POST query GetUser"];
+    assert_eq!(get_user.success_count, expected);
+    assert_eq!(get_user.fail_count, 0);
+
+    // Responses with errors are failures, even though they returned a 200 status code.
+    let add_user = &goose_metrics.requests["POST mutation AddUser"];
+    assert_eq!(add_user.success_count, 0);
+    assert_eq!(add_user.fail_count, expected);
+    let error = goose_metrics
+        .errors
+        .values()
+        .find(|error| error.name == "mutation AddUser")
+        .expect("GraphQL errors were not recorded");
+    assert_eq!(error.error, "GraphQL error: not allowed");
+    assert_eq!(error.occurrences, expected);
+}
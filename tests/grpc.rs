@@ -0,0 +1,253 @@
+#![cfg(feature = "grpc")]
+
+use httpmock::MockServer;
+use serial_test::serial;
+use std::convert::Infallible;
+use tokio::net::TcpListener;
+use tonic::codegen::{empty_body, http, Body, BoxFuture, Context, Poll, Service, StdError};
+
+mod common;
+
+use goose::goose::GRPC_STATUS_CODE_OFFSET;
+use goose::prelude::*;
+
+// The gRPC service and methods used in load tests performed during these tests.
+const SERVICE: &str = "helloworld.Greeter";
+const SAY_HELLO: &str = "SayHello";
+const COUNT: &str = "Count";
+const MISSING: &str = "Missing";
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 3;
+
+// How many replies the Count method streams back.
+const REPLIES: usize = 4;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct HelloRequest {
+    #[prost(string, tag = "1")]
+    pub name: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct HelloReply {
+    #[prost(string, tag = "1")]
+    pub message: String,
+}
+
+// An in-process stand-in for a tonic generated Greeter server.
+#[derive(Clone)]
+struct Greeter;
+
+impl tonic::server::NamedService for Greeter {
+    const NAME: &'static str = SERVICE;
+}
+
+impl<B> Service<http::Request<B>> for Greeter
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        match request.uri().path() {
+            "/helloworld.Greeter/SayHello" => Box::pin(async move {
+                let mut grpc = tonic::server::Grpc::new(tonic::codec::ProstCodec::default());
+                Ok(grpc.unary(SayHello, request).await)
+            }),
+            "/helloworld.Greeter/Count" => Box::pin(async move {
+                let mut grpc = tonic::server::Grpc::new(tonic::codec::ProstCodec::default());
+                Ok(grpc.server_streaming(Count, request).await)
+            }),
+            // Any other method is unimplemented.
+            _ => Box::pin(async move {
+                Ok(http::Response::builder()
+                    .status(200)
+                    .header("grpc-status", "12")
+                    .header("content-type", "application/grpc")
+                    .body(empty_body())
+                    .unwrap())
+            }),
+        }
+    }
+}
+
+// Replies to SayHello with a greeting.
+struct SayHello;
+
+impl tonic::server::UnaryService<HelloRequest> for SayHello {
+    type Response = HelloReply;
+    type Future = BoxFuture<tonic::Response<HelloReply>, tonic::Status>;
+
+    fn call(&mut self, request: tonic::Request<HelloRequest>) -> Self::Future {
+        Box::pin(async move {
+            Ok(tonic::Response::new(HelloReply {
+                message: format!("Hello {}", request.into_inner().name),
+            }))
+        })
+    }
+}
+
+// Replies to Count by streaming back REPLIES numbered greetings.
+struct Count;
+
+impl tonic::server::ServerStreamingService<HelloRequest> for Count {
+    type Response = HelloReply;
+    type ResponseStream =
+        futures::stream::Iter<std::vec::IntoIter<Result<HelloReply, tonic::Status>>>;
+    type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+
+    fn call(&mut self, request: tonic::Request<HelloRequest>) -> Self::Future {
+        Box::pin(async move {
+            let name = request.into_inner().name;
+            let replies: Vec<Result<HelloReply, tonic::Status>> = (0..REPLIES)
+                .map(|i| HelloReply {
+                    message: format!("{} {}", name, i),
+                })
+                .map(Ok)
+                .collect();
+            Ok(tonic::Response::new(futures::stream::iter(replies)))
+        })
+    }
+}
+
+// Start the stand-in server on a random port, returning its address.
+async fn start_grpc_server() -> std::net::SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let incoming = Box::pin(futures::stream::unfold(listener, |listener| async {
+        let stream = listener.accept().await.map(|(stream, _)| stream);
+        Some((stream, listener))
+    }));
+    tokio::spawn(
+        tonic::transport::Server::builder()
+            .add_service(Greeter)
+            .serve_with_incoming(incoming),
+    );
+    address
+}
+
+// Test transaction, makes a unary call.
+pub async fn say_hello(user: &mut GooseUser) -> TransactionResult {
+    let request = HelloRequest {
+        name: "Goose".to_string(),
+    };
+    let goose = user
+        .grpc_unary::<_, HelloReply>(SERVICE, SAY_HELLO, request)
+        .await?;
+    assert_eq!(goose.response.unwrap().message, "Hello Goose");
+
+    Ok(())
+}
+
+// Test transaction, makes a server-streaming call.
+pub async fn count(user: &mut GooseUser) -> TransactionResult {
+    let request = HelloRequest {
+        name: "Goose".to_string(),
+    };
+    let goose = user
+        .grpc_server_streaming::<_, HelloReply>(SERVICE, COUNT, request)
+        .await?;
+    let replies = goose.response.unwrap();
+    assert_eq!(replies.len(), REPLIES);
+    assert_eq!(
+        replies[REPLIES - 1].message,
+        format!("Goose {}", REPLIES - 1)
+    );
+
+    Ok(())
+}
+
+// Test transaction, calls a method the server doesn't implement.
+pub async fn missing(user: &mut GooseUser) -> TransactionResult {
+    let goose = user
+        .grpc_unary::<_, HelloReply>(SERVICE, MISSING, HelloRequest::default())
+        .await?;
+    assert_eq!(
+        goose.response.unwrap_err().code(),
+        tonic::Code::Unimplemented
+    );
+    assert!(!goose.request.success);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+// Make unary and server-streaming gRPC calls during a load test.
+async fn test_grpc() {
+    // Start the stand-in gRPC server, and a mock server that isn't used.
+    let address = start_grpc_server().await;
+    let server = MockServer::start();
+
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let host = format!("http://{}", address);
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--host",
+            &host,
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest")
+                .register_transaction(transaction!(say_hello))
+                .register_transaction(transaction!(count))
+                .register_transaction(transaction!(missing))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each user made every call once per iteration, recorded by service and method.
+    let expected = USERS * ITERATIONS;
+    let say_hello = &goose_metrics.requests["GRPC helloworld.Greeter/SayHello"];
+    assert_eq!(say_hello.success_count, expected);
+    assert_eq!(say_hello.fail_count, 0);
+    assert_eq!(
+        say_hello.status_code_counts[&GRPC_STATUS_CODE_OFFSET],
+        expected
+    );
+    let count = &goose_metrics.requests["GRPC helloworld.Greeter/Count"];
+    assert_eq!(count.success_count, expected);
+    assert_eq!(count.status_code_counts[&GRPC_STATUS_CODE_OFFSET], expected);
+
+    // Failed calls are recorded with their gRPC status code, here `UNIMPLEMENTED`.
+    let missing = &goose_metrics.requests["GRPC helloworld.Greeter/Missing"];
+    assert_eq!(missing.success_count, 0);
+    assert_eq!(missing.fail_count, expected);
+    assert_eq!(
+        missing.status_code_counts[&(GRPC_STATUS_CODE_OFFSET + 12)],
+        expected
+    );
+    let error = goose_metrics
+        .errors
+        .values()
+        .find(|error| error.name == "helloworld.Greeter/Missing")
+        .expect("failed gRPC call was not recorded as an error");
+    assert_eq!(error.error, "Unimplemented: helloworld.Greeter/Missing");
+    assert_eq!(error.occurrences, expected);
+}
@@ -0,0 +1,142 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const STYLE_PATH: &str = "/style.css";
+
+// Indexes to the above paths.
+const NOT_MODIFIED_KEY: usize = 0;
+const INDEX_KEY: usize = 1;
+const STYLE_KEY: usize = 2;
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 3;
+
+// The page is revalidated each time it's loaded.
+const ETAG: &str = "\"index-v1\"";
+
+// Test transaction, enables the HTTP cache.
+pub async fn enable_cache(user: &mut GooseUser) -> TransactionResult {
+    user.enable_http_cache();
+    Ok(())
+}
+
+// Test transaction, loads the front page.
+pub async fn get_index(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test transaction, loads a static asset.
+pub async fn get_style(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(STYLE_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up INDEX_PATH revalidation, store in vector at NOT_MODIFIED_KEY. Mocks are
+        // matched in the order they're defined, so this is matched first.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(INDEX_PATH)
+                .header("If-None-Match", ETAG);
+            then.status(304).header("ETag", ETAG);
+        }),
+        // Set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200)
+                .header("Cache-Control", "no-cache")
+                .header("ETag", ETAG)
+                .body("<html><body>index</body></html>");
+        }),
+        // Set up STYLE_PATH, store in vector at STYLE_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(STYLE_PATH);
+            then.status(200)
+                .header("Cache-Control", "max-age=3600")
+                .body("body { color: black; }");
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+// Load pages and static assets with a browser-like HTTP cache.
+async fn test_http_cache() {
+    let report_file = "http-cache-report.html";
+
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+            "--report-file",
+            report_file,
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest")
+                .register_transaction(transaction!(enable_cache).set_on_start())
+                .register_transaction(transaction!(get_index))
+                .register_transaction(transaction!(get_style))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each user downloaded the page and the asset once, then revalidated the page.
+    mock_endpoints[INDEX_KEY].assert_hits(USERS);
+    mock_endpoints[NOT_MODIFIED_KEY].assert_hits(USERS * (ITERATIONS - 1));
+    mock_endpoints[STYLE_KEY].assert_hits(USERS);
+
+    // Revalidated pages are requests, counted as 304 responses.
+    let index = &goose_metrics.requests["GET /"];
+    assert_eq!(index.success_count, USERS * ITERATIONS);
+    assert_eq!(index.cache_hits, 0);
+    assert_eq!(index.not_modified, USERS * (ITERATIONS - 1));
+    assert_eq!(index.status_code_counts[&304], USERS * (ITERATIONS - 1),);
+
+    // Cache hits are counted separately from requests.
+    let style = &goose_metrics.requests["GET /style.css"];
+    assert_eq!(style.success_count, USERS);
+    assert_eq!(style.raw_data.counter, USERS);
+    assert_eq!(style.cache_hits, USERS * (ITERATIONS - 1));
+    assert_eq!(style.not_modified, 0);
+
+    // The cache is displayed and included in the html report.
+    assert!(goose_metrics.to_string().contains("HTTP cache:"));
+    let report = std::fs::read_to_string(report_file).unwrap();
+    assert!(report.contains("<h2>HTTP Cache Metrics</h2>"));
+
+    // Cleanup from test.
+    common::cleanup_files(vec![report_file]);
+}
@@ -0,0 +1,178 @@
+use httpmock::{
+    Method::{OPTIONS, PATCH, PUT, TRACE},
+    Mock, MockServer,
+};
+use serial_test::serial;
+
+mod common;
+
+use goose::config::GooseConfiguration;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const RESOURCE_PATH: &str = "/resource";
+const WEBDAV_PATH: &str = "/webdav/";
+
+// Indexes to the above paths.
+const PUT_KEY: usize = 0;
+const PATCH_KEY: usize = 1;
+const OPTIONS_KEY: usize = 2;
+const TRACE_KEY: usize = 3;
+const PROPFIND_KEY: usize = 4;
+
+// Load test configuration.
+const ITERATIONS: usize = 2;
+
+// Test transaction, replaces the resource.
+pub async fn put_resource(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.put(RESOURCE_PATH, "resource").await?;
+    Ok(())
+}
+
+// Test transaction, updates the resource.
+pub async fn patch_resource(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.patch(RESOURCE_PATH, "update").await?;
+    Ok(())
+}
+
+// Test transaction, makes a CORS preflight request.
+pub async fn options_resource(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.options(RESOURCE_PATH).await?;
+    Ok(())
+}
+
+// Test transaction, traces the resource.
+pub async fn trace_resource(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.trace(RESOURCE_PATH).await?;
+    Ok(())
+}
+
+// Test transaction, lists the properties of a WebDAV collection.
+pub async fn propfind_collection(user: &mut GooseUser) -> TransactionResult {
+    let goose_request = GooseRequest::builder()
+        .method(GooseMethod::Extension("PROPFIND".to_string()))
+        .path(WEBDAV_PATH)
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up PUT RESOURCE_PATH, store in vector at PUT_KEY.
+        server.mock(|when, then| {
+            when.method(PUT).path(RESOURCE_PATH).body("resource");
+            then.status(204);
+        }),
+        // Set up PATCH RESOURCE_PATH, store in vector at PATCH_KEY.
+        server.mock(|when, then| {
+            when.method(PATCH).path(RESOURCE_PATH).body("update");
+            then.status(204);
+        }),
+        // Set up OPTIONS RESOURCE_PATH, store in vector at OPTIONS_KEY.
+        server.mock(|when, then| {
+            when.method(OPTIONS).path(RESOURCE_PATH);
+            then.status(204)
+                .header("allow", "OPTIONS, PUT, PATCH, TRACE");
+        }),
+        // Set up TRACE RESOURCE_PATH, store in vector at TRACE_KEY.
+        server.mock(|when, then| {
+            when.method(TRACE).path(RESOURCE_PATH);
+            then.status(200);
+        }),
+        // Set up PROPFIND WEBDAV_PATH, store in vector at PROPFIND_KEY.
+        server.mock(|when, then| {
+            when.path(WEBDAV_PATH)
+                .matches(|request| request.method == "PROPFIND");
+            then.status(207);
+        }),
+    ]
+}
+
+// Build a configuration that runs a single user for a few iterations.
+fn build_configuration(server: &MockServer, custom: Vec<&str>) -> GooseConfiguration {
+    let iterations = ITERATIONS.to_string();
+    let mut args = vec!["--iterations", &iterations];
+    args.extend(custom);
+    let mut configuration = common::build_configuration(server, args);
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+    configuration
+}
+
+#[tokio::test]
+#[serial]
+// Make requests with every kind of HTTP method.
+async fn test_methods() {
+    let request_log = "methods-requests.csv";
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = build_configuration(
+        &server,
+        vec!["--request-log", request_log, "--request-format", "csv"],
+    );
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest")
+                .register_transaction(transaction!(put_resource))
+                .register_transaction(transaction!(patch_resource))
+                .register_transaction(transaction!(options_resource))
+                .register_transaction(transaction!(trace_resource))
+                .register_transaction(transaction!(propfind_collection))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each method was sent to the server.
+    for key in [PUT_KEY, PATCH_KEY, OPTIONS_KEY, TRACE_KEY, PROPFIND_KEY] {
+        mock_endpoints[key].assert_hits(ITERATIONS);
+    }
+
+    // Each method is recorded under its own name.
+    for (name, method) in [
+        ("PUT /resource", GooseMethod::Put),
+        ("PATCH /resource", GooseMethod::Patch),
+        ("OPTIONS /resource", GooseMethod::Options),
+        ("TRACE /resource", GooseMethod::Trace),
+        (
+            "HTTP PROPFIND /webdav/",
+            GooseMethod::Extension("PROPFIND".to_string()),
+        ),
+    ] {
+        let request = &goose_metrics.requests[name];
+        assert_eq!(request.method, method);
+        assert_eq!(request.success_count, ITERATIONS);
+        assert_eq!(request.fail_count, 0);
+    }
+
+    // All requests are logged.
+    assert_eq!(common::file_length(request_log), 5 * ITERATIONS + 1);
+
+    std::fs::remove_file(request_log).unwrap();
+}
+
+#[tokio::test]
+#[serial]
+// Extension methods must be valid HTTP methods.
+async fn test_invalid_extension_method() {
+    let server = MockServer::start();
+    let configuration = build_configuration(&server, vec![]);
+    let mut user = GooseUser::single(server.url("/").parse().unwrap(), &configuration).unwrap();
+
+    let goose_request = GooseRequest::builder()
+        .method(GooseMethod::Extension("NOT VALID".to_string()))
+        .build();
+    match user.request(goose_request).await {
+        Err(e) => assert!(matches!(
+            *e,
+            TransactionError::InvalidExtensionMethod { .. }
+        )),
+        Ok(_) => panic!("made a request with an invalid method"),
+    }
+}
@@ -0,0 +1,164 @@
+use httpmock::{
+    Method::{POST, PUT},
+    Mock, MockServer,
+};
+use serial_test::serial;
+
+mod common;
+
+use goose::config::GooseConfiguration;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const UPLOAD_PATH: &str = "/upload";
+
+// The file uploaded from disk.
+const UPLOAD_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tls/ca.pem");
+
+// Load test configuration.
+const ITERATIONS: usize = 2;
+
+// Test transaction, uploads a text field and a file from memory with a POST request.
+pub async fn post_upload(user: &mut GooseUser) -> TransactionResult {
+    let form = GooseMultipartForm::new().text("title", "Notes").bytes(
+        "notes",
+        "notes.txt",
+        "text/plain",
+        "Some notes.",
+    );
+    let _goose = user.post_multipart(UPLOAD_PATH, form).await?;
+    Ok(())
+}
+
+// Test transaction, uploads a file from disk with a PUT request.
+pub async fn put_upload(user: &mut GooseUser) -> TransactionResult {
+    let form = GooseMultipartForm::new()
+        .file("certificate", UPLOAD_FILE, "application/x-pem-file")
+        .await?;
+    let goose_request = GooseRequest::builder()
+        .method(GooseMethod::Put)
+        .path(UPLOAD_PATH)
+        .multipart(form)
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// Set up the upload endpoint, only accepting bodies that contain all the provided strings.
+fn setup_upload<'a>(server: &'a MockServer, method: httpmock::Method, parts: &[&str]) -> Mock<'a> {
+    server.mock(|mut when, then| {
+        when = when
+            .method(method)
+            .path(UPLOAD_PATH)
+            .header_exists("content-length");
+        for part in parts {
+            when = when.body_contains(*part);
+        }
+        when.matches(|request| {
+            request.headers.as_ref().is_some_and(|headers| {
+                headers.iter().any(|(name, value)| {
+                    name == "content-type" && value.starts_with("multipart/form-data; boundary=")
+                })
+            })
+        });
+        then.status(201);
+    })
+}
+
+// Build a configuration that runs a single user for a few iterations.
+fn build_configuration(server: &MockServer, custom: Vec<&str>) -> GooseConfiguration {
+    let iterations = ITERATIONS.to_string();
+    let mut args = vec!["--iterations", &iterations];
+    args.extend(custom);
+    let mut configuration = common::build_configuration(server, args);
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+    configuration
+}
+
+#[tokio::test]
+#[serial]
+// Upload text fields and files read from memory and disk.
+async fn test_multipart() {
+    let request_log = "multipart-requests.json";
+    let server = MockServer::start();
+    let post = setup_upload(
+        &server,
+        POST,
+        &[
+            "Content-Disposition: form-data; name=\"title\"\r\n\r\nNotes\r\n",
+            "Content-Disposition: form-data; name=\"notes\"; filename=\"notes.txt\"\r\nContent-Type: text/plain\r\n\r\nSome notes.\r\n",
+        ],
+    );
+    let certificate = std::fs::read_to_string(UPLOAD_FILE).unwrap();
+    let put = setup_upload(
+        &server,
+        PUT,
+        &[
+            "Content-Disposition: form-data; name=\"certificate\"; filename=\"ca.pem\"\r\nContent-Type: application/x-pem-file\r\n\r\n",
+            &certificate,
+        ],
+    );
+
+    let configuration = build_configuration(
+        &server,
+        vec!["--request-log", request_log, "--request-body"],
+    );
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest")
+                .register_transaction(transaction!(post_upload))
+                .register_transaction(transaction!(put_upload))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    post.assert_hits(ITERATIONS);
+    put.assert_hits(ITERATIONS);
+    assert_eq!(goose_metrics.requests["POST /upload"].fail_count, 0);
+    assert_eq!(goose_metrics.requests["PUT /upload"].fail_count, 0);
+
+    // The size of the encoded forms is recorded.
+    assert!(
+        goose_metrics.requests["PUT /upload"].request_body_bytes
+            > (ITERATIONS * certificate.len()) as u64
+    );
+
+    // The request log describes the parts instead of including the encoded body.
+    let log = std::fs::read_to_string(request_log).unwrap();
+    let mut bodies: Vec<String> = log
+        .lines()
+        .map(|line| {
+            let request: serde_json::Value = serde_json::from_str(line).unwrap();
+            request["raw"]["body"].as_str().unwrap().to_string()
+        })
+        .collect();
+    bodies.sort();
+    bodies.dedup();
+    assert_eq!(
+        bodies,
+        vec![
+            format!(
+                "multipart/form-data: certificate (file ca.pem, application/x-pem-file, {} bytes)",
+                certificate.len()
+            ),
+            "multipart/form-data: title (text, 5 bytes), notes (file notes.txt, text/plain, 11 bytes)"
+                .to_string(),
+        ]
+    );
+
+    std::fs::remove_file(request_log).unwrap();
+}
+
+#[tokio::test]
+// Files that can't be read are reported when they're added to the form.
+async fn test_missing_file() {
+    let form = GooseMultipartForm::new()
+        .file("file", "tests/missing.txt", "text/plain")
+        .await;
+    assert!(matches!(form, Err(e) if matches!(*e, TransactionError::Io(_))));
+}
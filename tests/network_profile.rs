@@ -0,0 +1,125 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 2;
+
+// Network profile emulated by mobile users.
+const LATENCY: u64 = 200;
+const DOWNLOAD_BANDWIDTH: u64 = 10_000;
+// Downloading the body takes half a second at the above bandwidth.
+const BODY_SIZE: usize = 5_000;
+
+// Test transaction, loads the front page.
+pub async fn get_index(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200).body("a".repeat(BODY_SIZE));
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+// Emulate a slow network for one scenario only.
+async fn test_network_profile() {
+    let request_log = "network-profile-requests.csv";
+
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+            "--request-log",
+            request_log,
+            "--request-format",
+            "csv",
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    let mobile = GooseNetworkProfile::new("mobile")
+        .set_latency(Duration::from_millis(LATENCY))
+        .set_download_bandwidth(DOWNLOAD_BANDWIDTH)
+        .unwrap();
+
+    // Run the load test, with one user in each scenario.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![
+                scenario!("Mobile")
+                    .set_network_profile(mobile)
+                    .register_transaction(transaction!(get_index).set_name("mobile")),
+                scenario!("Desktop")
+                    .register_transaction(transaction!(get_index).set_name("desktop")),
+            ],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each user loaded the front page once per iteration.
+    mock_endpoints[INDEX_KEY].assert_hits(USERS * ITERATIONS);
+
+    // Only mobile requests were slowed down by latency and the download bandwidth.
+    let mobile = &goose_metrics.requests["GET mobile"];
+    let desktop = &goose_metrics.requests["GET desktop"];
+    let throttled = LATENCY as usize + BODY_SIZE * 1_000 / DOWNLOAD_BANDWIDTH as usize;
+    assert!(mobile.raw_data.minimum_time >= throttled);
+    assert!(mobile.timings.download.minimum_time >= throttled - LATENCY as usize);
+    assert!(desktop.raw_data.maximum_time < throttled);
+
+    // The active network profile is recorded in the request log.
+    let log = std::fs::read_to_string(request_log).unwrap();
+    let mut lines = log.lines();
+    assert!(lines
+        .next()
+        .unwrap()
+        .ends_with(",network_profile,cache_hit"));
+    let profiles: Vec<&str> = lines.map(|line| line.rsplit(',').nth(1).unwrap()).collect();
+    assert_eq!(profiles.len(), USERS * ITERATIONS);
+    assert_eq!(
+        profiles.iter().filter(|p| **p == "mobile").count(),
+        ITERATIONS
+    );
+    assert_eq!(profiles.iter().filter(|p| p.is_empty()).count(), ITERATIONS);
+
+    // Cleanup from test.
+    common::cleanup_files(vec![request_log]);
+}
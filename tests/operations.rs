@@ -0,0 +1,111 @@
+use httpmock::MockServer;
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 3;
+
+// Test transaction, records a successful non-HTTP operation.
+pub async fn get_session(user: &mut GooseUser) -> TransactionResult {
+    let result: Result<&str, &str> = Ok("session");
+    let operation = GooseOperation::new("GET session", Duration::from_millis(5))
+        .protocol("redis")
+        .target("redis://127.0.0.1:6379")
+        .result(&result);
+    user.record_operation(operation).await?;
+
+    Ok(())
+}
+
+// Test transaction, records a failed non-HTTP operation.
+pub async fn set_session(user: &mut GooseUser) -> TransactionResult {
+    let result: Result<&str, &str> = Err("connection reset");
+    let operation = GooseOperation::new("SET session", Duration::from_millis(10))
+        .protocol("redis")
+        .target("redis://127.0.0.1:6379")
+        .status_code(1)
+        .result(&result);
+    user.record_operation(operation).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+// Record non-HTTP operations, which are counted like requests.
+async fn test_record_operations() {
+    let request_log = "operations-request.log";
+
+    // Start the mock server, which isn't used as no HTTP requests are made.
+    let server = MockServer::start();
+
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+            "--request-log",
+            request_log,
+            "--request-format",
+            "csv",
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest")
+                .register_transaction(transaction!(get_session))
+                .register_transaction(transaction!(set_session))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each user recorded both operations every iteration.
+    let expected = USERS * ITERATIONS;
+
+    // Operations are aggregated like requests, keyed by protocol and name.
+    let get = &goose_metrics.requests["REDIS GET session"];
+    assert_eq!(get.method, GooseMethod::Custom("REDIS".to_string()));
+    assert_eq!(get.success_count, expected);
+    assert_eq!(get.fail_count, 0);
+    assert_eq!(get.raw_data.minimum_time, 5);
+    assert_eq!(get.raw_data.maximum_time, 5);
+    assert_eq!(get.status_code_counts[&0], expected);
+    let set = &goose_metrics.requests["REDIS SET session"];
+    assert_eq!(set.success_count, 0);
+    assert_eq!(set.fail_count, expected);
+    assert_eq!(set.raw_data.total_time, 10 * expected);
+    assert_eq!(set.status_code_counts[&1], expected);
+
+    // Failed operations are included in the error summary.
+    assert_eq!(goose_metrics.errors.len(), 1);
+    let error = goose_metrics.errors.values().next().unwrap();
+    assert_eq!(error.method, GooseMethod::Custom("REDIS".to_string()));
+    assert_eq!(error.name, "SET session");
+    assert_eq!(error.error, "connection reset: SET session");
+    assert_eq!(error.occurrences, expected);
+
+    // Operations are written to the request log, after the header.
+    assert_eq!(common::file_length(request_log), expected * 2 + 1);
+
+    // Cleanup from test.
+    common::cleanup_files(vec![request_log]);
+}
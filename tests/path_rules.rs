@@ -0,0 +1,193 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::config::GooseConfiguration;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const NODE_PATH: &str = "/node/";
+const FILE_PATH: &str = "/file/";
+const USER_PATH: &str = "/user/";
+
+// Indexes to the above paths.
+const NODE_KEY: usize = 0;
+const FILE_KEY: usize = 1;
+const USER_KEY: usize = 2;
+
+// Identifiers requested by each transaction.
+const NODES: [usize; 3] = [1, 22, 333];
+const FILES: [&str; 2] = [
+    "0b8e5c8a-6a3e-4c1f-9d8b-6c5e2f0a1b3c",
+    "f47ac10b-58cc-4372-a567-0e02b2c3d479",
+];
+
+// Load test configuration.
+const ITERATIONS: usize = 2;
+
+// Test transaction, loads several nodes.
+pub async fn get_nodes(user: &mut GooseUser) -> TransactionResult {
+    for node in NODES {
+        let _goose = user.get(&format!("{}{}", NODE_PATH, node)).await?;
+    }
+    Ok(())
+}
+
+// Test transaction, downloads several files.
+pub async fn get_files(user: &mut GooseUser) -> TransactionResult {
+    for file in FILES {
+        let _goose = user.get(&format!("{}{}", FILE_PATH, file)).await?;
+    }
+    Ok(())
+}
+
+// Test transaction, edits a user.
+pub async fn edit_user(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user
+        .get(&format!("{}{}/edit", USER_PATH, ITERATIONS))
+        .await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up NODE_PATH, store in vector at NODE_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path_contains(NODE_PATH);
+            then.status(200);
+        }),
+        // Set up FILE_PATH, store in vector at FILE_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path_contains(FILE_PATH);
+            then.status(200);
+        }),
+        // Set up USER_PATH, store in vector at USER_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path_contains(USER_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+// Build a configuration that runs a single user for a few iterations.
+fn build_configuration(server: &MockServer, custom: Vec<&str>) -> GooseConfiguration {
+    let iterations = ITERATIONS.to_string();
+    let mut args = vec!["--iterations", &iterations];
+    args.extend(custom);
+    let mut configuration = common::build_configuration(server, args);
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+    configuration
+}
+
+// Run the load test, optionally adding a path rule to the GooseAttack.
+async fn run_load_test(
+    configuration: GooseConfiguration,
+    path_rule: Option<(&str, &str)>,
+) -> GooseMetrics {
+    let mut goose_attack = common::build_load_test(
+        configuration,
+        vec![scenario!("LoadTest")
+            .register_transaction(transaction!(get_nodes))
+            .register_transaction(transaction!(get_files))
+            .register_transaction(transaction!(edit_user))],
+        None,
+        None,
+    );
+    if let Some((pattern, name)) = path_rule {
+        goose_attack = goose_attack.add_path_rule(pattern, name).unwrap();
+    }
+    common::run_load_test(goose_attack, None).await
+}
+
+// Confirm each endpoint was requested, and the requests recorded under the expected names.
+fn validate_requests(
+    mock_endpoints: &[Mock],
+    goose_metrics: &GooseMetrics,
+    names: &[(&str, usize)],
+) {
+    mock_endpoints[NODE_KEY].assert_hits(NODES.len() * ITERATIONS);
+    mock_endpoints[FILE_KEY].assert_hits(FILES.len() * ITERATIONS);
+    mock_endpoints[USER_KEY].assert_hits(ITERATIONS);
+
+    assert_eq!(goose_metrics.requests.len(), names.len());
+    for (name, count) in names {
+        assert_eq!(
+            goose_metrics.requests[*name].success_count,
+            count * ITERATIONS
+        );
+    }
+}
+
+#[tokio::test]
+#[serial]
+// Without rules, each path is recorded separately.
+async fn test_no_path_rules() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_load_test(build_configuration(&server, vec![]), None).await;
+
+    assert_eq!(goose_metrics.requests.len(), NODES.len() + FILES.len() + 1);
+    mock_endpoints[NODE_KEY].assert_hits(NODES.len() * ITERATIONS);
+}
+
+#[tokio::test]
+#[serial]
+// Group numeric and UUID path segments with --normalize-paths.
+async fn test_normalize_paths() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_load_test(
+        build_configuration(&server, vec!["--normalize-paths"]),
+        None,
+    )
+    .await;
+
+    validate_requests(
+        &mock_endpoints,
+        &goose_metrics,
+        &[
+            ("GET /node/{id}", NODES.len()),
+            ("GET /file/{uuid}", FILES.len()),
+            ("GET /user/{id}/edit", 1),
+        ],
+    );
+}
+
+#[tokio::test]
+#[serial]
+// Group paths with --path-rules and GooseAttack::add_path_rule, falling back to
+// --normalize-paths for paths that don't match any rule.
+async fn test_path_rules() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_load_test(
+        build_configuration(
+            &server,
+            vec![
+                "--path-rules",
+                r"^/node/\d+$=/node/{nid}",
+                "--normalize-paths",
+            ],
+        ),
+        // Rules added to the GooseAttack support capture groups, and are checked after
+        // those configured with --path-rules.
+        Some((r"^/(node|user)/\d+(/\w+)?$", "/$1/{any}$2")),
+    )
+    .await;
+
+    validate_requests(
+        &mock_endpoints,
+        &goose_metrics,
+        &[
+            ("GET /node/{nid}", NODES.len()),
+            ("GET /file/{uuid}", FILES.len()),
+            ("GET /user/{any}/edit", 1),
+        ],
+    );
+}
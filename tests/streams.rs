@@ -0,0 +1,118 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const PRICES_PATH: &str = "/prices";
+
+// Indexes to the above paths.
+const PRICES_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 3;
+
+// The Server-Sent Events returned by the prices endpoint.
+const PRICES: &str = "event: price\ndata: 10\n\n: keep-alive\n\nevent: price\ndata: 11\n\nevent: price\ndata: 12\n\n";
+const EVENTS: usize = 3;
+
+// Test transaction, reads a stream of Server-Sent Events.
+pub async fn prices(user: &mut GooseUser) -> TransactionResult {
+    let request = GooseRequest::builder().path(PRICES_PATH).build();
+    let prices = user
+        .request_stream(request, GooseStream::server_sent_events())
+        .await?;
+    assert_eq!(prices.events.len(), EVENTS);
+
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up PRICES_PATH, store in vector at PRICES_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(PRICES_PATH);
+            then.status(200)
+                .header("content-type", "text/event-stream")
+                .body(PRICES);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+// Measure Server-Sent Events streams during a load test.
+async fn test_server_sent_events() {
+    let report_file = "streams-report.html";
+
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+            "--report-file",
+            report_file,
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest").register_transaction(transaction!(prices))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each user opened the stream once per iteration.
+    let expected = USERS * ITERATIONS;
+    mock_endpoints[PRICES_KEY].assert_hits(expected);
+
+    // The request that opened each stream is recorded as usual.
+    let key = format!("GET {}", PRICES_PATH);
+    assert_eq!(goose_metrics.requests[&key].success_count, expected);
+
+    // Each stream is also recorded, with all of its events.
+    let stream = &goose_metrics.streams[&key];
+    assert_eq!(stream.method, GooseMethod::Get);
+    assert_eq!(stream.name, PRICES_PATH);
+    assert_eq!(stream.counter, expected);
+    assert_eq!(stream.fail_count, 0);
+    assert_eq!(stream.events, expected * EVENTS);
+    assert_eq!(stream.time_to_first_byte.counter, expected);
+    assert_eq!(stream.time_to_first_event.counter, expected);
+    assert_eq!(stream.time_between_events.counter, expected * (EVENTS - 1));
+    assert_eq!(stream.duration.counter, expected);
+
+    // Streams are displayed, serialized, and included in the html report.
+    let displayed = goose_metrics.to_string();
+    assert!(displayed.contains("PER STREAM METRICS"));
+    let json = serde_json::to_value(&goose_metrics).unwrap();
+    assert_eq!(json["streams"][&key]["events"], expected * EVENTS);
+    let report = std::fs::read_to_string(report_file).unwrap();
+    assert!(report.contains("<h2>Stream Metrics</h2>"));
+
+    // Cleanup from test.
+    common::cleanup_files(vec![report_file]);
+}
@@ -0,0 +1,137 @@
+use httpmock::{
+    Method::{GET, POST},
+    Mock, MockServer,
+};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const UPLOAD_PATH: &str = "/upload";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const UPLOAD_KEY: usize = 1;
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 3;
+
+// Bodies sent and received during these tests.
+const INDEX_BODY: &str = "<html><body>index</body></html>";
+const UPLOAD_BODY: &str = "a large upload";
+const UPLOAD_RESPONSE: &str = "ok";
+
+// Test transaction, loads the front page.
+pub async fn get_index(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test transaction, uploads a body.
+pub async fn upload(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.post(UPLOAD_PATH, UPLOAD_BODY).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200).body(INDEX_BODY);
+        }),
+        // Set up UPLOAD_PATH, store in vector at UPLOAD_KEY.
+        server.mock(|when, then| {
+            when.method(POST).path(UPLOAD_PATH);
+            then.status(200).body(UPLOAD_RESPONSE);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+// Record the bytes sent and received in request and response bodies.
+async fn test_throughput() {
+    let report_file = "throughput-report.html";
+
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+            "--report-file",
+            report_file,
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest")
+                .register_transaction(transaction!(get_index))
+                .register_transaction(transaction!(upload))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each user made both requests once per iteration.
+    let expected = USERS * ITERATIONS;
+    mock_endpoints[INDEX_KEY].assert_hits(expected);
+    mock_endpoints[UPLOAD_KEY].assert_hits(expected);
+
+    // Body sizes are totaled per request.
+    let index = &goose_metrics.requests["GET /"];
+    assert_eq!(index.request_body_bytes, 0);
+    assert_eq!(
+        index.response_body_bytes,
+        (expected * INDEX_BODY.len()) as u64
+    );
+    let upload = &goose_metrics.requests["POST /upload"];
+    assert_eq!(
+        upload.request_body_bytes,
+        (expected * UPLOAD_BODY.len()) as u64
+    );
+    assert_eq!(
+        upload.response_body_bytes,
+        (expected * UPLOAD_RESPONSE.len()) as u64
+    );
+
+    // Throughput is displayed, serialized, and included in the html report.
+    let displayed = goose_metrics.to_string();
+    assert!(displayed.contains("Request body bytes sent:"));
+    assert!(displayed.contains("Response body bytes received:"));
+    let json = serde_json::to_value(&goose_metrics).unwrap();
+    assert_eq!(
+        json["requests"]["POST /upload"]["request_body_bytes"],
+        expected * UPLOAD_BODY.len()
+    );
+    let report = std::fs::read_to_string(report_file).unwrap();
+    assert!(report.contains("<h2>Throughput Metrics</h2>"));
+    assert!(report.contains("graph-response-bytes"));
+
+    // Cleanup from test.
+    common::cleanup_files(vec![report_file]);
+}
@@ -0,0 +1,122 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const CART_PATH: &str = "/cart";
+const CHECKOUT_PATH: &str = "/checkout";
+
+// Indexes to the above paths.
+const CART_KEY: usize = 0;
+const CHECKOUT_KEY: usize = 1;
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 3;
+
+// Name of the custom timer.
+const TIMER_NAME: &str = "checkout";
+
+// Test transaction, starts the custom timer.
+pub async fn add_to_cart(user: &mut GooseUser) -> TransactionResult {
+    user.start_timer(TIMER_NAME);
+    let _goose = user.get(CART_PATH).await?;
+
+    Ok(())
+}
+
+// Test transaction, stops the custom timer started by the previous transaction.
+pub async fn checkout(user: &mut GooseUser) -> TransactionResult {
+    let goose = user.get(CHECKOUT_PATH).await?;
+    user.stop_timer(TIMER_NAME, goose.request.success)?;
+
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // First set up CART_PATH, store in vector at CART_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(CART_PATH);
+            then.status(200);
+        }),
+        // Next set up CHECKOUT_PATH, store in vector at CHECKOUT_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(CHECKOUT_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+// Time an operation that spans two transactions with a custom timer.
+async fn test_custom_timer() {
+    let timer_log = "timers-test.log";
+
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+            "--timer-log",
+            timer_log,
+            "--timer-format",
+            "csv",
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test, with both transactions running in order.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest")
+                .register_transaction(transaction!(add_to_cart).set_sequence(1))
+                .register_transaction(transaction!(checkout).set_sequence(2))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each user made both requests every iteration.
+    let expected = USERS * ITERATIONS;
+    mock_endpoints[CART_KEY].assert_hits(expected);
+    mock_endpoints[CHECKOUT_KEY].assert_hits(expected);
+
+    // The custom timer was recorded each time it was stopped.
+    let timer = goose_metrics
+        .timers
+        .get(TIMER_NAME)
+        .expect("custom timer was not recorded");
+    assert_eq!(timer.name, TIMER_NAME);
+    assert_eq!(timer.counter, expected);
+    assert_eq!(timer.success_count, expected);
+    assert_eq!(timer.fail_count, 0);
+    assert!(timer.min_time <= timer.max_time);
+
+    // The timer log contains a header followed by one line per timer.
+    assert_eq!(common::file_length(timer_log), expected + 1);
+
+    // Cleanup from test.
+    common::cleanup_files(vec![timer_log]);
+}
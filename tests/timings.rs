@@ -0,0 +1,407 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const SLOW_PATH: &str = "/slow";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const SLOW_KEY: usize = 1;
+
+// How long the server takes to respond to requests for SLOW_PATH.
+const SLOW_DELAY: u64 = 70;
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 3;
+
+// Test transaction, loads the front page.
+pub async fn get_index(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test transaction, loads a slow page.
+pub async fn get_slow(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(SLOW_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
+    vec![
+        // Set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200).body("<html><body>index</body></html>");
+        }),
+        // Set up SLOW_PATH, store in vector at SLOW_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(SLOW_PATH);
+            then.status(200)
+                .delay(Duration::from_millis(SLOW_DELAY))
+                .body("<html><body>slow</body></html>");
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+// Record how long each phase of every request takes.
+async fn test_request_timings() {
+    let report_file = "timings-report.html";
+    let request_log = "timings-requests.csv";
+
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    // Connect to the server by host name so DNS is queried.
+    let host = format!("http://localhost:{}", server.port());
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--host",
+            &host,
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--iterations",
+            &iterations,
+            "--report-file",
+            report_file,
+            "--request-log",
+            request_log,
+            "--request-format",
+            "csv",
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest").register_transaction(transaction!(get_index))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each user loaded the front page once per iteration.
+    let expected = USERS * ITERATIONS;
+    mock_endpoints[INDEX_KEY].assert_hits(expected);
+
+    // Every request received and downloaded a response.
+    let index = &goose_metrics.requests["GET /"];
+    assert_eq!(index.timings.time_to_first_byte.counter, expected);
+    assert_eq!(index.timings.download.counter, expected);
+    // Each user resolved the host name when opening a connection, then reused it.
+    assert!(index.timings.dns.counter >= USERS);
+    assert!(index.timings.dns.counter < expected);
+    // A connection was opened each time the host name was resolved.
+    assert_eq!(index.timings.connect.counter, index.timings.dns.counter);
+
+    // Timings are included in the html report.
+    let report = std::fs::read_to_string(report_file).unwrap();
+    assert!(report.contains("<h2>Request Timing Metrics</h2>"));
+
+    // Timings are included in the request log, with empty columns for skipped lookups and
+    // reused connections.
+    let log = std::fs::read_to_string(request_log).unwrap();
+    let mut lines = log.lines();
+    assert!(lines.next().unwrap().ends_with(
+        ",dns,connect,time_to_first_byte,download,request_body_size,response_body_size,network_profile,cache_hit"
+    ));
+    let mut skipped_lookups = 0;
+    for line in lines {
+        // The dns, connect, time_to_first_byte and download timings are followed by two body
+        // sizes, the network profile and whether the response was cached.
+        let timings: Vec<&str> = line.rsplitn(9, ',').skip(4).collect();
+        assert!(!timings[0].is_empty());
+        assert!(!timings[1].is_empty());
+        assert_eq!(timings[2].is_empty(), timings[3].is_empty());
+        if timings[3].is_empty() {
+            skipped_lookups += 1;
+        }
+    }
+    assert_eq!(skipped_lookups, expected - index.timings.dns.counter);
+
+    // Cleanup from test.
+    common::cleanup_files(vec![report_file, request_log]);
+}
+
+#[tokio::test]
+#[serial]
+// Store response times accurate to a configurable number of significant digits.
+async fn test_significant_digits() {
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    // Store times accurate to a single significant digit.
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec!["--iterations", &iterations, "--significant-digits", "1"],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration.clone(),
+            vec![scenario!("LoadTest").register_transaction(transaction!(get_slow))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+    mock_endpoints[SLOW_KEY].assert_hits(ITERATIONS);
+
+    // All response times are at least 64ms, so are stored in buckets at least 4ms wide,
+    // identified by the middle of the bucket.
+    let slow = &goose_metrics.requests["GET /slow"];
+    assert!(slow.raw_data.minimum_time >= SLOW_DELAY as usize);
+    assert_eq!(slow.raw_data.times.values().sum::<usize>(), ITERATIONS);
+    for time in slow.raw_data.times.keys() {
+        assert_eq!(time % 2, 0);
+        if *time < 128 {
+            assert_eq!(time % 4, 2);
+        }
+        assert!(*time + 4 >= slow.raw_data.minimum_time);
+        assert!(*time <= slow.raw_data.maximum_time + 4);
+    }
+
+    // Times are still serialized with the rest of the metrics.
+    let json = serde_json::to_value(&goose_metrics).unwrap();
+    let times = &json["requests"]["GET /slow"]["raw_data"]["times"];
+    for (time, count) in &slow.raw_data.times {
+        assert_eq!(times[time.to_string()], *count);
+    }
+
+    // Times can only be accurate to between 1 and 5 significant digits.
+    configuration.significant_digits = 6;
+    let goose_attack = common::build_load_test(
+        configuration,
+        vec![scenario!("LoadTest").register_transaction(transaction!(get_slow))],
+        None,
+        None,
+    );
+    assert!(matches!(
+        goose_attack.execute().await,
+        Err(GooseError::InvalidOption { .. })
+    ));
+}
+
+#[tokio::test]
+#[serial]
+// Display and report the configured percentiles of response times.
+async fn test_percentiles() {
+    let report_file = "percentiles-report.html";
+
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--iterations",
+            &iterations,
+            "--percentiles",
+            "90, 99.5,99.99",
+            "--report-file",
+            report_file,
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration.clone(),
+            vec![scenario!("LoadTest")
+                .register_transaction(transaction!(get_index))
+                .register_transaction(transaction!(get_slow))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+    mock_endpoints[INDEX_KEY].assert_hits(ITERATIONS);
+    mock_endpoints[SLOW_KEY].assert_hits(ITERATIONS);
+
+    // Only the configured percentiles are displayed.
+    let displayed = goose_metrics.to_string();
+    assert!(displayed.contains("Slowest page load within specified percentile"));
+    assert!(displayed.contains("Slowest transaction within specified percentile"));
+    assert!(displayed.contains("Slowest scenario within specified percentile"));
+    assert!(displayed.contains("|    90% |  99.5% | 99.99%"));
+    assert!(!displayed.contains("98%"));
+
+    // The configured percentiles are included in the serialized metrics, for requests,
+    // transactions and scenarios alike.
+    let json = serde_json::to_value(&goose_metrics).unwrap();
+    let percentiles = &json["percentiles"];
+    assert_eq!(
+        percentiles["percentiles"],
+        serde_json::json!([90.0, 99.5, 99.99])
+    );
+    let slow = &goose_metrics.requests["GET /slow"];
+    let slow_percentiles = percentiles["requests"]["GET /slow"].as_array().unwrap();
+    assert_eq!(slow_percentiles.len(), 3);
+    for time in slow_percentiles {
+        let time = time.as_u64().unwrap() as usize;
+        assert!(time >= slow.raw_data.minimum_time && time <= slow.raw_data.maximum_time);
+    }
+    assert_eq!(percentiles["transactions"][0].as_array().unwrap().len(), 2);
+    assert_eq!(
+        percentiles["transactions"][0][1].as_array().unwrap().len(),
+        3
+    );
+    assert_eq!(percentiles["scenarios"][0].as_array().unwrap().len(), 3);
+
+    // The configured percentiles are included in the html report.
+    let report = std::fs::read_to_string(report_file).unwrap();
+    assert!(report.contains("<th>99.5%ile (ms)</th>"));
+    assert!(!report.contains("<th>50%ile (ms)</th>"));
+
+    // Percentiles must be larger than 0 and no larger than 100.
+    configuration.percentiles = "50,101".to_string();
+    let goose_attack = common::build_load_test(
+        configuration,
+        vec![scenario!("LoadTest").register_transaction(transaction!(get_index))],
+        None,
+        None,
+    );
+    assert!(matches!(
+        goose_attack.execute().await,
+        Err(GooseError::InvalidOption { .. })
+    ));
+
+    // Cleanup from test.
+    common::cleanup_files(vec![report_file]);
+}
+
+#[tokio::test]
+#[serial]
+// Check thresholds against the final metrics, failing the load test if any aren't met.
+async fn test_thresholds() {
+    let report_file = "thresholds-report.html";
+
+    // Start the mock server.
+    let server = MockServer::start();
+
+    // Setup the mock endpoints needed for this test.
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let iterations = ITERATIONS.to_string();
+    let mut configuration = common::build_configuration(
+        &server,
+        vec![
+            "--iterations",
+            &iterations,
+            "--thresholds",
+            "GET /slow min >= 70ms;error rate < 1%",
+            "--report-file",
+            report_file,
+        ],
+    );
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run a load test that meets all thresholds.
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration.clone(),
+            vec![scenario!("LoadTest")
+                .register_transaction(transaction!(get_index))
+                .register_transaction(transaction!(get_slow))],
+            None,
+            None,
+        )
+        .add_threshold("scenario LoadTest avg < 60s")
+        .unwrap(),
+        None,
+    )
+    .await;
+    mock_endpoints[INDEX_KEY].assert_hits(ITERATIONS);
+    mock_endpoints[SLOW_KEY].assert_hits(ITERATIONS);
+
+    // Thresholds configured at run-time and in the load test are all checked.
+    assert_eq!(goose_metrics.thresholds.len(), 3);
+    assert!(goose_metrics.thresholds.iter().all(|result| result.passed));
+    let displayed = goose_metrics.to_string();
+    assert!(displayed.contains("=== THRESHOLDS ==="));
+    assert!(displayed.contains("GET /slow min >= 70ms"));
+    let report = std::fs::read_to_string(report_file).unwrap();
+    assert!(report.contains("<h2>Thresholds</h2>"));
+    assert!(report.contains("error rate < 1%"));
+
+    // Run a load test that doesn't meet a threshold.
+    let goose_attack = common::build_load_test(
+        configuration.clone(),
+        vec![scenario!("LoadTest")
+            .register_transaction(transaction!(get_index))
+            .register_transaction(transaction!(get_slow))],
+        None,
+        None,
+    )
+    .add_threshold("GET /slow p95 < 10ms")
+    .unwrap();
+    match goose_attack.execute().await {
+        Err(GooseError::ThresholdsFailed { metrics, detail }) => {
+            assert_eq!(detail, "failed thresholds: GET /slow p95 < 10ms");
+            let failed = metrics
+                .thresholds
+                .iter()
+                .filter(|result| !result.passed)
+                .collect::<Vec<_>>();
+            assert_eq!(failed.len(), 1);
+            assert!(failed[0].value.unwrap() >= SLOW_DELAY as f64);
+        }
+        _ => panic!("load test should fail its thresholds"),
+    }
+    let report = std::fs::read_to_string(report_file).unwrap();
+    assert!(report.contains("FAIL"));
+
+    // Invalid thresholds are rejected.
+    configuration.thresholds = "GET /slow p95 300ms".to_string();
+    let goose_attack = common::build_load_test(
+        configuration,
+        vec![scenario!("LoadTest").register_transaction(transaction!(get_index))],
+        None,
+        None,
+    );
+    assert!(matches!(
+        goose_attack.execute().await,
+        Err(GooseError::InvalidOption { .. })
+    ));
+
+    // Cleanup from test.
+    common::cleanup_files(vec![report_file]);
+}
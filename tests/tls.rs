@@ -0,0 +1,243 @@
+use httpmock::MockServer;
+use rustls::server::AllowAnyAnonymousOrAuthenticatedClient;
+use rustls::{Certificate, PrivateKey, RootCertStore, ServerConfig, ServerConnection, StreamOwned};
+use serial_test::serial;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+mod common;
+
+use goose::config::GooseConfiguration;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Certificates and keys signed by a test CA, valid for 127.0.0.1.
+const CA_CERT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tls/ca.pem");
+const CA_CERT_DER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tls/ca.der");
+const SERVER_CERT_DER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tls/server.der");
+const SERVER_KEY_DER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tls/server-key.der");
+const CLIENT_CERT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tls/client.pem");
+const CLIENT_KEY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tls/client-key.pem");
+
+// Load test configuration.
+const USERS: usize = 2;
+const ITERATIONS: usize = 2;
+
+// Test transaction, loads the front page.
+pub async fn get_index(user: &mut GooseUser) -> TransactionResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Start an HTTPS server, returning its port and whether each request it answered was
+// made with a client certificate.
+fn start_tls_server() -> (u16, Arc<Mutex<Vec<bool>>>) {
+    let mut roots = RootCertStore::empty();
+    roots
+        .add(&Certificate(std::fs::read(CA_CERT_DER).unwrap()))
+        .unwrap();
+    let config = Arc::new(
+        ServerConfig::builder()
+            .with_safe_defaults()
+            .with_client_cert_verifier(AllowAnyAnonymousOrAuthenticatedClient::new(roots))
+            .with_single_cert(
+                vec![Certificate(std::fs::read(SERVER_CERT_DER).unwrap())],
+                PrivateKey(std::fs::read(SERVER_KEY_DER).unwrap()),
+            )
+            .unwrap(),
+    );
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let client_certificates: Arc<Mutex<Vec<bool>>> = Arc::new(Mutex::new(Vec::new()));
+    let recorded_client_certificates = client_certificates.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let stream = match stream {
+                Ok(s) => s,
+                Err(_) => break,
+            };
+            let config = config.clone();
+            let recorded_client_certificates = recorded_client_certificates.clone();
+            // Handle each connection in its own thread so a failed handshake can't block.
+            std::thread::spawn(move || {
+                let mut tls = StreamOwned::new(ServerConnection::new(config).unwrap(), stream);
+                let mut buffer = [0; 1024];
+                // The handshake fails if the client doesn't trust the server certificate.
+                if tls.read(&mut buffer).is_err() {
+                    return;
+                }
+                recorded_client_certificates
+                    .lock()
+                    .unwrap()
+                    .push(tls.conn.peer_certificates().is_some());
+                let _ = tls.write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+                );
+                tls.conn.send_close_notify();
+                let _ = tls.flush();
+            });
+        }
+    });
+    (port, client_certificates)
+}
+
+// Build a configuration that runs each user for a few iterations against the host.
+fn build_configuration(server: &MockServer, host: &str, custom: Vec<&str>) -> GooseConfiguration {
+    let users = USERS.to_string();
+    let iterations = ITERATIONS.to_string();
+    let mut args = vec![
+        "--host",
+        host,
+        "--users",
+        &users,
+        "--hatch-rate",
+        &users,
+        "--iterations",
+        &iterations,
+    ];
+    args.extend(custom);
+    let mut configuration = common::build_configuration(server, args);
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+    configuration
+}
+
+// Run a load test that loads the front page.
+async fn run_load_test(configuration: GooseConfiguration) -> GooseMetrics {
+    common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest").register_transaction(transaction!(get_index))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await
+}
+
+#[tokio::test]
+#[serial]
+// Requests fail if the server certificate isn't trusted.
+async fn test_untrusted_certificate() {
+    let (port, client_certificates) = start_tls_server();
+
+    // The mock server isn't used, but provides the common configuration.
+    let server = MockServer::start();
+    let host = format!("https://127.0.0.1:{}/", port);
+    let configuration = build_configuration(&server, &host, vec![]);
+    let goose_metrics = run_load_test(configuration).await;
+
+    // No request was answered.
+    assert_eq!(
+        goose_metrics.requests["GET /"].fail_count,
+        USERS * ITERATIONS
+    );
+    assert!(client_certificates.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+#[serial]
+// Trust the server certificate with an additional CA.
+async fn test_ca_cert() {
+    let (port, client_certificates) = start_tls_server();
+
+    // The mock server isn't used, but provides the common configuration.
+    let server = MockServer::start();
+    let host = format!("https://127.0.0.1:{}/", port);
+    let configuration = build_configuration(
+        &server,
+        &host,
+        vec!["--ca-cert", CA_CERT, "--min-tls-version", "1.2"],
+    );
+    let goose_metrics = run_load_test(configuration).await;
+
+    // All requests were answered, without a client certificate.
+    assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+    assert_eq!(
+        *client_certificates.lock().unwrap(),
+        vec![false; USERS * ITERATIONS]
+    );
+}
+
+#[tokio::test]
+#[serial]
+// Accept the server certificate without verifying it.
+async fn test_insecure() {
+    let (port, client_certificates) = start_tls_server();
+
+    // The mock server isn't used, but provides the common configuration.
+    let server = MockServer::start();
+    let host = format!("https://127.0.0.1:{}/", port);
+    let configuration = build_configuration(&server, &host, vec!["--insecure"]);
+    let goose_metrics = run_load_test(configuration).await;
+
+    // All requests were answered.
+    assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+    assert_eq!(
+        client_certificates.lock().unwrap().len(),
+        USERS * ITERATIONS
+    );
+}
+
+#[tokio::test]
+#[serial]
+// Authenticate with a client certificate.
+async fn test_client_cert() {
+    let (port, client_certificates) = start_tls_server();
+
+    // The mock server isn't used, but provides the common configuration.
+    let server = MockServer::start();
+    let host = format!("https://127.0.0.1:{}/", port);
+    let configuration = build_configuration(
+        &server,
+        &host,
+        vec![
+            "--ca-cert",
+            CA_CERT,
+            "--client-cert",
+            CLIENT_CERT,
+            "--client-key",
+            CLIENT_KEY,
+        ],
+    );
+    let goose_metrics = run_load_test(configuration).await;
+
+    // All requests were made with the client certificate.
+    assert_eq!(goose_metrics.requests["GET /"].fail_count, 0);
+    assert_eq!(
+        *client_certificates.lock().unwrap(),
+        vec![true; USERS * ITERATIONS]
+    );
+}
+
+#[tokio::test]
+#[serial]
+// Invalid TLS options are rejected before the load test starts.
+async fn test_invalid_options() {
+    let server = MockServer::start();
+    for options in [
+        vec!["--client-cert", CLIENT_CERT],
+        vec!["--client-key", CLIENT_KEY],
+        vec!["--client-cert", CA_CERT_DER, "--client-key", CLIENT_KEY],
+        vec!["--ca-cert", "tests/tls/missing.pem"],
+        vec!["--ca-cert", CLIENT_KEY],
+        vec!["--min-tls-version", "1.4"],
+    ] {
+        let configuration = build_configuration(&server, "https://127.0.0.1/", options);
+        let goose_attack = common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest").register_transaction(transaction!(get_index))],
+            None,
+            None,
+        );
+        assert!(matches!(
+            goose_attack.execute().await,
+            Err(GooseError::InvalidOption { .. })
+        ));
+    }
+}
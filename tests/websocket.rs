@@ -0,0 +1,114 @@
+use httpmock::MockServer;
+use serial_test::serial;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+
+mod common;
+
+use goose::prelude::*;
+
+// How long WebSocket activity may take before timing out, in seconds.
+const TIMEOUT: &str = "1";
+
+// Start a server that accepts connections but never answers, returning its address.
+async fn start_unresponsive_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        // Keep connections open without ever reading from them.
+        let mut sockets = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            sockets.push(socket);
+        }
+    });
+    format!("ws://{}/", address)
+}
+
+// Start a WebSocket server that completes the opening handshake and then never sends or
+// reads another message, returning its address.
+async fn start_silent_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut streams = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            streams.push(tokio_tungstenite::accept_async(socket).await.unwrap());
+        }
+    });
+    format!("ws://{}/", address)
+}
+
+#[tokio::test]
+#[serial]
+// WebSocket activity that takes longer than --timeout fails, and is recorded as a failed
+// request.
+async fn test_websocket_timeout() {
+    // Start the mock server, which is only used as the host of the load test.
+    let server = MockServer::start();
+
+    let unresponsive = start_unresponsive_server().await;
+    let silent = start_silent_server().await;
+
+    let closure: TransactionFunction = Arc::new(move |user| {
+        let unresponsive = unresponsive.clone();
+        let silent = silent.clone();
+        Box::pin(async move {
+            // The server never completes the opening handshake.
+            let _ = user.websocket_connect("never", &unresponsive).await;
+
+            // The server never sends a message. Timing out closes the connection.
+            user.websocket_connect("silent", &silent).await?;
+            let _ = user.websocket_receive("silent").await;
+
+            // The server never replies.
+            user.websocket_connect("silent", &silent).await?;
+            let _ = user.websocket_request("silent", "hello").await;
+
+            // The server never acknowledges the close.
+            user.websocket_connect("silent", &silent).await?;
+            let _ = user.websocket_close("silent").await;
+
+            Ok(())
+        })
+    });
+
+    let mut configuration =
+        common::build_configuration(&server, vec!["--iterations", "1", "--timeout", TIMEOUT]);
+    // Do not set --run-time with --iterations.
+    configuration.run_time = "".to_string();
+
+    // Run the load test.
+    let started = Instant::now();
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(
+            configuration,
+            vec![scenario!("LoadTest").register_transaction(Transaction::new(closure))],
+            None,
+            None,
+        ),
+        None,
+    )
+    .await;
+
+    // Each timeout took about as long as --timeout, rather than the 60 second default.
+    assert!(started.elapsed() < Duration::from_secs(30));
+
+    // Every timeout was recorded as a failed request.
+    for name in [
+        "WS never connect",
+        "WS silent receive",
+        "WS silent message",
+        "WS silent close",
+    ] {
+        let request = &goose_metrics.requests[name];
+        assert_eq!(request.raw_data.counter, 1);
+        assert_eq!(request.fail_count, 1);
+        assert!(request.raw_data.minimum_time >= 1_000);
+    }
+    assert_eq!(goose_metrics.requests["WS silent connect"].success_count, 3);
+    assert!(goose_metrics
+        .errors
+        .keys()
+        .any(|error| error.contains("timed out after 1 seconds")));
+}